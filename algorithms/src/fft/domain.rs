@@ -1095,6 +1095,37 @@ mod tests {
         }
     }
 
+    /// Tests that the FFTs output the correct result over the BW6-761 scalar field.
+    #[test]
+    fn test_fft_correctness_bw6() {
+        use snarkvm_curves::bw6_761::Fr;
+
+        let mut rng = TestRng::default();
+
+        let log_degree = 5;
+        let degree = 1 << log_degree;
+        let random_polynomial = DensePolynomial::<Fr>::rand(degree - 1, &mut rng);
+
+        for log_domain_size in log_degree..(log_degree + 2) {
+            let domain_size = 1 << log_domain_size;
+            let domain = EvaluationDomain::<Fr>::new(domain_size).unwrap();
+            let polynomial_evaluations = domain.fft(&random_polynomial.coeffs);
+            let polynomial_coset_evaluations = domain.coset_fft(&random_polynomial.coeffs);
+            for (i, x) in domain.elements().enumerate() {
+                let coset_x = Fr::multiplicative_generator() * x;
+
+                assert_eq!(polynomial_evaluations[i], random_polynomial.evaluate(x));
+                assert_eq!(polynomial_coset_evaluations[i], random_polynomial.evaluate(coset_x));
+            }
+
+            assert_eq!(random_polynomial, DensePolynomial::from_coefficients_vec(domain.ifft(&polynomial_evaluations)));
+            assert_eq!(
+                random_polynomial,
+                DensePolynomial::from_coefficients_vec(domain.coset_ifft(&polynomial_coset_evaluations))
+            );
+        }
+    }
+
     /// Tests that FFT precomputation is correctly subdomained
     #[test]
     fn test_fft_precomputation() {
@@ -20,7 +20,7 @@ pub mod standard;
 #[cfg(target_arch = "x86_64")]
 pub mod prefetch;
 
-use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
+use snarkvm_curves::{bls12_377, bw6_761, traits::AffineCurve};
 use snarkvm_fields::PrimeField;
 
 use core::any::TypeId;
@@ -30,7 +30,7 @@ pub struct VariableBase;
 impl VariableBase {
     pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
         // For BLS12-377, we perform variable base MSM using a batched addition technique.
-        if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
+        if TypeId::of::<G>() == TypeId::of::<bls12_377::G1Affine>() {
             #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
             // TODO SNP: where to set the threshold
             if scalars.len() > 1024 {
@@ -43,6 +43,10 @@ impl VariableBase {
             }
             batched::msm(bases, scalars)
         }
+        // For BW6-761, the batched addition technique is likewise faster than Pippenger's algorithm.
+        else if TypeId::of::<G>() == TypeId::of::<bw6_761::G1Affine>() {
+            batched::msm(bases, scalars)
+        }
         // For all other curves, we perform variable base MSM using Pippenger's algorithm.
         else {
             standard::msm(bases, scalars)
@@ -0,0 +1,315 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::AffineCurve;
+use snarkvm_fields::{ConstraintFieldError, Field, ToConstraintField, Zero};
+use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
+
+use core::ops::{Add, AddAssign};
+
+/// `UniversalParams` are the public parameters for the inner product argument.
+/// They are sampled by hashing to the curve, and therefore require no trusted setup.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct UniversalParams<G: AffineCurve> {
+    /// The generators used to commit to the coefficients of a polynomial.
+    pub comm_key: Vec<G>,
+    /// The generator used to bind the inner product in evaluation proofs.
+    pub h: G,
+    /// The generator used to hide commitments.
+    pub s: G,
+}
+
+impl<G: AffineCurve> UniversalParams<G> {
+    /// Returns the maximum degree supported by `self`.
+    pub fn max_degree(&self) -> usize {
+        self.comm_key.len() - 1
+    }
+}
+
+impl<G: AffineCurve> FromBytes for UniversalParams<G> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader)
+            .map_err(|_| error("could not deserialize UniversalParams"))
+    }
+}
+
+impl<G: AffineCurve> ToBytes for UniversalParams<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer)
+            .map_err(|_| error("could not serialize UniversalParams"))
+    }
+}
+
+/// `CommitterKey` is used to commit to, and create evaluation proofs for, a given polynomial.
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct CommitterKey<G: AffineCurve> {
+    /// The generators used to commit to polynomials.
+    /// The number of generators is a power of two.
+    pub comm_key: Vec<G>,
+
+    /// The generator used to bind the inner product in evaluation proofs.
+    pub h: G,
+
+    /// The generator used to hide commitments.
+    pub s: G,
+
+    /// The degree bounds that are supported by `self`.
+    /// Sorted in ascending order from smallest bound to largest bound.
+    /// This is `None` if `self` does not support enforcing any degree bounds.
+    pub enforced_degree_bounds: Option<Vec<usize>>,
+
+    /// The maximum degree supported by the `UniversalParams` from which `self` was derived
+    pub max_degree: usize,
+}
+
+impl<G: AffineCurve> CommitterKey<G> {
+    /// Returns the maximum degree of polynomials supported by `self`.
+    pub fn supported_degree(&self) -> usize {
+        self.comm_key.len() - 1
+    }
+
+    /// Returns the number of rounds in an evaluation proof for `self`.
+    pub fn log_size_of_group(&self) -> usize {
+        self.comm_key.len().trailing_zeros() as usize
+    }
+}
+
+impl<G: AffineCurve> FromBytes for CommitterKey<G> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader)
+            .map_err(|_| error("could not deserialize CommitterKey"))
+    }
+}
+
+impl<G: AffineCurve> ToBytes for CommitterKey<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer)
+            .map_err(|_| error("could not serialize CommitterKey"))
+    }
+}
+
+/// `VerifierKey` is used to check evaluation proofs for a given commitment.
+/// Checking an inner product argument requires all of the generators, so this coincides with the `CommitterKey`.
+pub type VerifierKey<G> = CommitterKey<G>;
+
+/// `Commitment` commits to a polynomial. It is output by `InnerProductArgPC::commit`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Commitment<G: AffineCurve> {
+    /// The commitment to the coefficients of the polynomial.
+    pub comm: G,
+    /// The commitment to the polynomial shifted up to the supported degree.
+    /// This is `Some` if and only if the polynomial has a degree bound.
+    pub shifted_comm: Option<G>,
+}
+
+impl<G: AffineCurve> Commitment<G> {
+    #[inline]
+    pub fn empty() -> Self {
+        Self { comm: G::zero(), shifted_comm: None }
+    }
+
+    pub fn has_degree_bound(&self) -> bool {
+        self.shifted_comm.is_some()
+    }
+
+    pub fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
+        self.comm.is_in_correct_subgroup_assuming_on_curve()
+            && self.shifted_comm.iter().all(|c| c.is_in_correct_subgroup_assuming_on_curve())
+    }
+}
+
+impl<G: AffineCurve> FromBytes for Commitment<G> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader).map_err(|_| error("could not deserialize Commitment"))
+    }
+}
+
+impl<G: AffineCurve> ToBytes for Commitment<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize Commitment"))
+    }
+}
+
+impl<F: Field, G: AffineCurve + ToConstraintField<F>> ToConstraintField<F> for Commitment<G> {
+    fn to_field_elements(&self) -> Result<Vec<F>, ConstraintFieldError> {
+        let mut res = self.comm.to_field_elements()?;
+        if let Some(shifted_comm) = &self.shifted_comm {
+            res.extend(shifted_comm.to_field_elements()?);
+        }
+        Ok(res)
+    }
+}
+
+/// `Randomness` hides the polynomial inside a commitment. It is output by `InnerProductArgPC::commit`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Randomness<G: AffineCurve> {
+    /// The multiple of the hiding generator in the commitment to the polynomial.
+    pub rand: G::ScalarField,
+    /// The multiple of the hiding generator in the commitment to the shifted polynomial.
+    pub shifted_rand: Option<G::ScalarField>,
+}
+
+impl<G: AffineCurve> Randomness<G> {
+    pub fn empty() -> Self {
+        Self { rand: G::ScalarField::zero(), shifted_rand: None }
+    }
+
+    /// Does `self` provide any hiding properties to the corresponding commitment?
+    #[inline]
+    pub fn is_hiding(&self) -> bool {
+        !self.rand.is_zero()
+    }
+}
+
+impl<G: AffineCurve> FromBytes for Randomness<G> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader).map_err(|_| error("could not deserialize Randomness"))
+    }
+}
+
+impl<G: AffineCurve> ToBytes for Randomness<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize Randomness"))
+    }
+}
+
+impl<'a, G: AffineCurve> Add<&'a Randomness<G>> for Randomness<G> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: &'a Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<'a, G: AffineCurve> Add<(G::ScalarField, &'a Randomness<G>)> for Randomness<G> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: (G::ScalarField, &'a Randomness<G>)) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<'a, G: AffineCurve> AddAssign<&'a Randomness<G>> for Randomness<G> {
+    #[inline]
+    fn add_assign(&mut self, other: &'a Self) {
+        self.rand += other.rand;
+        if let Some(other_shifted_rand) = other.shifted_rand {
+            *self.shifted_rand.get_or_insert_with(G::ScalarField::zero) += other_shifted_rand;
+        }
+    }
+}
+
+impl<'a, G: AffineCurve> AddAssign<(G::ScalarField, &'a Randomness<G>)> for Randomness<G> {
+    #[inline]
+    fn add_assign(&mut self, (f, other): (G::ScalarField, &'a Randomness<G>)) {
+        self.rand += f * other.rand;
+        if let Some(other_shifted_rand) = other.shifted_rand {
+            *self.shifted_rand.get_or_insert_with(G::ScalarField::zero) += f * other_shifted_rand;
+        }
+    }
+}
+
+/// `Proof` is an evaluation proof that is output by `InnerProductArgPC::batch_open`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Proof<G: AffineCurve> {
+    /// The left commitments of each round of the inner product argument.
+    pub l_vec: Vec<G>,
+    /// The right commitments of each round of the inner product argument.
+    pub r_vec: Vec<G>,
+    /// The last coefficient of the folded polynomial.
+    pub c: G::ScalarField,
+    /// The commitment to a random polynomial that vanishes at the point of the evaluation.
+    /// This is `Some` if and only if one of the opened polynomials is hiding.
+    pub hiding_comm: Option<G>,
+    /// The combined randomness of the opened commitments and of `hiding_comm`.
+    /// This is `Some` if and only if one of the opened polynomials is hiding.
+    pub rand: Option<G::ScalarField>,
+}
+
+impl<G: AffineCurve> Proof<G> {
+    pub fn is_hiding(&self) -> bool {
+        self.hiding_comm.is_some()
+    }
+}
+
+impl<G: AffineCurve> FromBytes for Proof<G> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader).map_err(|_| error("could not deserialize IPA proof"))
+    }
+}
+
+impl<G: AffineCurve> ToBytes for Proof<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize IPA proof"))
+    }
+}
+
+/// `BatchProof` contains one evaluation proof per point in a query set.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct BatchProof<G: AffineCurve>(pub Vec<Proof<G>>);
+
+impl<G: AffineCurve> BatchProof<G> {
+    pub fn is_hiding(&self) -> bool {
+        self.0.iter().any(|c| c.is_hiding())
+    }
+
+    pub fn proofs(&self) -> &[Proof<G>] {
+        &self.0
+    }
+}
+
+impl<G: AffineCurve> FromBytes for BatchProof<G> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader).map_err(|_| error("could not deserialize struct"))
+    }
+}
+
+impl<G: AffineCurve> ToBytes for BatchProof<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize struct"))
+    }
+}
+
+/// A proof of satisfaction of linear combinations.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct BatchLCProof<G: AffineCurve> {
+    /// Evaluation proof.
+    pub proof: BatchProof<G>,
+    /// Evaluations required to verify the proof.
+    pub evaluations: Option<Vec<G::ScalarField>>,
+}
+
+impl<G: AffineCurve> BatchLCProof<G> {
+    pub fn is_hiding(&self) -> bool {
+        self.proof.is_hiding()
+    }
+}
+
+impl<G: AffineCurve> FromBytes for BatchLCProof<G> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader).map_err(|_| error("could not deserialize struct"))
+    }
+}
+
+impl<G: AffineCurve> ToBytes for BatchLCProof<G> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer).map_err(|_| error("could not serialize struct"))
+    }
+}
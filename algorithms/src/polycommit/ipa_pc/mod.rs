@@ -0,0 +1,1086 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crypto_hash::sha256::sha256,
+    fft::DensePolynomial,
+    msm::variable_base::VariableBase,
+    polycommit::{
+        sonic_pc::{
+            Evaluations,
+            LabeledCommitment,
+            LabeledPolynomial,
+            LabeledPolynomialWithBasis,
+            LinearCombination,
+            PolynomialWithBasis,
+            QuerySet,
+        },
+        PCError,
+    },
+    AlgebraicSponge,
+};
+use hashbrown::HashMap;
+use itertools::Itertools;
+use snarkvm_curves::traits::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{batch_inversion, Field, One, PrimeField, Zero};
+use snarkvm_utilities::{cfg_into_iter, cfg_iter, rand::Uniform, ToBytes};
+
+use anyhow::anyhow;
+use core::{
+    convert::TryInto,
+    marker::PhantomData,
+    sync::atomic::{AtomicBool, Ordering},
+};
+use rand::rngs::StdRng;
+use rand_core::{RngCore, SeedableRng};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+mod data_structures;
+pub use data_structures::*;
+
+/// The domain separator used to sample the generators of the `UniversalParams`.
+const PROTOCOL_NAME: &[u8] = b"AleoIPAPolynomialCommitment0";
+
+/// Polynomial commitment based on the inner product argument of [[BCCGP16]][bccgp]
+/// and [[BBBPWM18, “Bulletproofs”]][bulletproofs], in the form used by [[BCMS20]][pcd-acc].
+/// Commitments are Pedersen vector commitments to the coefficients of the polynomial,
+/// so the scheme is transparent: the `UniversalParams` are sampled by hashing to the curve.
+/// Degree bounds are enforced by additionally committing to the polynomial shifted up to
+/// the supported degree, and hiding follows the approach of [[BCMS20]][pcd-acc].
+///
+/// Compared to [`SonicKZG10`](crate::polycommit::sonic_pc::SonicKZG10), evaluation proofs
+/// are logarithmic in the supported degree, and checking them takes a linear-size MSM.
+/// Any curve may be used, with a sponge over the base field of the curve.
+///
+/// [`MarlinIPA`](crate::snark::marlin::MarlinIPA) instantiates Marlin with this scheme. It requires an FFT-friendly
+/// scalar field, such as that of the G1 group of BLS12-377.
+///
+/// [bccgp]: https://eprint.iacr.org/2016/263
+/// [bulletproofs]: https://eprint.iacr.org/2017/1066
+/// [pcd-acc]: https://eprint.iacr.org/2020/499
+#[derive(Clone, Debug)]
+pub struct InnerProductArgPC<G: AffineCurve, S>
+where
+    G::BaseField: PrimeField,
+    S: AlgebraicSponge<G::BaseField, 2>,
+{
+    _curve: PhantomData<(G, S)>,
+}
+
+impl<G: AffineCurve, S: AlgebraicSponge<G::BaseField, 2>> InnerProductArgPC<G, S>
+where
+    G::BaseField: PrimeField,
+{
+    /// Samples the parameters for polynomials of degree up to `max_degree`.
+    /// The generators are derived deterministically by hashing to the curve, so no trapdoor is known.
+    pub fn setup(max_degree: usize) -> Result<UniversalParams<G>, PCError> {
+        if max_degree < 1 {
+            return Err(PCError::DegreeIsZero);
+        }
+        let setup_time = start_timer!(|| format!("Sampling {} generators", max_degree + 3));
+        let mut generators = Self::sample_generators(max_degree + 3);
+        let s = generators.pop().unwrap();
+        let h = generators.pop().unwrap();
+        end_timer!(setup_time);
+
+        Ok(UniversalParams { comm_key: generators, h, s })
+    }
+
+    pub fn trim(
+        pp: &UniversalParams<G>,
+        supported_degree: usize,
+        supported_lagrange_sizes: impl IntoIterator<Item = usize>,
+        _supported_hiding_bound: usize,
+        enforced_degree_bounds: Option<&[usize]>,
+    ) -> Result<(CommitterKey<G>, VerifierKey<G>), PCError> {
+        let trim_time = start_timer!(|| "Trimming public parameters");
+        // The inner product argument halves the generators in each round,
+        // so the number of generators is rounded up to a power of two.
+        let supported_num_coeffs = (supported_degree + 1).next_power_of_two();
+        if supported_num_coeffs > pp.comm_key.len() {
+            return Err(PCError::TrimmingDegreeTooLarge);
+        }
+
+        for size in supported_lagrange_sizes {
+            if !size.is_power_of_two() {
+                return Err(PCError::LagrangeBasisSizeIsNotPowerOfTwo);
+            }
+            if size > supported_num_coeffs {
+                return Err(PCError::LagrangeBasisSizeIsTooLarge);
+            }
+        }
+
+        let enforced_degree_bounds = enforced_degree_bounds.map(|bounds| {
+            let mut v = bounds.to_vec();
+            v.sort_unstable();
+            v.dedup();
+            v
+        });
+        if let Some(&highest_enforced_degree_bound) = enforced_degree_bounds.as_ref().and_then(|v| v.last()) {
+            if highest_enforced_degree_bound >= supported_num_coeffs {
+                return Err(PCError::UnsupportedDegreeBound(highest_enforced_degree_bound));
+            }
+        }
+
+        let ck = CommitterKey {
+            comm_key: pp.comm_key[..supported_num_coeffs].to_vec(),
+            h: pp.h,
+            s: pp.s,
+            enforced_degree_bounds,
+            max_degree: pp.max_degree(),
+        };
+        let vk = ck.clone();
+
+        end_timer!(trim_time);
+        Ok((ck, vk))
+    }
+
+    /// Outputs a commitments to `polynomials`. If `polynomials[i].is_hiding()`,
+    /// then the `i`-th commitment is hiding.
+    /// `rng` should not be `None` if `polynomials[i].is_hiding() == true` for any `i`.
+    ///
+    /// If for some `i`, `polynomials[i].is_hiding() == false`, then the
+    /// corresponding randomness is `Randomness<G>::empty()`.
+    ///
+    /// If for some `i`, `polynomials[i].degree_bound().is_some()`, then that
+    /// polynomial will have the corresponding degree bound enforced.
+    pub fn commit<'b>(
+        ck: &CommitterKey<G>,
+        polynomials: impl IntoIterator<Item = LabeledPolynomialWithBasis<'b, G::ScalarField>>,
+        rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Vec<LabeledCommitment<Commitment<G>>>, Vec<Randomness<G>>), PCError> {
+        Self::commit_with_terminator(ck, polynomials, &AtomicBool::new(false), rng)
+    }
+
+    /// Outputs a commitment to `polynomial`.
+    pub fn commit_with_terminator<'a>(
+        ck: &CommitterKey<G>,
+        polynomials: impl IntoIterator<Item = LabeledPolynomialWithBasis<'a, G::ScalarField>>,
+        terminator: &AtomicBool,
+        mut rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Vec<LabeledCommitment<Commitment<G>>>, Vec<Randomness<G>>), PCError> {
+        let commit_time = start_timer!(|| "Committing to polynomials");
+        let mut comms = Vec::new();
+        let mut labeled_info = Vec::new();
+        let mut randomness = Vec::new();
+
+        for p in polynomials {
+            if terminator.load(Ordering::Relaxed) {
+                return Err(PCError::Terminated);
+            }
+            Self::check_degrees_and_bounds(ck, &p)?;
+            let degree_bound = p.degree_bound();
+            let hiding_bound = p.hiding_bound();
+            let label = p.label().to_string();
+
+            let commit_time = start_timer!(|| format!(
+                "Polynomial {} of degree {}, degree bound {:?}, and hiding bound {:?}",
+                label,
+                p.degree(),
+                degree_bound,
+                hiding_bound,
+            ));
+
+            let rand = if hiding_bound.is_some() {
+                let rng = rng.as_mut().ok_or(PCError::MissingRng)?;
+                Randomness {
+                    rand: G::ScalarField::rand(rng),
+                    shifted_rand: degree_bound.map(|_| G::ScalarField::rand(rng)),
+                }
+            } else {
+                Randomness::empty()
+            };
+
+            let polynomial = Self::to_dense_polynomial(&p);
+            let comm = Self::commit_coeffs(&ck.comm_key, &polynomial.coeffs, ck.s, rand.rand);
+            let shifted_comm = degree_bound.map(|degree_bound| {
+                let shift = ck.supported_degree() - degree_bound;
+                let shifted_rand = rand.shifted_rand.unwrap_or_else(G::ScalarField::zero);
+                Self::commit_coeffs(&ck.comm_key[shift..], &polynomial.coeffs, ck.s, shifted_rand)
+            });
+
+            comms.push((comm, shifted_comm));
+            labeled_info.push((label, degree_bound));
+            randomness.push(rand);
+            end_timer!(commit_time);
+        }
+
+        let labeled_comms = labeled_info
+            .into_iter()
+            .zip_eq(Self::normalize_commitments(comms))
+            .map(|((label, degree_bound), comm)| LabeledCommitment::new(label, comm, degree_bound))
+            .collect();
+
+        end_timer!(commit_time);
+        Ok((labeled_comms, randomness))
+    }
+
+    /// On input a list of labeled polynomials and a query set, `open` outputs a proof of evaluation
+    /// of the polynomials at the points in the query set.
+    pub fn batch_open<'a>(
+        ck: &CommitterKey<G>,
+        labeled_polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<G::ScalarField>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<G>>>,
+        query_set: &QuerySet<G::ScalarField>,
+        rands: impl IntoIterator<Item = &'a Randomness<G>>,
+        fs_rng: &mut S,
+    ) -> Result<BatchProof<G>, PCError>
+    where
+        Randomness<G>: 'a,
+        Commitment<G>: 'a,
+    {
+        let poly_rand_comm: HashMap<_, _> = labeled_polynomials
+            .into_iter()
+            .zip_eq(rands)
+            .zip_eq(commitments)
+            .map(|((poly, r), comm)| (poly.label(), (poly, r, comm)))
+            .collect();
+
+        let open_time = start_timer!(|| format!(
+            "Opening {} polynomials at query set of size {}",
+            poly_rand_comm.len(),
+            query_set.len(),
+        ));
+
+        let mut query_to_labels_map = BTreeMap::new();
+
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+
+        let mut proofs = Vec::with_capacity(query_to_labels_map.len());
+        for (_point_name, (&point, labels)) in query_to_labels_map.into_iter() {
+            let mut combined_polynomial = DensePolynomial::zero();
+            let mut combined_rand = G::ScalarField::zero();
+            let mut combined_comm = Vec::new();
+            let mut hiding = false;
+
+            for label in labels {
+                let &(polynomial, rand, comm) =
+                    poly_rand_comm.get(label as &str).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+                Self::check_degrees_and_bounds(ck, &polynomial.into())?;
+                hiding |= polynomial.is_hiding();
+
+                let challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+                combined_polynomial += (challenge, polynomial.polynomial());
+                combined_rand += challenge * rand.rand;
+                combined_comm.push((challenge, comm.commitment().comm));
+
+                if let Some(degree_bound) = polynomial.degree_bound() {
+                    let shifted_comm = Self::shifted_commitment(comm)?;
+                    let challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+                    let shift = ck.supported_degree() - degree_bound;
+                    let mut shifted_coeffs = vec![G::ScalarField::zero(); shift];
+                    shifted_coeffs.extend_from_slice(&polynomial.polynomial().to_dense().coeffs);
+                    combined_polynomial += (challenge, &DensePolynomial::from_coefficients_vec(shifted_coeffs));
+                    combined_rand += challenge * rand.shifted_rand.unwrap_or_else(G::ScalarField::zero);
+                    combined_comm.push((challenge, shifted_comm));
+                }
+            }
+
+            // Mask the combined polynomial with a random polynomial that vanishes at `point`.
+            let (hiding_comm, rand) = if hiding {
+                let mut rng = Self::hiding_rng(&combined_polynomial, combined_rand, point)?;
+                let mut hiding_polynomial = DensePolynomial::rand(ck.supported_degree(), &mut rng);
+                let hiding_evaluation = hiding_polynomial.evaluate(point);
+                hiding_polynomial.coeffs[0] -= hiding_evaluation;
+                let hiding_rand = G::ScalarField::rand(&mut rng);
+                let hiding_comm = Self::commit_coeffs(&ck.comm_key, &hiding_polynomial.coeffs, ck.s, hiding_rand);
+                let hiding_comm = hiding_comm.to_affine();
+
+                Self::absorb_points(fs_rng, &[hiding_comm]);
+                let hiding_challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+                combined_polynomial += (hiding_challenge, &hiding_polynomial);
+                combined_rand += hiding_challenge * hiding_rand;
+                combined_comm.push((hiding_challenge, hiding_comm));
+                combined_comm.push((-combined_rand, ck.s));
+
+                (Some(hiding_comm), Some(combined_rand))
+            } else {
+                (None, None)
+            };
+
+            let proof_time = start_timer!(|| "Creating proof");
+            let combined_comm = Self::combine_commitments(combined_comm).to_affine();
+            let (l_vec, r_vec, c) = Self::prove_inner_product(ck, &combined_polynomial, combined_comm, point, fs_rng);
+            fs_rng.absorb_nonnative_field_elements([c]);
+            let _randomizer = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+            end_timer!(proof_time);
+
+            proofs.push(Proof { l_vec, r_vec, c, hiding_comm, rand });
+        }
+        end_timer!(open_time);
+
+        Ok(BatchProof(proofs))
+    }
+
+    pub fn batch_check<'a>(
+        vk: &VerifierKey<G>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<G>>>,
+        query_set: &QuerySet<G::ScalarField>,
+        values: &Evaluations<G::ScalarField>,
+        proof: &BatchProof<G>,
+        fs_rng: &mut S,
+    ) -> Result<bool, PCError>
+    where
+        Commitment<G>: 'a,
+    {
+        let commitments: BTreeMap<_, _> = commitments.into_iter().map(|c| (c.label().to_owned(), c)).collect();
+        let batch_check_time = start_timer!(|| format!(
+            "Checking {} commitments at query set of size {}",
+            commitments.len(),
+            query_set.len(),
+        ));
+        let mut query_to_labels_map = BTreeMap::new();
+
+        for (label, (point_name, point)) in query_set.iter() {
+            let labels = query_to_labels_map.entry(point_name).or_insert((point, BTreeSet::new()));
+            labels.1.insert(label);
+        }
+
+        assert_eq!(proof.0.len(), query_to_labels_map.len());
+
+        let log_num_coeffs = vk.log_size_of_group();
+        let mut randomizer = G::ScalarField::one();
+
+        // All of the checks are accumulated into a single MSM, which is zero if and only if every check passes
+        // (with overwhelming probability over the randomizers).
+        let mut bases = Vec::new();
+        let mut scalars = Vec::new();
+        let mut comm_key_scalars = vec![G::ScalarField::zero(); vk.comm_key.len()];
+        let mut h_scalar = G::ScalarField::zero();
+
+        for ((_query_name, (&point, labels)), p) in query_to_labels_map.into_iter().zip_eq(&proof.0) {
+            if p.l_vec.len() != log_num_coeffs
+                || p.r_vec.len() != log_num_coeffs
+                || p.hiding_comm.is_some() != p.rand.is_some()
+            {
+                return Ok(false);
+            }
+
+            let mut combined_comm = Vec::new();
+            let mut combined_value = G::ScalarField::zero();
+            for label in labels {
+                let commitment =
+                    commitments.get(label).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+                let value = values
+                    .get(&(label.clone(), point))
+                    .ok_or(PCError::MissingEvaluation { label: label.to_string() })?;
+
+                let challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+                combined_comm.push((challenge, commitment.commitment().comm));
+                combined_value += challenge * value;
+
+                if let Some(degree_bound) = commitment.degree_bound() {
+                    if degree_bound > vk.supported_degree() {
+                        return Err(PCError::UnsupportedDegreeBound(degree_bound));
+                    }
+                    let shifted_comm = Self::shifted_commitment(commitment)?;
+                    let challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+                    let shift = (vk.supported_degree() - degree_bound) as u64;
+                    combined_comm.push((challenge, shifted_comm));
+                    combined_value += challenge * value * point.pow([shift]);
+                }
+            }
+
+            if let (Some(hiding_comm), Some(rand)) = (p.hiding_comm, p.rand) {
+                Self::absorb_points(fs_rng, &[hiding_comm]);
+                let hiding_challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+                combined_comm.push((hiding_challenge, hiding_comm));
+                combined_comm.push((-rand, vk.s));
+            }
+
+            let combined_comm = Self::combine_commitments(combined_comm).to_affine();
+            Self::absorb_points(fs_rng, &[combined_comm]);
+            fs_rng.absorb_nonnative_field_elements([point, combined_value]);
+            let h_challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+
+            let mut round_challenges = Vec::with_capacity(log_num_coeffs);
+            for (l, r) in p.l_vec.iter().zip_eq(&p.r_vec) {
+                Self::absorb_points(fs_rng, &[*l, *r]);
+                round_challenges.push(fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>());
+            }
+            fs_rng.absorb_nonnative_field_elements([p.c]);
+
+            let mut round_challenge_inverses = round_challenges.clone();
+            batch_inversion(&mut round_challenge_inverses);
+
+            // The folded vector of powers of `point` is the evaluation of `prod_j (1 + xi_j X^{2^{k-1-j}})` at `point`.
+            let mut folded_point = G::ScalarField::one();
+            let mut point_power = point;
+            for challenge in round_challenges.iter().rev() {
+                folded_point *= G::ScalarField::one() + *challenge * point_power;
+                point_power.square_in_place();
+            }
+
+            // Check that `C + v * h' + sum_j (L_j / xi_j + xi_j * R_j) = c * (G_final + b_final * h')`,
+            // where `h' = h_challenge * h`.
+            bases.push(combined_comm);
+            scalars.push(randomizer);
+            bases.extend(p.l_vec.iter().chain(&p.r_vec));
+            scalars.extend(round_challenge_inverses.iter().chain(&round_challenges).map(|c| randomizer * c));
+            h_scalar += randomizer * h_challenge * (combined_value - p.c * folded_point);
+
+            let neg_randomized_c = -(randomizer * p.c);
+            for (scalar, coeff) in comm_key_scalars.iter_mut().zip_eq(Self::folded_comm_key_coeffs(&round_challenges)) {
+                *scalar += neg_randomized_c * coeff;
+            }
+
+            randomizer = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+        }
+
+        bases.extend_from_slice(&vk.comm_key);
+        scalars.extend(comm_key_scalars);
+        bases.push(vk.h);
+        scalars.push(h_scalar);
+
+        let scalars = scalars.iter().map(|s| s.to_bigint()).collect::<Vec<_>>();
+        let result = VariableBase::msm(&bases, &scalars).is_zero();
+        end_timer!(batch_check_time);
+        Ok(result)
+    }
+
+    pub fn open_combinations<'a>(
+        ck: &CommitterKey<G>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<G::ScalarField>>,
+        polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<G::ScalarField>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<G>>>,
+        query_set: &QuerySet<G::ScalarField>,
+        rands: impl IntoIterator<Item = &'a Randomness<G>>,
+        fs_rng: &mut S,
+    ) -> Result<BatchLCProof<G>, PCError>
+    where
+        Randomness<G>: 'a,
+        Commitment<G>: 'a,
+    {
+        let (lc_polynomials, lc_commitments, lc_randomness) =
+            Self::combine_lcs_for_open(linear_combinations, polynomials, commitments, rands)?;
+
+        let proof = Self::batch_open(
+            ck,
+            lc_polynomials.iter(),
+            lc_commitments.iter(),
+            query_set,
+            lc_randomness.iter(),
+            fs_rng,
+        )?;
+
+        Ok(BatchLCProof { proof, evaluations: None })
+    }
+
+    /// Combines `polynomials`, their `commitments`, and their `rands` according to `linear_combinations`.
+    /// The outputs are labeled by the linear combinations, and can be opened via [`Self::batch_open`].
+    #[allow(clippy::type_complexity)]
+    pub fn combine_lcs_for_open<'a>(
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<G::ScalarField>>,
+        polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<G::ScalarField>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<G>>>,
+        rands: impl IntoIterator<Item = &'a Randomness<G>>,
+    ) -> Result<
+        (Vec<LabeledPolynomial<G::ScalarField>>, Vec<LabeledCommitment<Commitment<G>>>, Vec<Randomness<G>>),
+        PCError,
+    >
+    where
+        Randomness<G>: 'a,
+        Commitment<G>: 'a,
+    {
+        let label_map = polynomials
+            .into_iter()
+            .zip_eq(rands)
+            .zip_eq(commitments)
+            .map(|((p, r), c)| (p.label(), (p, r, c)))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut lc_polynomials = Vec::new();
+        let mut lc_randomness = Vec::new();
+        let mut lc_commitments = Vec::new();
+        let mut lc_info = Vec::new();
+
+        for lc in linear_combinations {
+            let lc_label = lc.label().to_string();
+            let mut poly = DensePolynomial::zero();
+            let mut degree_bound = None;
+            let mut hiding_bound = None;
+
+            let mut randomness = Randomness::empty();
+            let mut coeffs_and_comms = Vec::new();
+
+            let num_polys = lc.len();
+            for (coeff, label) in lc.iter().filter(|(_, l)| !l.is_one()) {
+                let label: &String = label.try_into().expect("cannot be one!");
+                let &(cur_poly, cur_rand, cur_comm) =
+                    label_map.get(label as &str).ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+                if num_polys == 1 && cur_poly.degree_bound().is_some() {
+                    assert!(coeff.is_one(), "Coefficient must be one for degree-bounded equations");
+                    degree_bound = cur_poly.degree_bound();
+                } else if cur_poly.degree_bound().is_some() {
+                    return Err(PCError::EquationHasDegreeBounds(lc_label));
+                }
+                // Some(_) > None, always.
+                hiding_bound = core::cmp::max(hiding_bound, cur_poly.hiding_bound());
+                poly += (*coeff, cur_poly.polynomial());
+                randomness += (*coeff, cur_rand);
+                coeffs_and_comms.push((*coeff, cur_comm.commitment()));
+            }
+
+            let lc_poly = LabeledPolynomial::new(lc_label.clone(), poly, degree_bound, hiding_bound);
+            lc_polynomials.push(lc_poly);
+            lc_randomness.push(randomness);
+            lc_commitments.push(Self::combine_labeled_commitments(coeffs_and_comms));
+            lc_info.push((lc_label, degree_bound));
+        }
+
+        let comms = Self::normalize_commitments(lc_commitments);
+        let lc_commitments = lc_info
+            .into_iter()
+            .zip_eq(comms)
+            .map(|((label, d), c)| LabeledCommitment::new(label, c, d))
+            .collect::<Vec<_>>();
+
+        Ok((lc_polynomials, lc_commitments, lc_randomness))
+    }
+
+    /// Checks that `values` are the true evaluations at `query_set` of the polynomials
+    /// committed in `labeled_commitments`.
+    pub fn check_combinations<'a>(
+        vk: &VerifierKey<G>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<G::ScalarField>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<G>>>,
+        query_set: &QuerySet<G::ScalarField>,
+        evaluations: &Evaluations<G::ScalarField>,
+        proof: &BatchLCProof<G>,
+        fs_rng: &mut S,
+    ) -> Result<bool, PCError>
+    where
+        Commitment<G>: 'a,
+    {
+        let BatchLCProof { proof, .. } = proof;
+        let (lc_commitments, evaluations) = Self::combine_lcs_for_check(linear_combinations, commitments, evaluations)?;
+
+        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+    }
+
+    /// Combines `commitments` according to `linear_combinations`, and adjusts `evaluations`
+    /// by the constant terms of the linear combinations.
+    /// The outputs are labeled by the linear combinations, and can be checked via [`Self::batch_check`].
+    pub fn combine_lcs_for_check<'a>(
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<G::ScalarField>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<G>>>,
+        evaluations: &Evaluations<G::ScalarField>,
+    ) -> Result<(Vec<LabeledCommitment<Commitment<G>>>, Evaluations<'a, G::ScalarField>), PCError>
+    where
+        Commitment<G>: 'a,
+    {
+        let label_comm_map = commitments.into_iter().map(|c| (c.label(), c)).collect::<BTreeMap<_, _>>();
+
+        let mut lc_commitments = Vec::new();
+        let mut lc_info = Vec::new();
+        let mut evaluations = evaluations.clone();
+
+        let lc_processing_time = start_timer!(|| "Combining commitments");
+        for lc in linear_combinations {
+            let lc_label = lc.label().to_string();
+            let num_polys = lc.len();
+
+            let mut degree_bound = None;
+            let mut coeffs_and_comms = Vec::new();
+
+            for (coeff, label) in lc.iter() {
+                if label.is_one() {
+                    for ((label, _), ref mut eval) in evaluations.iter_mut() {
+                        if label == &lc_label {
+                            **eval -= coeff;
+                        }
+                    }
+                } else {
+                    let label: &String = label.try_into().unwrap();
+                    let &cur_comm = label_comm_map
+                        .get(label as &str)
+                        .ok_or(PCError::MissingPolynomial { label: label.to_string() })?;
+
+                    if num_polys == 1 && cur_comm.degree_bound().is_some() {
+                        assert!(coeff.is_one(), "Coefficient must be one for degree-bounded equations");
+                        degree_bound = cur_comm.degree_bound();
+                    } else if cur_comm.degree_bound().is_some() {
+                        return Err(PCError::EquationHasDegreeBounds(lc_label));
+                    }
+                    coeffs_and_comms.push((*coeff, cur_comm.commitment()));
+                }
+            }
+            let lc_time = start_timer!(|| format!("Combining {num_polys} commitments for {lc_label}"));
+            lc_commitments.push(Self::combine_labeled_commitments(coeffs_and_comms));
+            end_timer!(lc_time);
+            lc_info.push((lc_label, degree_bound));
+        }
+        end_timer!(lc_processing_time);
+        let combined_comms_norm_time = start_timer!(|| "Normalizing commitments");
+        let comms = Self::normalize_commitments(lc_commitments);
+        let lc_commitments = lc_info
+            .into_iter()
+            .zip_eq(comms)
+            .map(|((label, d), c)| LabeledCommitment::new(label, c, d))
+            .collect::<Vec<_>>();
+        end_timer!(combined_comms_norm_time);
+
+        Ok((lc_commitments, evaluations))
+    }
+}
+
+impl<G: AffineCurve, S: AlgebraicSponge<G::BaseField, 2>> InnerProductArgPC<G, S>
+where
+    G::BaseField: PrimeField,
+{
+    /// Samples `num_generators` generators of unknown discrete logarithm, by hashing to the curve.
+    fn sample_generators(num_generators: usize) -> Vec<G> {
+        let generators = cfg_into_iter!(0..num_generators)
+            .map(|i| {
+                let mut counter = 0u64;
+                loop {
+                    let input = [PROTOCOL_NAME, &(i as u64).to_le_bytes(), &counter.to_le_bytes()].concat();
+                    // Hash to twice the size of the digest, so that the bytes cover the base field.
+                    let hash = sha256(&input);
+                    let bytes = [hash, sha256(&hash)].concat();
+                    if let Some(generator) = G::from_random_bytes(&bytes) {
+                        let generator = generator.mul_by_cofactor_to_projective();
+                        if !generator.is_zero() {
+                            return generator;
+                        }
+                    }
+                    counter += 1;
+                }
+            })
+            .collect::<Vec<_>>();
+        G::Projective::batch_normalization_into_affine(generators)
+    }
+
+    fn check_degrees_and_bounds(
+        ck: &CommitterKey<G>,
+        p: &LabeledPolynomialWithBasis<G::ScalarField>,
+    ) -> Result<(), PCError> {
+        if p.degree() > ck.supported_degree() {
+            return Err(PCError::TooManyCoefficients {
+                num_coefficients: p.degree() + 1,
+                num_powers: ck.comm_key.len(),
+            });
+        }
+        if let Some(bound) = p.degree_bound() {
+            let enforced_degree_bounds =
+                ck.enforced_degree_bounds.as_deref().ok_or(PCError::UnsupportedDegreeBound(bound))?;
+
+            if enforced_degree_bounds.binary_search(&bound).is_err() {
+                return Err(PCError::UnsupportedDegreeBound(bound));
+            } else if bound < p.degree() {
+                return Err(PCError::IncorrectDegreeBound {
+                    poly_degree: p.degree(),
+                    degree_bound: bound,
+                    supported_degree: ck.supported_degree(),
+                    label: p.label().to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Converts `p` into a polynomial in the monomial basis.
+    fn to_dense_polynomial(p: &LabeledPolynomialWithBasis<G::ScalarField>) -> DensePolynomial<G::ScalarField> {
+        let mut polynomial = DensePolynomial::zero();
+        for p in p.sum() {
+            match p {
+                PolynomialWithBasis::Lagrange { evaluations } => polynomial += &evaluations.interpolate_by_ref(),
+                PolynomialWithBasis::Monomial { polynomial: p, .. } => polynomial += &*p.to_dense(),
+            }
+        }
+        polynomial
+    }
+
+    /// Returns `sum_i coeffs_i * comm_key_i + rand * s`.
+    fn commit_coeffs(comm_key: &[G], coeffs: &[G::ScalarField], s: G, rand: G::ScalarField) -> G::Projective {
+        let scalars = cfg_iter!(coeffs).map(|c| c.to_bigint()).collect::<Vec<_>>();
+        let mut comm = VariableBase::msm(&comm_key[..scalars.len()], &scalars);
+        if !rand.is_zero() {
+            comm += s * rand;
+        }
+        comm
+    }
+
+    /// MSM for `coeffs_and_bases`.
+    fn combine_commitments(coeffs_and_bases: Vec<(G::ScalarField, G)>) -> G::Projective {
+        let (scalars, bases): (Vec<_>, Vec<_>) = coeffs_and_bases.into_iter().map(|(f, g)| (f.to_bigint(), g)).unzip();
+        VariableBase::msm(&bases, &scalars)
+    }
+
+    /// MSM for `commitments` and `coeffs`, including their shifted commitments (if any).
+    fn combine_labeled_commitments<'a>(
+        coeffs_and_comms: impl IntoIterator<Item = (G::ScalarField, &'a Commitment<G>)>,
+    ) -> (G::Projective, Option<G::Projective>) {
+        let mut comms = Vec::new();
+        let mut shifted_comms = Vec::new();
+        for (coeff, comm) in coeffs_and_comms {
+            comms.push((coeff, comm.comm));
+            if let Some(shifted_comm) = comm.shifted_comm {
+                shifted_comms.push((coeff, shifted_comm));
+            }
+        }
+        let shifted_comm = (!shifted_comms.is_empty()).then(|| Self::combine_commitments(shifted_comms));
+        (Self::combine_commitments(comms), shifted_comm)
+    }
+
+    fn normalize_commitments(
+        commitments: Vec<(G::Projective, Option<G::Projective>)>,
+    ) -> impl Iterator<Item = Commitment<G>> {
+        let mut comms = Vec::with_capacity(commitments.len());
+        let mut shifted_comms = Vec::new();
+        let mut has_shifted_comm = Vec::with_capacity(commitments.len());
+        for (comm, shifted_comm) in commitments {
+            comms.push(comm);
+            has_shifted_comm.push(shifted_comm.is_some());
+            shifted_comms.extend(shifted_comm);
+        }
+        let comms = G::Projective::batch_normalization_into_affine(comms);
+        let mut shifted_comms = G::Projective::batch_normalization_into_affine(shifted_comms).into_iter();
+        comms.into_iter().zip_eq(has_shifted_comm).map(move |(comm, has_shifted_comm)| Commitment {
+            comm,
+            shifted_comm: if has_shifted_comm { shifted_comms.next() } else { None },
+        })
+    }
+
+    fn shifted_commitment(comm: &LabeledCommitment<Commitment<G>>) -> Result<G, PCError> {
+        comm.commitment()
+            .shifted_comm
+            .ok_or_else(|| {
+                anyhow!("the commitment to \"{}\" has a degree bound but no shifted commitment", comm.label())
+            })
+            .map_err(Into::into)
+    }
+
+    fn absorb_points(fs_rng: &mut S, points: &[G]) {
+        let coordinates =
+            points.iter().flat_map(|p| [p.to_x_coordinate(), p.to_y_coordinate()]).collect::<Vec<G::BaseField>>();
+        fs_rng.absorb_native_field_elements(&coordinates);
+    }
+
+    /// Derives the randomness of the hiding polynomial from the (secret) combined commitment randomness,
+    /// so that opening does not require an additional source of randomness.
+    fn hiding_rng(
+        polynomial: &DensePolynomial<G::ScalarField>,
+        rand: G::ScalarField,
+        point: G::ScalarField,
+    ) -> Result<StdRng, PCError> {
+        let mut input = PROTOCOL_NAME.to_vec();
+        input.extend(rand.to_bytes_le()?);
+        input.extend(point.to_bytes_le()?);
+        input.extend(polynomial.coeffs.to_bytes_le()?);
+        Ok(StdRng::from_seed(sha256(&input)))
+    }
+
+    /// Returns the coefficients of `prod_j (1 + xi_j X^{2^{k-1-j}})`, which are the coefficients of the
+    /// folded generator `G_final` in terms of the `comm_key`.
+    fn folded_comm_key_coeffs(round_challenges: &[G::ScalarField]) -> Vec<G::ScalarField> {
+        let mut coeffs = Vec::with_capacity(1 << round_challenges.len());
+        coeffs.push(G::ScalarField::one());
+        for challenge in round_challenges.iter().rev() {
+            let len = coeffs.len();
+            for i in 0..len {
+                let coeff = coeffs[i] * challenge;
+                coeffs.push(coeff);
+            }
+        }
+        coeffs
+    }
+
+    /// Proves that `commitment` is a commitment to `polynomial` (without blinding), and returns the
+    /// round commitments and the last coefficient of the folded polynomial.
+    fn prove_inner_product(
+        ck: &CommitterKey<G>,
+        polynomial: &DensePolynomial<G::ScalarField>,
+        commitment: G,
+        point: G::ScalarField,
+        fs_rng: &mut S,
+    ) -> (Vec<G>, Vec<G>, G::ScalarField) {
+        let num_coeffs = ck.comm_key.len();
+        let value = polynomial.evaluate(point);
+
+        Self::absorb_points(fs_rng, &[commitment]);
+        fs_rng.absorb_nonnative_field_elements([point, value]);
+        let h_prime = ck.h * fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+
+        let mut coeffs = polynomial.coeffs.clone();
+        coeffs.resize(num_coeffs, G::ScalarField::zero());
+        let mut powers_of_point = Vec::with_capacity(num_coeffs);
+        let mut power = G::ScalarField::one();
+        for _ in 0..num_coeffs {
+            powers_of_point.push(power);
+            power *= point;
+        }
+        let mut comm_key = ck.comm_key.clone();
+
+        let mut l_vec = Vec::with_capacity(ck.log_size_of_group());
+        let mut r_vec = Vec::with_capacity(ck.log_size_of_group());
+        let mut n = num_coeffs;
+        while n > 1 {
+            let round_time = start_timer!(|| format!("Inner product round of size {n}"));
+            let half = n / 2;
+            let (coeffs_lo, coeffs_hi) = coeffs.split_at(half);
+            let (powers_lo, powers_hi) = powers_of_point.split_at(half);
+            let (comm_key_lo, comm_key_hi) = comm_key.split_at(half);
+
+            let l = Self::commit_coeffs(comm_key_lo, coeffs_hi, G::zero(), G::ScalarField::zero())
+                + h_prime * Self::inner_product(coeffs_hi, powers_lo);
+            let r = Self::commit_coeffs(comm_key_hi, coeffs_lo, G::zero(), G::ScalarField::zero())
+                + h_prime * Self::inner_product(coeffs_lo, powers_hi);
+            let lr = G::Projective::batch_normalization_into_affine(vec![l, r]);
+            let (l, r) = (lr[0], lr[1]);
+
+            Self::absorb_points(fs_rng, &[l, r]);
+            let challenge = fs_rng.squeeze_short_nonnative_field_element::<G::ScalarField>();
+            let challenge_inv = challenge.inverse().expect("round challenges are nonzero");
+
+            coeffs = cfg_iter!(coeffs_lo).zip(coeffs_hi).map(|(lo, hi)| *lo + challenge_inv * hi).collect();
+            powers_of_point = cfg_iter!(powers_lo).zip(powers_hi).map(|(lo, hi)| *lo + challenge * hi).collect();
+            comm_key = G::Projective::batch_normalization_into_affine(
+                cfg_iter!(comm_key_lo).zip(comm_key_hi).map(|(lo, hi)| lo.to_projective() + *hi * challenge).collect(),
+            );
+
+            l_vec.push(l);
+            r_vec.push(r);
+            n = half;
+            end_timer!(round_time);
+        }
+
+        (l_vec, r_vec, coeffs[0])
+    }
+
+    fn inner_product(a: &[G::ScalarField], b: &[G::ScalarField]) -> G::ScalarField {
+        a.iter().zip_eq(b).map(|(a, b)| *a * b).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_camel_case_types)]
+
+    use super::{CommitterKey, InnerProductArgPC};
+    use crate::{
+        crypto_hash::PoseidonSponge,
+        fft::{DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain},
+        polycommit::{
+            sonic_pc::{Evaluations, LabeledPolynomial, LabeledPolynomialWithBasis, LinearCombination, QuerySet},
+            PCError,
+        },
+        AlgebraicSponge,
+    };
+    use snarkvm_curves::{bls12_377, edwards_bls12, AffineCurve};
+    use snarkvm_fields::{One, PrimeField};
+    use snarkvm_utilities::{
+        rand::{TestRng, Uniform},
+        FromBytes,
+        ToBytes,
+    };
+
+    type Sponge_Edwards = PoseidonSponge<edwards_bls12::Fq, 2, 1>;
+    type PC_Edwards = InnerProductArgPC<edwards_bls12::EdwardsAffine, Sponge_Edwards>;
+
+    type Sponge_Bls12_377 = PoseidonSponge<bls12_377::Fq, 2, 1>;
+    type PC_Bls12_377 = InnerProductArgPC<bls12_377::G1Affine, Sponge_Bls12_377>;
+
+    fn batch_open_and_check_test<G: AffineCurve, S: AlgebraicSponge<G::BaseField, 2>>() -> Result<(), PCError>
+    where
+        G::BaseField: PrimeField,
+    {
+        let rng = &mut TestRng::default();
+        let max_degree = 63;
+        let pp = InnerProductArgPC::<G, S>::setup(max_degree)?;
+        let (ck, vk) = InnerProductArgPC::<G, S>::trim(&pp, max_degree, [], 1, Some(&[17, 40]))?;
+
+        for hiding_bound in [None, Some(1)] {
+            let polynomials = vec![
+                LabeledPolynomial::new("a".to_string(), DensePolynomial::rand(max_degree, rng), None, hiding_bound),
+                LabeledPolynomial::new("b".to_string(), DensePolynomial::rand(17, rng), Some(17), hiding_bound),
+                LabeledPolynomial::new("c".to_string(), DensePolynomial::rand(30, rng), Some(40), hiding_bound),
+            ];
+            let (comms, rands) = InnerProductArgPC::<G, S>::commit(&ck, polynomials.iter().map(Into::into), Some(rng))?;
+            assert!(!comms[0].commitment().has_degree_bound());
+            assert!(comms[1].commitment().has_degree_bound());
+
+            let (point_1, point_2) = (G::ScalarField::rand(rng), G::ScalarField::rand(rng));
+            let mut query_set = QuerySet::new();
+            for (label, (point_name, point)) in
+                [("a", ("1", point_1)), ("b", ("1", point_1)), ("a", ("2", point_2)), ("c", ("2", point_2))]
+            {
+                query_set.insert((label.to_string(), (point_name.to_string(), point)));
+            }
+            let mut values = Evaluations::new();
+            for (label, (_, point)) in &query_set {
+                let polynomial = polynomials.iter().find(|p| p.label() == label).unwrap();
+                values.insert((label.clone(), *point), polynomial.evaluate(*point));
+            }
+
+            let proof =
+                InnerProductArgPC::<G, S>::batch_open(&ck, &polynomials, &comms, &query_set, &rands, &mut S::new())?;
+            assert_eq!(proof.is_hiding(), hiding_bound.is_some());
+            assert_eq!(proof.0.len(), 2);
+            let proof = FromBytes::read_le(&proof.to_bytes_le().unwrap()[..]).unwrap();
+            assert!(InnerProductArgPC::<G, S>::batch_check(&vk, &comms, &query_set, &values, &proof, &mut S::new())?);
+
+            // An incorrect evaluation fails the check.
+            let mut bad_values = values.clone();
+            *bad_values.values_mut().next().unwrap() += G::ScalarField::one();
+            assert!(!InnerProductArgPC::<G, S>::batch_check(
+                &vk,
+                &comms,
+                &query_set,
+                &bad_values,
+                &proof,
+                &mut S::new()
+            )?);
+
+            // A tampered proof fails the check.
+            let mut bad_proof = proof.clone();
+            bad_proof.0[1].c += G::ScalarField::one();
+            assert!(!InnerProductArgPC::<G, S>::batch_check(
+                &vk,
+                &comms,
+                &query_set,
+                &values,
+                &bad_proof,
+                &mut S::new()
+            )?);
+
+            // A different transcript fails the check.
+            let mut bad_sponge = S::new();
+            bad_sponge.absorb_nonnative_field_elements([G::ScalarField::one()]);
+            assert!(!InnerProductArgPC::<G, S>::batch_check(
+                &vk,
+                &comms,
+                &query_set,
+                &values,
+                &proof,
+                &mut bad_sponge
+            )?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_setup_is_deterministic() {
+        let pp = PC_Edwards::setup(15).unwrap();
+        assert_eq!(pp.max_degree(), 15);
+        assert_eq!(pp, PC_Edwards::setup(15).unwrap());
+        assert_eq!(pp.comm_key[..8], PC_Edwards::setup(7).unwrap().comm_key[..]);
+        assert!(pp.comm_key.iter().all(|g| g.is_in_correct_subgroup_assuming_on_curve()));
+    }
+
+    #[test]
+    fn test_committer_key_serialization() {
+        let pp = PC_Edwards::setup(31).unwrap();
+        let (ck, _vk) = PC_Edwards::trim(&pp, 20, [32], 1, Some(&[10, 20])).unwrap();
+        assert_eq!(ck.supported_degree(), 31);
+
+        let ck_bytes = ck.to_bytes_le().unwrap();
+        let ck_recovered: CommitterKey<edwards_bls12::EdwardsAffine> = FromBytes::read_le(&ck_bytes[..]).unwrap();
+        assert_eq!(ck, ck_recovered);
+    }
+
+    #[test]
+    fn test_batch_open_and_check() {
+        batch_open_and_check_test::<edwards_bls12::EdwardsAffine, Sponge_Edwards>().expect("test failed for edwards");
+        batch_open_and_check_test::<bls12_377::G1Affine, Sponge_Bls12_377>().expect("test failed for bls12-377");
+    }
+
+    #[test]
+    fn test_degree_bound_is_enforced() {
+        let rng = &mut TestRng::default();
+        let pp = PC_Edwards::setup(31).unwrap();
+        let (ck, _vk) = PC_Edwards::trim(&pp, 31, [], 0, Some(&[8])).unwrap();
+
+        let polynomial = LabeledPolynomial::new("a".to_string(), DensePolynomial::rand(9, rng), Some(8), None);
+        assert!(PC_Edwards::commit(&ck, [(&polynomial).into()], None).is_err());
+        let polynomial = LabeledPolynomial::new("a".to_string(), DensePolynomial::rand(5, rng), Some(5), None);
+        assert!(PC_Edwards::commit(&ck, [(&polynomial).into()], None).is_err());
+        let polynomial = LabeledPolynomial::new("a".to_string(), DensePolynomial::rand(32, rng), None, None);
+        assert!(PC_Edwards::commit(&ck, [(&polynomial).into()], None).is_err());
+        // Hiding commitments require an RNG.
+        let polynomial = LabeledPolynomial::new("a".to_string(), DensePolynomial::rand(5, rng), None, Some(1));
+        assert!(PC_Edwards::commit(&ck, [(&polynomial).into()], None).is_err());
+    }
+
+    #[test]
+    fn test_lagrange_commitment() {
+        let rng = &mut TestRng::default();
+        let pp = PC_Bls12_377::setup(31).unwrap();
+        let (ck, _vk) = PC_Bls12_377::trim(&pp, 31, [16], 0, None).unwrap();
+
+        let domain = EvaluationDomain::<bls12_377::Fr>::new(16).unwrap();
+        let evaluations = (0..16).map(|_| bls12_377::Fr::rand(rng)).collect();
+        let evaluations = EvaluationsOnDomain::from_vec_and_domain(evaluations, domain);
+        let monomial = LabeledPolynomial::new("a".to_string(), evaluations.interpolate_by_ref(), None, None);
+        let lagrange = LabeledPolynomialWithBasis::new_lagrange_basis("a".to_string(), evaluations, None);
+
+        let (lagrange_comm, _) = PC_Bls12_377::commit(&ck, [lagrange], None).unwrap();
+        let (monomial_comm, _) = PC_Bls12_377::commit(&ck, [(&monomial).into()], None).unwrap();
+        assert_eq!(lagrange_comm, monomial_comm);
+    }
+
+    #[test]
+    fn test_open_and_check_combinations() {
+        let rng = &mut TestRng::default();
+        let max_degree = 31;
+        let pp = PC_Bls12_377::setup(max_degree).unwrap();
+        let (ck, vk) = PC_Bls12_377::trim(&pp, max_degree, [], 1, Some(&[10])).unwrap();
+
+        let polynomials = vec![
+            LabeledPolynomial::new("a".to_string(), DensePolynomial::rand(max_degree, rng), None, Some(1)),
+            LabeledPolynomial::new("b".to_string(), DensePolynomial::rand(20, rng), None, None),
+            LabeledPolynomial::new("c".to_string(), DensePolynomial::rand(10, rng), Some(10), Some(1)),
+        ];
+        let (comms, rands) = PC_Bls12_377::commit(&ck, polynomials.iter().map(Into::into), Some(rng)).unwrap();
+
+        let coeff = bls12_377::Fr::rand(rng);
+        let mut lc = LinearCombination::new("lc", [(bls12_377::Fr::one(), "a"), (coeff, "b")]);
+        lc += bls12_377::Fr::one();
+        let lc_c = LinearCombination::new("lc_c", [(bls12_377::Fr::one(), "c")]);
+
+        let point = bls12_377::Fr::rand(rng);
+        let mut query_set = QuerySet::new();
+        query_set.insert(("lc".to_string(), ("point".to_string(), point)));
+        query_set.insert(("lc_c".to_string(), ("point".to_string(), point)));
+        let mut evaluations = Evaluations::new();
+        let value = polynomials[0].evaluate(point) + coeff * polynomials[1].evaluate(point) + bls12_377::Fr::one();
+        evaluations.insert(("lc".to_string(), point), value);
+        evaluations.insert(("lc_c".to_string(), point), polynomials[2].evaluate(point));
+
+        let lcs = [lc, lc_c];
+        let mut sponge = Sponge_Bls12_377::new();
+        let proof =
+            PC_Bls12_377::open_combinations(&ck, &lcs, &polynomials, &comms, &query_set, &rands, &mut sponge).unwrap();
+        let mut sponge = Sponge_Bls12_377::new();
+        assert!(
+            PC_Bls12_377::check_combinations(&vk, &lcs, &comms, &query_set, &evaluations, &proof, &mut sponge).unwrap()
+        );
+
+        let mut bad_evaluations = evaluations.clone();
+        *bad_evaluations.get_mut(&("lc".to_string(), point)).unwrap() -= bls12_377::Fr::one();
+        let mut sponge = Sponge_Bls12_377::new();
+        assert!(
+            !PC_Bls12_377::check_combinations(&vk, &lcs, &comms, &query_set, &bad_evaluations, &proof, &mut sponge)
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,646 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A multi-party "powers of tau" ceremony for the KZG10 universal parameters.
+//!
+//! Each contributor samples secrets `tau` and `rho`, and re-randomizes the latest
+//! [`UniversalParams`] by replacing `beta` with `tau * beta` and `gamma` with `rho * gamma`.
+//! Only the contributors jointly know the resulting trapdoor, so the parameters are secure
+//! as long as a single contributor discarded their secrets.
+
+use super::UniversalParams;
+use crate::{crypto_hash::sha256, msm::VariableBase};
+use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_parameters::testnet3::PowersOfG;
+use snarkvm_utilities::{
+    cfg_iter,
+    error,
+    io::{Read, Write},
+    rand::Uniform,
+    serialize::{CanonicalDeserialize, CanonicalSerialize},
+    to_bytes_le,
+    FromBytes,
+    ToBytes,
+};
+
+use anyhow::{anyhow, ensure, Result};
+use rand::{CryptoRng, Rng};
+use std::{collections::BTreeSet, io};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// The domain separator for the challenges of the proofs of knowledge.
+const PROTOCOL_NAME: &[u8] = b"AleoKZG10PowersOfTauCeremony0";
+
+/// A proof that a contributor knows the secrets `tau` and `rho` with which they re-randomized the parameters.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ContributionProof<E: PairingEngine> {
+    /// `tau` times the generator of G1.
+    pub tau_g: E::G1Affine,
+    /// `tau` times the generator of G2.
+    pub tau_h: E::G2Affine,
+    /// `rho` times the generator of G1.
+    pub rho_g: E::G1Affine,
+    /// `rho` times the generator of G2.
+    pub rho_h: E::G2Affine,
+    /// The Schnorr commitment for `tau`.
+    pub tau_nonce_g: E::G1Affine,
+    /// The Schnorr commitment for `rho`.
+    pub rho_nonce_g: E::G1Affine,
+    /// The Schnorr response for `tau`.
+    pub tau_response: E::Fr,
+    /// The Schnorr response for `rho`.
+    pub rho_response: E::Fr,
+}
+
+impl<E: PairingEngine> ContributionProof<E> {
+    /// Proves knowledge of `tau` and `rho`, binding the proof to the `previous` parameters.
+    fn new<R: Rng + CryptoRng>(previous: &UniversalParams<E>, tau: E::Fr, rho: E::Fr, rng: &mut R) -> Result<Self> {
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+
+        let tau_nonce = E::Fr::rand(rng);
+        let rho_nonce = E::Fr::rand(rng);
+
+        let mut proof = Self {
+            tau_g: (g * tau).to_affine(),
+            tau_h: (h * tau).to_affine(),
+            rho_g: (g * rho).to_affine(),
+            rho_h: (h * rho).to_affine(),
+            tau_nonce_g: (g * tau_nonce).to_affine(),
+            rho_nonce_g: (g * rho_nonce).to_affine(),
+            tau_response: E::Fr::zero(),
+            rho_response: E::Fr::zero(),
+        };
+        let challenge = proof.challenge(previous)?;
+        proof.tau_response = tau_nonce + challenge * tau;
+        proof.rho_response = rho_nonce + challenge * rho;
+        Ok(proof)
+    }
+
+    /// Returns the Fiat-Shamir challenge for the Schnorr proofs, which commits to the `previous` parameters.
+    fn challenge(&self, previous: &UniversalParams<E>) -> Result<E::Fr> {
+        let gamma_g = previous.powers_of_beta_times_gamma_g().get(&0).copied().unwrap_or_else(E::G1Affine::zero);
+        let input = to_bytes_le![
+            PROTOCOL_NAME,
+            previous.beta_h(),
+            gamma_g,
+            self.tau_g,
+            self.tau_h,
+            self.rho_g,
+            self.rho_h,
+            self.tau_nonce_g,
+            self.rho_nonce_g
+        ]?;
+        Ok(E::Fr::from_bytes_le_mod_order(&sha256(&input)))
+    }
+
+    /// Checks the Schnorr proofs, and adds to `check` the pairing equations which ensure
+    /// that the G1 and G2 elements of the proof use the same secrets.
+    fn check<R: Rng>(&self, previous: &UniversalParams<E>, check: &mut PairingCheck<E>, rng: &mut R) -> Result<()> {
+        let g = E::G1Affine::prime_subgroup_generator();
+        let h = E::G2Affine::prime_subgroup_generator();
+
+        ensure!(!self.tau_g.is_zero() && !self.rho_g.is_zero(), "The contribution secrets must be non-zero");
+
+        let challenge = self.challenge(previous)?;
+        ensure!(
+            g * self.tau_response == self.tau_nonce_g.to_projective() + self.tau_g * challenge,
+            "The proof of knowledge of tau is invalid"
+        );
+        ensure!(
+            g * self.rho_response == self.rho_nonce_g.to_projective() + self.rho_g * challenge,
+            "The proof of knowledge of rho is invalid"
+        );
+
+        // e(tau G, H) = e(G, tau H), and e(rho G, H) = e(G, rho H).
+        check.add(&[(self.tau_g.to_projective(), h), (-g.to_projective(), self.tau_h)], rng);
+        check.add(&[(self.rho_g.to_projective(), h), (-g.to_projective(), self.rho_h)], rng);
+        Ok(())
+    }
+}
+
+impl<E: PairingEngine> FromBytes for ContributionProof<E> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        CanonicalDeserialize::deserialize_compressed(&mut reader)
+            .map_err(|_| error("could not deserialize ContributionProof"))
+    }
+}
+
+impl<E: PairingEngine> ToBytes for ContributionProof<E> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        CanonicalSerialize::serialize_compressed(self, &mut writer)
+            .map_err(|_| error("could not serialize ContributionProof"))
+    }
+}
+
+/// A single contribution to the ceremony: the re-randomized parameters, and a proof that
+/// the contributor knows the secrets used to derive them from the previous parameters.
+#[derive(Clone, Debug)]
+pub struct Contribution<E: PairingEngine> {
+    /// The parameters after this contribution.
+    pub params: UniversalParams<E>,
+    /// The proof of knowledge of the secrets of this contribution.
+    pub proof: ContributionProof<E>,
+}
+
+impl<E: PairingEngine> Contribution<E> {
+    /// Re-randomizes the powers of `previous` which are in memory with freshly sampled secrets.
+    /// The secrets are discarded once the contribution is computed.
+    pub fn new<R: Rng + CryptoRng>(previous: &UniversalParams<E>, rng: &mut R) -> Result<Self> {
+        let tau = sample_nonzero::<E::Fr, _>(rng);
+        let rho = sample_nonzero::<E::Fr, _>(rng);
+        let tau_inverse = tau.inverse().ok_or_else(|| anyhow!("Failed to invert tau"))?;
+        let max_degree = previous.max_degree();
+
+        // `\beta^i G` becomes `\tau^i \beta^i G`.
+        let (powers_of_beta_g, shifted_powers_of_beta_g) = previous.loaded_powers_of_beta_g();
+        let shifted_start = max_degree + 1 - shifted_powers_of_beta_g.len();
+        let powers_of_beta_g = rerandomize_powers(&powers_of_beta_g, tau, 0);
+        let shifted_powers_of_beta_g = rerandomize_powers(&shifted_powers_of_beta_g, tau, shifted_start);
+
+        // `\beta^i \gamma G` becomes `\tau^i \rho \beta^i \gamma G`.
+        let powers_of_beta_times_gamma_g = previous
+            .powers_of_beta_times_gamma_g()
+            .iter()
+            .map(|(i, power)| (*i, (*power * (rho * tau.pow([*i as u64]))).to_affine()))
+            .collect();
+
+        // `\beta^{-(max_degree - d)} H` becomes `\tau^{-(max_degree - d)} \beta^{-(max_degree - d)} H`.
+        let negative_powers_of_beta_h = previous
+            .neg_powers_of_beta_h()
+            .iter()
+            .map(|(d, power)| {
+                ensure!(*d <= max_degree, "The degree bound {d} exceeds the maximum degree");
+                Ok((*d, (*power * tau_inverse.pow([(max_degree - d) as u64])).to_affine()))
+            })
+            .collect::<Result<_>>()?;
+
+        let beta_h = (previous.beta_h() * tau).to_affine();
+
+        let powers = PowersOfG::from_parts(
+            powers_of_beta_g,
+            shifted_powers_of_beta_g,
+            powers_of_beta_times_gamma_g,
+            negative_powers_of_beta_h,
+            beta_h,
+        )?;
+        let params = UniversalParams::from_powers(powers);
+        let proof = ContributionProof::new(previous, tau, rho, rng)?;
+        Ok(Self { params, proof })
+    }
+
+    /// Verifies that this contribution correctly re-randomizes the `previous` parameters.
+    pub fn verify<R: Rng + CryptoRng>(&self, previous: &UniversalParams<E>, rng: &mut R) -> Result<()> {
+        let mut check = PairingCheck::new();
+        self.check(previous, &mut check, rng)?;
+        ensure!(check.verify(), "The contribution failed its pairing checks");
+        Ok(())
+    }
+
+    /// Adds to `check` the pairing equations which ensure that `self.params` are well-formed,
+    /// and that they are derived from the `previous` parameters with the secrets of `self.proof`.
+    fn check<R: Rng>(&self, previous: &UniversalParams<E>, check: &mut PairingCheck<E>, rng: &mut R) -> Result<()> {
+        let (previous_powers, previous_shifted_powers) = previous.loaded_powers_of_beta_g();
+        let (powers, shifted_powers) = self.params.loaded_powers_of_beta_g();
+        ensure!(self.params.max_degree() == previous.max_degree(), "The maximum degree changed");
+        ensure!(
+            powers.len() == previous_powers.len() && shifted_powers.len() == previous_shifted_powers.len(),
+            "The number of powers of beta G changed"
+        );
+        ensure!(
+            self.params.powers_of_beta_times_gamma_g().keys().eq(previous.powers_of_beta_times_gamma_g().keys()),
+            "The powers of beta times gamma G changed"
+        );
+        ensure!(
+            self.params.neg_powers_of_beta_h().keys().eq(previous.neg_powers_of_beta_h().keys()),
+            "The degree bounds of the negative powers of beta H changed"
+        );
+
+        check_powers(&self.params, check, rng)?;
+        self.proof.check(previous, check, rng)?;
+
+        let h = self.params.h;
+        // e(\tau \beta G, H) = e(\beta G, \tau H).
+        check.add(&[(powers[1].to_projective(), h), (-previous_powers[1].to_projective(), self.proof.tau_h)], rng);
+        // e(\rho \gamma G, H) = e(\gamma G, \rho H).
+        let gamma_g = self.params.powers_of_beta_times_gamma_g()[&0];
+        let previous_gamma_g = previous.powers_of_beta_times_gamma_g()[&0];
+        check.add(&[(gamma_g.to_projective(), h), (-previous_gamma_g.to_projective(), self.proof.rho_h)], rng);
+        Ok(())
+    }
+}
+
+impl<E: PairingEngine> FromBytes for Contribution<E> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        let params = FromBytes::read_le(&mut reader)?;
+        let proof = FromBytes::read_le(&mut reader)?;
+        Ok(Self { params, proof })
+    }
+}
+
+impl<E: PairingEngine> ToBytes for Contribution<E> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        self.params.write_le(&mut writer)?;
+        self.proof.write_le(&mut writer)
+    }
+}
+
+/// The transcript of a ceremony: the initial parameters, followed by every contribution in order.
+#[derive(Clone, Debug)]
+pub struct Transcript<E: PairingEngine> {
+    /// The parameters the ceremony started from.
+    initial: UniversalParams<E>,
+    /// The contributions, in the order in which they were made.
+    contributions: Vec<Contribution<E>>,
+}
+
+impl<E: PairingEngine> Transcript<E> {
+    /// Starts a ceremony from the `initial` parameters.
+    pub fn new(initial: UniversalParams<E>) -> Self {
+        Self { initial, contributions: Vec::new() }
+    }
+
+    /// Returns the parameters the ceremony started from.
+    pub fn initial(&self) -> &UniversalParams<E> {
+        &self.initial
+    }
+
+    /// Returns the contributions, in the order in which they were made.
+    pub fn contributions(&self) -> &[Contribution<E>] {
+        &self.contributions
+    }
+
+    /// Returns the parameters after the latest contribution.
+    pub fn latest(&self) -> &UniversalParams<E> {
+        self.contributions.last().map_or(&self.initial, |contribution| &contribution.params)
+    }
+
+    /// Contributes fresh randomness to the latest parameters, and returns the proof of the contribution.
+    pub fn contribute<R: Rng + CryptoRng>(&mut self, rng: &mut R) -> Result<&ContributionProof<E>> {
+        let contribution = Contribution::new(self.latest(), rng)?;
+        self.contributions.push(contribution);
+        Ok(&self.contributions[self.contributions.len() - 1].proof)
+    }
+
+    /// Appends a contribution made by another party, after verifying it against the latest parameters.
+    pub fn add_contribution<R: Rng + CryptoRng>(&mut self, contribution: Contribution<E>, rng: &mut R) -> Result<()> {
+        contribution.verify(self.latest(), rng)?;
+        self.contributions.push(contribution);
+        Ok(())
+    }
+
+    /// Verifies the initial parameters and every contribution, with a single batched pairing check.
+    pub fn verify<R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<()> {
+        let mut check = PairingCheck::new();
+        check_powers(&self.initial, &mut check, rng)?;
+
+        let mut previous = &self.initial;
+        for (i, contribution) in self.contributions.iter().enumerate() {
+            contribution.check(previous, &mut check, rng).map_err(|e| anyhow!("Contribution {i} is invalid: {e}"))?;
+            previous = &contribution.params;
+        }
+
+        ensure!(check.verify(), "The ceremony transcript failed its pairing checks");
+        Ok(())
+    }
+}
+
+impl<E: PairingEngine> FromBytes for Transcript<E> {
+    fn read_le<R: Read>(mut reader: R) -> io::Result<Self> {
+        let initial = FromBytes::read_le(&mut reader)?;
+        let num_contributions: u32 = FromBytes::read_le(&mut reader)?;
+        let contributions =
+            (0..num_contributions).map(|_| FromBytes::read_le(&mut reader)).collect::<io::Result<Vec<_>>>()?;
+        Ok(Self { initial, contributions })
+    }
+}
+
+impl<E: PairingEngine> ToBytes for Transcript<E> {
+    fn write_le<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        self.initial.write_le(&mut writer)?;
+        (self.contributions.len() as u32).write_le(&mut writer)?;
+        for contribution in &self.contributions {
+            contribution.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// A batch of pairing equations of the form `\prod_i e(A_i, B_i) = 1`.
+/// Each equation is scaled by a random coefficient, so that the whole batch
+/// is checked with a single multi-pairing.
+struct PairingCheck<E: PairingEngine> {
+    /// The accumulated G1 element for each distinct G2 element.
+    terms: Vec<(E::G1Projective, E::G2Affine)>,
+}
+
+impl<E: PairingEngine> PairingCheck<E> {
+    fn new() -> Self {
+        Self { terms: Vec::new() }
+    }
+
+    /// Adds the equation `\prod_i e(A_i, B_i) = 1` to the batch.
+    fn add<R: Rng>(&mut self, equation: &[(E::G1Projective, E::G2Affine)], rng: &mut R) {
+        let coefficient = E::Fr::rand(rng);
+        for (g1, g2) in equation {
+            let g1 = *g1 * coefficient;
+            match self.terms.iter_mut().find(|(_, term)| term == g2) {
+                Some((accumulator, _)) => *accumulator += g1,
+                None => self.terms.push((g1, *g2)),
+            }
+        }
+    }
+
+    /// Returns `true` if every equation in the batch holds, except with negligible probability.
+    fn verify(self) -> bool {
+        let (g1_elems, g2_elems): (Vec<_>, Vec<_>) = self.terms.into_iter().unzip();
+        let g1_prepared_elems = E::G1Projective::batch_normalization_into_affine(g1_elems)
+            .into_iter()
+            .map(|a| a.prepare())
+            .collect::<Vec<_>>();
+        let g2_prepared_elems = g2_elems.iter().map(|a| a.prepare()).collect::<Vec<_>>();
+        E::product_of_pairings(g1_prepared_elems.iter().zip(g2_prepared_elems.iter())).is_one()
+    }
+}
+
+/// Adds to `check` the pairing equations which ensure that the powers of `params` which are in memory
+/// are well-formed for some `beta` and `gamma`. In detail, that:
+/// * consecutive powers of beta G and of beta times gamma G differ by a factor of `beta`,
+/// * `beta H` is consistent with `beta G`, and
+/// * each negative power of beta H is the inverse of a power of beta G or of beta times gamma G.
+fn check_powers<E: PairingEngine, R: Rng>(
+    params: &UniversalParams<E>,
+    check: &mut PairingCheck<E>,
+    rng: &mut R,
+) -> Result<()> {
+    let g = E::G1Affine::prime_subgroup_generator();
+    let h = params.h;
+    let beta_h = params.beta_h();
+    let max_degree = params.max_degree();
+
+    let (powers, shifted_powers) = params.loaded_powers_of_beta_g();
+    ensure!(powers.len() >= 2, "The parameters must contain at least two powers of beta G");
+    ensure!(powers[0] == g, "The 0-th power of beta G must be the generator of G1");
+    ensure!(h == E::G2Affine::prime_subgroup_generator(), "H must be the generator of G2");
+    ensure!(!beta_h.is_zero(), "beta H must be non-zero");
+    let shifted_start = max_degree + 1 - shifted_powers.len();
+    let power_of_beta_g = |i: usize| match i < powers.len() {
+        true => Some(powers[i]),
+        false => i.checked_sub(shifted_start).and_then(|j| shifted_powers.get(j).copied()),
+    };
+
+    let powers_of_beta_times_gamma_g = params.powers_of_beta_times_gamma_g();
+    let gamma_g = *powers_of_beta_times_gamma_g.get(&0).ok_or_else(|| anyhow!("Missing gamma G"))?;
+    ensure!(!gamma_g.is_zero(), "gamma G must be non-zero");
+
+    // Collect every pair of consecutive powers `(x, \beta x)`.
+    let mut lower = Vec::with_capacity(powers.len() + shifted_powers.len());
+    let mut upper = Vec::with_capacity(powers.len() + shifted_powers.len());
+    lower.extend_from_slice(&powers[..powers.len() - 1]);
+    upper.extend_from_slice(&powers[1..]);
+    if shifted_powers.len() >= 2 {
+        lower.extend_from_slice(&shifted_powers[..shifted_powers.len() - 1]);
+        upper.extend_from_slice(&shifted_powers[1..]);
+    }
+    let mut checked_gamma_powers = BTreeSet::from([0]);
+    for (i, power) in powers_of_beta_times_gamma_g.iter() {
+        if let Some(next_power) = powers_of_beta_times_gamma_g.get(&(i + 1)) {
+            lower.push(*power);
+            upper.push(*next_power);
+            checked_gamma_powers.extend([*i, i + 1]);
+        }
+    }
+
+    // e(\sum_i r_i \beta x_i, H) = e(\sum_i r_i x_i, \beta H).
+    let coefficients = (0..lower.len()).map(|_| E::Fr::rand(rng).to_bigint()).collect::<Vec<_>>();
+    let lower = VariableBase::msm(&lower, &coefficients);
+    let upper = VariableBase::msm(&upper, &coefficients);
+    check.add(&[(upper, h), (-lower, beta_h)], rng);
+
+    // e(\beta G, H) = e(G, \beta H).
+    check.add(&[(powers[1].to_projective(), h), (-g.to_projective(), beta_h)], rng);
+
+    // e(\beta^{max_degree - d} G, \beta^{-(max_degree - d)} H) = e(G, H),
+    // and likewise for the powers of beta times gamma G.
+    for (degree_bound, negative_power) in params.neg_powers_of_beta_h().iter() {
+        let shift = max_degree
+            .checked_sub(*degree_bound)
+            .ok_or_else(|| anyhow!("The degree bound {degree_bound} exceeds the maximum degree"))?;
+        let mut is_checked = false;
+        if let Some(power) = power_of_beta_g(shift) {
+            check.add(&[(power.to_projective(), *negative_power), (-g.to_projective(), h)], rng);
+            is_checked = true;
+        }
+        if let Some(power) = powers_of_beta_times_gamma_g.get(&shift) {
+            check.add(&[(power.to_projective(), *negative_power), (-gamma_g.to_projective(), h)], rng);
+            checked_gamma_powers.insert(shift);
+            is_checked = true;
+        }
+        ensure!(is_checked, "The negative power of beta H for degree bound {degree_bound} cannot be checked");
+    }
+
+    if let Some(i) = powers_of_beta_times_gamma_g.keys().find(|i| !checked_gamma_powers.contains(i)) {
+        return Err(anyhow!("The {i}-th power of beta times gamma G cannot be checked"));
+    }
+    Ok(())
+}
+
+/// Returns `\tau^{offset + i} P_i` for each point `P_i` in `powers`.
+fn rerandomize_powers<G: AffineCurve>(powers: &[G], tau: G::ScalarField, offset: usize) -> Vec<G> {
+    let mut scalar = tau.pow([offset as u64]);
+    let scalars = powers
+        .iter()
+        .map(|_| {
+            let current = scalar;
+            scalar *= tau;
+            current
+        })
+        .collect::<Vec<_>>();
+    let powers = cfg_iter!(powers).zip(cfg_iter!(scalars)).map(|(power, scalar)| *power * *scalar).collect::<Vec<_>>();
+    G::Projective::batch_normalization_into_affine(powers)
+}
+
+/// Samples a non-zero field element.
+fn sample_nonzero<F: Field, R: Rng>(rng: &mut R) -> F {
+    loop {
+        let element = F::rand(rng);
+        if !element.is_zero() {
+            return element;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fft::DensePolynomial, polycommit::kzg10::KZG10};
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr, G1Affine, G2Affine};
+    use snarkvm_utilities::rand::TestRng;
+
+    use std::{collections::BTreeMap, sync::atomic::AtomicBool};
+
+    /// Samples parameters with a known trapdoor, shaped like the hard-coded parameters but with far fewer powers.
+    fn sample_params(rng: &mut TestRng) -> UniversalParams<Bls12_377> {
+        const NUM_POWERS: usize = 64;
+        let max_degree = (1usize << 28) - 1;
+        let beta = Fr::rand(rng);
+        let gamma = Fr::rand(rng);
+        let beta_inverse = beta.inverse().unwrap();
+        let g = G1Affine::prime_subgroup_generator();
+        let h = G2Affine::prime_subgroup_generator();
+
+        let power_of_beta_g = |i: usize| (g * beta.pow([i as u64])).to_affine();
+        let powers_of_beta_g = (0..NUM_POWERS).map(power_of_beta_g).collect();
+        let shifted_powers_of_beta_g = ((max_degree + 1 - NUM_POWERS)..=max_degree).map(power_of_beta_g).collect();
+
+        let degree_bounds = [0, 2, 6, 14, 30, 62];
+        let mut powers_of_beta_times_gamma_g = BTreeMap::new();
+        for shift in [0].into_iter().chain(degree_bounds.iter().map(|d| max_degree - d)) {
+            for i in shift..(shift + 3) {
+                powers_of_beta_times_gamma_g.insert(i, (g * (gamma * beta.pow([i as u64]))).to_affine());
+            }
+        }
+        let negative_powers_of_beta_h =
+            degree_bounds.iter().map(|d| (*d, (h * beta_inverse.pow([(max_degree - d) as u64])).to_affine())).collect();
+
+        let powers = PowersOfG::from_parts(
+            powers_of_beta_g,
+            shifted_powers_of_beta_g,
+            powers_of_beta_times_gamma_g,
+            negative_powers_of_beta_h,
+            (h * beta).to_affine(),
+        )
+        .unwrap();
+        let params = UniversalParams::from_powers(powers);
+        assert_eq!(params.max_degree(), max_degree);
+        params
+    }
+
+    fn transcript_with_contributions(num_contributions: usize, rng: &mut TestRng) -> Transcript<Bls12_377> {
+        let mut transcript = Transcript::new(sample_params(rng));
+        for _ in 0..num_contributions {
+            transcript.contribute(rng).unwrap();
+        }
+        transcript
+    }
+
+    #[test]
+    fn test_transcript_verifies() {
+        let rng = &mut TestRng::default();
+        let transcript = transcript_with_contributions(3, rng);
+        assert_eq!(transcript.contributions().len(), 3);
+        transcript.verify(rng).unwrap();
+
+        let previous = &transcript.contributions()[1].params;
+        transcript.contributions()[2].verify(previous, rng).unwrap();
+    }
+
+    #[test]
+    fn test_transcript_from_hard_coded_params_verifies() {
+        let rng = &mut TestRng::default();
+        let mut transcript = Transcript::new(UniversalParams::<Bls12_377>::load().unwrap());
+        transcript.contribute(rng).unwrap();
+        transcript.verify(rng).unwrap();
+    }
+
+    #[test]
+    fn test_contributed_params_commit_and_open() {
+        let rng = &mut TestRng::default();
+        let transcript = transcript_with_contributions(1, rng);
+
+        let degree = 20;
+        let hiding_bound = Some(1);
+        let (ck, vk) = KZG10::trim(transcript.latest(), degree, hiding_bound);
+        let p = DensePolynomial::rand(degree, rng);
+        let (comm, rand) =
+            KZG10::<Bls12_377>::commit(&ck, &(&p).into(), hiding_bound, &AtomicBool::new(false), Some(rng)).unwrap();
+        let point = Fr::rand(rng);
+        let value = p.evaluate(point);
+        let proof = KZG10::<Bls12_377>::open(&ck, &p, point, &rand).unwrap();
+        assert!(KZG10::<Bls12_377>::check(&vk, &comm, point, value, &proof).unwrap());
+        assert_ne!(ck.powers_of_beta_g[1], transcript.initial().power_of_beta_g(1).unwrap());
+    }
+
+    #[test]
+    fn test_tampered_powers_fail() {
+        let rng = &mut TestRng::default();
+        let mut transcript = transcript_with_contributions(1, rng);
+
+        let params = &transcript.contributions()[0].params;
+        let (mut powers, shifted_powers) = params.loaded_powers_of_beta_g();
+        powers[7] = (powers[7] * Fr::from(2u64)).to_affine();
+        let tampered = PowersOfG::from_parts(
+            powers,
+            shifted_powers,
+            (*params.powers_of_beta_times_gamma_g()).clone(),
+            (*params.neg_powers_of_beta_h()).clone(),
+            params.beta_h(),
+        )
+        .unwrap();
+        transcript.contributions[0].params = UniversalParams::from_powers(tampered);
+        assert!(transcript.verify(rng).is_err());
+    }
+
+    #[test]
+    fn test_replayed_proof_fails() {
+        let rng = &mut TestRng::default();
+        let mut transcript = transcript_with_contributions(2, rng);
+
+        transcript.contributions[1].proof = transcript.contributions[0].proof;
+        assert!(transcript.verify(rng).is_err());
+
+        let mut contribution = Contribution::new(transcript.initial(), rng).unwrap();
+        contribution.proof.tau_response += Fr::one();
+        assert!(contribution.verify(transcript.initial(), rng).is_err());
+    }
+
+    #[test]
+    fn test_add_contribution() {
+        let rng = &mut TestRng::default();
+        let mut transcript = transcript_with_contributions(1, rng);
+
+        let contribution = Contribution::new(transcript.initial(), rng).unwrap();
+        assert!(transcript.add_contribution(contribution, rng).is_err());
+
+        let contribution = Contribution::new(transcript.latest(), rng).unwrap();
+        transcript.add_contribution(contribution, rng).unwrap();
+        assert_eq!(transcript.contributions().len(), 2);
+    }
+
+    #[test]
+    fn test_transcript_serialization() {
+        let rng = &mut TestRng::default();
+        let transcript = transcript_with_contributions(2, rng);
+
+        let bytes = transcript.to_bytes_le().unwrap();
+        let recovered = Transcript::<Bls12_377>::read_le(&bytes[..]).unwrap();
+        assert_eq!(bytes, recovered.to_bytes_le().unwrap());
+        recovered.verify(rng).unwrap();
+    }
+
+    #[test]
+    fn test_contributed_params_do_not_download_powers() {
+        let rng = &mut TestRng::default();
+        let transcript = transcript_with_contributions(1, rng);
+
+        let num_powers = transcript.latest().loaded_powers_of_beta_g().0.len();
+        assert!(transcript.latest().download_powers_for(0..(num_powers + 1)).is_err());
+        assert!(transcript.latest().powers_of_beta_g(0, num_powers + 1).is_err());
+    }
+}
@@ -57,13 +57,18 @@ pub struct UniversalParams<E: PairingEngine> {
 
 impl<E: PairingEngine> UniversalParams<E> {
     pub fn load() -> Result<Self> {
-        let powers = Arc::new(RwLock::new(PowersOfG::<E>::load()?));
+        Ok(Self::from_powers(PowersOfG::<E>::load()?))
+    }
+
+    /// Initializes the universal parameters from the given powers, such as the output of a setup ceremony.
+    pub fn from_powers(powers: PowersOfG<E>) -> Self {
         let h = E::G2Affine::prime_subgroup_generator();
         let prepared_h = h.prepare();
-        let prepared_beta_h = powers.read().beta_h().prepare();
+        let prepared_beta_h = powers.beta_h().prepare();
+        let powers = Arc::new(RwLock::new(powers));
         let supported_degree_bounds = vec![1 << 10, 1 << 15, 1 << 20, 1 << 25, 1 << 30];
 
-        Ok(Self { powers, h, supported_degree_bounds, prepared_h, prepared_beta_h })
+        Self { powers, h, supported_degree_bounds, prepared_h, prepared_beta_h }
     }
 
     pub fn download_powers_for(&self, range: Range<usize>) -> Result<()> {
@@ -96,6 +101,14 @@ impl<E: PairingEngine> UniversalParams<E> {
         self.powers.read().negative_powers_of_beta_h()
     }
 
+    /// Returns the powers of beta G that are in memory, as the contiguous powers starting from G,
+    /// and the shifted powers ending at `\beta^{max_degree} G`.
+    pub fn loaded_powers_of_beta_g(&self) -> (Vec<E::G1Affine>, Vec<E::G1Affine>) {
+        let powers = self.powers.read();
+        let (normal, shifted) = powers.loaded_powers_of_beta_g();
+        (normal.to_vec(), shifted.to_vec())
+    }
+
     pub fn max_degree(&self) -> usize {
         self.powers.read().max_num_powers() - 1
     }
@@ -45,6 +45,8 @@ use rayon::prelude::*;
 mod data_structures;
 pub use data_structures::*;
 
+pub mod ceremony;
+
 use super::sonic_pc::LabeledPolynomialWithBasis;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -37,6 +37,14 @@ pub mod kzg10;
 /// [marlin]: https://eprint.iacr.org/2019/1047
 pub mod sonic_pc;
 
+/// Polynomial commitment scheme based on the inner product argument of
+/// [[BCCGP16]][bccgp] and [[BBBPWM18, “Bulletproofs”]][bulletproofs], which
+/// does not require a trusted setup.
+///
+/// [bccgp]: https://eprint.iacr.org/2016/263
+/// [bulletproofs]: https://eprint.iacr.org/2017/1066
+pub mod ipa_pc;
+
 /// Errors pertaining to query sets.
 pub mod error;
 pub use error::*;
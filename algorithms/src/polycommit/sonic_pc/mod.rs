@@ -485,6 +485,34 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         rands: impl IntoIterator<Item = &'a Randomness<E>>,
         fs_rng: &mut S,
     ) -> Result<BatchLCProof<E>, PCError>
+    where
+        Randomness<E>: 'a,
+        Commitment<E>: 'a,
+    {
+        let (lc_polynomials, lc_commitments, lc_randomness) =
+            Self::combine_lcs_for_open(linear_combinations, polynomials, commitments, rands)?;
+
+        let proof = Self::batch_open(
+            ck,
+            lc_polynomials.iter(),
+            lc_commitments.iter(),
+            query_set,
+            lc_randomness.iter(),
+            fs_rng,
+        )?;
+
+        Ok(BatchLCProof { proof, evaluations: None })
+    }
+
+    /// Combines `polynomials`, their `commitments`, and their `rands` according to `linear_combinations`.
+    /// The outputs are labeled by the linear combinations, and can be opened via [`Self::batch_open`].
+    #[allow(clippy::type_complexity)]
+    pub fn combine_lcs_for_open<'a>(
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        polynomials: impl IntoIterator<Item = &'a LabeledPolynomial<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        rands: impl IntoIterator<Item = &'a Randomness<E>>,
+    ) -> Result<(Vec<LabeledPolynomial<E::Fr>>, Vec<LabeledCommitment<Commitment<E>>>, Vec<Randomness<E>>), PCError>
     where
         Randomness<E>: 'a,
         Commitment<E>: 'a,
@@ -542,16 +570,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .map(|((label, d), c)| LabeledCommitment::new(label, c, d))
             .collect::<Vec<_>>();
 
-        let proof = Self::batch_open(
-            ck,
-            lc_polynomials.iter(),
-            lc_commitments.iter(),
-            query_set,
-            lc_randomness.iter(),
-            fs_rng,
-        )?;
-
-        Ok(BatchLCProof { proof, evaluations: None })
+        Ok((lc_polynomials, lc_commitments, lc_randomness))
     }
 
     /// Checks that `values` are the true evaluations at `query_set` of the polynomials
@@ -569,6 +588,22 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         Commitment<E>: 'a,
     {
         let BatchLCProof { proof, .. } = proof;
+        let (lc_commitments, evaluations) = Self::combine_lcs_for_check(linear_combinations, commitments, evaluations)?;
+
+        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+    }
+
+    /// Combines `commitments` according to `linear_combinations`, and adjusts `evaluations`
+    /// by the constant terms of the linear combinations.
+    /// The outputs are labeled by the linear combinations, and can be checked via [`Self::batch_check`].
+    pub fn combine_lcs_for_check<'a>(
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        evaluations: &Evaluations<E::Fr>,
+    ) -> Result<(Vec<LabeledCommitment<Commitment<E>>>, Evaluations<'a, E::Fr>), PCError>
+    where
+        Commitment<E>: 'a,
+    {
         let label_comm_map = commitments.into_iter().map(|c| (c.label(), c)).collect::<BTreeMap<_, _>>();
 
         let mut lc_commitments = Vec::new();
@@ -620,7 +655,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+        Ok((lc_commitments, evaluations))
     }
 }
 
@@ -32,12 +32,51 @@ use crate::{
 use snarkvm_fields::PrimeField;
 
 impl<TargetField: PrimeField, MM: MarlinMode> AHPForR1CS<TargetField, MM> {
-    /// Output the first message and next round state.
+    /// Output the first messages and next round states, one for each circuit in `index_infos`.
+    ///
+    /// The challenges `alpha`, `eta_b`, and `eta_c` are shared across all circuits,
+    /// while each circuit receives its own `batch_combiners`.
+    #[allow(clippy::type_complexity)]
     pub fn verifier_first_round<BaseField: PrimeField, R: AlgebraicSponge<BaseField, 2>>(
-        index_info: CircuitInfo<TargetField>,
-        batch_size: usize,
+        index_infos: &[(CircuitInfo<TargetField>, usize)],
         fs_rng: &mut R,
-    ) -> Result<(FirstMessage<TargetField>, State<TargetField, MM>), AHPError> {
+    ) -> Result<(Vec<FirstMessage<TargetField>>, Vec<State<TargetField, MM>>), AHPError> {
+        let mut states = Vec::with_capacity(index_infos.len());
+        for (index_info, batch_size) in index_infos {
+            states.push(Self::verifier_init_state(index_info, *batch_size)?);
+        }
+
+        let squeeze_time = start_timer!(|| "Squeezing challenges");
+        let num_combiners = states.iter().map(|state| state.batch_size - 1).sum::<usize>();
+        let elems = fs_rng.squeeze_nonnative_field_elements(3 + num_combiners);
+        let (first, mut rest) = elems.split_at(3);
+        let [alpha, eta_b, eta_c]: [_; 3] = first.try_into().unwrap();
+        end_timer!(squeeze_time);
+
+        let mut messages = Vec::with_capacity(states.len());
+        for state in &mut states {
+            let check_vanish_poly_time = start_timer!(|| "Evaluating vanishing polynomial");
+            assert!(!state.constraint_domain.evaluate_vanishing_polynomial(alpha).is_zero());
+            end_timer!(check_vanish_poly_time);
+
+            let (combiners, remaining) = rest.split_at(state.batch_size - 1);
+            rest = remaining;
+            let mut batch_combiners = vec![TargetField::one()];
+            batch_combiners.extend_from_slice(combiners);
+
+            let message = FirstMessage { alpha, eta_b, eta_c, batch_combiners };
+            state.first_round_message = Some(message.clone());
+            messages.push(message);
+        }
+
+        Ok((messages, states))
+    }
+
+    /// Output the initial state of the verifier for a circuit with the given `index_info`.
+    fn verifier_init_state(
+        index_info: &CircuitInfo<TargetField>,
+        batch_size: usize,
+    ) -> Result<State<TargetField, MM>, AHPError> {
         // Check that the R1CS is a square matrix.
         if index_info.num_constraints != index_info.num_variables {
             return Err(AHPError::NonSquareMatrix);
@@ -68,76 +107,66 @@ impl<TargetField: PrimeField, MM: MarlinMode> AHPForR1CS<TargetField, MM> {
             EvaluationDomain::new(index_info.num_public_inputs).ok_or(AHPError::PolynomialDegreeTooLarge)?;
         end_timer!(input_domain_time);
 
-        let squeeze_time = start_timer!(|| "Squeezing challenges");
-        let elems = fs_rng.squeeze_nonnative_field_elements(3 + batch_size - 1);
-        let (first, rest) = elems.split_at(3);
-        let [alpha, eta_b, eta_c]: [_; 3] = first.try_into().unwrap();
-        let mut batch_combiners = vec![TargetField::one()];
-        batch_combiners.extend_from_slice(rest);
-        end_timer!(squeeze_time);
-
-        let check_vanish_poly_time = start_timer!(|| "Evaluating vanishing polynomial");
-        assert!(!constraint_domain.evaluate_vanishing_polynomial(alpha).is_zero());
-        end_timer!(check_vanish_poly_time);
-
-        let message = FirstMessage { alpha, eta_b, eta_c, batch_combiners };
-
-        let new_state = State {
+        Ok(State {
             batch_size,
             input_domain,
             constraint_domain,
             non_zero_a_domain,
             non_zero_b_domain,
             non_zero_c_domain,
-            first_round_message: Some(message.clone()),
+            first_round_message: None,
             second_round_message: None,
             third_round_message: None,
             gamma: None,
             mode: PhantomData,
-        };
-
-        Ok((message, new_state))
+        })
     }
 
-    /// Output the second message and next round state.
+    /// Output the second message and next round states.
     pub fn verifier_second_round<BaseField: PrimeField, R: AlgebraicSponge<BaseField, 2>>(
-        mut state: State<TargetField, MM>,
+        mut states: Vec<State<TargetField, MM>>,
         fs_rng: &mut R,
-    ) -> Result<(SecondMessage<TargetField>, State<TargetField, MM>), AHPError> {
+    ) -> Result<(SecondMessage<TargetField>, Vec<State<TargetField, MM>>), AHPError> {
         let elems = fs_rng.squeeze_nonnative_field_elements(1);
         let beta = elems[0];
-        assert!(!state.constraint_domain.evaluate_vanishing_polynomial(beta).is_zero());
 
         let message = SecondMessage { beta };
-        state.second_round_message = Some(message);
+        for state in &mut states {
+            assert!(!state.constraint_domain.evaluate_vanishing_polynomial(beta).is_zero());
+            state.second_round_message = Some(message);
+        }
 
-        Ok((message, state))
+        Ok((message, states))
     }
 
-    /// Output the third message and next round state.
+    /// Output the third message and next round states.
     pub fn verifier_third_round<BaseField: PrimeField, R: AlgebraicSponge<BaseField, 2>>(
-        mut state: State<TargetField, MM>,
+        mut states: Vec<State<TargetField, MM>>,
         fs_rng: &mut R,
-    ) -> Result<(ThirdMessage<TargetField>, State<TargetField, MM>), AHPError> {
+    ) -> Result<(ThirdMessage<TargetField>, Vec<State<TargetField, MM>>), AHPError> {
         let elems = fs_rng.squeeze_nonnative_field_elements(2);
         let r_b = elems[0];
         let r_c = elems[1];
         let message = ThirdMessage { r_b, r_c };
 
-        state.third_round_message = Some(message);
-        Ok((message, state))
+        for state in &mut states {
+            state.third_round_message = Some(message);
+        }
+        Ok((message, states))
     }
 
-    /// Output the third message and next round state.
+    /// Output the next round states after the fourth round.
     pub fn verifier_fourth_round<BaseField: PrimeField, R: AlgebraicSponge<BaseField, 2>>(
-        mut state: State<TargetField, MM>,
+        mut states: Vec<State<TargetField, MM>>,
         fs_rng: &mut R,
-    ) -> Result<State<TargetField, MM>, AHPError> {
+    ) -> Result<Vec<State<TargetField, MM>>, AHPError> {
         let elems = fs_rng.squeeze_nonnative_field_elements(1);
         let gamma = elems[0];
 
-        state.gamma = Some(gamma);
-        Ok(state)
+        for state in &mut states {
+            state.gamma = Some(gamma);
+        }
+        Ok(states)
     }
 
     /// Output the query state and next round state.
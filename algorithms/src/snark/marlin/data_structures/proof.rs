@@ -212,19 +212,22 @@ impl<F: PrimeField> Evaluations<F> {
 }
 
 /// A zkSNARK proof.
+///
+/// A proof attests to a batch of instances for each of one or more circuits.
+/// The per-circuit prover messages are kept separately, while the opening proof is shared.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Proof<E: PairingEngine> {
-    /// The number of instances being proven in this proof.
-    batch_size: usize,
+    /// The number of instances being proven for each circuit in this proof.
+    batch_sizes: Vec<usize>,
 
-    /// Commitments to prover polynomials.
-    pub commitments: Commitments<E>,
+    /// Commitments to prover polynomials, for each circuit.
+    pub commitments: Vec<Commitments<E>>,
 
-    /// Evaluations of some of the committed polynomials.
-    pub evaluations: Evaluations<E::Fr>,
+    /// Evaluations of some of the committed polynomials, for each circuit.
+    pub evaluations: Vec<Evaluations<E::Fr>>,
 
-    /// Prover message: sum_a, sum_b, sum_c
-    pub msg: ahp::prover::ThirdMessage<E::Fr>,
+    /// Prover message: sum_a, sum_b, sum_c, for each circuit.
+    pub msg: Vec<ahp::prover::ThirdMessage<E::Fr>>,
 
     /// An evaluation proof from the polynomial commitment.
     pub pc_proof: sonic_pc::BatchLCProof<E>,
@@ -233,48 +236,82 @@ pub struct Proof<E: PairingEngine> {
 impl<E: PairingEngine> Proof<E> {
     /// Construct a new proof.
     pub fn new(
-        batch_size: usize,
-        commitments: Commitments<E>,
-        evaluations: Evaluations<E::Fr>,
-        msg: ahp::prover::ThirdMessage<E::Fr>,
+        batch_sizes: Vec<usize>,
+        commitments: Vec<Commitments<E>>,
+        evaluations: Vec<Evaluations<E::Fr>>,
+        msg: Vec<ahp::prover::ThirdMessage<E::Fr>>,
         pc_proof: sonic_pc::BatchLCProof<E>,
     ) -> Result<Self, SNARKError> {
-        if commitments.witness_commitments.len() != batch_size {
-            return Err(SNARKError::BatchSizeMismatch);
-        }
-        if evaluations.z_b_evals.len() != batch_size {
-            return Err(SNARKError::BatchSizeMismatch);
-        }
-        Ok(Self { batch_size, commitments, evaluations, msg, pc_proof })
+        let proof = Self { batch_sizes, commitments, evaluations, msg, pc_proof };
+        proof.batch_sizes()?;
+        Ok(proof)
     }
 
-    pub fn batch_size(&self) -> Result<usize, SNARKError> {
-        if self.commitments.witness_commitments.len() != self.batch_size {
-            return Err(SNARKError::BatchSizeMismatch);
+    /// Returns the number of instances proven for each circuit.
+    pub fn batch_sizes(&self) -> Result<&[usize], SNARKError> {
+        if self.batch_sizes.is_empty() || self.batch_sizes.contains(&0) {
+            return Err(SNARKError::EmptyBatch);
         }
-        if self.evaluations.z_b_evals.len() != self.batch_size {
+        if self.commitments.len() != self.batch_sizes.len()
+            || self.evaluations.len() != self.batch_sizes.len()
+            || self.msg.len() != self.batch_sizes.len()
+        {
             return Err(SNARKError::BatchSizeMismatch);
         }
-        Ok(self.batch_size)
+        for ((batch_size, commitments), evaluations) in
+            self.batch_sizes.iter().zip(&self.commitments).zip(&self.evaluations)
+        {
+            if commitments.witness_commitments.len() != *batch_size {
+                return Err(SNARKError::BatchSizeMismatch);
+            }
+            if evaluations.z_b_evals.len() != *batch_size {
+                return Err(SNARKError::BatchSizeMismatch);
+            }
+        }
+        Ok(&self.batch_sizes)
+    }
+
+    /// Returns the number of circuits proven in this proof.
+    pub fn num_circuits(&self) -> usize {
+        self.batch_sizes.len()
     }
 }
 
+/// A proof for a single circuit is serialized as its batch size, followed by its commitments,
+/// evaluations, prover message, and opening proof. A proof for multiple circuits is serialized as
+/// a batch size of `0`, followed by the number of circuits, their batch sizes, their commitments,
+/// evaluations, and prover messages, and the shared opening proof.
 impl<E: PairingEngine> CanonicalSerialize for Proof<E> {
     fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
-        CanonicalSerialize::serialize_with_mode(&self.batch_size, &mut writer, compress)?;
-        Commitments::serialize_with_mode(&self.commitments, &mut writer, compress)?;
-        Evaluations::serialize_with_mode(&self.evaluations, &mut writer, compress)?;
-        CanonicalSerialize::serialize_with_mode(&self.msg, &mut writer, compress)?;
+        match self.batch_sizes.as_slice() {
+            [batch_size] => CanonicalSerialize::serialize_with_mode(batch_size, &mut writer, compress)?,
+            batch_sizes => {
+                CanonicalSerialize::serialize_with_mode(&0usize, &mut writer, compress)?;
+                CanonicalSerialize::serialize_with_mode(&batch_sizes.len(), &mut writer, compress)?;
+                for batch_size in batch_sizes {
+                    CanonicalSerialize::serialize_with_mode(batch_size, &mut writer, compress)?;
+                }
+            }
+        }
+        for ((commitments, evaluations), msg) in self.commitments.iter().zip(&self.evaluations).zip(&self.msg) {
+            Commitments::serialize_with_mode(commitments, &mut writer, compress)?;
+            Evaluations::serialize_with_mode(evaluations, &mut writer, compress)?;
+            CanonicalSerialize::serialize_with_mode(msg, &mut writer, compress)?;
+        }
         CanonicalSerialize::serialize_with_mode(&self.pc_proof, &mut writer, compress)?;
         Ok(())
     }
 
     fn serialized_size(&self, mode: Compress) -> usize {
         let mut size = 0;
-        size += CanonicalSerialize::serialized_size(&self.batch_size, mode);
-        size += Commitments::serialized_size(&self.commitments, mode);
-        size += Evaluations::serialized_size(&self.evaluations, mode);
-        size += CanonicalSerialize::serialized_size(&self.msg, mode);
+        size += CanonicalSerialize::serialized_size(&self.batch_sizes[0], mode);
+        if self.batch_sizes.len() > 1 {
+            size += CanonicalSerialize::serialized_size(&self.batch_sizes.len(), mode);
+            size += self.batch_sizes.iter().map(|b| CanonicalSerialize::serialized_size(b, mode)).sum::<usize>();
+        }
+        size += self.commitments.iter().map(|c| Commitments::serialized_size(c, mode)).sum::<usize>();
+        size += self.evaluations.iter().map(|e| Evaluations::serialized_size(e, mode)).sum::<usize>();
+        size += self.msg.iter().map(|m| CanonicalSerialize::serialized_size(m, mode)).sum::<usize>();
         size += CanonicalSerialize::serialized_size(&self.pc_proof, mode);
         size
     }
@@ -282,7 +319,7 @@ impl<E: PairingEngine> CanonicalSerialize for Proof<E> {
 
 impl<E: PairingEngine> Valid for Proof<E> {
     fn check(&self) -> Result<(), SerializationError> {
-        self.batch_size.check()?;
+        self.batch_sizes.check()?;
         self.commitments.check()?;
         self.evaluations.check()?;
         self.msg.check()?;
@@ -296,12 +333,41 @@ impl<E: PairingEngine> CanonicalDeserialize for Proof<E> {
         compress: Compress,
         validate: Validate,
     ) -> Result<Self, SerializationError> {
-        let batch_size = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let batch_size: usize = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let batch_sizes = match batch_size {
+            0 => {
+                let num_circuits: usize = CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+                // A proof for a single circuit must use the single-circuit encoding.
+                if num_circuits < 2 {
+                    return Err(SerializationError::InvalidData);
+                }
+                let mut batch_sizes = Vec::new();
+                for _ in 0..num_circuits {
+                    let batch_size: usize =
+                        CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?;
+                    if batch_size == 0 {
+                        return Err(SerializationError::InvalidData);
+                    }
+                    batch_sizes.push(batch_size);
+                }
+                batch_sizes
+            }
+            batch_size => vec![batch_size],
+        };
+
+        let mut commitments = Vec::with_capacity(batch_sizes.len());
+        let mut evaluations = Vec::with_capacity(batch_sizes.len());
+        let mut msg = Vec::with_capacity(batch_sizes.len());
+        for batch_size in &batch_sizes {
+            commitments.push(Commitments::deserialize_with_mode(*batch_size, &mut reader, compress, validate)?);
+            evaluations.push(Evaluations::deserialize_with_mode(*batch_size, &mut reader, compress, validate)?);
+            msg.push(CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?);
+        }
         Ok(Proof {
-            batch_size,
-            commitments: Commitments::deserialize_with_mode(batch_size, &mut reader, compress, validate)?,
-            evaluations: Evaluations::deserialize_with_mode(batch_size, &mut reader, compress, validate)?,
-            msg: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
+            batch_sizes,
+            commitments,
+            evaluations,
+            msg,
             pc_proof: CanonicalDeserialize::deserialize_with_mode(&mut reader, compress, validate)?,
         })
     }
@@ -29,6 +29,8 @@ pub enum MarlinError {
     R1CSError(snarkvm_r1cs::SynthesisError),
     /// There was an error in the underlying polynomial commitment.
     PolynomialCommitmentError(crate::polycommit::PCError),
+    /// The verifying keys were not derived from the same universal SRS.
+    IncompatibleVerifyingKeys,
     Terminated,
 }
 
@@ -0,0 +1,548 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    fft::EvaluationDomain,
+    polycommit::{
+        ipa_pc::{BatchLCProof, Commitment, CommitterKey, InnerProductArgPC, Randomness, UniversalParams, VerifierKey},
+        sonic_pc::{Evaluations, LabeledCommitment, PolynomialInfo},
+    },
+    snark::marlin::{
+        ahp::{indexer::Circuit, AHPError, AHPForR1CS, CircuitInfo, EvaluationsProvider},
+        proof,
+        prover,
+        witness_label,
+        MarlinError,
+        MarlinMode,
+    },
+    AlgebraicSponge,
+    SNARKError,
+};
+use itertools::Itertools;
+use rand::{CryptoRng, Rng};
+use snarkvm_curves::AffineCurve;
+use snarkvm_fields::{One, PrimeField, ToConstraintField, Zero};
+use snarkvm_r1cs::ConstraintSynthesizer;
+use snarkvm_utilities::{to_bytes_le, ToBytes};
+
+use core::marker::PhantomData;
+use std::{borrow::Borrow, sync::Arc};
+
+/// The Marlin proof system, instantiated with the inner product argument polynomial commitment.
+///
+/// Unlike [`MarlinSNARK`](crate::snark::marlin::MarlinSNARK), which uses `SonicKZG10` over a pairing-friendly curve,
+/// this instantiation is transparent, and may be used over any curve whose scalar field has a large two-adic subgroup.
+/// The Fiat-Shamir sponge is over the base field of the curve. A proof is for a batch of assignments to one circuit.
+#[derive(Clone, Debug)]
+pub struct MarlinIPA<G: AffineCurve, FS: AlgebraicSponge<G::BaseField, 2>, MM: MarlinMode>(
+    #[doc(hidden)] PhantomData<(G, FS, MM)>,
+)
+where
+    G::BaseField: PrimeField;
+
+/// Proving key for a specific circuit, for [`MarlinIPA`].
+#[derive(Clone, Debug)]
+pub struct IPACircuitProvingKey<G: AffineCurve, MM: MarlinMode> {
+    /// The circuit verifying key.
+    pub circuit_verifying_key: IPACircuitVerifyingKey<G, MM>,
+    /// The randomness for the circuit polynomial commitments.
+    pub circuit_commitment_randomness: Vec<Randomness<G>>,
+    /// The circuit itself.
+    pub circuit: Arc<Circuit<G::ScalarField, MM>>,
+    /// The committer key for this index, trimmed from the universal parameters.
+    pub committer_key: Arc<CommitterKey<G>>,
+}
+
+/// Verification key for a specific circuit, for [`MarlinIPA`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IPACircuitVerifyingKey<G: AffineCurve, MM: MarlinMode> {
+    /// Stores information about the size of the circuit, as well as its defined field.
+    pub circuit_info: CircuitInfo<G::ScalarField>,
+    /// Commitments to the indexed polynomials.
+    pub circuit_commitments: Vec<Commitment<G>>,
+    /// The verifier key for this index, trimmed from the universal parameters.
+    pub verifier_key: VerifierKey<G>,
+    #[doc(hidden)]
+    pub mode: PhantomData<MM>,
+}
+
+/// A proof for a batch of assignments to a circuit, for [`MarlinIPA`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IPAProof<G: AffineCurve> {
+    /// The number of instances being proven.
+    pub batch_size: usize,
+    /// The commitments to the polynomials of the prover, in the order in which they are committed.
+    pub commitments: Vec<Commitment<G>>,
+    /// The evaluations of the polynomials of the prover at the query set.
+    pub evaluations: proof::Evaluations<G::ScalarField>,
+    /// The field elements sent by the prover in the third round.
+    pub msg: prover::ThirdMessage<G::ScalarField>,
+    /// The opening proof of the linear combinations of the polynomials.
+    pub pc_proof: BatchLCProof<G>,
+}
+
+impl<G, FS, MM> MarlinIPA<G, FS, MM>
+where
+    G: AffineCurve + ToConstraintField<G::BaseField>,
+    G::BaseField: PrimeField,
+    FS: AlgebraicSponge<G::BaseField, 2>,
+    MM: MarlinMode,
+{
+    /// The personalization string for this protocol.
+    /// Used to personalize the Fiat-Shamir RNG.
+    pub const PROTOCOL_NAME: &'static [u8] = b"MARLIN-IPA-2023";
+
+    /// Samples the universal parameters for polynomials of degree up to `max_degree`.
+    /// The parameters are transparent, and can be rerun by anyone.
+    pub fn universal_setup(max_degree: usize) -> Result<UniversalParams<G>, SNARKError> {
+        let setup_time = start_timer!(|| format!("MarlinIPA::UniversalSetup with max_degree {max_degree}"));
+        let universal_params = InnerProductArgPC::<G, FS>::setup(max_degree)?;
+        end_timer!(setup_time);
+        Ok(universal_params)
+    }
+
+    /// Generates the circuit proving and verifying keys.
+    /// This is a deterministic algorithm that anyone can rerun.
+    pub fn circuit_setup<C: ConstraintSynthesizer<G::ScalarField>>(
+        universal_params: &UniversalParams<G>,
+        circuit: &C,
+    ) -> Result<(IPACircuitProvingKey<G, MM>, IPACircuitVerifyingKey<G, MM>), SNARKError> {
+        let index_time = start_timer!(|| "MarlinIPA::CircuitSetup");
+
+        let index = AHPForR1CS::<_, MM>::index(circuit)?;
+        if universal_params.max_degree() < index.max_degree() {
+            return Err(MarlinError::IndexTooLarge(universal_params.max_degree(), index.max_degree()).into());
+        }
+
+        let coefficient_support = AHPForR1CS::<_, MM>::get_degree_bounds(&index.index_info);
+
+        // Marlin only needs degree 2 random polynomials.
+        let supported_hiding_bound = 1;
+        let (committer_key, verifier_key) = InnerProductArgPC::<G, FS>::trim(
+            universal_params,
+            index.max_degree(),
+            [index.constraint_domain_size()],
+            supported_hiding_bound,
+            Some(&coefficient_support),
+        )?;
+
+        let commit_time = start_timer!(|| "Commit to index polynomials");
+        let (mut circuit_commitments, circuit_commitment_randomness) =
+            InnerProductArgPC::<G, FS>::commit(&committer_key, index.iter().map(Into::into), None)?;
+        end_timer!(commit_time);
+
+        circuit_commitments.sort_by(|c1, c2| c1.label().cmp(c2.label()));
+        let circuit_commitments = circuit_commitments.into_iter().map(|c| *c.commitment()).collect();
+        let circuit_verifying_key = IPACircuitVerifyingKey {
+            circuit_info: index.index_info,
+            circuit_commitments,
+            verifier_key,
+            mode: PhantomData,
+        };
+
+        let circuit_proving_key = IPACircuitProvingKey {
+            circuit: Arc::new(index),
+            circuit_commitment_randomness,
+            circuit_verifying_key: circuit_verifying_key.clone(),
+            committer_key: Arc::new(committer_key),
+        };
+
+        end_timer!(index_time);
+
+        Ok((circuit_proving_key, circuit_verifying_key))
+    }
+
+    /// Creates a proof for an assignment to the circuit.
+    pub fn prove<C: ConstraintSynthesizer<G::ScalarField>, R: Rng + CryptoRng>(
+        fs_parameters: &FS::Parameters,
+        circuit_proving_key: &IPACircuitProvingKey<G, MM>,
+        circuit: &C,
+        zk_rng: &mut R,
+    ) -> Result<IPAProof<G>, SNARKError> {
+        Self::prove_batch(fs_parameters, circuit_proving_key, core::slice::from_ref(circuit), zk_rng)
+    }
+
+    /// Creates a proof for a batch of assignments to the circuit.
+    pub fn prove_batch<C: ConstraintSynthesizer<G::ScalarField>, R: Rng + CryptoRng>(
+        fs_parameters: &FS::Parameters,
+        circuit_proving_key: &IPACircuitProvingKey<G, MM>,
+        circuits: &[C],
+        zk_rng: &mut R,
+    ) -> Result<IPAProof<G>, SNARKError> {
+        let prover_time = start_timer!(|| "MarlinIPA::Prover");
+        if circuits.is_empty() {
+            return Err(SNARKError::EmptyBatch);
+        }
+        let batch_size = circuits.len();
+        let committer_key = &circuit_proving_key.committer_key;
+        let circuit_verifying_key = &circuit_proving_key.circuit_verifying_key;
+
+        let prover_state = AHPForR1CS::<_, MM>::init_prover(&circuit_proving_key.circuit, circuits)?;
+        let public_inputs = prover_state.public_inputs();
+        let padded_public_inputs = prover_state.padded_public_inputs();
+
+        let mut sponge = Self::init_sponge(
+            fs_parameters,
+            batch_size,
+            &circuit_verifying_key.circuit_commitments,
+            &padded_public_inputs,
+        );
+
+        // --------------------------------------------------------------------
+        // First round
+
+        let mut prover_state = AHPForR1CS::<_, MM>::prover_first_round(prover_state, zk_rng)?;
+
+        let first_round_comm_time = start_timer!(|| "Committing to first round polys");
+        let (first_commitments, first_commitment_randomnesses) = {
+            let first_round_oracles = Arc::get_mut(prover_state.first_round_oracles.as_mut().unwrap()).unwrap();
+            InnerProductArgPC::<G, FS>::commit(committer_key, first_round_oracles.iter_for_commit(), Some(zk_rng))?
+        };
+        end_timer!(first_round_comm_time);
+
+        Self::absorb_labeled(&first_commitments, &mut sponge);
+
+        let index_info = [(circuit_verifying_key.circuit_info, batch_size)];
+        let (verifier_first_messages, verifier_state) =
+            AHPForR1CS::<_, MM>::verifier_first_round(&index_info, &mut sponge)?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Second round
+
+        let (second_oracles, prover_state) =
+            AHPForR1CS::<_, MM>::prover_second_round(&verifier_first_messages[0], prover_state, zk_rng);
+
+        let second_round_comm_time = start_timer!(|| "Committing to second round polys");
+        let (second_commitments, second_commitment_randomnesses) =
+            InnerProductArgPC::<G, FS>::commit(committer_key, second_oracles.iter().map(Into::into), Some(zk_rng))?;
+        end_timer!(second_round_comm_time);
+
+        Self::absorb_labeled(&second_commitments, &mut sponge);
+
+        let (verifier_second_msg, verifier_state) =
+            AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut sponge)?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Third round
+
+        let (prover_third_message, third_oracles, prover_state) =
+            AHPForR1CS::<_, MM>::prover_third_round(&verifier_second_msg, prover_state, zk_rng)?;
+
+        let third_round_comm_time = start_timer!(|| "Committing to third round polys");
+        let (third_commitments, third_commitment_randomnesses) =
+            InnerProductArgPC::<G, FS>::commit(committer_key, third_oracles.iter().map(Into::into), Some(zk_rng))?;
+        end_timer!(third_round_comm_time);
+
+        Self::absorb_labeled(&third_commitments, &mut sponge);
+        sponge.absorb_nonnative_field_elements([
+            prover_third_message.sum_a,
+            prover_third_message.sum_b,
+            prover_third_message.sum_c,
+        ]);
+
+        let (verifier_third_msg, verifier_state) =
+            AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge)?;
+        // --------------------------------------------------------------------
+
+        // --------------------------------------------------------------------
+        // Fourth round
+
+        let first_round_oracles = Arc::clone(prover_state.first_round_oracles.as_ref().unwrap());
+        let fourth_oracles = AHPForR1CS::<_, MM>::prover_fourth_round(&verifier_third_msg, prover_state, zk_rng)?;
+
+        let fourth_round_comm_time = start_timer!(|| "Committing to fourth round polys");
+        let (fourth_commitments, fourth_commitment_randomnesses) =
+            InnerProductArgPC::<G, FS>::commit(committer_key, fourth_oracles.iter().map(Into::into), Some(zk_rng))?;
+        end_timer!(fourth_round_comm_time);
+
+        Self::absorb_labeled(&fourth_commitments, &mut sponge);
+
+        let verifier_state = AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)?.pop().unwrap();
+        // --------------------------------------------------------------------
+
+        // Gather prover polynomials in one vector.
+        let polynomials: Vec<_> = circuit_proving_key
+            .circuit
+            .iter()
+            .chain(first_round_oracles.iter_for_open())
+            .chain(second_oracles.iter())
+            .chain(third_oracles.iter())
+            .chain(fourth_oracles.iter())
+            .collect();
+
+        // Gather the commitments of the prover in one vector.
+        let prover_commitments: Vec<_> = first_commitments
+            .iter()
+            .chain(&second_commitments)
+            .chain(&third_commitments)
+            .chain(&fourth_commitments)
+            .cloned()
+            .collect();
+        let labeled_commitments: Vec<_> = Self::index_commitments(circuit_verifying_key)
+            .into_iter()
+            .chain(prover_commitments.iter().cloned())
+            .collect();
+
+        // Gather commitment randomness together.
+        let commitment_randomnesses: Vec<&Randomness<G>> = circuit_proving_key
+            .circuit_commitment_randomness
+            .iter()
+            .chain(&first_commitment_randomnesses)
+            .chain(&second_commitment_randomnesses)
+            .chain(&third_commitment_randomnesses)
+            .chain(&fourth_commitment_randomnesses)
+            .collect();
+
+        // Compute the AHP verifier's query set.
+        let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
+        let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
+            &public_inputs,
+            &polynomials,
+            &prover_third_message,
+            &verifier_state,
+        )?;
+
+        let eval_time = start_timer!(|| "Evaluating linear combinations over query set");
+        let mut evaluations = std::collections::BTreeMap::new();
+        for (label, (_, point)) in query_set.to_set() {
+            if !AHPForR1CS::<G::ScalarField, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_str()) {
+                let lc = lc_s.get(&label).ok_or_else(|| AHPError::MissingEval(label.to_string()))?;
+                let evaluation = polynomials.get_lc_eval(lc, point)?;
+                evaluations.insert(label, evaluation);
+            }
+        }
+        let evaluations = proof::Evaluations::from_map(&evaluations, batch_size);
+        end_timer!(eval_time);
+
+        sponge.absorb_nonnative_field_elements(evaluations.to_field_elements());
+
+        let pc_proof = InnerProductArgPC::<G, FS>::open_combinations(
+            committer_key,
+            lc_s.values(),
+            polynomials,
+            &labeled_commitments,
+            &query_set.to_set(),
+            commitment_randomnesses,
+            &mut sponge,
+        )?;
+
+        let proof = IPAProof {
+            batch_size,
+            commitments: prover_commitments.into_iter().map(|c| *c.commitment()).collect(),
+            evaluations,
+            msg: prover_third_message,
+            pc_proof,
+        };
+        assert_eq!(proof.pc_proof.proof.is_hiding(), MM::ZK);
+        end_timer!(prover_time);
+
+        Ok(proof)
+    }
+
+    /// Verifies a proof for the given public input to the circuit.
+    pub fn verify<B: Borrow<[G::ScalarField]>>(
+        fs_parameters: &FS::Parameters,
+        circuit_verifying_key: &IPACircuitVerifyingKey<G, MM>,
+        public_input: B,
+        proof: &IPAProof<G>,
+    ) -> Result<bool, SNARKError> {
+        Self::verify_batch(fs_parameters, circuit_verifying_key, &[public_input], proof)
+    }
+
+    /// Verifies a proof for a batch of public inputs to the circuit.
+    pub fn verify_batch<B: Borrow<[G::ScalarField]>>(
+        fs_parameters: &FS::Parameters,
+        circuit_verifying_key: &IPACircuitVerifyingKey<G, MM>,
+        public_inputs: &[B],
+        proof: &IPAProof<G>,
+    ) -> Result<bool, SNARKError> {
+        if public_inputs.is_empty() {
+            return Err(SNARKError::EmptyBatch);
+        }
+        let batch_size = public_inputs.len();
+        if batch_size != proof.batch_size {
+            return Err(SNARKError::BatchSizeMismatch);
+        }
+        let verifier_time = start_timer!(|| format!("MarlinIPA::Verify with batch size {batch_size}"));
+
+        // Ensure the proof has the hiding mode of the verifier.
+        if proof.pc_proof.proof.is_hiding() != MM::ZK {
+            eprintln!("Proof has incorrect hiding mode ({})", proof.pc_proof.proof.is_hiding());
+            return Ok(false);
+        }
+
+        // Label the commitments of the prover.
+        let prover_polynomial_info = Self::prover_polynomial_info(&circuit_verifying_key.circuit_info, batch_size);
+        if proof.commitments.len() != prover_polynomial_info.len() {
+            return Ok(false);
+        }
+        let prover_commitments: Vec<_> = proof
+            .commitments
+            .iter()
+            .cloned()
+            .zip_eq(&prover_polynomial_info)
+            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+            .collect();
+        let num_first_round = prover_polynomial_info.len() - 6;
+        let (first_commitments, rest) = prover_commitments.split_at(num_first_round);
+        let (second_commitments, rest) = rest.split_at(2);
+        let (third_commitments, fourth_commitments) = rest.split_at(3);
+
+        let input_domain =
+            EvaluationDomain::<G::ScalarField>::new(circuit_verifying_key.circuit_info.num_public_inputs).unwrap();
+        let (padded_public_inputs, public_inputs): (Vec<_>, Vec<_>) = public_inputs
+            .iter()
+            .map(|input| {
+                let input = input.borrow().to_field_elements().unwrap();
+                let mut new_input = vec![G::ScalarField::one()];
+                new_input.extend_from_slice(&input);
+                new_input.resize(input.len().max(input_domain.size()), G::ScalarField::zero());
+                let unformatted = prover::ConstraintSystem::unformat_public_input(&new_input);
+                (new_input, unformatted)
+            })
+            .unzip();
+
+        let mut sponge = Self::init_sponge(
+            fs_parameters,
+            batch_size,
+            &circuit_verifying_key.circuit_commitments,
+            &padded_public_inputs,
+        );
+
+        // First round
+        Self::absorb_labeled(first_commitments, &mut sponge);
+        let index_info = [(circuit_verifying_key.circuit_info, batch_size)];
+        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_first_round(&index_info, &mut sponge)?;
+
+        // Second round
+        Self::absorb_labeled(second_commitments, &mut sponge);
+        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut sponge)?;
+
+        // Third round
+        Self::absorb_labeled(third_commitments, &mut sponge);
+        sponge.absorb_nonnative_field_elements([proof.msg.sum_a, proof.msg.sum_b, proof.msg.sum_c]);
+        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge)?;
+
+        // Fourth round
+        Self::absorb_labeled(fourth_commitments, &mut sponge);
+        let verifier_state = AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)?.pop().unwrap();
+
+        sponge.absorb_nonnative_field_elements(proof.evaluations.to_field_elements());
+
+        let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
+
+        let mut evaluations = Evaluations::new();
+        for (label, (_point_name, q)) in query_set.to_set() {
+            if AHPForR1CS::<G::ScalarField, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_ref()) {
+                evaluations.insert((label, q), G::ScalarField::zero());
+            } else {
+                let eval = proof.evaluations.get(&label).ok_or_else(|| AHPError::MissingEval(label.clone()))?;
+                evaluations.insert((label, q), eval);
+            }
+        }
+
+        let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
+            &public_inputs,
+            &evaluations,
+            &proof.msg,
+            &verifier_state,
+        )?;
+
+        // Gather the commitments in one vector. Indexed polynomials have *no*
+        // degree bounds because we know the committed index polynomial has the correct degree.
+        let commitments: Vec<_> =
+            Self::index_commitments(circuit_verifying_key).into_iter().chain(prover_commitments).collect();
+
+        let pc_time = start_timer!(|| "Checking linear combinations with PC");
+        let evaluations_are_correct = InnerProductArgPC::<G, FS>::check_combinations(
+            &circuit_verifying_key.verifier_key,
+            lc_s.values(),
+            &commitments,
+            &query_set.to_set(),
+            &evaluations,
+            &proof.pc_proof,
+            &mut sponge,
+        )?;
+        end_timer!(pc_time);
+
+        if !evaluations_are_correct {
+            #[cfg(debug_assertions)]
+            eprintln!("InnerProductArgPC::Check failed");
+        }
+        end_timer!(verifier_time, || format!(
+            " InnerProductArgPC::Check for AHP Verifier linear equations: {evaluations_are_correct}"
+        ));
+        Ok(evaluations_are_correct)
+    }
+
+    fn init_sponge(
+        fs_parameters: &FS::Parameters,
+        batch_size: usize,
+        circuit_commitments: &[Commitment<G>],
+        inputs: &[Vec<G::ScalarField>],
+    ) -> FS {
+        let mut sponge = FS::new_with_parameters(fs_parameters);
+        sponge.absorb_bytes(&to_bytes_le![&Self::PROTOCOL_NAME].unwrap());
+        sponge.absorb_bytes(&(u64::try_from(batch_size).unwrap()).to_le_bytes());
+        sponge.absorb_native_field_elements(circuit_commitments);
+        for input in inputs {
+            sponge.absorb_nonnative_field_elements(input.iter().copied());
+        }
+        sponge
+    }
+
+    fn absorb_labeled(comms: &[LabeledCommitment<Commitment<G>>], sponge: &mut FS) {
+        let commitments: Vec<_> = comms.iter().map(|c| *c.commitment()).collect();
+        sponge.absorb_native_field_elements(&commitments);
+    }
+
+    /// Returns the labeled commitments to the indexed polynomials of the circuit.
+    fn index_commitments(
+        circuit_verifying_key: &IPACircuitVerifyingKey<G, MM>,
+    ) -> Vec<LabeledCommitment<Commitment<G>>> {
+        circuit_verifying_key
+            .circuit_commitments
+            .iter()
+            .cloned()
+            .zip_eq(AHPForR1CS::<G::ScalarField, MM>::index_polynomial_info().values())
+            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+            .collect()
+    }
+
+    /// Returns the information of the polynomials of the prover, in the order in which they are committed,
+    /// for a proof for a batch of `batch_size` assignments to the circuit.
+    fn prover_polynomial_info(circuit_info: &CircuitInfo<G::ScalarField>, batch_size: usize) -> Vec<PolynomialInfo> {
+        let first_round_info = AHPForR1CS::<G::ScalarField, MM>::first_round_polynomial_info(batch_size);
+        let mut first_round_labels =
+            (0..batch_size).flat_map(|i| ["w", "z_a", "z_b"].map(|label| witness_label(label, i))).collect::<Vec<_>>();
+        if MM::ZK {
+            first_round_labels.push("mask_poly".to_string());
+        }
+        let second_round_info = AHPForR1CS::<G::ScalarField, MM>::second_round_polynomial_info(circuit_info);
+        let third_round_info = AHPForR1CS::<G::ScalarField, MM>::third_round_polynomial_info(circuit_info);
+        let fourth_round_info = AHPForR1CS::<G::ScalarField, MM>::fourth_round_polynomial_info();
+
+        first_round_labels
+            .iter()
+            .map(|label| first_round_info[label].clone())
+            .chain(["g_1", "h_1"].map(|label| second_round_info[label].clone()))
+            .chain(["g_a", "g_b", "g_c"].map(|label| third_round_info[label].clone()))
+            .chain(["h_2"].map(|label| fourth_round_info[label].clone()))
+            .collect()
+    }
+}
@@ -16,7 +16,19 @@
 
 use crate::{
     fft::EvaluationDomain,
-    polycommit::sonic_pc::{Commitment, Evaluations, LabeledCommitment, QuerySet, Randomness, SonicKZG10},
+    polycommit::{
+        kzg10::KZG10,
+        sonic_pc::{
+            BatchLCProof,
+            Commitment,
+            Evaluations,
+            LabeledCommitment,
+            PolynomialInfo,
+            QuerySet,
+            Randomness,
+            SonicKZG10,
+        },
+    },
     snark::marlin::{
         ahp::{AHPError, AHPForR1CS, EvaluationsProvider},
         proof,
@@ -139,21 +151,25 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: MarlinMode> MarlinSNAR
     }
 
     fn terminate(terminator: &AtomicBool) -> Result<(), MarlinError> {
-        if terminator.load(Ordering::Relaxed) { Err(MarlinError::Terminated) } else { Ok(()) }
+        if terminator.load(Ordering::Relaxed) {
+            Err(MarlinError::Terminated)
+        } else {
+            Ok(())
+        }
     }
 
     fn init_sponge(
         fs_parameters: &FS::Parameters,
-        batch_size: usize,
-        circuit_commitments: &[crate::polycommit::sonic_pc::Commitment<E>],
-        inputs: &[Vec<E::Fr>],
+        batch_sizes_commitments_and_inputs: &[(usize, &[Commitment<E>], &[Vec<E::Fr>])],
     ) -> FS {
         let mut sponge = FS::new_with_parameters(fs_parameters);
         sponge.absorb_bytes(&to_bytes_le![&Self::PROTOCOL_NAME].unwrap());
-        sponge.absorb_bytes(&(u64::try_from(batch_size).unwrap()).to_le_bytes());
-        sponge.absorb_native_field_elements(circuit_commitments);
-        for input in inputs {
-            sponge.absorb_nonnative_field_elements(input.iter().copied());
+        for (batch_size, circuit_commitments, inputs) in batch_sizes_commitments_and_inputs {
+            sponge.absorb_bytes(&(u64::try_from(*batch_size).unwrap()).to_le_bytes());
+            sponge.absorb_native_field_elements(circuit_commitments);
+            for input in inputs.iter() {
+                sponge.absorb_nonnative_field_elements(input.iter().copied());
+            }
         }
         sponge
     }
@@ -194,210 +210,129 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: MarlinMode> MarlinSNAR
         sponge.absorb_nonnative_field_elements([msg.sum_a, msg.sum_b, msg.sum_c]);
         end_timer!(sponge_time);
     }
-}
-
-impl<E: PairingEngine, FS, MM> SNARK for MarlinSNARK<E, FS, MM>
-where
-    E::Fr: PrimeField,
-    E::Fq: PrimeField,
-    FS: AlgebraicSponge<E::Fq, 2>,
-    MM: MarlinMode,
-{
-    type BaseField = E::Fq;
-    type Certificate = Certificate<E>;
-    type FSParameters = FS::Parameters;
-    type FiatShamirRng = FS;
-    type Proof = Proof<E>;
-    type ProvingKey = CircuitProvingKey<E, MM>;
-    type ScalarField = E::Fr;
-    type UniversalSetupConfig = usize;
-    type UniversalSetupParameters = UniversalSRS<E>;
-    type VerifierInput = [E::Fr];
-    type VerifyingKey = CircuitVerifyingKey<E, MM>;
-
-    fn universal_setup(max_degree: &Self::UniversalSetupConfig) -> Result<Self::UniversalSetupParameters, SNARKError> {
-        let setup_time = start_timer!(|| { format!("Marlin::UniversalSetup with max_degree {max_degree}",) });
-
-        let srs = SonicKZG10::<E, FS>::load_srs(*max_degree).map_err(Into::into);
-        end_timer!(setup_time);
-        srs
-    }
 
-    fn setup<C: ConstraintSynthesizer<E::Fr>>(
-        circuit: &C,
-        srs: &mut SRS<Self::UniversalSetupParameters>,
-    ) -> Result<(Self::ProvingKey, Self::VerifyingKey), SNARKError> {
-        match srs {
-            SRS::CircuitSpecific => Self::circuit_specific_setup(circuit),
-            SRS::Universal(srs) => Self::circuit_setup(srs, circuit),
-        }
-        .map_err(SNARKError::from)
+    /// Returns the label under which the polynomial `label` of the `i`-th circuit is opened.
+    fn circuit_label(i: usize, label: &str) -> String {
+        format!("circuit_{i:0>8}_{label}")
     }
 
-    fn prove_vk(
-        fs_parameters: &Self::FSParameters,
-        verifying_key: &Self::VerifyingKey,
-        proving_key: &Self::ProvingKey,
-    ) -> Result<Self::Certificate, SNARKError> {
-        // Initialize sponge
-        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
-        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
-        // The linear combination requires `num_polynomials - 1` coefficients
-        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
-        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
-        let point = challenges.pop().unwrap();
-        let one = E::Fr::one();
-        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
-
-        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
-        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
-        for (poly, &c) in proving_key.circuit.iter().zip(linear_combination_challenges) {
-            lc.add(c, poly.label());
-        }
-
-        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
-        let commitments = verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .collect::<Vec<_>>();
-
-        let certificate = SonicKZG10::<E, FS>::open_combinations(
-            &proving_key.committer_key,
-            &[lc],
-            proving_key.circuit.iter(),
-            &commitments,
-            &query_set,
-            &proving_key.circuit_commitment_randomness.clone(),
-            &mut sponge,
-        )?;
-
-        Ok(Self::Certificate::new(certificate))
-    }
-
-    fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
-        fs_parameters: &Self::FSParameters,
-        circuit: &C,
-        verifying_key: &Self::VerifyingKey,
-        certificate: &Self::Certificate,
-    ) -> Result<bool, SNARKError> {
-        let info = AHPForR1CS::<E::Fr, MM>::index_polynomial_info();
-        // Initialize sponge.
-        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
-        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
-        // The linear combination requires `num_polynomials - 1` coefficients
-        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
-        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
-        let point = challenges.pop().unwrap();
-
-        let evaluations_at_point = AHPForR1CS::<E::Fr, MM>::evaluate_index_polynomials(circuit, point)?;
-        let one = E::Fr::one();
-        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
-
-        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
-        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
-        let mut evaluation = E::Fr::zero();
-        for ((label, &c), eval) in info.keys().zip_eq(linear_combination_challenges).zip_eq(evaluations_at_point) {
-            lc.add(c, label.as_str());
-            evaluation += c * eval;
-        }
-
-        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
-        let commitments = verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(info.values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .collect::<Vec<_>>();
-        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
-
-        SonicKZG10::<E, FS>::check_combinations(
-            &verifying_key.verifier_key,
-            &[lc],
-            &commitments,
-            &query_set,
-            &evaluations,
-            &certificate.pc_proof,
-            &mut sponge,
-        )
-        .map_err(Into::into)
+    /// Creates a proof for batches of assignments to one or more circuits.
+    ///
+    /// The circuits share the verifier challenges, so that the evaluations
+    /// of all of their polynomials are proven by a single opening proof.
+    pub fn prove_multi_batch<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        fs_parameters: &FS::Parameters,
+        keys_to_constraints: &[(&CircuitProvingKey<E, MM>, &[C])],
+        zk_rng: &mut R,
+    ) -> Result<Proof<E>, SNARKError> {
+        Self::prove_multi_batch_with_terminator(fs_parameters, keys_to_constraints, &AtomicBool::new(false), zk_rng)
     }
 
-    #[allow(clippy::only_used_in_recursion)]
-    fn prove_batch_with_terminator<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
-        fs_parameters: &Self::FSParameters,
-        circuit_proving_key: &CircuitProvingKey<E, MM>,
-        circuits: &[C],
+    /// Creates a proof for batches of assignments to one or more circuits,
+    /// aborting if `terminator` is set.
+    pub fn prove_multi_batch_with_terminator<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        fs_parameters: &FS::Parameters,
+        keys_to_constraints: &[(&CircuitProvingKey<E, MM>, &[C])],
         terminator: &AtomicBool,
         zk_rng: &mut R,
-    ) -> Result<Self::Proof, SNARKError> {
+    ) -> Result<Proof<E>, SNARKError> {
         let prover_time = start_timer!(|| "Marlin::Prover");
-        let batch_size = circuits.len();
-        if batch_size == 0 {
+        if keys_to_constraints.is_empty() || keys_to_constraints.iter().any(|(_, circuits)| circuits.is_empty()) {
             return Err(SNARKError::EmptyBatch);
         }
+        let batch_sizes = keys_to_constraints.iter().map(|(_, circuits)| circuits.len()).collect::<Vec<_>>();
 
         Self::terminate(terminator)?;
 
-        let prover_state = AHPForR1CS::<_, MM>::init_prover(&circuit_proving_key.circuit, circuits)?;
-        let public_input = prover_state.public_inputs();
-        let padded_public_input = prover_state.padded_public_inputs();
-        assert_eq!(prover_state.batch_size, batch_size);
+        let mut prover_states = Vec::with_capacity(keys_to_constraints.len());
+        let mut public_inputs = Vec::with_capacity(keys_to_constraints.len());
+        let mut padded_public_inputs = Vec::with_capacity(keys_to_constraints.len());
+        for (circuit_proving_key, circuits) in keys_to_constraints {
+            let prover_state = AHPForR1CS::<_, MM>::init_prover(&circuit_proving_key.circuit, circuits)?;
+            assert_eq!(prover_state.batch_size, circuits.len());
+            public_inputs.push(prover_state.public_inputs());
+            padded_public_inputs.push(prover_state.padded_public_inputs());
+            prover_states.push(prover_state);
+        }
 
-        let mut sponge = Self::init_sponge(
-            fs_parameters,
-            batch_size,
-            &circuit_proving_key.circuit_verifying_key.circuit_commitments,
-            &padded_public_input,
-        );
+        let sponge_inputs = keys_to_constraints
+            .iter()
+            .zip_eq(&padded_public_inputs)
+            .map(|((pk, circuits), inputs)| {
+                (circuits.len(), pk.circuit_verifying_key.circuit_commitments.as_slice(), inputs.as_slice())
+            })
+            .collect::<Vec<_>>();
+        let mut sponge = Self::init_sponge(fs_parameters, &sponge_inputs);
 
         // --------------------------------------------------------------------
         // First round
 
-        Self::terminate(terminator)?;
-        let mut prover_state = AHPForR1CS::<_, MM>::prover_first_round(prover_state, zk_rng)?;
-        Self::terminate(terminator)?;
-
-        let first_round_comm_time = start_timer!(|| "Committing to first round polys");
-        let (first_commitments, first_commitment_randomnesses) = {
-            let first_round_oracles = Arc::get_mut(prover_state.first_round_oracles.as_mut().unwrap()).unwrap();
-            SonicKZG10::<E, FS>::commit(
-                &circuit_proving_key.committer_key,
-                first_round_oracles.iter_for_commit(),
-                Some(zk_rng),
-            )?
-        };
-        end_timer!(first_round_comm_time);
-
-        Self::absorb_labeled(&first_commitments, &mut sponge);
+        let mut first_commitments = Vec::with_capacity(keys_to_constraints.len());
+        let mut first_commitment_randomnesses = Vec::with_capacity(keys_to_constraints.len());
+        let mut next_prover_states = Vec::with_capacity(keys_to_constraints.len());
+        for ((circuit_proving_key, _), prover_state) in keys_to_constraints.iter().zip_eq(prover_states) {
+            Self::terminate(terminator)?;
+            let mut prover_state = AHPForR1CS::<_, MM>::prover_first_round(prover_state, zk_rng)?;
+            Self::terminate(terminator)?;
+
+            let first_round_comm_time = start_timer!(|| "Committing to first round polys");
+            let (commitments, randomnesses) = {
+                let first_round_oracles = Arc::get_mut(prover_state.first_round_oracles.as_mut().unwrap()).unwrap();
+                SonicKZG10::<E, FS>::commit(
+                    &circuit_proving_key.committer_key,
+                    first_round_oracles.iter_for_commit(),
+                    Some(zk_rng),
+                )?
+            };
+            end_timer!(first_round_comm_time);
+
+            Self::absorb_labeled(&commitments, &mut sponge);
+            first_commitments.push(commitments);
+            first_commitment_randomnesses.push(randomnesses);
+            next_prover_states.push(prover_state);
+        }
+        let prover_states = next_prover_states;
         Self::terminate(terminator)?;
 
-        let (verifier_first_message, verifier_state) = AHPForR1CS::<_, MM>::verifier_first_round(
-            circuit_proving_key.circuit_verifying_key.circuit_info,
-            batch_size,
-            &mut sponge,
-        )?;
+        let index_infos = keys_to_constraints
+            .iter()
+            .map(|(pk, circuits)| (pk.circuit_verifying_key.circuit_info, circuits.len()))
+            .collect::<Vec<_>>();
+        let (verifier_first_messages, verifier_state) =
+            AHPForR1CS::<_, MM>::verifier_first_round(&index_infos, &mut sponge)?;
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
         // Second round
 
-        Self::terminate(terminator)?;
-        let (second_oracles, prover_state) =
-            AHPForR1CS::<_, MM>::prover_second_round(&verifier_first_message, prover_state, zk_rng);
-        Self::terminate(terminator)?;
-
-        let second_round_comm_time = start_timer!(|| "Committing to second round polys");
-        let (second_commitments, second_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
-            &circuit_proving_key.committer_key,
-            second_oracles.iter().map(Into::into),
-            terminator,
-            Some(zk_rng),
-        )?;
-        end_timer!(second_round_comm_time);
+        let mut second_oracles = Vec::with_capacity(keys_to_constraints.len());
+        let mut second_commitments = Vec::with_capacity(keys_to_constraints.len());
+        let mut second_commitment_randomnesses = Vec::with_capacity(keys_to_constraints.len());
+        let mut next_prover_states = Vec::with_capacity(keys_to_constraints.len());
+        for (((circuit_proving_key, _), prover_state), verifier_first_message) in
+            keys_to_constraints.iter().zip_eq(prover_states).zip_eq(&verifier_first_messages)
+        {
+            Self::terminate(terminator)?;
+            let (oracles, prover_state) =
+                AHPForR1CS::<_, MM>::prover_second_round(verifier_first_message, prover_state, zk_rng);
+            Self::terminate(terminator)?;
+
+            let second_round_comm_time = start_timer!(|| "Committing to second round polys");
+            let (commitments, randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
+                &circuit_proving_key.committer_key,
+                oracles.iter().map(Into::into),
+                terminator,
+                Some(zk_rng),
+            )?;
+            end_timer!(second_round_comm_time);
 
-        Self::absorb_labeled(&second_commitments, &mut sponge);
+            Self::absorb_labeled(&commitments, &mut sponge);
+            second_oracles.push(oracles);
+            second_commitments.push(commitments);
+            second_commitment_randomnesses.push(randomnesses);
+            next_prover_states.push(prover_state);
+        }
+        let prover_states = next_prover_states;
         Self::terminate(terminator)?;
 
         let (verifier_second_msg, verifier_state) =
@@ -407,22 +342,34 @@ where
         // --------------------------------------------------------------------
         // Third round
 
-        Self::terminate(terminator)?;
-
-        let (prover_third_message, third_oracles, prover_state) =
-            AHPForR1CS::<_, MM>::prover_third_round(&verifier_second_msg, prover_state, zk_rng)?;
-        Self::terminate(terminator)?;
-
-        let third_round_comm_time = start_timer!(|| "Committing to third round polys");
-        let (third_commitments, third_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
-            &circuit_proving_key.committer_key,
-            third_oracles.iter().map(Into::into),
-            terminator,
-            Some(zk_rng),
-        )?;
-        end_timer!(third_round_comm_time);
-
-        Self::absorb_labeled_with_msg(&third_commitments, &prover_third_message, &mut sponge);
+        let mut prover_third_messages = Vec::with_capacity(keys_to_constraints.len());
+        let mut third_oracles = Vec::with_capacity(keys_to_constraints.len());
+        let mut third_commitments = Vec::with_capacity(keys_to_constraints.len());
+        let mut third_commitment_randomnesses = Vec::with_capacity(keys_to_constraints.len());
+        let mut next_prover_states = Vec::with_capacity(keys_to_constraints.len());
+        for ((circuit_proving_key, _), prover_state) in keys_to_constraints.iter().zip_eq(prover_states) {
+            Self::terminate(terminator)?;
+            let (message, oracles, prover_state) =
+                AHPForR1CS::<_, MM>::prover_third_round(&verifier_second_msg, prover_state, zk_rng)?;
+            Self::terminate(terminator)?;
+
+            let third_round_comm_time = start_timer!(|| "Committing to third round polys");
+            let (commitments, randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
+                &circuit_proving_key.committer_key,
+                oracles.iter().map(Into::into),
+                terminator,
+                Some(zk_rng),
+            )?;
+            end_timer!(third_round_comm_time);
+
+            Self::absorb_labeled_with_msg(&commitments, &message, &mut sponge);
+            prover_third_messages.push(message);
+            third_oracles.push(oracles);
+            third_commitments.push(commitments);
+            third_commitment_randomnesses.push(randomnesses);
+            next_prover_states.push(prover_state);
+        }
+        let prover_states = next_prover_states;
 
         let (verifier_third_msg, verifier_state) =
             AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge)?;
@@ -431,166 +378,234 @@ where
         // --------------------------------------------------------------------
         // Fourth round
 
-        Self::terminate(terminator)?;
+        let mut first_round_oracles = Vec::with_capacity(keys_to_constraints.len());
+        let mut fourth_oracles = Vec::with_capacity(keys_to_constraints.len());
+        let mut fourth_commitments = Vec::with_capacity(keys_to_constraints.len());
+        let mut fourth_commitment_randomnesses = Vec::with_capacity(keys_to_constraints.len());
+        for ((circuit_proving_key, _), prover_state) in keys_to_constraints.iter().zip_eq(prover_states) {
+            Self::terminate(terminator)?;
 
-        let first_round_oracles = Arc::clone(prover_state.first_round_oracles.as_ref().unwrap());
-        let fourth_oracles = AHPForR1CS::<_, MM>::prover_fourth_round(&verifier_third_msg, prover_state, zk_rng)?;
-        Self::terminate(terminator)?;
+            first_round_oracles.push(Arc::clone(prover_state.first_round_oracles.as_ref().unwrap()));
+            let oracles = AHPForR1CS::<_, MM>::prover_fourth_round(&verifier_third_msg, prover_state, zk_rng)?;
+            Self::terminate(terminator)?;
 
-        let fourth_round_comm_time = start_timer!(|| "Committing to fourth round polys");
-        let (fourth_commitments, fourth_commitment_randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
-            &circuit_proving_key.committer_key,
-            fourth_oracles.iter().map(Into::into),
-            terminator,
-            Some(zk_rng),
-        )?;
-        end_timer!(fourth_round_comm_time);
+            let fourth_round_comm_time = start_timer!(|| "Committing to fourth round polys");
+            let (commitments, randomnesses) = SonicKZG10::<E, FS>::commit_with_terminator(
+                &circuit_proving_key.committer_key,
+                oracles.iter().map(Into::into),
+                terminator,
+                Some(zk_rng),
+            )?;
+            end_timer!(fourth_round_comm_time);
 
-        Self::absorb_labeled(&fourth_commitments, &mut sponge);
+            Self::absorb_labeled(&commitments, &mut sponge);
+            fourth_oracles.push(oracles);
+            fourth_commitments.push(commitments);
+            fourth_commitment_randomnesses.push(randomnesses);
+        }
 
-        let verifier_state = AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)?;
+        let verifier_states = AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)?;
         // --------------------------------------------------------------------
 
         Self::terminate(terminator)?;
 
-        // Gather prover polynomials in one vector.
-        let polynomials: Vec<_> = circuit_proving_key
-            .circuit
-            .iter() // 12 items
-            .chain(first_round_oracles.iter_for_open()) // 3 * batch_size + (MM::ZK as usize) items
-            .chain(second_oracles.iter())// 2 items
-            .chain(third_oracles.iter())// 3 items
-            .chain(fourth_oracles.iter())// 1 item
-            .collect();
-
-        Self::terminate(terminator)?;
-
-        // Gather commitments in one vector.
-        let witness_commitments = first_commitments.chunks_exact(3);
-        let mask_poly = MM::ZK.then(|| *witness_commitments.remainder()[0].commitment());
-        let witness_commitments = witness_commitments
-            .map(|c| proof::WitnessCommitments {
-                w: *c[0].commitment(),
-                z_a: *c[1].commitment(),
-                z_b: *c[2].commitment(),
-            })
-            .collect();
-        #[rustfmt::skip]
-        let commitments = proof::Commitments {
-            witness_commitments,
-            mask_poly,
+        let num_circuits = keys_to_constraints.len();
+        let mut commitments = Vec::with_capacity(num_circuits);
+        let mut evaluations = Vec::with_capacity(num_circuits);
+        let mut opening_polynomials = Vec::new();
+        let mut opening_commitments = Vec::new();
+        let mut opening_randomnesses = Vec::new();
+        let mut opening_query_set = QuerySet::new();
+        for (i, verifier_state) in verifier_states.into_iter().enumerate() {
+            let circuit_proving_key = keys_to_constraints[i].0;
+
+            // Gather prover polynomials in one vector.
+            let polynomials: Vec<_> = circuit_proving_key
+                .circuit
+                .iter() // 12 items
+                .chain(first_round_oracles[i].iter_for_open()) // 3 * batch_size + (MM::ZK as usize) items
+                .chain(second_oracles[i].iter())// 2 items
+                .chain(third_oracles[i].iter())// 3 items
+                .chain(fourth_oracles[i].iter())// 1 item
+                .collect();
+
+            Self::terminate(terminator)?;
+
+            // Gather commitments in one vector.
+            let witness_commitments = first_commitments[i].chunks_exact(3);
+            let mask_poly = MM::ZK.then(|| *witness_commitments.remainder()[0].commitment());
+            let witness_commitments = witness_commitments
+                .map(|c| proof::WitnessCommitments {
+                    w: *c[0].commitment(),
+                    z_a: *c[1].commitment(),
+                    z_b: *c[2].commitment(),
+                })
+                .collect();
+            #[rustfmt::skip]
+            commitments.push(proof::Commitments {
+                witness_commitments,
+                mask_poly,
 
-            g_1: *second_commitments[0].commitment(),
-            h_1: *second_commitments[1].commitment(),
+                g_1: *second_commitments[i][0].commitment(),
+                h_1: *second_commitments[i][1].commitment(),
 
 
-            g_a: *third_commitments[0].commitment(),
-            g_b: *third_commitments[1].commitment(),
-            g_c: *third_commitments[2].commitment(),
+                g_a: *third_commitments[i][0].commitment(),
+                g_b: *third_commitments[i][1].commitment(),
+                g_c: *third_commitments[i][2].commitment(),
 
-            h_2: *fourth_commitments[0].commitment(),
-        };
+                h_2: *fourth_commitments[i][0].commitment(),
+            });
 
-        let labeled_commitments: Vec<_> = circuit_proving_key
-            .circuit_verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .chain(first_commitments.into_iter())
-            .chain(second_commitments.into_iter())
-            .chain(third_commitments.into_iter())
-            .chain(fourth_commitments.into_iter())
-            .collect();
-
-        // Gather commitment randomness together.
-        let commitment_randomnesses: Vec<Randomness<E>> = circuit_proving_key
-            .circuit_commitment_randomness
-            .clone()
-            .into_iter()
-            .chain(first_commitment_randomnesses)
-            .chain(second_commitment_randomnesses)
-            .chain(third_commitment_randomnesses)
-            .chain(fourth_commitment_randomnesses)
-            .collect();
-
-        if !MM::ZK {
-            let empty_randomness = Randomness::<E>::empty();
-            assert!(commitment_randomnesses.iter().all(|r| r == &empty_randomness));
-        }
+            let labeled_commitments: Vec<_> = circuit_proving_key
+                .circuit_verifying_key
+                .iter()
+                .cloned()
+                .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
+                .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+                .chain(first_commitments[i].iter().cloned())
+                .chain(second_commitments[i].iter().cloned())
+                .chain(third_commitments[i].iter().cloned())
+                .chain(fourth_commitments[i].iter().cloned())
+                .collect();
+
+            // Gather commitment randomness together.
+            let commitment_randomnesses: Vec<&Randomness<E>> = circuit_proving_key
+                .circuit_commitment_randomness
+                .iter()
+                .chain(&first_commitment_randomnesses[i])
+                .chain(&second_commitment_randomnesses[i])
+                .chain(&third_commitment_randomnesses[i])
+                .chain(&fourth_commitment_randomnesses[i])
+                .collect();
+
+            if !MM::ZK {
+                let empty_randomness = Randomness::<E>::empty();
+                assert!(commitment_randomnesses.iter().all(|r| r == &&empty_randomness));
+            }
 
-        // Compute the AHP verifier's query set.
-        let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
-        let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
-            &public_input,
-            &polynomials,
-            &prover_third_message,
-            &verifier_state,
-        )?;
+            // Compute the AHP verifier's query set.
+            let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
+            let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
+                &public_inputs[i],
+                &polynomials,
+                &prover_third_messages[i],
+                &verifier_state,
+            )?;
 
-        Self::terminate(terminator)?;
+            Self::terminate(terminator)?;
 
-        let eval_time = start_timer!(|| "Evaluating linear combinations over query set");
-        let mut evaluations = std::collections::BTreeMap::new();
-        for (label, (_, point)) in query_set.to_set() {
-            if !AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_str()) {
-                let lc = lc_s.get(&label).ok_or_else(|| AHPError::MissingEval(label.to_string()))?;
-                let evaluation = polynomials.get_lc_eval(lc, point)?;
-                evaluations.insert(label, evaluation);
+            let eval_time = start_timer!(|| "Evaluating linear combinations over query set");
+            let mut circuit_evaluations = std::collections::BTreeMap::new();
+            for (label, (_, point)) in query_set.to_set() {
+                if !AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_str()) {
+                    let lc = lc_s.get(&label).ok_or_else(|| AHPError::MissingEval(label.to_string()))?;
+                    let evaluation = polynomials.get_lc_eval(lc, point)?;
+                    circuit_evaluations.insert(label, evaluation);
+                }
+            }
+            evaluations.push(proof::Evaluations::from_map(&circuit_evaluations, batch_sizes[i]));
+            end_timer!(eval_time);
+
+            Self::terminate(terminator)?;
+
+            // Combine the polynomials of this circuit into its linear combinations, and add these to the
+            // opening under labels that are unique to this circuit. The degree bounds are checked against
+            // the committer key of this circuit, as the opening may be created with another committer key.
+            let (lc_polynomials, lc_commitments, lc_randomnesses) = SonicKZG10::<E, FS>::combine_lcs_for_open(
+                lc_s.values(),
+                polynomials,
+                &labeled_commitments,
+                commitment_randomnesses,
+            )?;
+            let committer_key = &circuit_proving_key.committer_key;
+            for ((mut polynomial, commitment), randomness) in
+                lc_polynomials.into_iter().zip_eq(lc_commitments).zip_eq(lc_randomnesses)
+            {
+                KZG10::<E>::check_degrees_and_bounds(
+                    committer_key.supported_degree(),
+                    committer_key.max_degree,
+                    committer_key.enforced_degree_bounds.as_deref(),
+                    &polynomial,
+                )?;
+                let label = Self::circuit_label(i, polynomial.label());
+                polynomial.info = PolynomialInfo::new(label.clone(), None, polynomial.hiding_bound());
+                opening_polynomials.push(polynomial);
+                opening_commitments.push(LabeledCommitment::new(label, *commitment.commitment(), None));
+                opening_randomnesses.push(randomness);
+            }
+            for (label, query) in query_set.to_set() {
+                opening_query_set.insert((Self::circuit_label(i, &label), query));
             }
         }
 
-        let evaluations = proof::Evaluations::from_map(&evaluations, batch_size);
-        end_timer!(eval_time);
-
-        Self::terminate(terminator)?;
-
-        sponge.absorb_nonnative_field_elements(evaluations.to_field_elements());
+        for circuit_evaluations in &evaluations {
+            sponge.absorb_nonnative_field_elements(circuit_evaluations.to_field_elements());
+        }
 
-        let pc_proof = SonicKZG10::<E, FS>::open_combinations(
-            &circuit_proving_key.committer_key,
-            lc_s.values(),
-            polynomials,
-            &labeled_commitments,
-            &query_set.to_set(),
-            &commitment_randomnesses,
+        // All committer keys are trimmed from the same universal SRS,
+        // so the one supporting the largest degree can open every polynomial.
+        let committer_key =
+            keys_to_constraints.iter().map(|(pk, _)| &pk.committer_key).max_by_key(|ck| ck.supported_degree()).unwrap();
+        let proof = SonicKZG10::<E, FS>::batch_open(
+            committer_key,
+            &opening_polynomials,
+            &opening_commitments,
+            &opening_query_set,
+            &opening_randomnesses,
             &mut sponge,
         )?;
+        let pc_proof = BatchLCProof { proof, evaluations: None };
 
         Self::terminate(terminator)?;
 
-        let proof = Proof::<E>::new(batch_size, commitments, evaluations, prover_third_message, pc_proof)?;
+        let proof = Proof::<E>::new(batch_sizes, commitments, evaluations, prover_third_messages, pc_proof)?;
         assert_eq!(proof.pc_proof.is_hiding(), MM::ZK);
 
         #[cfg(debug_assertions)]
-        if !Self::verify_batch(fs_parameters, &circuit_proving_key.circuit_verifying_key, &public_input, &proof)? {
-            println!("Invalid proof")
+        {
+            let keys_to_inputs = keys_to_constraints
+                .iter()
+                .zip_eq(&public_inputs)
+                .map(|((pk, _), inputs)| (&pk.circuit_verifying_key, inputs.as_slice()))
+                .collect::<Vec<_>>();
+            if !Self::verify_multi_batch(fs_parameters, &keys_to_inputs, &proof)? {
+                println!("Invalid proof")
+            }
         }
         end_timer!(prover_time);
 
         Ok(proof)
     }
 
-    fn verify_batch_prepared<B: Borrow<Self::VerifierInput>>(
-        fs_parameters: &Self::FSParameters,
-        prepared_verifying_key: &<Self::VerifyingKey as Prepare>::Prepared,
-        public_inputs: &[B],
-        proof: &Self::Proof,
+    /// Verifies a proof for batches of public inputs to one or more circuits.
+    pub fn verify_multi_batch<B: Borrow<[E::Fr]>>(
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &[(&CircuitVerifyingKey<E, MM>, &[B])],
+        proof: &Proof<E>,
     ) -> Result<bool, SNARKError> {
-        let circuit_verifying_key = &prepared_verifying_key.orig_vk;
-        if public_inputs.is_empty() {
+        if keys_to_inputs.is_empty() || keys_to_inputs.iter().any(|(_, inputs)| inputs.is_empty()) {
             return Err(SNARKError::EmptyBatch);
         }
 
-        if public_inputs.len() != proof.batch_size()? {
+        let batch_sizes = keys_to_inputs.iter().map(|(_, inputs)| inputs.len()).collect::<Vec<_>>();
+        if batch_sizes != proof.batch_sizes()? {
             return Err(SNARKError::BatchSizeMismatch);
         }
 
-        let comms = &proof.commitments;
+        // The opening proof is checked against the verifier key of the first circuit,
+        // which requires all circuits to be derived from the same universal SRS.
+        let verifier_key = &keys_to_inputs[0].0.verifier_key;
+        if keys_to_inputs.iter().any(|(vk, _)| {
+            vk.verifier_key.vk != verifier_key.vk || vk.verifier_key.max_degree() != verifier_key.max_degree()
+        }) {
+            return Err(MarlinError::IncompatibleVerifyingKeys.into());
+        }
+
         let proof_has_correct_zk_mode = if MM::ZK {
-            proof.pc_proof.is_hiding() & comms.mask_poly.is_some()
+            proof.pc_proof.is_hiding() & proof.commitments.iter().all(|comms| comms.mask_poly.is_some())
         } else {
-            !proof.pc_proof.is_hiding() & comms.mask_poly.is_none()
+            !proof.pc_proof.is_hiding() & proof.commitments.iter().all(|comms| comms.mask_poly.is_none())
         };
         if !proof_has_correct_zk_mode {
             eprintln!(
@@ -600,87 +615,103 @@ where
             return Ok(false);
         }
 
-        let batch_size = public_inputs.len();
-        let verifier_time = start_timer!(|| format!("Marlin::Verify with batch size {batch_size}"));
+        let verifier_time = start_timer!(|| format!("Marlin::Verify with batch sizes {batch_sizes:?}"));
 
-        let first_round_info = AHPForR1CS::<E::Fr, MM>::first_round_polynomial_info(batch_size);
-        let mut first_commitments = comms
-            .witness_commitments
-            .iter()
-            .enumerate()
-            .flat_map(|(i, c)| {
-                [
-                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("w", i)], c.w),
-                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_a", i)], c.z_a),
-                    LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_b", i)], c.z_b),
-                ]
-            })
-            .collect::<Vec<_>>();
-        if MM::ZK {
-            first_commitments.push(LabeledCommitment::new_with_info(
-                first_round_info.get("mask_poly").unwrap(),
-                comms.mask_poly.unwrap(),
-            ));
-        }
+        let mut first_commitments = Vec::with_capacity(keys_to_inputs.len());
+        let mut second_commitments = Vec::with_capacity(keys_to_inputs.len());
+        let mut third_commitments = Vec::with_capacity(keys_to_inputs.len());
+        let mut fourth_commitments = Vec::with_capacity(keys_to_inputs.len());
+        let mut padded_public_inputs = Vec::with_capacity(keys_to_inputs.len());
+        let mut public_inputs = Vec::with_capacity(keys_to_inputs.len());
+        for ((circuit_verifying_key, inputs), comms) in keys_to_inputs.iter().zip_eq(&proof.commitments) {
+            let batch_size = inputs.len();
 
-        let second_round_info =
-            AHPForR1CS::<E::Fr, MM>::second_round_polynomial_info(&circuit_verifying_key.circuit_info);
-        let second_commitments = [
-            LabeledCommitment::new_with_info(&second_round_info["g_1"], comms.g_1),
-            LabeledCommitment::new_with_info(&second_round_info["h_1"], comms.h_1),
-        ];
-
-        let third_round_info =
-            AHPForR1CS::<E::Fr, MM>::third_round_polynomial_info(&circuit_verifying_key.circuit_info);
-        let third_commitments = [
-            LabeledCommitment::new_with_info(&third_round_info["g_a"], comms.g_a),
-            LabeledCommitment::new_with_info(&third_round_info["g_b"], comms.g_b),
-            LabeledCommitment::new_with_info(&third_round_info["g_c"], comms.g_c),
-        ];
-
-        let fourth_round_info = AHPForR1CS::<E::Fr, MM>::fourth_round_polynomial_info();
-        let fourth_commitments = [LabeledCommitment::new_with_info(&fourth_round_info["h_2"], comms.h_2)];
-
-        let input_domain =
-            EvaluationDomain::<E::Fr>::new(circuit_verifying_key.circuit_info.num_public_inputs).unwrap();
-
-        let (padded_public_inputs, public_inputs): (Vec<_>, Vec<_>) = {
-            public_inputs
+            let first_round_info = AHPForR1CS::<E::Fr, MM>::first_round_polynomial_info(batch_size);
+            let mut circuit_first_commitments = comms
+                .witness_commitments
                 .iter()
-                .map(|input| {
-                    let input = input.borrow().to_field_elements().unwrap();
-                    let mut new_input = vec![E::Fr::one()];
-                    new_input.extend_from_slice(&input);
-                    new_input.resize(input.len().max(input_domain.size()), E::Fr::zero());
-                    if cfg!(debug_assertions) {
-                        println!("Number of padded public variables: {}", new_input.len());
-                    }
-                    let unformatted = prover::ConstraintSystem::unformat_public_input(&new_input);
-                    (new_input, unformatted)
+                .enumerate()
+                .flat_map(|(i, c)| {
+                    [
+                        LabeledCommitment::new_with_info(&first_round_info[&witness_label("w", i)], c.w),
+                        LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_a", i)], c.z_a),
+                        LabeledCommitment::new_with_info(&first_round_info[&witness_label("z_b", i)], c.z_b),
+                    ]
                 })
-                .unzip()
-        };
+                .collect::<Vec<_>>();
+            if MM::ZK {
+                circuit_first_commitments.push(LabeledCommitment::new_with_info(
+                    first_round_info.get("mask_poly").unwrap(),
+                    comms.mask_poly.unwrap(),
+                ));
+            }
+            first_commitments.push(circuit_first_commitments);
+
+            let second_round_info =
+                AHPForR1CS::<E::Fr, MM>::second_round_polynomial_info(&circuit_verifying_key.circuit_info);
+            second_commitments.push([
+                LabeledCommitment::new_with_info(&second_round_info["g_1"], comms.g_1),
+                LabeledCommitment::new_with_info(&second_round_info["h_1"], comms.h_1),
+            ]);
+
+            let third_round_info =
+                AHPForR1CS::<E::Fr, MM>::third_round_polynomial_info(&circuit_verifying_key.circuit_info);
+            third_commitments.push([
+                LabeledCommitment::new_with_info(&third_round_info["g_a"], comms.g_a),
+                LabeledCommitment::new_with_info(&third_round_info["g_b"], comms.g_b),
+                LabeledCommitment::new_with_info(&third_round_info["g_c"], comms.g_c),
+            ]);
+
+            let fourth_round_info = AHPForR1CS::<E::Fr, MM>::fourth_round_polynomial_info();
+            fourth_commitments.push([LabeledCommitment::new_with_info(&fourth_round_info["h_2"], comms.h_2)]);
+
+            let input_domain =
+                EvaluationDomain::<E::Fr>::new(circuit_verifying_key.circuit_info.num_public_inputs).unwrap();
+
+            let (padded, unformatted): (Vec<_>, Vec<_>) = {
+                inputs
+                    .iter()
+                    .map(|input| {
+                        let input = input.borrow().to_field_elements().unwrap();
+                        let mut new_input = vec![E::Fr::one()];
+                        new_input.extend_from_slice(&input);
+                        new_input.resize(input.len().max(input_domain.size()), E::Fr::zero());
+                        if cfg!(debug_assertions) {
+                            println!("Number of padded public variables: {}", new_input.len());
+                        }
+                        let unformatted = prover::ConstraintSystem::unformat_public_input(&new_input);
+                        (new_input, unformatted)
+                    })
+                    .unzip()
+            };
+            padded_public_inputs.push(padded);
+            public_inputs.push(unformatted);
+        }
 
-        let mut sponge = Self::init_sponge(
-            fs_parameters,
-            batch_size,
-            &circuit_verifying_key.circuit_commitments,
-            &padded_public_inputs,
-        );
+        let sponge_inputs = keys_to_inputs
+            .iter()
+            .zip_eq(&padded_public_inputs)
+            .map(|((vk, inputs), padded)| (inputs.len(), vk.circuit_commitments.as_slice(), padded.as_slice()))
+            .collect::<Vec<_>>();
+        let mut sponge = Self::init_sponge(fs_parameters, &sponge_inputs);
 
         // --------------------------------------------------------------------
         // First round
         let first_round_time = start_timer!(|| "First round");
-        Self::absorb_labeled(&first_commitments, &mut sponge);
-        let (_, verifier_state) =
-            AHPForR1CS::<_, MM>::verifier_first_round(circuit_verifying_key.circuit_info, batch_size, &mut sponge)?;
+        for commitments in &first_commitments {
+            Self::absorb_labeled(commitments, &mut sponge);
+        }
+        let index_infos = keys_to_inputs.iter().map(|(vk, inputs)| (vk.circuit_info, inputs.len())).collect::<Vec<_>>();
+        let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_first_round(&index_infos, &mut sponge)?;
         end_timer!(first_round_time);
         // --------------------------------------------------------------------
 
         // --------------------------------------------------------------------
         // Second round
         let second_round_time = start_timer!(|| "Second round");
-        Self::absorb_labeled(&second_commitments, &mut sponge);
+        for commitments in &second_commitments {
+            Self::absorb_labeled(commitments, &mut sponge);
+        }
         let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_second_round(verifier_state, &mut sponge)?;
         end_timer!(second_round_time);
         // --------------------------------------------------------------------
@@ -688,8 +719,9 @@ where
         // --------------------------------------------------------------------
         // Third round
         let third_round_time = start_timer!(|| "Third round");
-
-        Self::absorb_labeled_with_msg(&third_commitments, &proof.msg, &mut sponge);
+        for (commitments, msg) in third_commitments.iter().zip_eq(&proof.msg) {
+            Self::absorb_labeled_with_msg(commitments, msg, &mut sponge);
+        }
         let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_third_round(verifier_state, &mut sponge)?;
         end_timer!(third_round_time);
         // --------------------------------------------------------------------
@@ -697,62 +729,89 @@ where
         // --------------------------------------------------------------------
         // Fourth round
         let fourth_round_time = start_timer!(|| "Fourth round");
-
-        Self::absorb_labeled(&fourth_commitments, &mut sponge);
-        let verifier_state = AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)?;
+        for commitments in &fourth_commitments {
+            Self::absorb_labeled(commitments, &mut sponge);
+        }
+        let verifier_states = AHPForR1CS::<_, MM>::verifier_fourth_round(verifier_state, &mut sponge)?;
         end_timer!(fourth_round_time);
         // --------------------------------------------------------------------
 
-        // Collect degree bounds for commitments. Indexed polynomials have *no*
-        // degree bounds because we know the committed index polynomial has the
-        // correct degree.
+        for evaluations in &proof.evaluations {
+            sponge.absorb_nonnative_field_elements(evaluations.to_field_elements());
+        }
 
-        // Gather commitments in one vector.
-        let commitments: Vec<_> = circuit_verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .chain(first_commitments)
-            .chain(second_commitments)
-            .chain(third_commitments)
-            .chain(fourth_commitments)
-            .collect();
-
-        let query_set_time = start_timer!(|| "Constructing query set");
-        let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
-        end_timer!(query_set_time);
-
-        sponge.absorb_nonnative_field_elements(proof.evaluations.to_field_elements());
-
-        let mut evaluations = Evaluations::new();
-
-        for (label, (_point_name, q)) in query_set.to_set() {
-            if AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_ref()) {
-                evaluations.insert((label, q), E::Fr::zero());
-            } else {
-                let eval = proof.evaluations.get(&label).ok_or_else(|| AHPError::MissingEval(label.clone()))?;
-                evaluations.insert((label, q), eval);
+        let mut opening_commitments = Vec::new();
+        let mut opening_query_set = QuerySet::new();
+        let mut opening_evaluations = Evaluations::new();
+        for (i, verifier_state) in verifier_states.into_iter().enumerate() {
+            let circuit_verifying_key = keys_to_inputs[i].0;
+
+            // Collect degree bounds for commitments. Indexed polynomials have *no*
+            // degree bounds because we know the committed index polynomial has the
+            // correct degree.
+
+            // Gather commitments in one vector.
+            let commitments: Vec<_> = circuit_verifying_key
+                .iter()
+                .cloned()
+                .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
+                .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+                .chain(first_commitments[i].iter().cloned())
+                .chain(second_commitments[i].iter().cloned())
+                .chain(third_commitments[i].iter().cloned())
+                .chain(fourth_commitments[i].iter().cloned())
+                .collect();
+
+            let query_set_time = start_timer!(|| "Constructing query set");
+            let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state);
+            end_timer!(query_set_time);
+
+            let mut evaluations = Evaluations::new();
+            for (label, (_point_name, q)) in query_set.to_set() {
+                if AHPForR1CS::<E::Fr, MM>::LC_WITH_ZERO_EVAL.contains(&label.as_ref()) {
+                    evaluations.insert((label, q), E::Fr::zero());
+                } else {
+                    let eval = proof.evaluations[i].get(&label).ok_or_else(|| AHPError::MissingEval(label.clone()))?;
+                    evaluations.insert((label, q), eval);
+                }
             }
-        }
 
-        let lc_time = start_timer!(|| "Constructing linear combinations");
-        let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
-            &public_inputs,
-            &evaluations,
-            &proof.msg,
-            &verifier_state,
-        )?;
-        end_timer!(lc_time);
+            let lc_time = start_timer!(|| "Constructing linear combinations");
+            let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(
+                &public_inputs[i],
+                &evaluations,
+                &proof.msg[i],
+                &verifier_state,
+            )?;
+            end_timer!(lc_time);
+
+            // Combine the commitments of this circuit into its linear combinations,
+            // and add these to the opening under labels that are unique to this circuit.
+            let (lc_commitments, evaluations) =
+                SonicKZG10::<E, FS>::combine_lcs_for_check(lc_s.values(), &commitments, &evaluations)?;
+            for commitment in lc_commitments {
+                let label = Self::circuit_label(i, commitment.label());
+                opening_commitments.push(LabeledCommitment::new(
+                    label,
+                    *commitment.commitment(),
+                    commitment.degree_bound(),
+                ));
+            }
+            for ((label, point), evaluation) in evaluations {
+                opening_evaluations.insert((Self::circuit_label(i, &label), point), evaluation);
+            }
+            for (label, query) in query_set.to_set() {
+                opening_query_set.insert((Self::circuit_label(i, &label), query));
+            }
+        }
 
         let pc_time = start_timer!(|| "Checking linear combinations with PC");
-        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
-            &circuit_verifying_key.verifier_key,
-            lc_s.values(),
-            &commitments,
-            &query_set.to_set(),
-            &evaluations,
-            &proof.pc_proof,
+        let evaluations_are_correct = SonicKZG10::<E, FS>::batch_check(
+            verifier_key,
+            &opening_commitments,
+            &opening_query_set,
+            &opening_evaluations,
+            &proof.pc_proof.proof,
             &mut sponge,
         )?;
         end_timer!(pc_time);
@@ -769,6 +828,154 @@ where
     }
 }
 
+impl<E: PairingEngine, FS, MM> SNARK for MarlinSNARK<E, FS, MM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    MM: MarlinMode,
+{
+    type BaseField = E::Fq;
+    type Certificate = Certificate<E>;
+    type FSParameters = FS::Parameters;
+    type FiatShamirRng = FS;
+    type Proof = Proof<E>;
+    type ProvingKey = CircuitProvingKey<E, MM>;
+    type ScalarField = E::Fr;
+    type UniversalSetupConfig = usize;
+    type UniversalSetupParameters = UniversalSRS<E>;
+    type VerifierInput = [E::Fr];
+    type VerifyingKey = CircuitVerifyingKey<E, MM>;
+
+    fn universal_setup(max_degree: &Self::UniversalSetupConfig) -> Result<Self::UniversalSetupParameters, SNARKError> {
+        let setup_time = start_timer!(|| { format!("Marlin::UniversalSetup with max_degree {max_degree}",) });
+
+        let srs = SonicKZG10::<E, FS>::load_srs(*max_degree).map_err(Into::into);
+        end_timer!(setup_time);
+        srs
+    }
+
+    fn setup<C: ConstraintSynthesizer<E::Fr>>(
+        circuit: &C,
+        srs: &mut SRS<Self::UniversalSetupParameters>,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey), SNARKError> {
+        match srs {
+            SRS::CircuitSpecific => Self::circuit_specific_setup(circuit),
+            SRS::Universal(srs) => Self::circuit_setup(srs, circuit),
+        }
+        .map_err(SNARKError::from)
+    }
+
+    fn prove_vk(
+        fs_parameters: &Self::FSParameters,
+        verifying_key: &Self::VerifyingKey,
+        proving_key: &Self::ProvingKey,
+    ) -> Result<Self::Certificate, SNARKError> {
+        // Initialize sponge
+        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
+        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
+        // The linear combination requires `num_polynomials - 1` coefficients
+        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
+        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
+        let point = challenges.pop().unwrap();
+        let one = E::Fr::one();
+        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
+
+        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
+        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
+        for (poly, &c) in proving_key.circuit.iter().zip(linear_combination_challenges) {
+            lc.add(c, poly.label());
+        }
+
+        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
+        let commitments = verifying_key
+            .iter()
+            .cloned()
+            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info().values())
+            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+            .collect::<Vec<_>>();
+
+        let certificate = SonicKZG10::<E, FS>::open_combinations(
+            &proving_key.committer_key,
+            &[lc],
+            proving_key.circuit.iter(),
+            &commitments,
+            &query_set,
+            &proving_key.circuit_commitment_randomness.clone(),
+            &mut sponge,
+        )?;
+
+        Ok(Self::Certificate::new(certificate))
+    }
+
+    fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
+        fs_parameters: &Self::FSParameters,
+        circuit: &C,
+        verifying_key: &Self::VerifyingKey,
+        certificate: &Self::Certificate,
+    ) -> Result<bool, SNARKError> {
+        let info = AHPForR1CS::<E::Fr, MM>::index_polynomial_info();
+        // Initialize sponge.
+        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
+        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
+        // The linear combination requires `num_polynomials - 1` coefficients
+        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
+        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
+        let point = challenges.pop().unwrap();
+
+        let evaluations_at_point = AHPForR1CS::<E::Fr, MM>::evaluate_index_polynomials(circuit, point)?;
+        let one = E::Fr::one();
+        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
+
+        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
+        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
+        let mut evaluation = E::Fr::zero();
+        for ((label, &c), eval) in info.keys().zip_eq(linear_combination_challenges).zip_eq(evaluations_at_point) {
+            lc.add(c, label.as_str());
+            evaluation += c * eval;
+        }
+
+        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
+        let commitments = verifying_key
+            .iter()
+            .cloned()
+            .zip_eq(info.values())
+            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+            .collect::<Vec<_>>();
+        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
+
+        SonicKZG10::<E, FS>::check_combinations(
+            &verifying_key.verifier_key,
+            &[lc],
+            &commitments,
+            &query_set,
+            &evaluations,
+            &certificate.pc_proof,
+            &mut sponge,
+        )
+        .map_err(Into::into)
+    }
+
+    fn prove_batch_with_terminator<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        fs_parameters: &Self::FSParameters,
+        circuit_proving_key: &CircuitProvingKey<E, MM>,
+        circuits: &[C],
+        terminator: &AtomicBool,
+        zk_rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError> {
+        Self::prove_multi_batch_with_terminator(fs_parameters, &[(circuit_proving_key, circuits)], terminator, zk_rng)
+    }
+
+    fn verify_batch_prepared<B: Borrow<Self::VerifierInput>>(
+        fs_parameters: &Self::FSParameters,
+        prepared_verifying_key: &<Self::VerifyingKey as Prepare>::Prepared,
+        public_inputs: &[B],
+        proof: &Self::Proof,
+    ) -> Result<bool, SNARKError> {
+        Self::verify_multi_batch(fs_parameters, &[(&prepared_verifying_key.orig_vk, public_inputs)], proof)
+    }
+}
+
 #[cfg(test)]
 pub mod test {
     use super::*;
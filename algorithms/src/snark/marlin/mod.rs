@@ -37,6 +37,10 @@ pub use data_structures::*;
 mod errors;
 pub use errors::*;
 
+/// Implements the Marlin zkSNARK over the inner product argument polynomial commitment.
+mod ipa;
+pub use ipa::*;
+
 /// Implements the base Marlin zkSNARK proof system.
 mod marlin;
 pub use marlin::*;
@@ -157,6 +157,82 @@ mod marlin {
                     }
                 }
 
+                pub(crate) fn test_multiple_circuits(circuit_sizes: &[(usize, usize)]) {
+                    use snarkvm_utilities::{FromBytes, ToBytes};
+
+                    let rng = &mut snarkvm_utilities::rand::TestRng::default();
+
+                    let max_degree = AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(&max_degree).unwrap();
+                    let fs_parameters = FS::sample_parameters();
+
+                    // Sample a batch of assignments for each circuit, with a different batch size per circuit.
+                    let mut keys = Vec::new();
+                    let mut circuit_batches = Vec::new();
+                    let mut input_batches = Vec::new();
+                    for (i, (num_constraints, num_variables)) in circuit_sizes.iter().copied().enumerate() {
+                        let (circuit_batch, input_batch): (Vec<_>, Vec<_>) = (0..i + 1)
+                            .map(|_| {
+                                let a = Fr::rand(rng);
+                                let b = Fr::rand(rng);
+                                let mut c = a;
+                                c.mul_assign(&b);
+                                let mut d = c;
+                                d.mul_assign(&b);
+
+                                let circ = Circuit { a: Some(a), b: Some(b), num_constraints, num_variables };
+                                (circ, [c, d])
+                            })
+                            .unzip();
+                        keys.push($marlin_inst::circuit_setup(&universal_srs, &circuit_batch[0]).unwrap());
+                        circuit_batches.push(circuit_batch);
+                        input_batches.push(input_batch);
+                    }
+
+                    let keys_to_constraints = keys
+                        .iter()
+                        .zip(&circuit_batches)
+                        .map(|((pk, _), circuits)| (pk, circuits.as_slice()))
+                        .collect::<Vec<_>>();
+                    let proof = $marlin_inst::prove_multi_batch(&fs_parameters, &keys_to_constraints, rng).unwrap();
+                    println!("Called prover");
+                    let keys_to_inputs = keys
+                        .iter()
+                        .zip(&input_batches)
+                        .map(|((_, vk), inputs)| (vk, inputs.as_slice()))
+                        .collect::<Vec<_>>();
+                    assert!($marlin_inst::verify_multi_batch(&fs_parameters, &keys_to_inputs, &proof).unwrap());
+                    println!("Called verifier");
+
+                    // Check that the proof survives a round trip through its byte representation.
+                    let proof_bytes = proof.to_bytes_le().unwrap();
+                    let candidate_proof = crate::snark::marlin::Proof::read_le(&proof_bytes[..]).unwrap();
+                    assert_eq!(proof, candidate_proof);
+                    assert!(
+                        $marlin_inst::verify_multi_batch(&fs_parameters, &keys_to_inputs, &candidate_proof).unwrap()
+                    );
+
+                    println!("\nShould not verify (i.e. verifier messages should print below):");
+                    let mut bad_input_batches = input_batches.clone();
+                    bad_input_batches.last_mut().unwrap()[0] = [Fr::rand(rng), Fr::rand(rng)];
+                    let bad_keys_to_inputs = keys
+                        .iter()
+                        .zip(&bad_input_batches)
+                        .map(|((_, vk), inputs)| (vk, inputs.as_slice()))
+                        .collect::<Vec<_>>();
+                    assert!(!$marlin_inst::verify_multi_batch(&fs_parameters, &bad_keys_to_inputs, &proof).unwrap());
+
+                    // Check that the proof is rejected for a different number of instances.
+                    assert!(
+                        $marlin_inst::verify_multi_batch(
+                            &fs_parameters,
+                            &keys_to_inputs[..keys_to_inputs.len() - 1],
+                            &proof
+                        )
+                        .is_err()
+                    );
+                }
+
                 pub(crate) fn test_serde_json(num_constraints: usize, num_variables: usize) {
                     use std::str::FromStr;
 
@@ -215,6 +291,14 @@ mod marlin {
     impl_marlin_test!(SonicPCTest, MarlinSonicInst, MarlinHidingMode);
     impl_marlin_test!(SonicPCPoswTest, MarlinSonicPoswInst, MarlinNonHidingMode);
 
+    #[test]
+    fn prove_and_verify_with_multiple_circuits() {
+        let circuit_sizes = [(25, 25), (100, 25), (25, 100)];
+
+        SonicPCTest::test_multiple_circuits(&circuit_sizes);
+        SonicPCPoswTest::test_multiple_circuits(&circuit_sizes);
+    }
+
     #[test]
     fn prove_and_verify_with_tall_matrix_big() {
         let num_constraints = 100;
@@ -509,3 +593,79 @@ mod marlin_hiding {
         assert!(MarlinInst::verify(&fs_parameters, &vk1, [c1, d1], &proof1).unwrap());
     }
 }
+
+mod marlin_ipa {
+    use super::*;
+    use crate::{
+        crypto_hash::PoseidonSponge,
+        snark::marlin::{ahp::AHPForR1CS, MarlinHidingMode, MarlinIPA, MarlinMode, MarlinNonHidingMode},
+    };
+    use snarkvm_curves::bls12_377::{Fq, Fr, G1Affine};
+    use snarkvm_utilities::rand::{TestRng, Uniform};
+
+    use core::ops::MulAssign;
+
+    type FS = PoseidonSponge<Fq, 2, 1>;
+
+    fn test_circuit<MM: MarlinMode>(num_constraints: usize, num_variables: usize) {
+        let rng = &mut TestRng::default();
+
+        // The inner product argument requires the number of generators to be a power of two.
+        let max_degree = AHPForR1CS::<Fr, MM>::max_degree(100, 25, 300).unwrap();
+        let universal_params =
+            MarlinIPA::<G1Affine, FS, MM>::universal_setup((max_degree + 1).next_power_of_two() - 1).unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        for batch_size in [1, 2, 3] {
+            let (circuit_batch, input_batch): (Vec<_>, Vec<_>) = (0..batch_size)
+                .map(|_| {
+                    let a = Fr::rand(rng);
+                    let b = Fr::rand(rng);
+                    let mut c = a;
+                    c.mul_assign(&b);
+                    let mut d = c;
+                    d.mul_assign(&b);
+
+                    (Circuit { a: Some(a), b: Some(b), num_constraints, num_variables }, [c, d])
+                })
+                .unzip();
+
+            let (index_pk, index_vk) =
+                MarlinIPA::<G1Affine, FS, MM>::circuit_setup(&universal_params, &circuit_batch[0]).unwrap();
+            println!("Called circuit setup");
+
+            let proof =
+                MarlinIPA::<G1Affine, FS, MM>::prove_batch(&fs_parameters, &index_pk, &circuit_batch, rng).unwrap();
+            println!("Called prover");
+
+            assert!(
+                MarlinIPA::<G1Affine, FS, MM>::verify_batch(&fs_parameters, &index_vk, &input_batch, &proof).unwrap()
+            );
+            println!("Called verifier");
+            println!("\nShould not verify (i.e. verifier messages should print below):");
+            let mut fake_inputs = input_batch.clone();
+            fake_inputs[0] = [fake_inputs[0][1], fake_inputs[0][0]];
+            assert!(
+                !MarlinIPA::<G1Affine, FS, MM>::verify_batch(&fs_parameters, &index_vk, &fake_inputs, &proof).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn prove_and_verify_with_square_matrix() {
+        test_circuit::<MarlinNonHidingMode>(25, 25);
+        test_circuit::<MarlinHidingMode>(25, 25);
+    }
+
+    #[test]
+    fn prove_and_verify_with_tall_matrix() {
+        test_circuit::<MarlinNonHidingMode>(25, 10);
+        test_circuit::<MarlinHidingMode>(25, 10);
+    }
+
+    #[test]
+    fn prove_and_verify_with_squat_matrix() {
+        test_circuit::<MarlinNonHidingMode>(10, 25);
+        test_circuit::<MarlinHidingMode>(10, 25);
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> FromBits for Signature<A> {
+    type Boolean = Boolean<A>;
+
+    /// Initializes a new signature from a list of little-endian bits.
+    fn from_bits_le(bits_le: &[Self::Boolean]) -> Self {
+        // Ensure the number of bits is correct.
+        let size_in_bits = 2 * A::ScalarField::size_in_bits() + 2 * A::BaseField::size_in_bits();
+        if bits_le.len() != size_in_bits {
+            A::halt(format!("Expected {size_in_bits} bits for a signature, found {} bits", bits_le.len()))
+        }
+
+        // Split the bits into the challenge, response, `pk_sig`, and `pr_sig`.
+        let (challenge, bits_le) = bits_le.split_at(A::ScalarField::size_in_bits());
+        let (response, bits_le) = bits_le.split_at(A::ScalarField::size_in_bits());
+        let (pk_sig, pr_sig) = bits_le.split_at(A::BaseField::size_in_bits());
+
+        // Recover the signature.
+        let challenge = Scalar::from_bits_le(challenge);
+        let response = Scalar::from_bits_le(response);
+        let compute_key = ComputeKey::from((Group::from_bits_le(pk_sig), Group::from_bits_le(pr_sig)));
+        Self { challenge, response, compute_key }
+    }
+
+    /// Initializes a new signature from a list of big-endian bits.
+    fn from_bits_be(bits_be: &[Self::Boolean]) -> Self {
+        // Ensure the number of bits is correct.
+        let size_in_bits = 2 * A::ScalarField::size_in_bits() + 2 * A::BaseField::size_in_bits();
+        if bits_be.len() != size_in_bits {
+            A::halt(format!("Expected {size_in_bits} bits for a signature, found {} bits", bits_be.len()))
+        }
+
+        // Split the bits into the challenge, response, `pk_sig`, and `pr_sig`.
+        let (challenge, bits_be) = bits_be.split_at(A::ScalarField::size_in_bits());
+        let (response, bits_be) = bits_be.split_at(A::ScalarField::size_in_bits());
+        let (pk_sig, pr_sig) = bits_be.split_at(A::BaseField::size_in_bits());
+
+        // Recover the signature.
+        let challenge = Scalar::from_bits_be(challenge);
+        let response = Scalar::from_bits_be(response);
+        let compute_key = ComputeKey::from((Group::from_bits_be(pk_sig), Group::from_bits_be(pr_sig)));
+        Self { challenge, response, compute_key }
+    }
+}
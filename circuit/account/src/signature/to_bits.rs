@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<A: Aleo> ToBits for Signature<A> {
+    type Boolean = Boolean<A>;
+
+    /// Outputs the little-endian bit representation of the signature *without* trailing zeros.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        (&self).to_bits_le()
+    }
+
+    /// Outputs the big-endian bit representation of the signature *without* leading zeros.
+    fn to_bits_be(&self) -> Vec<Self::Boolean> {
+        (&self).to_bits_be()
+    }
+}
+
+impl<A: Aleo> ToBits for &Signature<A> {
+    type Boolean = Boolean<A>;
+
+    /// Outputs the little-endian bit representation of the signature *without* trailing zeros.
+    fn to_bits_le(&self) -> Vec<Self::Boolean> {
+        [
+            self.challenge.to_bits_le(),
+            self.response.to_bits_le(),
+            self.compute_key.pk_sig().to_bits_le(),
+            self.compute_key.pr_sig().to_bits_le(),
+        ]
+        .concat()
+    }
+
+    /// Outputs the big-endian bit representation of the signature *without* leading zeros.
+    fn to_bits_be(&self) -> Vec<Self::Boolean> {
+        [
+            self.challenge.to_bits_be(),
+            self.response.to_bits_be(),
+            self.compute_key.pk_sig().to_bits_be(),
+            self.compute_key.pr_sig().to_bits_be(),
+        ]
+        .concat()
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::{helpers::generate_account, Circuit};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_to_bits(mode: Mode) -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Generate a private key.
+        let (private_key, _compute_key, _view_key, _address) = generate_account()?;
+
+        for i in 0..ITERATIONS {
+            // Generate a signature.
+            let message: Vec<_> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let expected = console::Signature::sign(&private_key, &message, rng)?;
+
+            let candidate = Signature::<Circuit>::new(mode, expected);
+            // Check the little-endian bits.
+            let candidate_bits = candidate.to_bits_le();
+            assert_eq!(expected.to_bits_le(), candidate_bits.eject_value());
+            assert_eq!(expected, Signature::from_bits_le(&candidate_bits).eject_value());
+            // Check the big-endian bits.
+            let candidate_bits = candidate.to_bits_be();
+            assert_eq!(expected.to_bits_be(), candidate_bits.eject_value());
+            assert_eq!(expected, Signature::from_bits_be(&candidate_bits).eject_value());
+            Circuit::reset();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_bits_constant() -> Result<()> {
+        check_to_bits(Mode::Constant)
+    }
+
+    #[test]
+    fn test_to_bits_public() -> Result<()> {
+        check_to_bits(Mode::Public)
+    }
+
+    #[test]
+    fn test_to_bits_private() -> Result<()> {
+        check_to_bits(Mode::Private)
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Identifier;
+use snarkvm_circuit_network::Aleo;
+use snarkvm_circuit_types::{environment::prelude::*, U32};
+
+/// An access into a plaintext value, i.e. a struct member or an array index.
+#[derive(Clone)]
+pub enum Access<A: Aleo> {
+    /// The access is a struct member, i.e. `.{identifier}`.
+    Member(Identifier<A>),
+    /// The access is an array index, i.e. `[{index}]`.
+    Index(U32<A>),
+}
+
+#[cfg(console)]
+impl<A: Aleo> Inject for Access<A> {
+    type Primitive = console::Access<A::Network>;
+
+    /// Initializes a new access circuit from a primitive.
+    /// Note: Accesses are always `Mode::Constant`.
+    fn new(_: Mode, access: Self::Primitive) -> Self {
+        match access {
+            Self::Primitive::Member(identifier) => Self::Member(Identifier::new(Mode::Constant, identifier)),
+            Self::Primitive::Index(index) => Self::Index(U32::new(Mode::Constant, index)),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Eject for Access<A> {
+    type Primitive = console::Access<A::Network>;
+
+    /// Ejects the mode of the access.
+    fn eject_mode(&self) -> Mode {
+        match self {
+            Self::Member(identifier) => identifier.eject_mode(),
+            Self::Index(index) => index.eject_mode(),
+        }
+    }
+
+    /// Ejects the access.
+    fn eject_value(&self) -> Self::Primitive {
+        match self {
+            Self::Member(identifier) => console::Access::Member(identifier.eject_value()),
+            Self::Index(index) => console::Access::Index(index.eject_value()),
+        }
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Debug for Access<A> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(console)]
+impl<A: Aleo> Display for Access<A> {
+    /// Prints the access as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.eject_value())
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+
+    type CurrentNetwork = <Circuit as Environment>::Network;
+
+    #[test]
+    fn test_inject_and_eject() -> Result<()> {
+        for string in [".owner", "[0u32]", "[31u32]"] {
+            let expected = console::Access::<CurrentNetwork>::from_str(string)?;
+            // Ensure the access is always a constant.
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                let candidate = Access::<Circuit>::new(mode, expected);
+                assert_eq!(Mode::Constant, candidate.eject_mode());
+                assert_eq!(expected, candidate.eject_value());
+                assert_eq!(string, candidate.to_string());
+            }
+        }
+        Ok(())
+    }
+}
@@ -36,6 +36,11 @@ impl<A: Aleo> Literal<A> {
             Self::U128(..) => console::U128::<A::Network>::size_in_bits() as u16,
             Self::Scalar(..) => console::Scalar::<A::Network>::size_in_bits() as u16,
             Self::String(string) => string.to_bits_le().len() as u16,
+            // A signature is serialized as the challenge, response, and the x-coordinates of `pk_sig` and `pr_sig`.
+            Self::Signature(..) => {
+                (2 * console::Scalar::<A::Network>::size_in_bits() + 2 * console::Field::<A::Network>::size_in_bits())
+                    as u16
+            }
         }))
     }
 }
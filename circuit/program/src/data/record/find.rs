@@ -18,16 +18,18 @@ use super::*;
 
 impl<A: Aleo> Record<A, Plaintext<A>> {
     /// Returns the entry from the given path.
-    pub fn find(&self, path: &[Identifier<A>]) -> Result<Entry<A, Plaintext<A>>> {
+    pub fn find(&self, path: &[Access<A>]) -> Result<Entry<A, Plaintext<A>>> {
         // If the path is of length one, check if the path is requesting the `owner`.
-        if path.len() == 1 && path[0] == Identifier::from_str("owner")? {
-            return Ok(self.owner.to_entry());
+        if let [Access::Member(member)] = path {
+            if *member == Identifier::from_str("owner")? {
+                return Ok(self.owner.to_entry());
+            }
         }
 
         // Ensure the path is not empty.
-        if let Some((first, rest)) = path.split_first() {
+        match path.split_first() {
             // Retrieve the top-level entry.
-            match self.data.get(first) {
+            Some((Access::Member(first), rest)) => match self.data.get(first) {
                 Some(entry) => match rest.is_empty() {
                     // If the remaining path is empty, return the top-level entry.
                     true => Ok(entry.clone()),
@@ -35,9 +37,10 @@ impl<A: Aleo> Record<A, Plaintext<A>> {
                     false => entry.find(rest),
                 },
                 None => bail!("Record entry `{first}` not found."),
-            }
-        } else {
-            bail!("Attempted to find record entry with an empty path.")
+            },
+            // Halts if the path attempts to index into the record.
+            Some((Access::Index(index), _)) => bail!("Attempted to index into a record with '{index}'."),
+            None => bail!("Attempted to find record entry with an empty path."),
         }
     }
 }
@@ -58,6 +58,12 @@ mod tests {
     const ALEO_PRIVATE_KEY: &str = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p";
     const ALEO_VIEW_KEY: &str = "AViewKey1n1n3ZbnVEtXVe3La2xWkUvY3EY7XaCG6RZJJ3tbvrrrD";
     const ALEO_ADDRESS: &str = "aleo1wvgwnqvy46qq0zemj0k6sfp3zv0mp77rw97khvwuhac05yuwscxqmfyhwf";
+    const ALEO_MNEMONIC: &str = "clutch coyote glide claim affair train coral acquire knock pudding world estate attack bounce parrot aware usual shift calm tornado jelly ribbon bunker dune";
+    const ALEO_CHILD_PRIVATE_KEYS: [(&str, &str); 3] = [
+        ("m/0", "APrivateKey1zkp5QTYzxBwBv1YHaKJqAzFnVNGndLaCdKNLqAX5YA1gBPk"),
+        ("m/1", "APrivateKey1zkp8KEnpxjp5k4ofuR7iZ5m5LLpjCEjgfWMZmXFCPAFe9f8"),
+        ("m/0/1", "APrivateKey1zkpAZoFHcF5LnF4DcaBh994SpTcGT44C2mefVvpfUPGxhBM"),
+    ];
 
     const ITERATIONS: usize = 1_000;
 
@@ -72,6 +78,23 @@ mod tests {
         assert_eq!(ALEO_ADDRESS, address.to_string());
     }
 
+    #[test]
+    fn test_private_key_mnemonic() {
+        let private_key = PrivateKey::<CurrentNetwork>::from_str(ALEO_PRIVATE_KEY).unwrap();
+        assert_eq!(ALEO_MNEMONIC, private_key.to_mnemonic().unwrap());
+        assert_eq!(private_key, PrivateKey::from_mnemonic(ALEO_MNEMONIC).unwrap());
+    }
+
+    #[test]
+    fn test_private_key_derive() {
+        let private_key = PrivateKey::<CurrentNetwork>::from_str(ALEO_PRIVATE_KEY).unwrap();
+        for (path, expected) in ALEO_CHILD_PRIVATE_KEYS {
+            let child = private_key.derive(path).unwrap();
+            assert_eq!(expected, child.to_string());
+            assert_eq!(child, PrivateKey::from_mnemonic(&child.to_mnemonic().unwrap()).unwrap());
+        }
+    }
+
     #[test]
     fn test_private_key_from_str() {
         let private_key = PrivateKey::<CurrentNetwork>::from_str(ALEO_PRIVATE_KEY).unwrap();
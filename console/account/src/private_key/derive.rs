@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+static ACCOUNT_DERIVATION_DOMAIN: &str = "AleoAccountDerivation0";
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the child private key at the given `index`, derived from the account seed.
+    ///
+    /// The child seed is computed as `Poseidon4(domain, seed, index)`, and as such,
+    /// a child private key does not reveal its parent private key or its siblings.
+    pub fn derive_child(&self, index: u32) -> Result<Self> {
+        // Construct the derivation domain separator.
+        let domain = Field::<N>::new_domain_separator(ACCOUNT_DERIVATION_DOMAIN);
+        // Compute the child seed.
+        let child_seed = N::hash_psd4(&[domain, self.seed, Field::from_u32(index)])?;
+        // Return the child private key.
+        Self::try_from(child_seed)
+    }
+
+    /// Returns the descendant private key for the given derivation `path`, where `self` is the master private key.
+    ///
+    /// The path is of the form `m/i/j/...`, where each index is a `u32`, and `m` denotes the master private key.
+    pub fn derive(&self, path: &str) -> Result<Self> {
+        // Split the path into its components.
+        let mut components = path.trim().split('/');
+        // Ensure the path starts with the master private key.
+        ensure!(
+            components.next() == Some("m"),
+            "Invalid derivation path '{path}': expected a path of the form 'm/i/j/...'"
+        );
+        // Derive the private key for each index in the path.
+        components.try_fold(*self, |private_key, component| match component.parse::<u32>() {
+            Ok(index) => private_key.derive_child(index),
+            Err(_) => bail!("Invalid derivation path '{path}': '{component}' is not a valid index"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_derive_child() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+
+            // Ensure the derivation is deterministic.
+            let child = private_key.derive_child(0)?;
+            assert_eq!(child, private_key.derive_child(0)?);
+            assert_eq!(child, private_key.derive("m/0")?);
+            // Ensure the children are distinct from each other and the parent.
+            assert_ne!(child, private_key);
+            assert_ne!(child, private_key.derive_child(1)?);
+            // Ensure the path is applied in order.
+            assert_eq!(child.derive_child(1)?, private_key.derive("m/0/1")?);
+            assert_ne!(private_key.derive("m/0/1")?, private_key.derive("m/1/0")?);
+            // Ensure the master path returns the master private key.
+            assert_eq!(private_key, private_key.derive("m")?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_invalid_path() -> Result<()> {
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut TestRng::default())?;
+
+        assert!(private_key.derive("").is_err());
+        assert!(private_key.derive("0/1").is_err());
+        assert!(private_key.derive("m/").is_err());
+        assert!(private_key.derive("m/a").is_err());
+        assert!(private_key.derive("m/-1").is_err());
+        assert!(private_key.derive("m/4294967296").is_err());
+        Ok(())
+    }
+}
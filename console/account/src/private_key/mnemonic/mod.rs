@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+static ACCOUNT_MNEMONIC_DOMAIN: &str = "AleoAccountMnemonic0";
+
+/// The English wordlist, as specified in BIP-39.
+static WORDLIST: &str = include_str!("english.txt");
+
+/// The number of bits encoded by each word.
+const BITS_PER_WORD: usize = 11;
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the mnemonic phrase for the account seed.
+    ///
+    /// The phrase encodes the bits of the account seed, followed by a checksum of the seed,
+    /// with each word encoding 11 bits (in little-endian order) as an index into the BIP-39 English wordlist.
+    pub fn to_mnemonic(&self) -> Result<String> {
+        // Retrieve the wordlist.
+        let wordlist = WORDLIST.lines().collect::<Vec<_>>();
+        // Compute the bits of the seed, followed by the checksum.
+        let mut bits = self.seed.to_bits_le();
+        bits.extend(checksum::<N>(&bits)?);
+        // Encode each chunk of bits as a word.
+        let words = bits
+            .chunks(BITS_PER_WORD)
+            .map(|chunk| wordlist[chunk.iter().rev().fold(0, |index, bit| (index << 1) | *bit as usize)])
+            .collect::<Vec<_>>();
+        Ok(words.join(" "))
+    }
+
+    /// Returns the private key for the given mnemonic phrase.
+    pub fn from_mnemonic(phrase: &str) -> Result<Self> {
+        // Retrieve the wordlist.
+        let wordlist = WORDLIST.lines().collect::<Vec<_>>();
+        // Retrieve the words.
+        let words = phrase.split_whitespace().collect::<Vec<_>>();
+        // Ensure the number of words is correct.
+        let num_words = num_words::<N>();
+        ensure!(words.len() == num_words, "Invalid mnemonic: found {} words, expected {num_words}", words.len());
+
+        // Decode each word into its bits.
+        let mut bits = Vec::with_capacity(num_words * BITS_PER_WORD);
+        for word in words {
+            let index = match wordlist.binary_search(&word) {
+                Ok(index) => index,
+                Err(_) => bail!("Invalid mnemonic: '{word}' is not in the wordlist"),
+            };
+            bits.extend((0..BITS_PER_WORD).map(|i| (index >> i) & 1 == 1));
+        }
+
+        // Split the bits into the seed and the checksum.
+        let (seed_bits, checksum_bits) = bits.split_at(Field::<N>::size_in_bits());
+        // Ensure the checksum is correct.
+        ensure!(checksum::<N>(seed_bits)? == checksum_bits, "Invalid mnemonic: incorrect checksum");
+        // Recover the seed.
+        let seed = Field::from_bits_le(seed_bits).map_err(|_| anyhow!("Invalid mnemonic: the seed is out of range"))?;
+        // Return the private key.
+        Self::try_from(seed)
+    }
+}
+
+/// Returns the number of words in a mnemonic phrase, which is the smallest number of words that encode a seed and a checksum.
+fn num_words<N: Network>() -> usize {
+    Field::<N>::size_in_bits() / BITS_PER_WORD + 1
+}
+
+/// Returns the checksum bits for the given seed bits.
+fn checksum<N: Network>(seed_bits: &[bool]) -> Result<Vec<bool>> {
+    // Construct the mnemonic domain separator.
+    let domain = Field::<N>::new_domain_separator(ACCOUNT_MNEMONIC_DOMAIN);
+    // Compute the number of checksum bits.
+    let num_checksum_bits = num_words::<N>() * BITS_PER_WORD - Field::<N>::size_in_bits();
+    // Hash the domain separator and seed bits.
+    let preimage = domain.to_bits_le().into_iter().chain(seed_bits.iter().copied()).collect::<Vec<_>>();
+    // Return the checksum bits.
+    Ok(N::hash_bhp256(&preimage)?.to_bits_le().into_iter().take(num_checksum_bits).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_wordlist() {
+        let wordlist = WORDLIST.lines().collect::<Vec<_>>();
+        // Ensure the wordlist has an entry for every index.
+        assert_eq!(wordlist.len(), 1 << BITS_PER_WORD);
+        // Ensure the wordlist is sorted and unique, as required for the binary search.
+        assert!(wordlist.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_mnemonic() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let expected = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+
+            // Check the mnemonic representation.
+            let mnemonic = expected.to_mnemonic()?;
+            assert_eq!(mnemonic.split(' ').count(), num_words::<CurrentNetwork>());
+            assert_eq!(expected, PrivateKey::from_mnemonic(&mnemonic)?);
+            // Ensure extra whitespace is tolerated.
+            assert_eq!(expected, PrivateKey::from_mnemonic(&format!("  {}\n", mnemonic.replace(' ', "   ")))?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_mnemonic_invalid() -> Result<()> {
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut TestRng::default())?;
+        let mnemonic = private_key.to_mnemonic()?;
+        let mut words = mnemonic.split(' ').collect::<Vec<_>>();
+
+        // Ensure a missing word is rejected.
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(&words[1..].join(" ")).is_err());
+        // Ensure an unknown word is rejected.
+        words[0] = "aleo";
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(&words.join(" ")).is_err());
+        // Ensure an incorrect checksum is rejected.
+        let mut words = mnemonic.split(' ').collect::<Vec<_>>();
+        words[0] = if words[0] == "abandon" { "ability" } else { "abandon" };
+        assert!(PrivateKey::<CurrentNetwork>::from_mnemonic(&words.join(" ")).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> ToBits for Signature<N> {
+    /// Outputs the little-endian bit representation of the signature *without* trailing zeros.
+    fn to_bits_le(&self) -> Vec<bool> {
+        [
+            self.challenge.to_bits_le(),
+            self.response.to_bits_le(),
+            self.compute_key.pk_sig().to_bits_le(),
+            self.compute_key.pr_sig().to_bits_le(),
+        ]
+        .concat()
+    }
+
+    /// Outputs the big-endian bit representation of the signature *without* leading zeros.
+    fn to_bits_be(&self) -> Vec<bool> {
+        [
+            self.challenge.to_bits_be(),
+            self.response.to_bits_be(),
+            self.compute_key.pk_sig().to_bits_be(),
+            self.compute_key.pr_sig().to_bits_be(),
+        ]
+        .concat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_to_bits() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample a new signature.
+            let signature = test_helpers::sample_signature(i, &mut rng);
+
+            // Check the little-endian bit representation.
+            let candidate = signature.to_bits_le();
+            assert_eq!(Signature::<CurrentNetwork>::size_in_bits(), candidate.len());
+            assert_eq!(signature, Signature::from_bits_le(&candidate)?);
+
+            // Check the big-endian bit representation.
+            let candidate = signature.to_bits_be();
+            assert_eq!(Signature::<CurrentNetwork>::size_in_bits(), candidate.len());
+            assert_eq!(signature, Signature::from_bits_be(&candidate)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod parse;
+mod serialize;
+
+use crate::Identifier;
+use snarkvm_console_network::Network;
+use snarkvm_console_types::{prelude::*, U32};
+
+/// An access into a plaintext value, i.e. a struct member or an array index.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Access<N: Network> {
+    /// The access is a struct member, i.e. `.{identifier}`.
+    Member(Identifier<N>),
+    /// The access is an array index, i.e. `[{index}]`.
+    Index(U32<N>),
+}
+
+impl<N: Network> From<Identifier<N>> for Access<N> {
+    /// Initializes a new member access from an identifier.
+    #[inline]
+    fn from(identifier: Identifier<N>) -> Self {
+        Self::Member(identifier)
+    }
+}
+
+impl<N: Network> From<U32<N>> for Access<N> {
+    /// Initializes a new index access from a `u32` index.
+    #[inline]
+    fn from(index: U32<N>) -> Self {
+        Self::Index(index)
+    }
+}
@@ -36,6 +36,12 @@ impl<N: Network> Literal<N> {
             LiteralType::U128 => Literal::U128(U128::rand(rng)),
             LiteralType::Scalar => Literal::Scalar(Scalar::rand(rng)),
             LiteralType::String => Literal::String(StringType::rand(rng)),
+            LiteralType::Signature => {
+                // Sample a compute key from random `pk_sig` and `pr_sig` values.
+                let compute_key = ComputeKey::try_from((Group::rand(rng), Group::rand(rng)))
+                    .or_halt_with::<N>("Failed to sample a compute key");
+                Literal::Signature(Box::new(Signature::from((Scalar::rand(rng), Scalar::rand(rng), compute_key))))
+            }
         }
     }
 }
@@ -17,52 +17,78 @@
 use super::*;
 
 impl<N: Network> Plaintext<N> {
-    /// Returns the plaintext member from the given path.
-    pub fn find(&self, path: &[Identifier<N>]) -> Result<Plaintext<N>> {
+    /// Returns the plaintext member or element from the given path.
+    pub fn find(&self, path: &[Access<N>]) -> Result<Plaintext<N>> {
         // Ensure the path is not empty.
         ensure!(!path.is_empty(), "Attempted to find member with an empty path.");
 
-        match self {
-            // Halts if the value is not a struct.
-            Self::Literal(..) => bail!("'{self}' is not a struct"),
-            // Retrieve the value of the member (from the value).
-            Self::Struct(members, ..) => {
-                // Initialize the members starting from the top-level.
-                let mut submembers = members;
+        // Initialize the plaintext starting from the top-level.
+        let mut plaintext = self;
 
-                // Initialize the output.
-                let mut output = None;
-
-                // Iterate through the path to retrieve the value.
-                for (i, identifier) in path.iter().enumerate() {
-                    // If this is not the last item in the path, ensure the value is a struct.
-                    if i != path.len() - 1 {
-                        match submembers.get(identifier) {
-                            // Halts if the member is not a struct.
-                            Some(Self::Literal(..)) => bail!("'{identifier}' must be a struct"),
-                            // Retrieve the member and update `submembers` for the next iteration.
-                            Some(Self::Struct(members, ..)) => submembers = members,
-                            // Halts if the member does not exist.
-                            None => bail!("Failed to locate member '{identifier}' in '{self}'"),
-                        }
-                    }
-                    // Otherwise, return the final member.
-                    else {
-                        match submembers.get(identifier) {
-                            // Return the plaintext member.
-                            Some(plaintext) => output = Some(plaintext.clone()),
-                            // Halts if the member does not exist.
-                            None => bail!("Failed to locate member '{identifier}' in '{self}'"),
-                        }
-                    }
-                }
-
-                // Return the output.
-                match output {
-                    Some(output) => Ok(output),
-                    None => bail!("Failed to locate member from path in '{self}': {:?}", path),
+        // Iterate through the path to retrieve the value.
+        for access in path {
+            plaintext = match (plaintext, access) {
+                // Retrieve the member from the struct.
+                (Self::Struct(members, ..), Access::Member(identifier)) => match members.get(identifier) {
+                    Some(member) => member,
+                    // Halts if the member does not exist.
+                    None => bail!("Failed to locate member '{identifier}' in '{self}'"),
+                },
+                // Retrieve the element from the array.
+                (Self::Array(elements, ..), Access::Index(index)) => match elements.get(**index as usize) {
+                    Some(element) => element,
+                    // Halts if the index is out of bounds.
+                    None => bail!("Index '{index}' is out of bounds in '{self}'"),
+                },
+                // Halts if the member is accessed on a non-struct.
+                (_, Access::Member(identifier)) => {
+                    bail!("Failed to access member '{identifier}': '{plaintext}' is not a struct")
                 }
-            }
+                // Halts if the index is accessed on a non-array.
+                (_, Access::Index(index)) => bail!("Failed to access index '{index}': '{plaintext}' is not an array"),
+            };
         }
+
+        // Return the output.
+        Ok(plaintext.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_find() -> Result<()> {
+        let plaintext = Plaintext::<CurrentNetwork>::from_str("{ a: [{ b: 1u8 }, { b: 2u8 }], c: 3u8 }")?;
+
+        // Find a member.
+        let path = [Access::Member(Identifier::from_str("c")?)];
+        assert_eq!(plaintext.find(&path)?, Plaintext::from_str("3u8")?);
+        // Find an element.
+        let path = [Access::Member(Identifier::from_str("a")?), Access::Index(U32::new(1))];
+        assert_eq!(plaintext.find(&path)?, Plaintext::from_str("{ b: 2u8 }")?);
+        // Find a member of an element.
+        let path = [
+            Access::Member(Identifier::from_str("a")?),
+            Access::Index(U32::new(0)),
+            Access::Member(Identifier::from_str("b")?),
+        ];
+        assert_eq!(plaintext.find(&path)?, Plaintext::from_str("1u8")?);
+
+        // Ensure the index is within bounds.
+        let path = [Access::Member(Identifier::from_str("a")?), Access::Index(U32::new(2))];
+        assert!(plaintext.find(&path).is_err());
+        // Ensure a struct cannot be indexed.
+        assert!(plaintext.find(&[Access::Index(U32::new(0))]).is_err());
+        // Ensure an array cannot be accessed by member.
+        let path = [Access::Member(Identifier::from_str("a")?), Access::Member(Identifier::from_str("b")?)];
+        assert!(plaintext.find(&path).is_err());
+        // Ensure the path is not empty.
+        assert!(plaintext.find(&[]).is_err());
+        Ok(())
     }
 }
@@ -62,6 +62,28 @@ impl<N: Network> Parser for Plaintext<N> {
             Ok((string, Plaintext::Struct(IndexMap::from_iter(members.into_iter()), Default::default())))
         }
 
+        /// Parses a plaintext as an array: `[plaintext_0, ..., plaintext_n]`.
+        fn parse_array<N: Network>(string: &str) -> ParserResult<Plaintext<N>> {
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the "[" from the string.
+            let (string, _) = tag("[")(string)?;
+            // Parse the elements.
+            let (string, elements) = map_res(separated_list1(tag(","), Plaintext::parse), |elements: Vec<_>| {
+                // Ensure the number of elements is within the maximum limit.
+                match elements.len() <= N::MAX_DATA_ENTRIES {
+                    true => Ok(elements),
+                    false => Err(error(format!("Found an array that exceeds size ({})", elements.len()))),
+                }
+            })(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the ']' from the string.
+            let (string, _) = tag("]")(string)?;
+            // Output the plaintext.
+            Ok((string, Plaintext::Array(elements, Default::default())))
+        }
+
         // Parse the whitespace from the string.
         let (string, _) = Sanitizer::parse_whitespaces(string)?;
         // Parse to determine the plaintext (order matters).
@@ -70,6 +92,8 @@ impl<N: Network> Parser for Plaintext<N> {
             map(Literal::parse, |literal| Self::Literal(literal, Default::default())),
             // Parse a plaintext struct.
             parse_struct,
+            // Parse a plaintext array.
+            parse_array,
         ))(string)
     }
 }
@@ -131,7 +155,7 @@ impl<N: Network> Plaintext<N> {
                             // Print the member with a comma.
                             false => write!(f, "\n{:indent$}{name}: {literal},", "", indent = (depth + 1) * INDENT),
                         },
-                        Self::Struct(..) => {
+                        Self::Struct(..) | Self::Array(..) => {
                             // Print the member name.
                             write!(f, "\n{:indent$}{name}: ", "", indent = (depth + 1) * INDENT)?;
                             // Print the member.
@@ -147,6 +171,31 @@ impl<N: Network> Plaintext<N> {
                     }
                 })
             }
+            // Prints the array, i.e. [ 10i64, 198u64 ]
+            Self::Array(array, ..) => {
+                // Print the opening bracket.
+                write!(f, "[")?;
+                // Print the elements.
+                array.iter().enumerate().try_for_each(|(i, plaintext)| {
+                    match plaintext {
+                        // Print the literal element.
+                        Self::Literal(literal, ..) => {
+                            write!(f, "\n{:indent$}{literal}", "", indent = (depth + 1) * INDENT)?
+                        }
+                        // Print the struct or array element.
+                        Self::Struct(..) | Self::Array(..) => {
+                            write!(f, "\n{:indent$}", "", indent = (depth + 1) * INDENT)?;
+                            plaintext.fmt_internal(f, depth + 1)?;
+                        }
+                    }
+                    match i == array.len() - 1 {
+                        // Print the closing bracket after the last element.
+                        true => write!(f, "\n{:indent$}]", "", indent = depth * INDENT),
+                        // Print the element with a comma.
+                        false => write!(f, ","),
+                    }
+                })
+            }
         }
     }
 }
@@ -213,6 +262,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_array() -> Result<()> {
+        // Sanity check.
+        let expected = r"[
+  1u8,
+  2u8
+]";
+        let (remainder, candidate) = Plaintext::<CurrentNetwork>::parse("[1u8, 2u8]")?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        let expected = r"{
+  foo: [
+    {
+      bar: 5u8
+    },
+    {
+      bar: 6u8
+    }
+  ],
+  baz: [
+    [
+      true
+    ],
+    [
+      false
+    ]
+  ]
+}";
+        let (remainder, candidate) =
+            Plaintext::<CurrentNetwork>::parse("{ foo: [{ bar: 5u8 }, { bar: 6u8 }], baz: [[true], [false]] }")?;
+        println!("\nExpected: {expected}\n\nFound: {candidate}\n");
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!("", remainder);
+
+        // Ensure the array is non-empty.
+        assert!(Plaintext::<CurrentNetwork>::parse("[]").is_err());
+        // Ensure the array does not exceed the maximum number of elements.
+        let elements = vec!["1u8"; CurrentNetwork::MAX_DATA_ENTRIES + 1].join(", ");
+        assert!(Plaintext::<CurrentNetwork>::parse(&format!("[{elements}]")).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_fails() {
         // Must be non-empty.
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod bytes;
+mod parse;
+mod serialize;
+
+use crate::PlaintextType;
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::U32;
+
+/// An `ArrayType` defines the type and length of a fixed-length array.
+/// The format of the type is `[{element_type}; {length}]`, i.e. `[address; 8u32]`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ArrayType<N: Network> {
+    /// The type of the elements.
+    element_type: Box<PlaintextType<N>>,
+    /// The number of elements.
+    length: U32<N>,
+}
+
+impl<N: Network> ArrayType<N> {
+    /// Initializes a new array type, with the given element type and length.
+    pub fn new(element_type: PlaintextType<N>, length: U32<N>) -> Result<Self> {
+        // Ensure the array is not empty.
+        ensure!(*length > 0, "An array must have at least one element");
+        // Ensure the number of elements does not exceed the maximum.
+        ensure!(
+            *length as usize <= N::MAX_DATA_ENTRIES,
+            "An array cannot exceed {} elements, found {length}",
+            N::MAX_DATA_ENTRIES
+        );
+
+        // Compute the depth of the nested array types.
+        let mut depth = 1;
+        let mut element_type_ = &element_type;
+        while let PlaintextType::Array(array_type) = element_type_ {
+            depth += 1;
+            element_type_ = array_type.element_type();
+        }
+        // Ensure the depth of the array does not exceed the maximum.
+        ensure!(depth <= N::MAX_DATA_DEPTH, "An array cannot exceed a depth of {}", N::MAX_DATA_DEPTH);
+
+        Ok(Self { element_type: Box::new(element_type), length })
+    }
+
+    /// Returns the element type.
+    pub const fn element_type(&self) -> &PlaintextType<N> {
+        &self.element_type
+    }
+
+    /// Returns the length of the array.
+    pub const fn length(&self) -> &U32<N> {
+        &self.length
+    }
+
+    /// Returns the base element type, i.e. the innermost element type that is not an array.
+    pub fn base_element_type(&self) -> &PlaintextType<N> {
+        let mut element_type = self.element_type();
+        while let PlaintextType::Array(array_type) = element_type {
+            element_type = array_type.element_type();
+        }
+        element_type
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LiteralType;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_new() -> Result<()> {
+        let element_type = PlaintextType::<CurrentNetwork>::Literal(LiteralType::Address);
+
+        // Ensure the length is within bounds.
+        assert!(ArrayType::new(element_type.clone(), U32::new(0)).is_err());
+        assert!(ArrayType::new(element_type.clone(), U32::new(1)).is_ok());
+        assert!(ArrayType::new(element_type.clone(), U32::new(CurrentNetwork::MAX_DATA_ENTRIES as u32)).is_ok());
+        assert!(ArrayType::new(element_type.clone(), U32::new(CurrentNetwork::MAX_DATA_ENTRIES as u32 + 1)).is_err());
+
+        // Ensure the depth is within bounds.
+        let mut plaintext_type = element_type;
+        for _ in 0..CurrentNetwork::MAX_DATA_DEPTH {
+            plaintext_type = PlaintextType::Array(ArrayType::new(plaintext_type, U32::new(1))?);
+        }
+        assert!(ArrayType::new(plaintext_type, U32::new(1)).is_err());
+        Ok(())
+    }
+}
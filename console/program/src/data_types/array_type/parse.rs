@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Parser for ArrayType<N> {
+    /// Parses a string into an array type.
+    /// The array type is of the form `[{element_type}; {length}]`.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opening bracket from the string.
+        let (string, _) = tag("[")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the element type from the string.
+        let (string, element_type) = PlaintextType::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the semicolon from the string.
+        let (string, _) = tag(";")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the length from the string, and initialize the array type.
+        let (string, array_type) = map_res(U32::parse, |length| Self::new(element_type.clone(), length))(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the closing bracket from the string.
+        let (string, _) = tag("]")(string)?;
+        // Return the array type.
+        Ok((string, array_type))
+    }
+}
+
+impl<N: Network> FromStr for ArrayType<N> {
+    type Err = Error;
+
+    /// Returns an array type from a string literal.
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for ArrayType<N> {
+    /// Prints the array type as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for ArrayType<N> {
+    /// Prints the array type as a string, i.e. `[address; 8u32]`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "[{}; {}]", self.element_type, self.length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Identifier, LiteralType};
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        assert_eq!(
+            ArrayType::<CurrentNetwork>::parse("[address; 8u32]"),
+            Ok(("", ArrayType::new(PlaintextType::Literal(LiteralType::Address), U32::new(8))?))
+        );
+        assert_eq!(
+            ArrayType::<CurrentNetwork>::parse("[ item;2u32 ]"),
+            Ok(("", ArrayType::new(PlaintextType::Struct(Identifier::from_str("item")?), U32::new(2))?))
+        );
+        assert_eq!(
+            ArrayType::<CurrentNetwork>::parse("[[u8; 2u32]; 3u32]"),
+            Ok((
+                "",
+                ArrayType::new(
+                    PlaintextType::Array(ArrayType::new(PlaintextType::Literal(LiteralType::U8), U32::new(2))?),
+                    U32::new(3)
+                )?
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_fails() {
+        assert!(ArrayType::<CurrentNetwork>::parse("").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8; 2]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8; 2u8]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8; 0u32]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8; 33u32]").is_err());
+        assert!(ArrayType::<CurrentNetwork>::parse("[u8.private; 2u32]").is_err());
+    }
+
+    #[test]
+    fn test_display() -> Result<()> {
+        for string in ["[address; 8u32]", "[item; 2u32]", "[[u8; 2u32]; 3u32]"] {
+            assert_eq!(ArrayType::<CurrentNetwork>::from_str(string)?.to_string(), string);
+        }
+        Ok(())
+    }
+}
@@ -22,6 +22,7 @@ impl<N: Network> Parser for PlaintextType<N> {
     fn parse(string: &str) -> ParserResult<Self> {
         // Parse to determine the plaintext type (order matters).
         alt((
+            map(ArrayType::parse, |type_| Self::Array(type_)),
             map(LiteralType::parse, |type_| Self::Literal(type_)),
             map(Identifier::parse, |identifier| Self::Struct(identifier)),
         ))(string)
@@ -60,6 +61,8 @@ impl<N: Network> Display for PlaintextType<N> {
             Self::Literal(literal) => Display::fmt(literal, f),
             // Prints the struct, i.e. signature
             Self::Struct(struct_) => Display::fmt(struct_, f),
+            // Prints the array, i.e. [field; 2u32]
+            Self::Array(array) => Display::fmt(array, f),
         }
     }
 }
@@ -78,8 +81,12 @@ mod tests {
             Ok(("", PlaintextType::<CurrentNetwork>::Literal(LiteralType::Field)))
         );
         assert_eq!(
-            PlaintextType::parse("signature"),
-            Ok(("", PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("signature")?)))
+            PlaintextType::parse("message"),
+            Ok(("", PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("message")?)))
+        );
+        assert_eq!(
+            PlaintextType::parse("[field; 2u32]"),
+            Ok(("", PlaintextType::<CurrentNetwork>::Array(ArrayType::from_str("[field; 2u32]")?)))
         );
         Ok(())
     }
@@ -153,6 +160,10 @@ mod tests {
             PlaintextType::<CurrentNetwork>::Struct(Identifier::from_str("signature")?).to_string(),
             "signature"
         );
+        assert_eq!(
+            PlaintextType::<CurrentNetwork>::Array(ArrayType::from_str("[signature; 4u32]")?).to_string(),
+            "[signature; 4u32]"
+        );
         Ok(())
     }
 }
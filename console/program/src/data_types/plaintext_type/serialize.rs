@@ -69,6 +69,10 @@ mod tests {
         "passport",
         "object",
         "array",
+        // Array
+        "[u8; 4u32]",
+        "[signature; 2u32]",
+        "[[field; 2u32]; 3u32]",
     ];
 
     fn check_serde_json<
@@ -0,0 +1,194 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{field, Field, PrimeField, Zero};
+use snarkvm_utilities::{
+    biginteger::{BigInteger384, BigInteger768 as BigInteger},
+    BitIteratorBE,
+};
+
+use crate::{
+    bw6_761::{Fq, Fr},
+    traits::{ModelParameters, ShortWeierstrassParameters},
+    AffineCurve,
+    ProjectiveCurve,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Bw6_761G1Parameters;
+
+impl ModelParameters for Bw6_761G1Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl ShortWeierstrassParameters for Bw6_761G1Parameters {
+    /// AFFINE_GENERATOR_COEFFS = (G1_GENERATOR_X, G1_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (G1_GENERATOR_X, G1_GENERATOR_Y);
+    /// B1 = 0, as the GLV decomposition is not used for this curve.
+    const B1: Fr = field!(Fr, BigInteger384([0, 0, 0, 0, 0, 0]));
+    /// B2 = 0, as the GLV decomposition is not used for this curve.
+    const B2: Fr = field!(Fr, BigInteger384([0, 0, 0, 0, 0, 0]));
+    /// COFACTOR =
+    /// 26642435879335816683987677701488073867751118270052650655942102502312977592501693353047140953112195348280268661194876
+    const COFACTOR: &'static [u64] = &[
+        0x3de580000000007c,
+        0x832ba4061000003b,
+        0xc61c554757551c0c,
+        0xc856a0853c9db94c,
+        0x2c77d5ac34cb12ef,
+        0xad1972339049ce76,
+    ];
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    ///              = 91141326767669940707819291241958318717982251277713150053234367522357946997763584490607453720072232540829942217804
+    const COFACTOR_INV: Fr = field!(
+        Fr,
+        BigInteger384([
+            0x6cbc6719306b799,
+            0x35e466ec4fc64c14,
+            0x174a5cff3b221c74,
+            0x512b12e900a45d2e,
+            0x62eba10945bdbdf0,
+            0xfe1019f053865a,
+        ])
+    );
+    /// PHI = 2^((q - 1) / 3), a primitive cube root of unity in Fq
+    const PHI: Fq = field!(
+        Fq,
+        BigInteger([
+            0x67a04ae427bfb5f8,
+            0x9d32d491eb6a5cff,
+            0x43d03c1cb68051d4,
+            0xb75ca96f69859a5,
+            0x763497f5325ec60,
+            0x48076b5c278dd94d,
+            0x8ca3965ff91efd06,
+            0x1e6077657ea02f5d,
+            0xcdd6c153a8c37724,
+            0x28b5b634e5c22ea4,
+            0x9e01e3efd42e902c,
+            0xe3d6815769a804,
+        ])
+    );
+    /// R128 = 2^128 - 1
+    const R128: Fr = field!(
+        Fr,
+        BigInteger384([
+            0x756769f442c231aa,
+            0x6e32813404448615,
+            0xb2b9914a353a4acb,
+            0xec54c9b5c69584ca,
+            0x6899ce22b746d7da,
+            0xa178088025a774,
+        ])
+    );
+    /// WEIERSTRASS_A = 0
+    const WEIERSTRASS_A: Fq = field!(Fq, BigInteger([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]));
+    /// WEIERSTRASS_B = -1
+    const WEIERSTRASS_B: Fq = field!(
+        Fq,
+        BigInteger([
+            0xf29a000000007ab6,
+            0x8c391832e000739b,
+            0x77738a6b6870f959,
+            0xbe36179047832b03,
+            0x84f3089e56574722,
+            0xc5a3614ac0b1d984,
+            0x5c81153f4906e9fe,
+            0x4d28be3a9f55c815,
+            0xd72c1d6f77d5f5c5,
+            0x73a18e069ac04458,
+            0xf9dfaa846595555f,
+            0xd0f0a60a5be58c,
+        ])
+    );
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(
+        p: &crate::templates::short_weierstrass_jacobian::Affine<Self>,
+    ) -> bool {
+        p.mul_bits(BitIteratorBE::new(Self::ScalarField::characteristic())).is_zero()
+    }
+
+    fn glv_endomorphism(
+        mut p: crate::templates::short_weierstrass_jacobian::Affine<Self>,
+    ) -> crate::templates::short_weierstrass_jacobian::Affine<Self> {
+        p.x *= &Self::PHI;
+        p
+    }
+
+    fn mul_projective(
+        p: crate::templates::short_weierstrass_jacobian::Projective<Self>,
+        by: Self::ScalarField,
+    ) -> crate::templates::short_weierstrass_jacobian::Projective<Self> {
+        let mut acc = crate::templates::short_weierstrass_jacobian::Projective::<Self>::zero();
+        for bit in BitIteratorBE::new_without_leading_zeros(by.to_bigint()) {
+            acc.double_in_place();
+            if bit {
+                acc += p;
+            }
+        }
+        acc
+    }
+}
+
+///
+/// G1_GENERATOR_X =
+/// 6238772257594679368032145693622812838779005809760824733138787810501188623461307351759238099287535516224314149266511977132140828635950940021790489507611754366317801811090811367945064510304504157188661901055903167026722666149426237
+///
+pub const G1_GENERATOR_X: Fq = field!(
+    Fq,
+    BigInteger([
+        0xd6e42d7614c2d770,
+        0x4bb886eddbc3fc21,
+        0x64648b044098b4d2,
+        0x1a585c895a422985,
+        0xf1a9ac17cf8685c9,
+        0x352785830727aea5,
+        0xddf8cb12306266fe,
+        0x6913b4bfbc9e949a,
+        0x3a4b78d67ba5f6ab,
+        0xf481c06a8d02a04,
+        0x91d4e7365c43edac,
+        0xf4d17cd48beca5,
+    ])
+);
+
+///
+/// G1_GENERATOR_Y =
+/// 2101735126520897423911504562215834951148127555913367997162789335052900271653517958562461315794228241561913734371411178226936527683203879553093934185950470971848972085321797958124416462268292467002957525517188485984766314758624099
+///
+pub const G1_GENERATOR_Y: Fq = field!(
+    Fq,
+    BigInteger([
+        0x97e805c4bd16411f,
+        0x870d844e1ee6dd08,
+        0x1eba7a37cb9eab4d,
+        0xd544c4df10b9889a,
+        0x8fe37f21a33897be,
+        0xe9bf99a43a0885d2,
+        0xd7ee0c9e273de139,
+        0xaa6a9ec7a38dd791,
+        0x8f95d3fcf765da8e,
+        0x42326e7db7357c99,
+        0xe217e407e218695f,
+        0x9d1eb23b7cf684,
+    ])
+);
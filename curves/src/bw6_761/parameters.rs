@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bw6_761::{g1::Bw6_761G1Parameters, g2::Bw6_761G2Parameters, Fq, Fq3Parameters, Fq6, Fq6Parameters},
+    templates::{
+        bls12::TwistType,
+        bw6::{
+            Bw6,
+            Bw6Parameters,
+            G1Affine as Bw6G1Affine,
+            G1Prepared,
+            G1Projective as Bw6G1Projective,
+            G2Affine as Bw6G2Affine,
+            G2Prepared,
+            G2Projective as Bw6G2Projective,
+        },
+    },
+    traits::{PairingCurve, PairingEngine},
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Bw6_761Parameters;
+
+impl Bw6Parameters for Bw6_761Parameters {
+    type Fp = Fq;
+    type Fp3Params = Fq3Parameters;
+    type Fp6Params = Fq6Parameters;
+    type G1Parameters = Bw6_761G1Parameters;
+    type G2Parameters = Bw6_761G2Parameters;
+
+    /// ATE_LOOP_COUNT_1 = u + 1, where u = 0x8508c00000000001 is the BLS12-377 parameter.
+    const ATE_LOOP_COUNT_1: &'static [u64] = &[0x8508c00000000002];
+    const ATE_LOOP_COUNT_1_IS_NEGATIVE: bool = false;
+    /// ATE_LOOP_COUNT_2 = u^3 - u^2 - u, in little-endian NAF form.
+    #[rustfmt::skip]
+    const ATE_LOOP_COUNT_2: &'static [i8] = &[
+        -1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, 0, -1, 0, 1, 0, -1, 0, 0, 0, 0, -1, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -1, 0, -1, 0, 0, 0, 0, -1, 0, 0, 1, 0, 0, 0, -1, 0, 0, -1, 0, 1, 0, -1, 0, 0, 0, 1, 0, 0, 1, 0, -1, 0, 1, 0, 1, 0, 0, 0, 1, 0, -1, 0, -1, 0, 0, 0, 0, 0, 1, 0, 0, 1,
+    ];
+    const ATE_LOOP_COUNT_2_IS_NEGATIVE: bool = false;
+    /// FINAL_EXPONENT_LAST_CHUNK = (q^2 - q + 1) / r
+    const FINAL_EXPONENT_LAST_CHUNK: &'static [u64] = &[
+        0xf335c00000004aef,
+        0xc14a69eb100066c1,
+        0x84d74e193dc6ff54,
+        0xb1ea2024801a659,
+        0x351f136eb9423dac,
+        0x75c1c8b32b6af1e2,
+        0x9660e55a2333ce25,
+        0xcbcdaa4d1bb2a327,
+        0xc3be81e9dcafdcc8,
+        0xe33fb491f2c5cc9,
+        0x77c4729e3955b7f0,
+        0xedeea0ca453b1ce2,
+        0x26b1eb1ee9c72b9,
+        0x3825a11a07c3de42,
+        0x9fac795e2fd52698,
+        0xb9bfdd86ce189a02,
+        0xd8c73eb8090bd134,
+        0xc4b3cb6f8d4fee,
+    ];
+    const TWIST_TYPE: TwistType = TwistType::M;
+}
+
+pub type BW6_761 = Bw6<Bw6_761Parameters>;
+
+pub type G1Affine = Bw6G1Affine<Bw6_761Parameters>;
+pub type G1Projective = Bw6G1Projective<Bw6_761Parameters>;
+
+impl PairingCurve for G1Affine {
+    type Engine = BW6_761;
+    type PairWith = G2Affine;
+    type PairingResult = Fq6;
+    type Prepared = G1Prepared<Bw6_761Parameters>;
+
+    fn prepare(&self) -> Self::Prepared {
+        Self::Prepared::from_affine(*self)
+    }
+
+    fn pairing_with(&self, other: &Self::PairWith) -> Self::PairingResult {
+        BW6_761::pairing(*self, *other)
+    }
+}
+
+pub type G2Affine = Bw6G2Affine<Bw6_761Parameters>;
+pub type G2Projective = Bw6G2Projective<Bw6_761Parameters>;
+
+impl PairingCurve for G2Affine {
+    type Engine = BW6_761;
+    type PairWith = G1Affine;
+    type PairingResult = Fq6;
+    type Prepared = G2Prepared<Bw6_761Parameters>;
+
+    fn prepare(&self) -> Self::Prepared {
+        Self::Prepared::from_affine(*self)
+    }
+
+    fn pairing_with(&self, other: &Self::PairWith) -> Self::PairingResult {
+        BW6_761::pairing(*other, *self)
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    templates::{
+        bls12::TwistType,
+        bw6::{
+            g1::{G1Affine, G1Prepared, G1Projective},
+            g2::{G2Affine, G2Prepared, G2Projective},
+        },
+        short_weierstrass_jacobian,
+    },
+    traits::{ModelParameters, PairingCurve, PairingEngine, ShortWeierstrassParameters},
+    AffineCurve,
+};
+use snarkvm_fields::{
+    fp6_2over3::{Fp6, Fp6Parameters},
+    Field,
+    Fp3Parameters,
+    One,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
+use snarkvm_utilities::bititerator::BitIteratorBE;
+
+use core::{fmt::Debug, hash::Hash, marker::PhantomData};
+use serde::{Deserialize, Serialize};
+
+pub trait Bw6Parameters: 'static + Copy + Clone + Debug + PartialEq + Eq + Hash + Send + Sync + Sized {
+    /// The first Miller loop count of the optimal ate pairing, `u + 1`.
+    const ATE_LOOP_COUNT_1: &'static [u64];
+    const ATE_LOOP_COUNT_1_IS_NEGATIVE: bool;
+    /// The second Miller loop count of the optimal ate pairing, `u^3 - u^2 - u`, in NAF form.
+    const ATE_LOOP_COUNT_2: &'static [i8];
+    const ATE_LOOP_COUNT_2_IS_NEGATIVE: bool;
+    /// The exponent `(q^2 - q + 1) / r` of the hard part of the final exponentiation.
+    const FINAL_EXPONENT_LAST_CHUNK: &'static [u64];
+    const TWIST_TYPE: TwistType;
+    type Fp: PrimeField + SquareRootField + Into<<Self::Fp as PrimeField>::BigInteger>;
+    type Fp3Params: Fp3Parameters<Fp = Self::Fp>;
+    type Fp6Params: Fp6Parameters<Fp3Params = Self::Fp3Params>;
+    type G1Parameters: ShortWeierstrassParameters<BaseField = Self::Fp>;
+    type G2Parameters: ShortWeierstrassParameters<
+            BaseField = Self::Fp,
+            ScalarField = <Self::G1Parameters as ModelParameters>::ScalarField,
+        >;
+
+    fn g1_is_in_correct_subgroup(p: &short_weierstrass_jacobian::Affine<Self::G1Parameters>) -> bool {
+        p.mul_bits(BitIteratorBE::new(<Self::G1Parameters as ModelParameters>::ScalarField::characteristic())).is_zero()
+    }
+
+    fn g2_is_in_correct_subgroup(p: &short_weierstrass_jacobian::Affine<Self::G2Parameters>) -> bool {
+        p.mul_bits(BitIteratorBE::new(<Self::G1Parameters as ModelParameters>::ScalarField::characteristic())).is_zero()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Bw6<P: Bw6Parameters>(PhantomData<P>);
+
+type CoeffTriplet<T> = (T, T, T);
+
+impl<P: Bw6Parameters> Bw6<P> {
+    /// Evaluate the line function at point p.
+    fn ell(f: &mut Fp6<P::Fp6Params>, coeffs: &CoeffTriplet<P::Fp>, p: &G1Affine<P>) {
+        let mut c0 = coeffs.0;
+        let mut c1 = coeffs.1;
+        let mut c2 = coeffs.2;
+
+        match P::TWIST_TYPE {
+            TwistType::M => {
+                c2 *= &p.y;
+                c1 *= &p.x;
+                f.mul_by_014(&c0, &c1, &c2);
+            }
+            TwistType::D => {
+                c0 *= &p.y;
+                c1 *= &p.x;
+                f.mul_by_034(&c0, &c1, &c2);
+            }
+        }
+    }
+
+    /// Computes `f^((q^3 - 1) * (q + 1))`, the easy part of the final exponentiation.
+    fn final_exponentiation_first_chunk(f: &Fp6<P::Fp6Params>, f_inv: &Fp6<P::Fp6Params>) -> Fp6<P::Fp6Params> {
+        // f1 = f^(q^3)
+        let mut f1 = *f;
+        f1.conjugate();
+        // f2 = f^(q^3 - 1)
+        let f2 = f1 * f_inv;
+        // f3 = f^((q^3 - 1) * q)
+        let mut f3 = f2;
+        f3.frobenius_map(1);
+        // f^((q^3 - 1) * (q + 1))
+        f3 * f2
+    }
+}
+
+impl<P: Bw6Parameters> PairingEngine for Bw6<P>
+where
+    G1Affine<P>: PairingCurve<
+            BaseField = <P::G1Parameters as ModelParameters>::BaseField,
+            ScalarField = <P::G1Parameters as ModelParameters>::ScalarField,
+            Projective = G1Projective<P>,
+            PairWith = G2Affine<P>,
+            Prepared = G1Prepared<P>,
+            PairingResult = Fp6<P::Fp6Params>,
+        >,
+    G2Affine<P>: PairingCurve<
+            BaseField = <P::G2Parameters as ModelParameters>::BaseField,
+            ScalarField = <P::G1Parameters as ModelParameters>::ScalarField,
+            Projective = G2Projective<P>,
+            PairWith = G1Affine<P>,
+            Prepared = G2Prepared<P>,
+            PairingResult = Fp6<P::Fp6Params>,
+        >,
+{
+    type Fq = P::Fp;
+    type Fqe = P::Fp;
+    type Fqk = Fp6<P::Fp6Params>;
+    type Fr = <P::G1Parameters as ModelParameters>::ScalarField;
+    type G1Affine = G1Affine<P>;
+    type G1Projective = G1Projective<P>;
+    type G2Affine = G2Affine<P>;
+    type G2Projective = G2Projective<P>;
+
+    fn miller_loop<'a, I>(i: I) -> Self::Fqk
+    where
+        I: Iterator<
+            Item = (&'a <Self::G1Affine as PairingCurve>::Prepared, &'a <Self::G2Affine as PairingCurve>::Prepared),
+        >,
+    {
+        // Alg.5 in https://eprint.iacr.org/2020/351.pdf
+        let mut pairs_1 = vec![];
+        let mut pairs_2 = vec![];
+        for (p, q) in i {
+            if !p.is_zero() && !q.is_zero() {
+                pairs_1.push((p, q.ell_coeffs_1.iter()));
+                pairs_2.push((p, q.ell_coeffs_2.iter()));
+            }
+        }
+
+        // f_{u+1,Q}(P)
+        let mut f_1 = Self::Fqk::one();
+
+        for i in BitIteratorBE::new(P::ATE_LOOP_COUNT_1).skip(1) {
+            f_1.square_in_place();
+
+            for &mut (p, ref mut coeffs) in &mut pairs_1 {
+                Self::ell(&mut f_1, coeffs.next().unwrap(), &p.0);
+            }
+
+            if i {
+                for &mut (p, ref mut coeffs) in &mut pairs_1 {
+                    Self::ell(&mut f_1, coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+
+        if P::ATE_LOOP_COUNT_1_IS_NEGATIVE {
+            f_1.conjugate();
+        }
+
+        // f_{u^3-u^2-u,Q}(P)
+        let mut f_2 = Self::Fqk::one();
+
+        for i in (1..P::ATE_LOOP_COUNT_2.len()).rev() {
+            if i != P::ATE_LOOP_COUNT_2.len() - 1 {
+                f_2.square_in_place();
+            }
+
+            for &mut (p, ref mut coeffs) in &mut pairs_2 {
+                Self::ell(&mut f_2, coeffs.next().unwrap(), &p.0);
+            }
+
+            if P::ATE_LOOP_COUNT_2[i - 1] != 0 {
+                for &mut (p, ref mut coeffs) in &mut pairs_2 {
+                    Self::ell(&mut f_2, coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+
+        if P::ATE_LOOP_COUNT_2_IS_NEGATIVE {
+            f_2.conjugate();
+        }
+
+        f_2.frobenius_map(1);
+
+        f_1 * f_2
+    }
+
+    fn final_exponentiation(f: &Self::Fqk) -> Option<Self::Fqk> {
+        // The easy part lands the result in the cyclotomic subgroup, so the hard
+        // part `(q^2 - q + 1) / r` can be computed with cyclotomic squarings.
+        f.inverse()
+            .map(|f_inv| Self::final_exponentiation_first_chunk(f, &f_inv).cyclotomic_exp(P::FINAL_EXPONENT_LAST_CHUNK))
+    }
+}
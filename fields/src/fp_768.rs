@@ -0,0 +1,589 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    impl_add_sub_from_field_ref,
+    impl_mul_div_from_field_ref,
+    FftField,
+    Field,
+    FieldError,
+    FieldParameters,
+    LegendreSymbol,
+    One,
+    PoseidonDefaultField,
+    PoseidonDefaultParameters,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
+use snarkvm_utilities::{
+    biginteger::{arithmetic as fa, BigInteger as _BigInteger, BigInteger768 as BigInteger},
+    serialize::CanonicalDeserialize,
+    FromBytes,
+    ToBits,
+    ToBytes,
+};
+
+use std::{
+    cmp::{Ord, Ordering, PartialOrd},
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    io::{Read, Result as IoResult, Write},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+pub trait Fp768Parameters: FieldParameters<BigInteger = BigInteger> {}
+
+#[derive(Derivative)]
+#[derivative(
+    Default(bound = "P: Fp768Parameters"),
+    Hash(bound = "P: Fp768Parameters"),
+    Clone(bound = "P: Fp768Parameters"),
+    Copy(bound = "P: Fp768Parameters"),
+    PartialEq(bound = "P: Fp768Parameters"),
+    Eq(bound = "P: Fp768Parameters")
+)]
+pub struct Fp768<P: Fp768Parameters>(
+    pub BigInteger,
+    #[derivative(Debug = "ignore")]
+    #[doc(hidden)]
+    pub PhantomData<P>,
+);
+
+impl<P: Fp768Parameters> Fp768<P> {
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.0 < P::MODULUS
+    }
+
+    #[inline]
+    fn reduce(&mut self) {
+        if !self.is_valid() {
+            self.0.sub_noborrow(&P::MODULUS);
+        }
+    }
+}
+
+impl<P: Fp768Parameters> Zero for Fp768<P> {
+    #[inline]
+    fn zero() -> Self {
+        Fp768::<P>(BigInteger::from(0), PhantomData)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<P: Fp768Parameters> One for Fp768<P> {
+    #[inline]
+    fn one() -> Self {
+        Fp768::<P>(P::R, PhantomData)
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        self.0 == P::R
+    }
+}
+
+impl<P: Fp768Parameters> Field for Fp768<P> {
+    type BasePrimeField = Self;
+
+    // 768/64 = 12 limbs.
+    impl_field_from_random_bytes_with_flags!(12);
+
+    fn from_base_prime_field(other: Self::BasePrimeField) -> Self {
+        other
+    }
+
+    fn half() -> Self {
+        // Compute 1/2 `(p+1)/2` as `1/2`.
+        // This is cheaper than `Self::one().double().inverse()`
+        let mut two_inv = P::MODULUS;
+        two_inv.add_nocarry(&1u64.into());
+        two_inv.div2();
+        Self::from_bigint(two_inv).unwrap() // Guaranteed to be valid.
+    }
+
+    fn sum_of_products<'a>(
+        a: impl Iterator<Item = &'a Self> + Clone,
+        b: impl Iterator<Item = &'a Self> + Clone,
+    ) -> Self {
+        // This follows the same interleaved operand scanning and Montgomery reduction
+        // as the smaller prime fields, expressed as loops over the twelve limbs.
+        let mut u = [0u64; 12];
+        for j in 0..12 {
+            // For each pair in the overall sum of products, compute digit_j x row and
+            // accumulate into `t`.
+            let mut t = u;
+            let mut t12 = 0u64;
+            for (a, b) in a.clone().zip(b.clone()) {
+                let mut carry = 0;
+                for (i, t_i) in t.iter_mut().enumerate() {
+                    *t_i = fa::mac_with_carry(*t_i, a.0.0[j], b.0.0[i], &mut carry);
+                }
+                let _ = fa::adc(&mut t12, 0, carry);
+            }
+
+            // This is a single step of the usual Montgomery reduction process.
+            let k = t[0].wrapping_mul(P::INV);
+            let mut carry = 0;
+            let _ = fa::mac_with_carry(t[0], k, P::MODULUS.0[0], &mut carry);
+            for i in 1..12 {
+                u[i - 1] = fa::mac_with_carry(t[i], k, P::MODULUS.0[i], &mut carry);
+            }
+            let _ = fa::adc(&mut t12, 0, carry);
+            u[11] = t12;
+        }
+
+        // Because we represent F_p elements in non-redundant form, we need a final
+        // conditional subtraction to ensure the output is in range.
+        let mut result = Self(BigInteger(u), PhantomData);
+        result.reduce();
+        result
+    }
+
+    #[inline]
+    fn double(&self) -> Self {
+        let mut temp = *self;
+        temp.double_in_place();
+        temp
+    }
+
+    #[inline]
+    fn double_in_place(&mut self) {
+        // This cannot exceed the backing capacity.
+        self.0.mul2();
+        // However, it may need to be reduced.
+        self.reduce();
+    }
+
+    #[inline]
+    fn characteristic<'a>() -> &'a [u64] {
+        P::MODULUS.as_ref()
+    }
+
+    #[inline]
+    fn square(&self) -> Self {
+        let mut temp = *self;
+        temp.square_in_place();
+        temp
+    }
+
+    #[inline]
+    fn square_in_place(&mut self) -> &mut Self {
+        let other = *self;
+        self.mul_assign(&other);
+        self
+    }
+
+    #[inline]
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // Guajardo Kumar Paar Pelzl
+            // Efficient Software-Implementation of Finite Fields with Applications to
+            // Cryptography
+            // Algorithm 16 (BEA for Inversion in Fp)
+
+            let one = BigInteger::from(1);
+
+            let mut u = self.0;
+            let mut v = P::MODULUS;
+            let mut b = Fp768::<P>(P::R2, PhantomData); // Avoids unnecessary reduction step.
+            let mut c = Self::zero();
+
+            while u != one && v != one {
+                while u.is_even() {
+                    u.div2();
+
+                    if b.0.is_even() {
+                        b.0.div2();
+                    } else {
+                        b.0.add_nocarry(&P::MODULUS);
+                        b.0.div2();
+                    }
+                }
+
+                while v.is_even() {
+                    v.div2();
+
+                    if c.0.is_even() {
+                        c.0.div2();
+                    } else {
+                        c.0.add_nocarry(&P::MODULUS);
+                        c.0.div2();
+                    }
+                }
+
+                if v < u {
+                    u.sub_noborrow(&v);
+                    b.sub_assign(&c);
+                } else {
+                    v.sub_noborrow(&u);
+                    c.sub_assign(&b);
+                }
+            }
+
+            if u == one { Some(b) } else { Some(c) }
+        }
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        if let Some(inverse) = self.inverse() {
+            *self = inverse;
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn frobenius_map(&mut self, _: usize) {
+        // No-op: No effect in a prime field.
+    }
+}
+
+impl<P: Fp768Parameters> PrimeField for Fp768<P> {
+    type BigInteger = BigInteger;
+    type Parameters = P;
+
+    #[inline]
+    fn from_bigint(r: BigInteger) -> Option<Self> {
+        let mut r = Fp768(r, PhantomData);
+        if r.is_zero() {
+            Some(r)
+        } else if r.is_valid() {
+            r *= &Fp768(P::R2, PhantomData);
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn to_bigint(&self) -> BigInteger {
+        let mut tmp = self.0;
+        let mut r = tmp.0;
+        // Montgomery Reduction
+        for i in 0..12 {
+            let k = r[i].wrapping_mul(P::INV);
+            let mut carry = 0;
+            fa::mac_with_carry(r[i], k, P::MODULUS.0[0], &mut carry);
+            for j in 1..12 {
+                r[(j + i) % 12] = fa::mac_with_carry(r[(j + i) % 12], k, P::MODULUS.0[j], &mut carry);
+            }
+            r[i] = carry;
+        }
+        tmp.0 = r;
+        tmp
+    }
+
+    #[inline]
+    fn decompose(
+        &self,
+        _q1: &[u64; 4],
+        _q2: &[u64; 4],
+        _b1: Self,
+        _b2: Self,
+        _r128: Self,
+        _half_r: &[u64; 8],
+    ) -> (Self, Self, bool, bool) {
+        unimplemented!()
+    }
+}
+
+impl<P: Fp768Parameters> FftField for Fp768<P> {
+    type FftParameters = P;
+
+    #[inline]
+    fn two_adic_root_of_unity() -> Self {
+        Self(P::TWO_ADIC_ROOT_OF_UNITY, PhantomData)
+    }
+
+    #[inline]
+    fn large_subgroup_root_of_unity() -> Option<Self> {
+        Some(Self(P::LARGE_SUBGROUP_ROOT_OF_UNITY?, PhantomData))
+    }
+
+    #[inline]
+    fn multiplicative_generator() -> Self {
+        Self(P::GENERATOR, PhantomData)
+    }
+}
+
+impl<P: Fp768Parameters> SquareRootField for Fp768<P> {
+    #[inline]
+    fn legendre(&self) -> LegendreSymbol {
+        use crate::LegendreSymbol::*;
+
+        // s = self^((MODULUS - 1) // 2)
+        let s = self.pow(P::MODULUS_MINUS_ONE_DIV_TWO);
+        if s.is_zero() {
+            Zero
+        } else if s.is_one() {
+            QuadraticResidue
+        } else {
+            QuadraticNonResidue
+        }
+    }
+
+    #[inline]
+    fn sqrt(&self) -> Option<Self> {
+        sqrt_impl!(Self, P, self)
+    }
+
+    fn sqrt_in_place(&mut self) -> Option<&mut Self> {
+        (*self).sqrt().map(|sqrt| {
+            *self = sqrt;
+            self
+        })
+    }
+}
+
+impl<P: Fp768Parameters> Ord for Fp768<P> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_bigint().cmp(&other.to_bigint())
+    }
+}
+
+impl<P: Fp768Parameters> PartialOrd for Fp768<P> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: Fp768Parameters + PoseidonDefaultParameters> PoseidonDefaultField for Fp768<P> {}
+
+impl_primefield_from_int!(Fp768, u128, Fp768Parameters);
+impl_primefield_from_int!(Fp768, u64, Fp768Parameters);
+impl_primefield_from_int!(Fp768, u32, Fp768Parameters);
+impl_primefield_from_int!(Fp768, u16, Fp768Parameters);
+impl_primefield_from_int!(Fp768, u8, Fp768Parameters);
+
+impl_primefield_standard_sample!(Fp768, Fp768Parameters);
+
+impl_add_sub_from_field_ref!(Fp768, Fp768Parameters);
+impl_mul_div_from_field_ref!(Fp768, Fp768Parameters);
+
+impl<P: Fp768Parameters> ToBits for Fp768<P> {
+    fn to_bits_le(&self) -> Vec<bool> {
+        let mut bits_vec = self.to_bigint().to_bits_le();
+        bits_vec.truncate(P::MODULUS_BITS as usize);
+        bits_vec
+    }
+
+    fn to_bits_be(&self) -> Vec<bool> {
+        let mut bits_vec = self.to_bits_le();
+        bits_vec.reverse();
+        bits_vec
+    }
+}
+
+impl<P: Fp768Parameters> ToBytes for Fp768<P> {
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.to_bigint().write_le(writer)
+    }
+}
+
+impl<P: Fp768Parameters> FromBytes for Fp768<P> {
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        BigInteger::read_le(reader).and_then(|b| match Self::from_bigint(b) {
+            Some(f) => Ok(f),
+            None => Err(FieldError::InvalidFieldElement.into()),
+        })
+    }
+}
+
+impl<P: Fp768Parameters> FromStr for Fp768<P> {
+    type Err = FieldError;
+
+    /// Interpret a string of numbers as a (congruent) prime field element.
+    /// Does not accept unnecessary leading zeroes or a blank string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(FieldError::ParsingEmptyString);
+        }
+
+        if s == "0" {
+            return Ok(Self::zero());
+        }
+
+        let mut res = Self::zero();
+
+        let ten =
+            Self::from_bigint(<Self as PrimeField>::BigInteger::from(10)).ok_or(FieldError::InvalidFieldElement)?;
+
+        let mut first_digit = true;
+
+        for c in s.chars() {
+            match c.to_digit(10) {
+                Some(c) => {
+                    if first_digit {
+                        if c == 0 {
+                            return Err(FieldError::InvalidString);
+                        }
+
+                        first_digit = false;
+                    }
+
+                    res.mul_assign(&ten);
+                    res.add_assign(
+                        &Self::from_bigint(<Self as PrimeField>::BigInteger::from(u64::from(c)))
+                            .ok_or(FieldError::InvalidFieldElement)?,
+                    );
+                }
+                None => return Err(FieldError::ParsingNonDigitCharacter),
+            }
+        }
+
+        if !res.is_valid() { Err(FieldError::InvalidFieldElement) } else { Ok(res) }
+    }
+}
+
+impl<P: Fp768Parameters> Debug for Fp768<P> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.to_bigint())
+    }
+}
+
+impl<P: Fp768Parameters> Display for Fp768<P> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.to_bigint())
+    }
+}
+
+impl<P: Fp768Parameters> Neg for Fp768<P> {
+    type Output = Self;
+
+    #[inline]
+    #[must_use]
+    fn neg(self) -> Self {
+        if !self.is_zero() {
+            let mut tmp = P::MODULUS;
+            tmp.sub_noborrow(&self.0);
+            Fp768::<P>(tmp, PhantomData)
+        } else {
+            self
+        }
+    }
+}
+
+impl<'a, P: Fp768Parameters> Add<&'a Fp768<P>> for Fp768<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: &Self) -> Self {
+        let mut result = self;
+        result.add_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp768Parameters> Sub<&'a Fp768<P>> for Fp768<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: &Self) -> Self {
+        let mut result = self;
+        result.sub_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp768Parameters> Mul<&'a Fp768<P>> for Fp768<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(other);
+        result
+    }
+}
+
+impl<'a, P: Fp768Parameters> Div<&'a Fp768<P>> for Fp768<P> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: &Self) -> Self {
+        let mut result = self;
+        result.mul_assign(&other.inverse().unwrap());
+        result
+    }
+}
+
+impl<'a, P: Fp768Parameters> AddAssign<&'a Self> for Fp768<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        // This cannot exceed the backing capacity.
+        self.0.add_nocarry(&other.0);
+        // However, it may need to be reduced
+        self.reduce();
+    }
+}
+
+impl<'a, P: Fp768Parameters> SubAssign<&'a Self> for Fp768<P> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        // If `other` is larger than `self`, add the modulus to self first.
+        if other.0 > self.0 {
+            self.0.add_nocarry(&P::MODULUS);
+        }
+
+        self.0.sub_noborrow(&other.0);
+    }
+}
+
+impl<'a, P: Fp768Parameters> MulAssign<&'a Self> for Fp768<P> {
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        // Montgomery multiplication with the "no-carry" optimization, which applies
+        // because the most significant bit of the modulus is unset.
+        let mut r = [0u64; 12];
+        for i in 0..12 {
+            let mut carry1 = 0u64;
+            r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[i], &mut carry1);
+            let k = r[0].wrapping_mul(P::INV);
+            let mut carry2 = 0u64;
+            fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
+            for j in 1..12 {
+                r[j] = fa::mac_with_carry(r[j], (self.0).0[j], (other.0).0[i], &mut carry1);
+                r[j - 1] = fa::mac_with_carry(r[j], k, P::MODULUS.0[j], &mut carry2);
+            }
+            r[11] = carry1 + carry2;
+        }
+        (self.0).0 = r;
+        self.reduce();
+    }
+}
+
+impl<'a, P: Fp768Parameters> DivAssign<&'a Self> for Fp768<P> {
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        self.mul_assign(&other.inverse().unwrap());
+    }
+}
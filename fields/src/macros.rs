@@ -94,6 +94,11 @@ macro_rules! sqrt_impl {
                 let n = $P::TWO_ADICITY as u64;
                 // `T` is equivalent to `m` in the paper.
                 let v = $self.pow($P::T_MINUS_ONE_DIV_TWO);
+                // When the two-adicity is 1 (i.e. `q = 3 mod 4`), `self^T = 1` for any
+                // quadratic residue, so `self^((T + 1) / 2)` is a square root.
+                if n == 1 {
+                    return Some(*$self * v);
+                }
                 let x = *$self * v.square();
 
                 let k = ((n - 1) as f64).sqrt().floor() as u64;
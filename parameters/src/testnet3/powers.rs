@@ -71,6 +71,9 @@ pub struct PowersOfG<E: PairingEngine> {
     negative_powers_of_beta_h: Arc<BTreeMap<usize, E::G2Affine>>,
     /// beta * h
     beta_h: E::G2Affine,
+    /// Whether `beta_h` is the hard-coded one, i.e. whether the powers may be extended with the hard-coded powers.
+    /// This is derived from `beta_h`, and is not serialized.
+    derives_from_hard_coded_powers: bool,
 }
 
 impl<E: PairingEngine> PowersOfG<E> {
@@ -89,16 +92,79 @@ impl<E: PairingEngine> PowersOfG<E> {
         let beta_h = E::G2Affine::deserialize_uncompressed_unchecked(&**BETA_H)?;
 
         // Initialize the powers.
-        let powers = Self { powers_of_beta_g, powers_of_beta_times_gamma_g, negative_powers_of_beta_h, beta_h };
+        let powers = Self {
+            powers_of_beta_g,
+            powers_of_beta_times_gamma_g,
+            negative_powers_of_beta_h,
+            beta_h,
+            derives_from_hard_coded_powers: true,
+        };
         // Return the powers.
         Ok(powers)
     }
 
+    /// Initializes the powers from their group elements, such as the output of a setup ceremony.
+    /// The shifted powers of beta G are the powers ending at the maximum degree supported by the SRS;
+    /// they must be empty if and only if `powers_of_beta_g` contains every power.
+    pub fn from_parts(
+        powers_of_beta_g: Vec<E::G1Affine>,
+        shifted_powers_of_beta_g: Vec<E::G1Affine>,
+        powers_of_beta_times_gamma_g: BTreeMap<usize, E::G1Affine>,
+        negative_powers_of_beta_h: BTreeMap<usize, E::G2Affine>,
+        beta_h: E::G2Affine,
+    ) -> Result<Self> {
+        ensure!(!powers_of_beta_g.is_empty(), "The SRS must contain at least one power of beta G");
+        ensure!(powers_of_beta_g.len() <= MAX_NUM_POWERS, "The SRS contains too many powers of beta G");
+        ensure!(shifted_powers_of_beta_g.len() < MAX_NUM_POWERS, "The SRS contains too many shifted powers of beta G");
+        ensure!(
+            !shifted_powers_of_beta_g.is_empty() || powers_of_beta_g.len() == MAX_NUM_POWERS,
+            "The SRS must contain shifted powers of beta G unless it contains every power"
+        );
+
+        let powers_of_beta_g = PowersOfBetaG { powers_of_beta_g, shifted_powers_of_beta_g };
+        let powers_of_beta_times_gamma_g = Arc::new(powers_of_beta_times_gamma_g);
+        let negative_powers_of_beta_h = Arc::new(negative_powers_of_beta_h);
+        let derives_from_hard_coded_powers = Self::is_hard_coded_beta_h(&beta_h)?;
+        Ok(Self {
+            powers_of_beta_g,
+            powers_of_beta_times_gamma_g,
+            negative_powers_of_beta_h,
+            beta_h,
+            derives_from_hard_coded_powers,
+        })
+    }
+
+    /// Returns `true` if the given `beta_h` is the hard-coded one.
+    fn is_hard_coded_beta_h(beta_h: &E::G2Affine) -> Result<bool, SerializationError> {
+        Ok(*beta_h == E::G2Affine::deserialize_uncompressed_unchecked(&**BETA_H)?)
+    }
+
     /// Download the powers of beta G specified by `range`.
     pub fn download_powers_for(&mut self, range: Range<usize>) -> Result<()> {
+        self.ensure_can_download(&range)?;
         self.powers_of_beta_g.download_powers_for(&range)
     }
 
+    /// Ensures the powers in `range` are either in memory or may be downloaded.
+    /// The hard-coded powers are only consistent with an SRS that has the hard-coded `beta_h`,
+    /// so powers that were re-randomized by a setup ceremony can never be extended with them.
+    fn ensure_can_download(&self, range: &Range<usize>) -> Result<()> {
+        if range.is_empty() || self.powers_of_beta_g.contains_powers(range) {
+            return Ok(());
+        }
+        ensure!(
+            self.derives_from_hard_coded_powers,
+            "Cannot download the hard-coded powers for an SRS that does not derive from them"
+        );
+        Ok(())
+    }
+
+    /// Returns the powers of beta G that are in memory, as the contiguous powers starting from the 0-th power,
+    /// and the shifted powers ending at the maximum degree supported by the SRS.
+    pub fn loaded_powers_of_beta_g(&self) -> (&[E::G1Affine], &[E::G1Affine]) {
+        (&self.powers_of_beta_g.powers_of_beta_g, &self.powers_of_beta_g.shifted_powers_of_beta_g)
+    }
+
     /// Returns the number of contiguous powers of beta G starting from the 0-th power.
     pub fn num_powers(&self) -> usize {
         self.powers_of_beta_g.num_powers()
@@ -116,11 +182,13 @@ impl<E: PairingEngine> PowersOfG<E> {
 
     /// Returns the `index`-th power of beta * G.
     pub fn power_of_beta_g(&mut self, index: usize) -> Result<E::G1Affine> {
+        self.ensure_can_download(&(index..(index + 1)))?;
         self.powers_of_beta_g.power(index)
     }
 
     /// Returns the powers of `beta * G` that lie within `range`.
     pub fn powers_of_beta_g(&mut self, range: Range<usize>) -> Result<&[E::G1Affine]> {
+        self.ensure_can_download(&range)?;
         self.powers_of_beta_g.powers(range)
     }
 
@@ -161,7 +229,14 @@ impl<E: PairingEngine> CanonicalDeserialize for PowersOfG<E> {
             Arc::new(BTreeMap::deserialize_with_mode(&mut reader, compress, Validate::No)?);
         let negative_powers_of_beta_h = Arc::new(BTreeMap::deserialize_with_mode(&mut reader, compress, Validate::No)?);
         let beta_h = E::G2Affine::deserialize_with_mode(&mut reader, compress, Validate::No)?;
-        let powers = Self { powers_of_beta_g, powers_of_beta_times_gamma_g, negative_powers_of_beta_h, beta_h };
+        let derives_from_hard_coded_powers = Self::is_hard_coded_beta_h(&beta_h)?;
+        let powers = Self {
+            powers_of_beta_g,
+            powers_of_beta_times_gamma_g,
+            negative_powers_of_beta_h,
+            beta_h,
+            derives_from_hard_coded_powers,
+        };
         if let Validate::Yes = validate {
             powers.check()?;
         }
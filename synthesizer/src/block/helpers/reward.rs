@@ -0,0 +1,332 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{block::Transactions, coinbase_puzzle::CoinbaseSolution};
+use console::{account::Address, network::prelude::*};
+
+use indexmap::IndexMap;
+
+/// The number of seconds in a year.
+const SECONDS_PER_YEAR: u32 = 31_536_000;
+/// The number of years over which the anchor reward decays to zero.
+const ANCHOR_REWARD_YEARS: u32 = 10;
+/// The annual issuance of the block reward, as a percentage of the total supply.
+const ANNUAL_BLOCK_REWARD_PERCENTAGE: u64 = 2;
+
+/// Returns the anchor reward (in microcredits) for the block at the given height.
+///
+/// The anchor reward decays linearly to zero over `ANCHOR_REWARD_YEARS` (at the anchor time),
+/// such that the anchor rewards sum to the starting supply.
+pub fn anchor_reward(height: u32, starting_supply: u64, anchor_time: u16) -> u64 {
+    // Compute the number of blocks until the anchor reward reaches zero.
+    let num_blocks = (SECONDS_PER_YEAR as u128 * ANCHOR_REWARD_YEARS as u128) / core::cmp::max(anchor_time, 1) as u128;
+    // Compute the anchor reward as `2 * starting_supply * (num_blocks - height) / (num_blocks * (num_blocks + 1))`.
+    let numerator = 2 * starting_supply as u128 * num_blocks.saturating_sub(height as u128);
+    let denominator = num_blocks * (num_blocks + 1);
+    // Note: The anchor reward is bounded by the starting supply, so this cast is safe.
+    (numerator / denominator) as u64
+}
+
+/// Returns the coinbase reward (in microcredits) for the block at the given height.
+///
+/// The coinbase reward is the anchor reward, scaled by the fraction of the coinbase target
+/// that was reached by the combined proof target of the coinbase solution.
+pub fn coinbase_reward(
+    height: u32,
+    starting_supply: u64,
+    anchor_time: u16,
+    combined_proof_target: u128,
+    coinbase_target: u64,
+) -> Result<u64> {
+    // Ensure the coinbase target is nonzero.
+    ensure!(coinbase_target > 0, "The coinbase target must be nonzero");
+    // Compute the anchor reward.
+    let anchor_reward = anchor_reward(height, starting_supply, anchor_time);
+    // Compute the fraction of the coinbase target that was reached, which is at most 1.
+    let reached_target = core::cmp::min(combined_proof_target, coinbase_target as u128);
+    // Return the coinbase reward.
+    Ok((anchor_reward as u128 * reached_target / coinbase_target as u128) as u64)
+}
+
+/// Returns the block reward (in microcredits), given the total supply and the coinbase reward.
+///
+/// The block reward issues `ANNUAL_BLOCK_REWARD_PERCENTAGE` of the total supply per year (at the anchor time),
+/// in addition to half of the coinbase reward.
+pub fn block_reward(total_supply: u64, anchor_time: u16, coinbase_reward: u64) -> u64 {
+    // Compute the number of blocks per year.
+    let blocks_per_year = SECONDS_PER_YEAR / core::cmp::max(anchor_time, 1) as u32;
+    // Compute the annual reward.
+    let annual_reward = (total_supply as u128 * ANNUAL_BLOCK_REWARD_PERCENTAGE as u128 / 100) as u64;
+    // Return the block reward.
+    (annual_reward / blocks_per_year as u64).saturating_add(coinbase_reward / 2)
+}
+
+/// Returns the prover rewards (in microcredits), given the coinbase solution and the coinbase reward.
+///
+/// The provers receive half of the coinbase reward, split in proportion to the target of each prover solution.
+pub fn prover_rewards<N: Network>(
+    coinbase_solution: &CoinbaseSolution<N>,
+    coinbase_reward: u64,
+) -> Result<IndexMap<Address<N>, u64>> {
+    // Compute the combined proof target of the coinbase solution.
+    let combined_proof_target = coinbase_solution.to_cumulative_proof_target()?;
+    // If the combined proof target is zero, there are no prover rewards.
+    if combined_proof_target == 0 {
+        return Ok(IndexMap::new());
+    }
+
+    // Compute the reward for each prover, in proportion to the target of their solution.
+    let mut rewards = IndexMap::<Address<N>, u64>::new();
+    for solution in coinbase_solution.partial_solutions() {
+        // Compute the prover reward.
+        let reward = (coinbase_reward as u128 / 2) * solution.to_target()? as u128 / combined_proof_target;
+        // Add the prover reward to the rewards for the address.
+        let entry = rewards.entry(solution.address()).or_default();
+        *entry = entry.saturating_add(reward as u64);
+    }
+    Ok(rewards)
+}
+
+/// Returns the rewards (in microcredits) issued in the block with the given height, coinbase target,
+/// and coinbase solution, as a map from each recipient address to its reward.
+///
+/// The block reward is issued to the block signer, and the prover rewards are issued to the provers.
+pub fn to_block_rewards<N: Network>(
+    block_signer: Address<N>,
+    previous_total_supply: u64,
+    height: u32,
+    coinbase_target: u64,
+    coinbase_solution: Option<&CoinbaseSolution<N>>,
+) -> Result<IndexMap<Address<N>, u64>> {
+    // Compute the coinbase reward.
+    let coinbase_reward = match coinbase_solution {
+        Some(coinbase_solution) => coinbase_reward(
+            height,
+            N::STARTING_SUPPLY,
+            N::ANCHOR_TIME,
+            coinbase_solution.to_cumulative_proof_target()?,
+            coinbase_target,
+        )?,
+        None => 0,
+    };
+
+    // Compute the prover rewards.
+    let mut rewards = match coinbase_solution {
+        Some(coinbase_solution) => prover_rewards(coinbase_solution, coinbase_reward)?,
+        None => IndexMap::new(),
+    };
+
+    // Compute the block reward, and add it to the rewards for the block signer.
+    let block_reward = block_reward(previous_total_supply, N::ANCHOR_TIME, coinbase_reward);
+    let entry = rewards.entry(block_signer).or_default();
+    *entry = entry.saturating_add(block_reward);
+
+    Ok(rewards)
+}
+
+/// Returns the total supply (in microcredits) after a block, given the previous total supply,
+/// the rewards issued in the block, and the transactions whose fees are burned in the block.
+pub fn to_next_total_supply<N: Network>(
+    previous_total_supply: u64,
+    rewards: &IndexMap<Address<N>, u64>,
+    transactions: &Transactions<N>,
+) -> Result<u64> {
+    // Compute the total rewards issued.
+    let issued = rewards.values().try_fold(0u64, |issued, reward| {
+        issued.checked_add(*reward).ok_or_else(|| anyhow!("The issued rewards overflowed"))
+    })?;
+    // Compute the total fees burned.
+    let burned = transactions
+        .transaction_fees()
+        .try_fold(0u64, |burned, fee| burned.checked_add(*fee?).ok_or_else(|| anyhow!("The burned fees overflowed")))?;
+
+    // Compute the next total supply.
+    match previous_total_supply.checked_add(issued).and_then(|supply| supply.checked_sub(burned)) {
+        Some(total_supply) => Ok(total_supply),
+        None => bail!("The total supply is out of range (issued {issued}, burned {burned})"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coinbase_puzzle::PartialSolution;
+    use console::{account::PrivateKey, network::Testnet3};
+    use snarkvm_algorithms::polycommit::kzg10::{KZGCommitment, KZGProof};
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: usize = 100;
+
+    /// Samples a coinbase solution with the given number of partial solutions, from the given provers.
+    fn sample_coinbase_solution(
+        provers: &[Address<CurrentNetwork>],
+        num_solutions: usize,
+        rng: &mut TestRng,
+    ) -> CoinbaseSolution<CurrentNetwork> {
+        let partial_solutions = (0..num_solutions)
+            .map(|i| PartialSolution::new(provers[i % provers.len()], u64::rand(rng), KZGCommitment(rng.gen())))
+            .collect();
+        CoinbaseSolution::new(partial_solutions, KZGProof { w: rng.gen(), random_v: None })
+    }
+
+    #[test]
+    fn test_anchor_reward() {
+        let starting_supply = CurrentNetwork::STARTING_SUPPLY;
+        let anchor_time = CurrentNetwork::ANCHOR_TIME;
+        let num_blocks = SECONDS_PER_YEAR * ANCHOR_REWARD_YEARS / anchor_time as u32;
+
+        // Ensure the anchor rewards decrease, and sum to (at most) the starting supply.
+        let mut total_reward = 0u64;
+        let mut previous_reward = u64::MAX;
+        for height in 0..=num_blocks {
+            let reward = anchor_reward(height, starting_supply, anchor_time);
+            assert!(reward <= previous_reward);
+            total_reward += reward;
+            previous_reward = reward;
+        }
+        assert!(total_reward <= starting_supply);
+        // Ensure the rounding error is at most one microcredit per block.
+        assert!(starting_supply - total_reward <= num_blocks as u64 + 1);
+
+        // Ensure the anchor reward is zero after the decay period.
+        assert_eq!(anchor_reward(num_blocks, starting_supply, anchor_time), 0);
+        assert_eq!(anchor_reward(u32::MAX, starting_supply, anchor_time), 0);
+    }
+
+    #[test]
+    fn test_coinbase_reward() {
+        let starting_supply = CurrentNetwork::STARTING_SUPPLY;
+        let anchor_time = CurrentNetwork::ANCHOR_TIME;
+        let coinbase_target = CurrentNetwork::GENESIS_COINBASE_TARGET;
+
+        for height in [1, 1_000, 1_000_000] {
+            let anchor_reward = anchor_reward(height, starting_supply, anchor_time);
+            let reward = |combined_proof_target| {
+                coinbase_reward(height, starting_supply, anchor_time, combined_proof_target, coinbase_target).unwrap()
+            };
+
+            // Ensure the coinbase reward is zero, if no target was reached.
+            assert_eq!(reward(0), 0);
+            // Ensure the coinbase reward is proportional to the fraction of the coinbase target that was reached.
+            assert_eq!(
+                reward(coinbase_target as u128 / 2),
+                (anchor_reward as u128 * (coinbase_target as u128 / 2) / coinbase_target as u128) as u64
+            );
+            // Ensure the coinbase reward is the anchor reward, if the coinbase target was reached.
+            assert_eq!(reward(coinbase_target as u128), anchor_reward);
+            assert_eq!(reward(u128::MAX), anchor_reward);
+        }
+
+        // Ensure a zero coinbase target fails.
+        assert!(coinbase_reward(1, starting_supply, anchor_time, 1, 0).is_err());
+    }
+
+    #[test]
+    fn test_block_reward() {
+        let total_supply = CurrentNetwork::STARTING_SUPPLY;
+        let anchor_time = CurrentNetwork::ANCHOR_TIME;
+
+        // Ensure the block reward issues the annual reward over a year of blocks.
+        let blocks_per_year = (SECONDS_PER_YEAR / anchor_time as u32) as u64;
+        let reward = block_reward(total_supply, anchor_time, 0);
+        let annual_reward = total_supply * ANNUAL_BLOCK_REWARD_PERCENTAGE / 100;
+        assert!(annual_reward - reward * blocks_per_year < blocks_per_year);
+
+        // Ensure the block reward includes half of the coinbase reward.
+        assert_eq!(block_reward(total_supply, anchor_time, 1_000), reward + 500);
+    }
+
+    #[test]
+    fn test_prover_rewards() {
+        let rng = &mut TestRng::default();
+
+        // Sample the provers.
+        let provers = (0..5)
+            .map(|_| Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap())
+            .collect::<Vec<_>>();
+
+        for _ in 0..ITERATIONS {
+            // Sample a coinbase solution, and a coinbase reward.
+            let coinbase_solution = sample_coinbase_solution(&provers, rng.gen_range(1..16), rng);
+            let coinbase_reward = rng.gen_range(0..u32::MAX as u64);
+
+            // Compute the prover rewards.
+            let rewards = prover_rewards(&coinbase_solution, coinbase_reward).unwrap();
+
+            // Ensure the prover rewards sum to (at most) half of the coinbase reward.
+            let total_reward = rewards.values().sum::<u64>();
+            assert!(total_reward <= coinbase_reward / 2);
+            assert!(coinbase_reward / 2 - total_reward <= coinbase_solution.len() as u64);
+
+            // Ensure each prover is rewarded in proportion to their targets.
+            let combined_proof_target = coinbase_solution.to_cumulative_proof_target().unwrap();
+            for (address, reward) in &rewards {
+                let prover_target = coinbase_solution
+                    .partial_solutions()
+                    .iter()
+                    .filter(|solution| solution.address() == *address)
+                    .map(|solution| solution.to_target().unwrap() as u128)
+                    .sum::<u128>();
+                let expected = (coinbase_reward as u128 / 2) * prover_target / combined_proof_target;
+                assert!(expected.abs_diff(*reward as u128) <= coinbase_solution.len() as u128);
+            }
+        }
+    }
+
+    #[test]
+    fn test_total_supply() {
+        let rng = &mut TestRng::default();
+
+        // Sample the provers, and the block signer.
+        let provers = (0..3)
+            .map(|_| Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap())
+            .collect::<Vec<_>>();
+        let block_signer = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        let mut total_supply = CurrentNetwork::STARTING_SUPPLY;
+        for height in 1..ITERATIONS as u32 {
+            // Sample an optional coinbase solution.
+            let coinbase_solution = match rng.gen_bool(0.5) {
+                true => Some(sample_coinbase_solution(&provers, rng.gen_range(1..8), rng)),
+                false => None,
+            };
+
+            // Compute the rewards.
+            let rewards = to_block_rewards(
+                block_signer,
+                total_supply,
+                height,
+                CurrentNetwork::GENESIS_COINBASE_TARGET,
+                coinbase_solution.as_ref(),
+            )
+            .unwrap();
+            // Ensure the block signer is rewarded.
+            assert!(rewards.get(&block_signer).copied().unwrap_or_default() > 0);
+            // Ensure only the block signer is rewarded, if there is no coinbase solution.
+            if coinbase_solution.is_none() {
+                assert_eq!(rewards.len(), 1);
+            }
+
+            // Compute the next total supply, without any fees.
+            let next_total_supply = to_next_total_supply(total_supply, &rewards, &Transactions::from(&[])).unwrap();
+            // Ensure the total supply increased by the issued rewards.
+            assert_eq!(next_total_supply, total_supply + rewards.values().sum::<u64>());
+
+            total_supply = next_total_supply;
+        }
+    }
+}
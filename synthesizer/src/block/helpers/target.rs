@@ -0,0 +1,303 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::block::Header;
+use console::network::prelude::*;
+
+/// The number of fractional bits in the fixed point arithmetic of the retargeting algorithm.
+const RBITS: u32 = 16;
+/// The radix of the fixed point arithmetic of the retargeting algorithm.
+const RADIX: u128 = 1 << RBITS;
+
+impl<N: Network> Header<N> {
+    /// Returns the coinbase target and proof target for the next block, given the timestamp of the next block.
+    pub fn next_targets(&self, next_timestamp: i64) -> Result<(u64, u64)> {
+        // Compute the coinbase target.
+        let coinbase_target = coinbase_target(
+            self.last_coinbase_target(),
+            self.last_coinbase_timestamp(),
+            next_timestamp,
+            N::ANCHOR_TIME,
+            N::NUM_BLOCKS_PER_EPOCH,
+            N::GENESIS_COINBASE_TARGET,
+        )?;
+        // Compute the proof target.
+        let proof_target = proof_target(coinbase_target, N::GENESIS_COINBASE_TARGET, N::GENESIS_PROOF_TARGET);
+        // Return the targets.
+        Ok((coinbase_target, proof_target))
+    }
+}
+
+/// Returns the coinbase target for the next block, given the last coinbase target and timestamp,
+/// the timestamp of the next block, the anchor time, and the number of blocks per epoch.
+///
+/// The coinbase target is expected to be reached once every `anchor_time` seconds. The target is adjusted
+/// exponentially in the time drift since the last coinbase, with a half-life of half an epoch,
+/// and never falls below the genesis coinbase target.
+pub fn coinbase_target(
+    last_coinbase_target: u64,
+    last_coinbase_timestamp: i64,
+    next_timestamp: i64,
+    anchor_time: u16,
+    num_blocks_per_epoch: u32,
+    genesis_coinbase_target: u64,
+) -> Result<u64> {
+    // Compute the half-life (in seconds), which is half of an epoch.
+    let half_life = num_blocks_per_epoch.saturating_div(2).saturating_mul(anchor_time as u32);
+    // Compute the candidate coinbase target.
+    let candidate_target =
+        retarget(last_coinbase_target, last_coinbase_timestamp, next_timestamp, anchor_time, half_life, true)?;
+    // Return the candidate coinbase target, floored at the genesis coinbase target.
+    Ok(core::cmp::max(genesis_coinbase_target, candidate_target))
+}
+
+/// Returns the proof target for the given coinbase target.
+///
+/// The proof target preserves the ratio of the genesis coinbase target to the genesis proof target,
+/// and never falls below the genesis proof target.
+pub fn proof_target(coinbase_target: u64, genesis_coinbase_target: u64, genesis_proof_target: u64) -> u64 {
+    // Compute the ratio of the genesis coinbase target to the genesis proof target.
+    let ratio = core::cmp::max(genesis_coinbase_target.saturating_div(genesis_proof_target), 1);
+    // Return the proof target, floored at the genesis proof target.
+    core::cmp::max(genesis_proof_target, coinbase_target.saturating_div(ratio))
+}
+
+/// Returns the retargeted value of the previous target, using the ASERT algorithm with fixed point arithmetic.
+/// See: <https://reference.cash/protocol/forks/2020-11-15-asert>.
+///
+/// If `is_inverse` is `true`, the target increases when the time elapsed is shorter than the anchor time,
+/// and decreases when it is longer (i.e. a higher target is harder to reach).
+fn retarget(
+    previous_target: u64,
+    previous_timestamp: i64,
+    next_timestamp: i64,
+    anchor_time: u16,
+    half_life: u32,
+    is_inverse: bool,
+) -> Result<u64> {
+    // Ensure the half-life is nonzero.
+    ensure!(half_life > 0, "The half-life of the retargeting algorithm must be nonzero");
+
+    // Compute the time elapsed (in seconds) since the previous timestamp.
+    // Note: The time elapsed is at least 1 second, to account for a repeated timestamp.
+    let time_elapsed = core::cmp::max(next_timestamp.saturating_sub(previous_timestamp), 1);
+    // Compute the drift between the time elapsed and the anchor time.
+    let drift = match is_inverse {
+        true => (anchor_time as i64).saturating_sub(time_elapsed),
+        false => time_elapsed.saturating_sub(anchor_time as i64),
+    };
+
+    // If there is no drift, return the previous target.
+    if drift == 0 {
+        return Ok(previous_target);
+    }
+
+    // Compute the exponent, and decompose it into its integral and fractional parts.
+    let exponent = (RADIX as i128).saturating_mul(drift as i128) / half_life as i128;
+    let integral = exponent >> RBITS;
+    let fractional = (exponent - (integral << RBITS)) as u128;
+    ensure!(fractional < RADIX, "The fractional part of the exponent is out of range");
+
+    // Approximate `2^fractional` (scaled by the radix) with the cubic polynomial from ASERT:
+    // `2^x ~= 1 + 0.695502049 * x + 0.2262698 * x^2 + 0.0782318 * x^3`.
+    let fractional_multiplier = RADIX
+        + ((195_766_423_245_049_u128 * fractional
+            + 971_821_376_u128 * fractional.pow(2)
+            + 5_127_u128 * fractional.pow(3)
+            + 2_u128.pow(RBITS * 3 - 1))
+            >> (RBITS * 3));
+
+    // Multiply the previous target by the fractional multiplier.
+    let candidate_target = (previous_target as u128).saturating_mul(fractional_multiplier);
+
+    // Shift the candidate target by the integral part, and remove the radix.
+    let shifts = integral.saturating_sub(RBITS as i128);
+    let candidate_target = match shifts < 0 {
+        true => match u32::try_from(shifts.unsigned_abs()) {
+            // Ensure the shift does not underflow.
+            Ok(shifts) if shifts < u128::BITS => candidate_target >> shifts,
+            _ => 0,
+        },
+        false => match u32::try_from(shifts) {
+            // Ensure the shift does not overflow.
+            Ok(shifts) if shifts < u128::BITS && candidate_target.leading_zeros() >= shifts => {
+                candidate_target << shifts
+            }
+            _ => u128::MAX,
+        },
+    };
+
+    // Return the candidate target, bounded to the range `[1, u64::MAX]`.
+    Ok(candidate_target.clamp(1, u64::MAX as u128) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// The number of blocks in a simulated chain.
+    const NUM_BLOCKS: usize = 10_000;
+
+    /// Returns the half-life (in seconds) of the coinbase target for the current network.
+    fn half_life() -> i64 {
+        (CurrentNetwork::NUM_BLOCKS_PER_EPOCH / 2 * CurrentNetwork::ANCHOR_TIME as u32) as i64
+    }
+
+    /// Returns the coinbase target for the next coinbase, given the last coinbase target and the time elapsed.
+    fn next_coinbase_target(last_coinbase_target: u64, time_elapsed: i64) -> u64 {
+        coinbase_target(
+            last_coinbase_target,
+            CurrentNetwork::GENESIS_TIMESTAMP,
+            CurrentNetwork::GENESIS_TIMESTAMP + time_elapsed,
+            CurrentNetwork::ANCHOR_TIME,
+            CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+        )
+        .unwrap()
+    }
+
+    /// Simulates a chain of coinbases with the given times elapsed, and returns the coinbase targets.
+    fn simulate_chain(initial_target: u64, times_elapsed: impl IntoIterator<Item = i64>) -> Vec<u64> {
+        let mut targets = vec![initial_target];
+        for time_elapsed in times_elapsed {
+            let target = next_coinbase_target(*targets.last().unwrap(), time_elapsed);
+            targets.push(target);
+        }
+        targets
+    }
+
+    #[test]
+    fn test_retarget_at_anchor_time() {
+        let anchor_time = CurrentNetwork::ANCHOR_TIME as i64;
+
+        // Ensure the target is unchanged when every coinbase is found at the anchor time.
+        for initial_target in [CurrentNetwork::GENESIS_COINBASE_TARGET, 1 << 20, 1 << 40, u64::MAX] {
+            let targets = simulate_chain(initial_target, (0..NUM_BLOCKS).map(|_| anchor_time));
+            assert!(targets.iter().all(|target| *target == initial_target));
+        }
+    }
+
+    #[test]
+    fn test_retarget_fast_and_slow_coinbases() {
+        let anchor_time = CurrentNetwork::ANCHOR_TIME as i64;
+
+        // Ensure the target increases monotonically when coinbases are found faster than the anchor time.
+        let targets = simulate_chain(CurrentNetwork::GENESIS_COINBASE_TARGET, (0..NUM_BLOCKS).map(|_| 1));
+        assert!(targets.windows(2).all(|window| window[0] <= window[1]));
+        assert!(targets.last().unwrap() > &CurrentNetwork::GENESIS_COINBASE_TARGET);
+
+        // Ensure the target saturates, instead of overflowing.
+        assert_eq!(*targets.last().unwrap(), u64::MAX);
+
+        // Ensure the target decreases monotonically when coinbases are found slower than the anchor time.
+        let targets = simulate_chain(u64::MAX, (0..NUM_BLOCKS).map(|_| anchor_time * 2));
+        assert!(targets.windows(2).all(|window| window[0] >= window[1]));
+        assert!(targets.last().unwrap() < &u64::MAX);
+
+        // Ensure the target never falls below the genesis coinbase target.
+        assert_eq!(*targets.last().unwrap(), CurrentNetwork::GENESIS_COINBASE_TARGET);
+    }
+
+    #[test]
+    fn test_retarget_half_life() {
+        let anchor_time = CurrentNetwork::ANCHOR_TIME as i64;
+        let initial_target = 1u64 << 40;
+
+        // Ensure the target halves when a coinbase is late by one half-life.
+        let target = next_coinbase_target(initial_target, anchor_time + half_life());
+        assert!(target.abs_diff(initial_target / 2) <= initial_target / 1000, "{target}");
+
+        // Ensure the target doubles when coinbases are early by one half-life, in total.
+        let drift = 16;
+        let num_coinbases = (half_life() / drift) as usize;
+        let targets = simulate_chain(initial_target, (0..num_coinbases).map(|_| anchor_time - drift));
+        let target = *targets.last().unwrap();
+        assert!(target.abs_diff(initial_target * 2) <= initial_target / 100, "{target}");
+    }
+
+    #[test]
+    fn test_retarget_random_chain() {
+        let mut rng = TestRng::default();
+
+        // Simulate a chain with random times elapsed.
+        let times_elapsed =
+            (0..NUM_BLOCKS).map(|_| rng.gen_range(-10..(4 * CurrentNetwork::ANCHOR_TIME as i64))).collect::<Vec<_>>();
+        let targets = simulate_chain(CurrentNetwork::GENESIS_COINBASE_TARGET, times_elapsed.iter().copied());
+
+        // Ensure the simulation is deterministic.
+        assert_eq!(targets, simulate_chain(CurrentNetwork::GENESIS_COINBASE_TARGET, times_elapsed.iter().copied()));
+
+        for coinbase_target in targets {
+            // Compute the proof target.
+            let proof_target = proof_target(
+                coinbase_target,
+                CurrentNetwork::GENESIS_COINBASE_TARGET,
+                CurrentNetwork::GENESIS_PROOF_TARGET,
+            );
+            // Ensure the targets are at or above the genesis targets.
+            assert!(coinbase_target >= CurrentNetwork::GENESIS_COINBASE_TARGET);
+            assert!(proof_target >= CurrentNetwork::GENESIS_PROOF_TARGET);
+            // Ensure the coinbase target is larger than the proof target.
+            assert!(coinbase_target > proof_target);
+        }
+    }
+
+    #[test]
+    fn test_retarget_converges_to_anchor_time() {
+        let mut rng = TestRng::default();
+
+        for _ in 0..10 {
+            // Sample the rate at which the provers reach a target, per second.
+            let prover_rate = rng.gen_range(1_000..1_000_000u64);
+
+            // Simulate a chain, where the time to reach the coinbase target is proportional to the target.
+            let mut target = CurrentNetwork::GENESIS_COINBASE_TARGET;
+            let mut times_elapsed = Vec::with_capacity(NUM_BLOCKS);
+            for _ in 0..NUM_BLOCKS {
+                let time_elapsed = core::cmp::max(target / prover_rate, 1) as i64;
+                target = next_coinbase_target(target, time_elapsed);
+                times_elapsed.push(time_elapsed);
+            }
+
+            // Ensure the average time elapsed over the last epoch is close to the anchor time.
+            let epoch = CurrentNetwork::NUM_BLOCKS_PER_EPOCH as usize;
+            let average = times_elapsed[NUM_BLOCKS - epoch..].iter().sum::<i64>() as f64 / epoch as f64;
+            let anchor_time = CurrentNetwork::ANCHOR_TIME as f64;
+            assert!((average - anchor_time).abs() <= anchor_time * 0.1, "Average time elapsed is {average}");
+        }
+    }
+
+    #[test]
+    fn test_proof_target() {
+        let genesis_coinbase_target = CurrentNetwork::GENESIS_COINBASE_TARGET;
+        let genesis_proof_target = CurrentNetwork::GENESIS_PROOF_TARGET;
+
+        // Ensure the genesis coinbase target maps to the genesis proof target.
+        assert_eq!(
+            proof_target(genesis_coinbase_target, genesis_coinbase_target, genesis_proof_target),
+            genesis_proof_target
+        );
+        // Ensure the proof target never falls below the genesis proof target.
+        assert_eq!(proof_target(0, genesis_coinbase_target, genesis_proof_target), genesis_proof_target);
+        // Ensure the proof target scales with the coinbase target.
+        let coinbase_target = genesis_coinbase_target * 1024;
+        let proof_target = proof_target(coinbase_target, genesis_coinbase_target, genesis_proof_target);
+        assert!(proof_target > genesis_proof_target && proof_target < coinbase_target);
+    }
+}
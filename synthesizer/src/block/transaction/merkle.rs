@@ -90,6 +90,11 @@ impl<N: Network> Transaction<N> {
 }
 
 impl<N: Network> Transaction<N> {
+    /// Returns the transaction ID for the given deployment, which is signed by the program owner.
+    pub fn deployment_id(deployment: &Deployment<N>, fee: &Fee<N>) -> Result<N::TransactionID> {
+        Ok((*Self::deployment_tree(deployment, fee)?.root()).into())
+    }
+
     /// Returns the Merkle tree for the given deployment.
     pub(super) fn deployment_tree(deployment: &Deployment<N>, fee: &Fee<N>) -> Result<TransactionTree<N>> {
         // Ensure the number of leaves is within the Merkle tree size.
@@ -142,6 +142,17 @@ impl<N: Network> Transaction<N> {
         Ok(vm.execute_fee(private_key, credits, fee_in_microcredits, query, rng)?.1)
     }
 
+    /// Initializes a new fee, paid from the public balance of the caller.
+    pub fn execute_fee_public<C: ConsensusStorage<N>, R: Rng + CryptoRng>(
+        vm: &VM<N, C>,
+        private_key: &PrivateKey<N>,
+        fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Fee<N>> {
+        Ok(vm.execute_fee_public(private_key, fee_in_microcredits, query, rng)?.1)
+    }
+
     /// Initializes a new execution transaction from an authorization.
     pub fn execute_authorization<C: ConsensusStorage<N>, R: Rng + CryptoRng>(
         vm: &VM<N, C>,
@@ -22,7 +22,8 @@ impl<N: Network> FromBytes for Transition<N> {
         // Read the version.
         let version = u16::read_le(&mut reader)?;
         // Ensure the version is valid.
-        if version != 0 {
+        // Version 0 contains the transition proof, and version 1 defers it to the execution proof.
+        if version > 1 {
             return Err(error("Invalid transition version"));
         }
 
@@ -70,8 +71,11 @@ impl<N: Network> FromBytes for Transition<N> {
             2.. => return Err(error(format!("Invalid transition finalize variant ({finalize_variant})"))),
         };
 
-        // Read the proof.
-        let proof = FromBytes::read_le(&mut reader)?;
+        // Read the proof, if it exists.
+        let proof = match version {
+            0 => Some(FromBytes::read_le(&mut reader)?),
+            _ => None,
+        };
 
         // Read the transition public key.
         let tpk = FromBytes::read_le(&mut reader)?;
@@ -93,7 +97,10 @@ impl<N: Network> ToBytes for Transition<N> {
     /// Writes the literal to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the version.
-        0u16.write_le(&mut writer)?;
+        match self.proof {
+            Some(_) => 0u16.write_le(&mut writer)?,
+            None => 1u16.write_le(&mut writer)?,
+        }
 
         // Write the transition ID.
         self.id.write_le(&mut writer)?;
@@ -128,8 +135,10 @@ impl<N: Network> ToBytes for Transition<N> {
             }
         }
 
-        // Write the proof.
-        self.proof.write_le(&mut writer)?;
+        // Write the proof, if it exists.
+        if let Some(proof) = &self.proof {
+            proof.write_le(&mut writer)?;
+        }
 
         // Write the transition public key.
         self.tpk.write_le(&mut writer)?;
@@ -155,6 +164,23 @@ mod tests {
         assert_eq!(expected, Transition::read_le(&expected_bytes[..])?);
         assert!(Transition::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
 
+        // Construct the transition, with its proof deferred to the execution proof.
+        let expected = Transition::new(
+            *expected.program_id(),
+            *expected.function_name(),
+            expected.inputs().to_vec(),
+            expected.outputs().to_vec(),
+            expected.finalize().cloned(),
+            None,
+            *expected.tpk(),
+            *expected.tcm(),
+        )?;
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Transition::read_le(&expected_bytes[..])?);
+        assert!(Transition::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+
         Ok(())
     }
 }
@@ -62,8 +62,8 @@ pub struct Transition<N: Network> {
     outputs: Vec<Output<N>>,
     /// The inputs for finalize.
     finalize: Option<Vec<Value<N>>>,
-    /// The transition proof.
-    proof: Proof<N>,
+    /// The transition proof, if it is not deferred to the execution proof.
+    proof: Option<Proof<N>>,
     /// The transition public key.
     tpk: Group<N>,
     /// The transition commitment.
@@ -79,7 +79,7 @@ impl<N: Network> Transition<N> {
         inputs: Vec<Input<N>>,
         outputs: Vec<Output<N>>,
         finalize: Option<Vec<Value<N>>>,
-        proof: Proof<N>,
+        proof: Option<Proof<N>>,
         tpk: Group<N>,
         tcm: Field<N>,
     ) -> Result<Self> {
@@ -96,7 +96,7 @@ impl<N: Network> Transition<N> {
         finalize: Option<Vec<Value<N>>>,
         output_types: &[ValueType<N>],
         output_registers: &[Option<Register<N>>],
-        proof: Proof<N>,
+        proof: Option<Proof<N>>,
     ) -> Result<Self> {
         let network_id = *request.network_id();
         let program_id = *request.program_id();
@@ -291,9 +291,9 @@ impl<N: Network> Transition<N> {
         self.finalize.as_ref()
     }
 
-    /// Returns the proof.
-    pub const fn proof(&self) -> &Proof<N> {
-        &self.proof
+    /// Returns the proof, if it is not deferred to the execution proof.
+    pub const fn proof(&self) -> Option<&Proof<N>> {
+        self.proof.as_ref()
     }
 
     /// Returns the transition public key.
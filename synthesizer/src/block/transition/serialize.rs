@@ -32,7 +32,9 @@ impl<N: Network> Serialize for Transition<N> {
                 if let Some(finalize) = &self.finalize {
                     transition.serialize_field("finalize", &finalize)?;
                 }
-                transition.serialize_field("proof", &self.proof)?;
+                if let Some(proof) = &self.proof {
+                    transition.serialize_field("proof", proof)?;
+                }
                 transition.serialize_field("tpk", &self.tpk)?;
                 transition.serialize_field("tcm", &self.tcm)?;
                 transition.end()
@@ -67,8 +69,11 @@ impl<'de, N: Network> Deserialize<'de> for Transition<N> {
                         Some(finalize) => Some(serde_json::from_value(finalize.clone()).map_err(de::Error::custom)?),
                         None => None,
                     },
-                    // Retrieve the proof.
-                    DeserializeExt::take_from_value::<D>(&mut transition, "proof")?,
+                    // Retrieve the proof, if it exists.
+                    match transition.get("proof") {
+                        Some(proof) => Some(serde_json::from_value(proof.clone()).map_err(de::Error::custom)?),
+                        None => None,
+                    },
                     // Retrieve the `tpk`.
                     DeserializeExt::take_from_value::<D>(&mut transition, "tpk")?,
                     // Retrieve the `tcm`.
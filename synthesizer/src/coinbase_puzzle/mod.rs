@@ -20,13 +20,16 @@ pub use helpers::*;
 mod hash;
 use hash::*;
 
+mod search;
+pub use search::*;
+
 #[cfg(test)]
 mod tests;
 
 use crate::UniversalSRS;
 use console::{
     account::Address,
-    prelude::{anyhow, bail, cfg_iter, ensure, has_duplicates, Network, Result, ToBytes},
+    prelude::{anyhow, bail, cfg_iter, ensure, has_duplicates, Network, Result, Rng, ToBytes},
     program::cfg_into_iter,
 };
 use snarkvm_algorithms::{
@@ -38,7 +41,7 @@ use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{PrimeField, Zero};
 use snarkvm_utilities::cfg_zip_fold;
 
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 #[cfg(feature = "serial")]
 use itertools::Itertools;
@@ -120,20 +123,8 @@ impl<N: Network> CoinbasePuzzle<N> {
             Self::Verifier(_) => bail!("Cannot prove the coinbase puzzle with a verifier"),
         };
 
-        let polynomial = Self::prover_polynomial(epoch_challenge, address, nonce)?;
-
-        let product_evaluations = {
-            let polynomial_evaluations = pk.product_domain.in_order_fft_with_pc(&polynomial, &pk.fft_precomputation);
-            let product_evaluations = pk.product_domain.mul_polynomials_in_evaluation_domain(
-                polynomial_evaluations,
-                &epoch_challenge.epoch_polynomial_evaluations().evaluations,
-            );
-            product_evaluations
-        };
-        let (commitment, _rand) =
-            KZG10::commit_lagrange(&pk.lagrange_basis(), &product_evaluations, None, &Default::default(), None)?;
-
-        let partial_solution = PartialSolution::new(address, nonce, commitment);
+        // Compute the partial solution.
+        let (polynomial, product_evaluations, partial_solution) = Self::commit(pk, epoch_challenge, address, nonce)?;
 
         // Check that the minimum target is met.
         if let Some(minimum_target) = minimum_proof_target {
@@ -144,21 +135,8 @@ impl<N: Network> CoinbasePuzzle<N> {
             );
         }
 
-        let point = hash_commitment(&commitment)?;
-        let product_eval_at_point = polynomial.evaluate(point) * epoch_challenge.epoch_polynomial().evaluate(point);
-
-        let proof = KZG10::open_lagrange(
-            &pk.lagrange_basis(),
-            pk.product_domain_elements(),
-            &product_evaluations,
-            point,
-            product_eval_at_point,
-        )?;
-        ensure!(!proof.is_hiding(), "The prover solution must contain a non-hiding proof");
-
-        debug_assert!(KZG10::check(&pk.verifying_key, &commitment, point, product_eval_at_point, &proof)?);
-
-        Ok(ProverSolution::new(partial_solution, proof))
+        // Compute the prover solution.
+        Self::open(pk, epoch_challenge, &polynomial, &product_evaluations, partial_solution)
     }
 
     /// Returns a coinbase solution for the given epoch challenge and prover solutions.
@@ -351,6 +329,63 @@ impl<N: Network> CoinbasePuzzle<N> {
         )?)
     }
 
+    /// Returns the indices of the given prover solutions that are invalid, for the given epoch challenge and proof target.
+    ///
+    /// The KZG opening proofs of the prover solutions are checked together with a single randomized product of pairings.
+    /// If the batch check fails, the prover solutions are bisected to find the invalid ones.
+    /// A prover solution is also invalid if its proof is hiding, if it does not meet the proof target,
+    /// or if its puzzle commitment already appeared earlier in the given prover solutions.
+    pub fn batch_verify_solutions<R: Rng>(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        prover_solutions: &[ProverSolution<N>],
+        proof_target: u64,
+        rng: &mut R,
+    ) -> Result<Vec<usize>> {
+        // Retrieve the coinbase verifying key.
+        let coinbase_verifying_key = self.coinbase_verifying_key();
+
+        // Compute the claimed evaluation of each prover solution at its challenge point.
+        let claims = cfg_iter!(prover_solutions)
+            .map(|solution| {
+                // Ensure the proof is non-hiding.
+                if solution.proof().is_hiding() {
+                    return None;
+                }
+                // Ensure the prover solution meets the proof target.
+                if solution.to_target().ok()? < proof_target {
+                    return None;
+                }
+                // Compute the challenge point.
+                let point = hash_commitment(&solution.commitment()).ok()?;
+                // Compute the claimed value, by multiplying the evaluations of the prover and epoch polynomials.
+                let prover_polynomial = solution.to_prover_polynomial(epoch_challenge).ok()?;
+                let value = prover_polynomial.evaluate(point) * epoch_challenge.epoch_polynomial().evaluate(point);
+                Some((point, value))
+            })
+            .collect::<Vec<_>>();
+
+        // Separate the prover solutions that failed the checks above, or repeat an earlier puzzle commitment.
+        let mut invalid = Vec::new();
+        let mut candidates = Vec::with_capacity(prover_solutions.len());
+        let mut commitments = HashSet::with_capacity(prover_solutions.len());
+        for (index, (solution, claim)) in prover_solutions.iter().zip(claims).enumerate() {
+            match claim {
+                Some((point, value)) if commitments.insert(solution.commitment()) => {
+                    candidates.push((index, *solution.commitment(), point, value, *solution.proof()))
+                }
+                _ => invalid.push(index),
+            }
+        }
+
+        // Check the KZG opening proofs of the remaining prover solutions.
+        Self::batch_check_openings(coinbase_verifying_key, &candidates, &mut invalid, rng)?;
+
+        // Return the indices of the invalid prover solutions, in order.
+        invalid.sort_unstable();
+        Ok(invalid)
+    }
+
     /// Returns the coinbase proving key.
     pub fn coinbase_proving_key(&self) -> Result<&CoinbaseProvingKey<N>> {
         match self {
@@ -385,6 +420,103 @@ impl<N: Network> CoinbasePuzzle<N> {
         Ok(product_domain)
     }
 
+    /// Returns the prover polynomial, the evaluations of the product polynomial,
+    /// and the partial solution committing to the product polynomial, for the given nonce.
+    #[allow(clippy::type_complexity)]
+    fn commit(
+        pk: &CoinbaseProvingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonce: u64,
+    ) -> Result<(
+        DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>,
+        Vec<<N::PairingCurve as PairingEngine>::Fr>,
+        PartialSolution<N>,
+    )> {
+        let polynomial = Self::prover_polynomial(epoch_challenge, address, nonce)?;
+
+        let product_evaluations = {
+            let polynomial_evaluations = pk.product_domain.in_order_fft_with_pc(&polynomial, &pk.fft_precomputation);
+            pk.product_domain.mul_polynomials_in_evaluation_domain(
+                polynomial_evaluations,
+                &epoch_challenge.epoch_polynomial_evaluations().evaluations,
+            )
+        };
+        let (commitment, _rand) =
+            KZG10::commit_lagrange(&pk.lagrange_basis(), &product_evaluations, None, &Default::default(), None)?;
+
+        Ok((polynomial, product_evaluations, PartialSolution::new(address, nonce, commitment)))
+    }
+
+    /// Returns the prover solution for the given partial solution, by opening the product polynomial
+    /// at the challenge point of the commitment.
+    fn open(
+        pk: &CoinbaseProvingKey<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        polynomial: &DensePolynomial<<N::PairingCurve as PairingEngine>::Fr>,
+        product_evaluations: &[<N::PairingCurve as PairingEngine>::Fr],
+        partial_solution: PartialSolution<N>,
+    ) -> Result<ProverSolution<N>> {
+        let commitment = *partial_solution.commitment();
+
+        let point = hash_commitment(&commitment)?;
+        let product_eval_at_point = polynomial.evaluate(point) * epoch_challenge.epoch_polynomial().evaluate(point);
+
+        let proof = KZG10::open_lagrange(
+            &pk.lagrange_basis(),
+            pk.product_domain_elements(),
+            product_evaluations,
+            point,
+            product_eval_at_point,
+        )?;
+        ensure!(!proof.is_hiding(), "The prover solution must contain a non-hiding proof");
+
+        debug_assert!(KZG10::check(&pk.verifying_key, &commitment, point, product_eval_at_point, &proof)?);
+
+        Ok(ProverSolution::new(partial_solution, proof))
+    }
+
+    /// Checks the given KZG openings with a single batch check, bisecting them on failure,
+    /// and appends the indices of the invalid openings to `invalid`.
+    #[allow(clippy::type_complexity)]
+    fn batch_check_openings<R: Rng>(
+        verifying_key: &CoinbaseVerifyingKey<N>,
+        openings: &[(
+            usize,
+            KZGCommitment<N::PairingCurve>,
+            <N::PairingCurve as PairingEngine>::Fr,
+            <N::PairingCurve as PairingEngine>::Fr,
+            PuzzleProof<N>,
+        )],
+        invalid: &mut Vec<usize>,
+        rng: &mut R,
+    ) -> Result<()> {
+        match openings {
+            // If there are no openings, there is nothing to check.
+            [] => Ok(()),
+            // If there is one opening, check it individually.
+            [(index, commitment, point, value, proof)] => {
+                if !KZG10::check(verifying_key, commitment, *point, *value, proof)? {
+                    invalid.push(*index);
+                }
+                Ok(())
+            }
+            // Otherwise, check the openings together, and bisect them if the batch check fails.
+            _ => {
+                let commitments = openings.iter().map(|opening| opening.1).collect::<Vec<_>>();
+                let points = openings.iter().map(|opening| opening.2).collect::<Vec<_>>();
+                let values = openings.iter().map(|opening| opening.3).collect::<Vec<_>>();
+                let proofs = openings.iter().map(|opening| opening.4).collect::<Vec<_>>();
+                if !KZG10::batch_check(verifying_key, &commitments, &points, &values, &proofs, rng)? {
+                    let (left, right) = openings.split_at(openings.len() / 2);
+                    Self::batch_check_openings(verifying_key, left, invalid, rng)?;
+                    Self::batch_check_openings(verifying_key, right, invalid, rng)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
     /// Returns the prover polynomial for the coinbase puzzle.
     fn prover_polynomial(
         epoch_challenge: &EpochChallenge<N>,
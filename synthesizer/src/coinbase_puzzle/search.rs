@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::{
+    ops::Range,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+/// The number of nonces attempted in each unit of work of the search.
+const NONCES_PER_CHUNK: u64 = 64;
+
+/// The statistics of a coinbase puzzle search.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SearchStatistics {
+    /// The number of nonces attempted.
+    pub num_attempts: u64,
+    /// The number of prover solutions found.
+    pub num_solutions: u64,
+    /// The duration of the search.
+    pub elapsed: Duration,
+    /// Whether the search was terminated before attempting every nonce.
+    pub terminated: bool,
+}
+
+impl SearchStatistics {
+    /// Returns the hash rate of the search, as the number of nonces attempted per second.
+    pub fn hash_rate(&self) -> f64 {
+        match self.elapsed.as_secs_f64() {
+            elapsed if elapsed > 0.0 => self.num_attempts as f64 / elapsed,
+            _ => 0.0,
+        }
+    }
+}
+
+impl<N: Network> CoinbasePuzzle<N> {
+    /// Searches the given range of nonces for prover solutions that meet the minimum proof target,
+    /// and passes each prover solution to `on_solution` as soon as it is found.
+    ///
+    /// The nonces are searched in chunks across the rayon thread pool (unless the `serial` feature is enabled),
+    /// with every thread sharing the product domain and FFT precomputation of the proving key.
+    /// The search stops early once the `terminator` is set, and returns the statistics of the search.
+    pub fn search<F: Fn(ProverSolution<N>) + Send + Sync>(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        address: Address<N>,
+        nonces: Range<u64>,
+        minimum_proof_target: u64,
+        terminator: &AtomicBool,
+        on_solution: F,
+    ) -> Result<SearchStatistics> {
+        // Retrieve the coinbase proving key.
+        let pk = match self {
+            Self::Prover(coinbase_proving_key) => coinbase_proving_key,
+            Self::Verifier(_) => bail!("Cannot search the coinbase puzzle with a verifier"),
+        };
+
+        // Prepare the counters.
+        let num_attempts = AtomicU64::new(0);
+        let num_solutions = AtomicU64::new(0);
+        let timer = Instant::now();
+
+        // Compute the number of chunks.
+        let num_nonces = nonces.end.saturating_sub(nonces.start);
+        let num_chunks = num_nonces.saturating_add(NONCES_PER_CHUNK - 1) / NONCES_PER_CHUNK;
+
+        // Search each chunk of nonces.
+        cfg_into_iter!(0..num_chunks).try_for_each(|chunk| {
+            let start = nonces.start + chunk * NONCES_PER_CHUNK;
+            let end = core::cmp::min(start.saturating_add(NONCES_PER_CHUNK), nonces.end);
+            for nonce in start..end {
+                // Stop the search, if the terminator is set.
+                if terminator.load(Ordering::Relaxed) {
+                    return Ok(());
+                }
+
+                // Compute the partial solution.
+                let (polynomial, product_evaluations, partial_solution) =
+                    Self::commit(pk, epoch_challenge, address, nonce)?;
+                num_attempts.fetch_add(1, Ordering::Relaxed);
+
+                // If the minimum proof target is met, compute and emit the prover solution.
+                if partial_solution.to_target()? >= minimum_proof_target {
+                    let solution =
+                        Self::open(pk, epoch_challenge, &polynomial, &product_evaluations, partial_solution)?;
+                    num_solutions.fetch_add(1, Ordering::Relaxed);
+                    on_solution(solution);
+                }
+            }
+            Ok::<_, anyhow::Error>(())
+        })?;
+
+        // Return the statistics of the search.
+        let num_attempts = num_attempts.into_inner();
+        Ok(SearchStatistics {
+            num_attempts,
+            num_solutions: num_solutions.into_inner(),
+            elapsed: timer.elapsed(),
+            terminated: num_attempts < num_nonces,
+        })
+    }
+}
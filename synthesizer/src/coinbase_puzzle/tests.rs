@@ -19,6 +19,10 @@ use console::{account::*, network::Testnet3};
 use snarkvm_utilities::Uniform;
 
 use rand::RngCore;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Mutex,
+};
 
 const ITERATIONS: u64 = 100;
 
@@ -108,3 +112,119 @@ fn test_edge_case_for_degree() {
     let coinbase_solution = puzzle.accumulate_unchecked(&epoch_challenge, &[prover_solution]).unwrap();
     assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64, 0u64).unwrap());
 }
+
+#[test]
+fn test_search() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let address = Address::try_from(private_key).unwrap();
+    let terminator = AtomicBool::new(false);
+
+    // Search without a minimum proof target, which should find a solution for every nonce.
+    let solutions = Mutex::new(Vec::new());
+    let statistics =
+        puzzle.search(&epoch_challenge, address, 0..ITERATIONS, 0, &terminator, |s| solutions.lock().unwrap().push(s));
+    let statistics = statistics.unwrap();
+    let solutions = solutions.into_inner().unwrap();
+    assert_eq!(statistics.num_attempts, ITERATIONS);
+    assert_eq!(statistics.num_solutions, ITERATIONS);
+    assert!(!statistics.terminated);
+    assert_eq!(solutions.len(), ITERATIONS as usize);
+
+    // Ensure each solution matches the prover solution for its nonce, and is valid.
+    for solution in &solutions {
+        assert_eq!(*solution, puzzle.prove(&epoch_challenge, address, solution.nonce(), None).unwrap());
+        assert!(solution.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, 0).unwrap());
+    }
+
+    // Search with the median proof target, which should only find the solutions meeting it.
+    let mut targets = solutions.iter().map(|solution| solution.to_target().unwrap()).collect::<Vec<_>>();
+    targets.sort_unstable();
+    let minimum_proof_target = targets[targets.len() / 2];
+    let num_expected = targets.iter().filter(|target| **target >= minimum_proof_target).count();
+
+    let solutions = Mutex::new(Vec::new());
+    let statistics = puzzle
+        .search(&epoch_challenge, address, 0..ITERATIONS, minimum_proof_target, &terminator, |s| {
+            solutions.lock().unwrap().push(s)
+        })
+        .unwrap();
+    let solutions = solutions.into_inner().unwrap();
+    assert_eq!(statistics.num_attempts, ITERATIONS);
+    assert_eq!(statistics.num_solutions, num_expected as u64);
+    assert_eq!(solutions.len(), num_expected);
+    assert!(solutions.iter().all(|solution| solution.to_target().unwrap() >= minimum_proof_target));
+
+    // Search with the terminator set, which should not attempt any nonce.
+    terminator.store(true, Ordering::Relaxed);
+    let statistics = puzzle.search(&epoch_challenge, address, 0..ITERATIONS, 0, &terminator, |_| panic!()).unwrap();
+    assert_eq!(statistics.num_attempts, 0);
+    assert_eq!(statistics.num_solutions, 0);
+    assert!(statistics.terminated);
+}
+
+#[test]
+fn test_batch_verify_solutions() {
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    let degree = (1 << 5) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    for batch_size in [0, 1, 2, 7, 16] {
+        let mut solutions = (0..batch_size)
+            .map(|_| {
+                let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+                let address = Address::try_from(private_key).unwrap();
+                let nonce = u64::rand(&mut rng);
+                puzzle.prove(&epoch_challenge, address, nonce, None).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure the valid prover solutions pass.
+        assert!(puzzle.batch_verify_solutions(&epoch_challenge, &solutions, 0, &mut rng).unwrap().is_empty());
+        if batch_size == 0 {
+            continue;
+        }
+
+        // Ensure the prover solutions fail for a different epoch challenge.
+        let bad_epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+        let expected = (0..batch_size).collect::<Vec<_>>();
+        assert_eq!(puzzle.batch_verify_solutions(&bad_epoch_challenge, &solutions, 0, &mut rng).unwrap(), expected);
+
+        // Ensure the prover solutions below the proof target fail.
+        let proof_target = solutions.iter().map(|solution| solution.to_target().unwrap()).max().unwrap();
+        let expected =
+            (0..batch_size).filter(|i| solutions[*i].to_target().unwrap() < proof_target).collect::<Vec<_>>();
+        assert_eq!(
+            puzzle.batch_verify_solutions(&epoch_challenge, &solutions, proof_target, &mut rng).unwrap(),
+            expected
+        );
+
+        // Ensure a prover solution with a mismatched proof fails.
+        let index = rng.gen_range(0..batch_size);
+        let other = puzzle.prove(&epoch_challenge, solutions[index].address(), u64::rand(&mut rng), None).unwrap();
+        let partial_solution =
+            PartialSolution::new(solutions[index].address(), solutions[index].nonce(), solutions[index].commitment());
+        let original = std::mem::replace(&mut solutions[index], ProverSolution::new(partial_solution, *other.proof()));
+        assert_eq!(puzzle.batch_verify_solutions(&epoch_challenge, &solutions, 0, &mut rng).unwrap(), vec![index]);
+        solutions[index] = original;
+
+        // Ensure a duplicate prover solution fails.
+        solutions.push(solutions[index]);
+        assert_eq!(puzzle.batch_verify_solutions(&epoch_challenge, &solutions, 0, &mut rng).unwrap(), vec![batch_size]);
+    }
+}
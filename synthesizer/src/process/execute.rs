@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::finalize::{Branch, Command, Finalize};
 
 impl<N: Network> Process<N> {
     /// Executes the given authorization.
@@ -23,6 +24,28 @@ impl<N: Network> Process<N> {
         &self,
         authorization: Authorization<N>,
         rng: &mut R,
+    ) -> Result<(Response<N>, Execution<N>, Inclusion<N>, Vec<CallMetrics<N>>)> {
+        self.execute_with_inclusion::<A, R>(authorization, Inclusion::new(), rng)
+    }
+
+    /// Executes the given authorization, deferring the transition proofs to the batch proof of the execution.
+    /// Note: The execution is only valid once it is proven with `Inclusion::prove_execution`.
+    #[inline]
+    pub fn execute_batch<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        authorization: Authorization<N>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Execution<N>, Inclusion<N>, Vec<CallMetrics<N>>)> {
+        self.execute_with_inclusion::<A, R>(authorization, Inclusion::new_batch(), rng)
+    }
+
+    /// Executes the given authorization, with the given (empty) inclusion.
+    #[inline]
+    fn execute_with_inclusion<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        authorization: Authorization<N>,
+        inclusion: Inclusion<N>,
+        rng: &mut R,
     ) -> Result<(Response<N>, Execution<N>, Inclusion<N>, Vec<CallMetrics<N>>)> {
         let timer = timer!("Process::execute");
 
@@ -35,7 +58,7 @@ impl<N: Network> Process<N> {
         // Initialize the execution.
         let execution = Arc::new(RwLock::new(Execution::new()));
         // Initialize the inclusion.
-        let inclusion = Arc::new(RwLock::new(Inclusion::new()));
+        let inclusion = Arc::new(RwLock::new(inclusion));
         // Initialize the metrics.
         let metrics = Arc::new(RwLock::new(Vec::new()));
         // Initialize the call stack.
@@ -59,6 +82,8 @@ impl<N: Network> Process<N> {
 
     /// Verifies the given execution is valid.
     /// Note: This does *not* check that the global state root exists in the ledger.
+    /// Note: If the transition proofs are deferred, the inclusion proof is part of the batch proof,
+    /// and is verified regardless of `VERIFY_INCLUSION`.
     #[inline]
     pub fn verify_execution<const VERIFY_INCLUSION: bool>(&self, execution: &Execution<N>) -> Result<()> {
         let timer = timer!("Process::verify_execution");
@@ -82,12 +107,23 @@ impl<N: Network> Process<N> {
         }
         lap!(timer, "Verify the number of transitions");
 
+        // Determine if the transition proofs are deferred to the batch proof of the execution.
+        let is_batch = execution.is_batch();
+        // Ensure the transition proofs are either all deferred, or all present.
+        ensure!(
+            is_batch || execution.transitions().all(|transition| transition.proof().is_some()),
+            "The execution contains both proven and deferred transitions"
+        );
+
         // Ensure the inclusion proof is valid.
-        if VERIFY_INCLUSION {
+        if VERIFY_INCLUSION && !is_batch {
             Inclusion::verify_execution(execution)?;
             lap!(timer, "Verify the inclusion proof");
         }
 
+        // Initialize a vector for the verifying keys and verifier inputs of the deferred transitions.
+        let mut deferred_transitions = Vec::new();
+
         // Replicate the execution stack for verification.
         let mut queue = execution.clone();
 
@@ -210,27 +246,45 @@ impl<N: Network> Process<N> {
 
             // Retrieve the verifying key.
             let verifying_key = self.get_verifying_key(stack.program_id(), function.name())?;
-            // Ensure the transition proof is valid.
-            ensure!(
-                verifying_key.verify(function.name(), &inputs, transition.proof()),
-                "Transition is invalid - failed to verify transition proof"
-            );
+            match transition.proof() {
+                Some(proof) => {
+                    // Ensure the transition proof is valid.
+                    ensure!(
+                        verifying_key.verify(function.name(), &inputs, proof),
+                        "Transition is invalid - failed to verify transition proof"
+                    );
+                    lap!(timer, "Verify transition proof for {}", function.name());
+                }
+                // Otherwise, defer the transition to the batch proof of the execution.
+                None => deferred_transitions.push(((*stack.program_id(), *function.name()), verifying_key, inputs)),
+            }
+        }
 
-            lap!(timer, "Verify transition proof for {}", function.name());
+        // Ensure the batch proof is valid, if the transition proofs are deferred.
+        if is_batch {
+            // Group the verifier inputs by function, in the order of the transitions.
+            let mut transition_batches = IndexMap::<_, (VerifyingKey<N>, Vec<Vec<N::Field>>)>::new();
+            for (locator, verifying_key, inputs) in deferred_transitions.into_iter().rev() {
+                transition_batches.entry(locator).or_insert_with(|| (verifying_key, vec![])).1.push(inputs);
+            }
+            let transition_batches = transition_batches.into_values().collect::<Vec<_>>();
+
+            Inclusion::verify_execution_batch(execution, &transition_batches)?;
+            lap!(timer, "Verify the batch proof");
         }
 
         finish!(timer);
         Ok(())
     }
 
-    /// Finalizes the execution.
+    /// Finalizes the execution, and returns the metrics of its finalize scopes.
     /// This method assumes the given execution **is valid**.
     #[inline]
     pub fn finalize_execution<P: ProgramStorage<N>>(
         &self,
         store: &ProgramStore<N, P>,
         execution: &Execution<N>,
-    ) -> Result<()> {
+    ) -> Result<FinalizeMetrics> {
         let timer = timer!("Program::finalize_execution");
 
         // Ensure the execution contains transitions.
@@ -255,46 +309,200 @@ impl<N: Network> Process<N> {
         // TODO (howardwu): This is a temporary approach. We should create a "CallStack" and recurse through the stack.
         //  Currently this loop assumes a linearly execution stack.
         // Finalize each transition, starting from the last one.
+        let mut metrics = FinalizeMetrics::default();
         for transition in execution.transitions().rev() {
-            #[cfg(debug_assertions)]
-            println!("Finalizing transition for {}/{}...", transition.program_id(), transition.function_name());
+            let transition_metrics = self.finalize_transition(store, transition)?;
+            metrics.num_commands = metrics.num_commands.saturating_add(transition_metrics.num_commands);
+            metrics.num_bytes = metrics.num_bytes.saturating_add(transition_metrics.num_bytes);
+            lap!(timer, "Finalize transition for {}", transition.function_name());
+        }
+        finish!(timer);
 
-            // Retrieve the stack.
-            let stack = self.get_stack(transition.program_id())?;
-            // Retrieve the function name.
-            let function_name = transition.function_name();
-
-            // If there is a finalize scope, finalize the function.
-            if let Some((_, finalize)) = stack.get_function(function_name)?.finalize() {
-                // Retrieve the finalize inputs.
-                let inputs = match transition.finalize() {
-                    Some(inputs) => inputs,
-                    // Ensure the transition contains finalize inputs.
-                    None => bail!("The transition is missing inputs for 'finalize'"),
-                };
+        Ok(metrics)
+    }
 
-                // Initialize the registers.
-                let mut registers = FinalizeRegisters::<N>::new(stack.get_finalize_types(finalize.name())?.clone());
+    /// Finalizes the fee.
+    /// This method assumes the given fee **is valid**.
+    #[inline]
+    pub fn finalize_fee<P: ProgramStorage<N>>(&self, store: &ProgramStore<N, P>, fee: &Fee<N>) -> Result<()> {
+        let timer = timer!("Program::finalize_fee");
 
-                // Store the inputs.
-                finalize.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
-                    // Assign the input value to the register.
-                    registers.store(stack, register, input.clone())
-                })?;
+        // Finalize the fee transition.
+        self.finalize_transition(store, fee.transition())?;
 
-                // Evaluate the commands.
-                for command in finalize.commands() {
-                    // If the evaluation fails, bail and return the error.
-                    if let Err(error) = command.finalize(stack, store, &mut registers) {
-                        bail!("'finalize' failed to evaluate command ({command}): {error}");
+        finish!(timer);
+
+        Ok(())
+    }
+
+    /// Finalizes the given transition, if its function has a finalize scope, and returns the metrics of the finalize scope.
+    fn finalize_transition<P: ProgramStorage<N>>(
+        &self,
+        store: &ProgramStore<N, P>,
+        transition: &Transition<N>,
+    ) -> Result<FinalizeMetrics> {
+        #[cfg(debug_assertions)]
+        println!("Finalizing transition for {}/{}...", transition.program_id(), transition.function_name());
+
+        // Retrieve the stack.
+        let stack = self.get_stack(transition.program_id())?;
+        // Retrieve the function name.
+        let function_name = transition.function_name();
+
+        // Initialize the metrics.
+        let mut metrics = FinalizeMetrics::default();
+
+        // If there is a finalize scope, finalize the function.
+        if let Some((_, finalize)) = stack.get_function(function_name)?.finalize() {
+            // Retrieve the finalize inputs.
+            let inputs = match transition.finalize() {
+                Some(inputs) => inputs,
+                // Ensure the transition contains finalize inputs.
+                None => bail!("The transition is missing inputs for 'finalize'"),
+            };
+
+            // Initialize the registers.
+            let mut registers = FinalizeRegisters::<N>::new(stack.get_finalize_types(finalize.name())?.clone());
+
+            // Store the inputs.
+            finalize.inputs().iter().map(|i| i.register()).zip_eq(inputs).try_for_each(|(register, input)| {
+                // Assign the input value to the register.
+                registers.store(stack, register, input.clone())
+            })?;
+
+            // Evaluate the commands, starting from the first command.
+            let mut counter = 0;
+            while let Some(command) = finalize.commands().get(counter) {
+                // Measure the bytes of the mapping entry written by the command, and then evaluate the command.
+                let result = num_bytes_written(command, stack, store, &registers).and_then(|num_bytes| {
+                    // Note: A position is a marker, and is not counted as an evaluated command.
+                    if !matches!(command, Command::Position(_)) {
+                        metrics.num_commands = metrics.num_commands.saturating_add(1);
                     }
+                    metrics.num_bytes = metrics.num_bytes.saturating_add(num_bytes);
+                    // Evaluate the command, and retrieve the index of the next command.
+                    match command {
+                        Command::BranchEq(branch_eq) => branch_to(counter, branch_eq, finalize, stack, &mut registers),
+                        Command::BranchNeq(branch_neq) => {
+                            branch_to(counter, branch_neq, finalize, stack, &mut registers)
+                        }
+                        _ => command.finalize(stack, store, &mut registers).map(|_| counter + 1),
+                    }
+                });
+                match result {
+                    Ok(next) => counter = next,
+                    // If the evaluation fails, bail and return the error.
+                    Err(error) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
                 }
+            }
+        }
+        Ok(metrics)
+    }
+}
 
-                lap!(timer, "Finalize transition for {function_name}");
+/// Returns the number of bytes of the mapping entry that the given command will write, if it is evaluated next.
+/// Note: A `get.or_init` command only writes its default value if the key is not yet in the mapping.
+/// As in the finalize commands, the key is looked up speculatively, to account for the writes of earlier commands.
+fn num_bytes_written<N: Network, P: ProgramStorage<N>>(
+    command: &Command<N>,
+    stack: &Stack<N>,
+    store: &ProgramStore<N, P>,
+    registers: &FinalizeRegisters<N>,
+) -> Result<u64> {
+    // Retrieve the key and value of the mapping entry that is written.
+    let (key, value) = match command {
+        Command::Set(set) => (registers.load_plaintext(stack, set.key())?, set.value()),
+        Command::GetOrInit(get_or_init) => {
+            let key = registers.load_plaintext(stack, get_or_init.key())?;
+            match store.contains_key(stack.program_id(), get_or_init.mapping_name(), &key)? {
+                true => return Ok(0),
+                false => (key, get_or_init.default()),
             }
         }
-        finish!(timer);
+        _ => return Ok(0),
+    };
+    let value = registers.load_plaintext(stack, value)?;
+    // Return the number of bytes of the key and value.
+    Ok(u64::try_from(key.to_bytes_le()?.len())?.saturating_add(u64::try_from(value.to_bytes_le()?.len())?))
+}
 
-        Ok(())
+/// Evaluates the given branch, and returns the index of the next command to finalize.
+fn branch_to<N: Network, const VARIANT: u8>(
+    counter: usize,
+    branch: &Branch<N, VARIANT>,
+    finalize: &Finalize<N>,
+    stack: &Stack<N>,
+    registers: &mut FinalizeRegisters<N>,
+) -> Result<usize> {
+    match branch.evaluate(stack, registers)? {
+        // If the branch is taken, jump to the position.
+        true => match finalize.positions().get(branch.position()) {
+            // Ensure the branch jumps forward.
+            Some(index) if *index > counter => Ok(*index),
+            Some(_) => bail!("Branch to position '{}' must jump forward", branch.position()),
+            None => bail!("Position '{}' is not defined", branch.position()),
+        },
+        // Otherwise, continue to the next command.
+        false => Ok(counter + 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProgramMemory;
+    use console::{network::Testnet3, program::Register};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_num_bytes_written_reads_earlier_writes() {
+        // Initialize a program with a `get.or_init` command.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program bytes.aleo;
+
+mapping entries:
+    key left as field.public;
+    value right as field.public;
+
+function init:
+    input r0 as field.public;
+    finalize r0;
+
+finalize init:
+    input r0 as field.public;
+    get.or_init entries[r0] 0field into r1;
+",
+        )
+        .unwrap();
+        let function_name = Identifier::from_str("init").unwrap();
+        let mapping_name = Identifier::from_str("entries").unwrap();
+        let key = Plaintext::from_str("1field").unwrap();
+
+        // Initialize a new process.
+        let mut process = Process::load().unwrap();
+        process.add_program(&program).unwrap();
+        let stack = process.get_stack(program.id()).unwrap();
+        let finalize = stack.get_function(&function_name).unwrap().finalize_logic().cloned().unwrap();
+        let command = &finalize.commands()[0];
+
+        // Initialize the registers.
+        let mut registers = FinalizeRegisters::new(stack.get_finalize_types(finalize.name()).unwrap().clone());
+        registers.store(stack, &Register::Locator(0), Value::Plaintext(key.clone())).unwrap();
+
+        // Initialize the program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        store.initialize_mapping(program.id(), &mapping_name).unwrap();
+
+        // Ensure the default value is counted, if the key is not yet in the mapping.
+        assert!(num_bytes_written(command, stack, &store, &registers).unwrap() > 0);
+
+        // Write the key in an atomic batch, as an earlier command in the same scope would.
+        store.start_atomic();
+        store.update_key_value(program.id(), &mapping_name, key, Value::from_str("2field").unwrap()).unwrap();
+        // Ensure the default value is not counted, as the key is already in the mapping.
+        assert_eq!(0, num_bytes_written(command, stack, &store, &registers).unwrap());
+        store.abort_atomic();
     }
 }
@@ -25,19 +25,45 @@ impl<N: Network> Process<N> {
         credits: Record<N, Plaintext<N>>,
         fee_in_microcredits: u64,
         rng: &mut R,
+    ) -> Result<(Response<N>, Transition<N>, Inclusion<N>, Vec<CallMetrics<N>>)> {
+        // Construct the inputs.
+        let inputs = [Value::Record(credits), Value::from_str(&format!("{}", U64::<N>::new(fee_in_microcredits)))?];
+        // Execute the fee.
+        self.execute_fee_function::<A, R>(private_key, "fee", &inputs, rng)
+    }
+
+    /// Executes the fee given the fee amount (in microcredits), which is paid from the public balance of the caller.
+    #[inline]
+    pub fn execute_fee_public<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        fee_in_microcredits: u64,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Transition<N>, Inclusion<N>, Vec<CallMetrics<N>>)> {
+        // Construct the inputs.
+        let inputs = [Value::from_str(&format!("{}", U64::<N>::new(fee_in_microcredits)))?];
+        // Execute the fee.
+        self.execute_fee_function::<A, R>(private_key, "fee_public", &inputs, rng)
+    }
+
+    /// Executes the given fee function in `credits.aleo` on the given inputs.
+    fn execute_fee_function<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        function_name: &str,
+        inputs: &[Value<N>],
+        rng: &mut R,
     ) -> Result<(Response<N>, Transition<N>, Inclusion<N>, Vec<CallMetrics<N>>)> {
         let timer = timer!("Process::execute_fee");
 
         // Ensure the fee has the correct program ID.
         let program_id = ProgramID::from_str("credits.aleo")?;
         // Ensure the fee has the correct function.
-        let function_name = Identifier::from_str("fee")?;
+        let function_name = Identifier::from_str(function_name)?;
 
         // Retrieve the input types.
         let input_types = self.get_program(program_id)?.get_function(&function_name)?.input_types();
-        // Construct the inputs.
-        let inputs = [Value::Record(credits), Value::from_str(&format!("{}", U64::<N>::new(fee_in_microcredits)))?];
-        lap!(timer, "Construct the inputs");
+        lap!(timer, "Retrieve the input types");
         // Compute the request.
         let request = Request::sign(private_key, program_id, function_name, inputs.iter(), &input_types, rng)?;
         lap!(timer, "Compute the request");
@@ -99,7 +125,7 @@ impl<N: Network> Process<N> {
 
         // Ensure the fee has the correct function.
         let fee_function = Identifier::from_str("fee")?;
-        ensure!(*fee.function_name() == fee_function, "Incorrect function name for fee");
+        ensure!(*fee.function_name() == fee_function || fee.is_public(), "Incorrect function name for fee");
 
         // Ensure the transition ID of the fee is correct.
         ensure!(**fee.id() == fee.to_root()?, "Transition ID of the fee is incorrect");
@@ -133,8 +159,12 @@ impl<N: Network> Process<N> {
         }
         lap!(timer, "Verify the outputs");
 
-        // Ensure the inclusion proof is valid.
-        Inclusion::verify_fee(fee)?;
+        match fee.is_public() {
+            // Ensure the public fee does not contain an inclusion proof, as it does not consume any records.
+            true => ensure!(fee.inclusion_proof().is_none(), "The public fee should not contain an inclusion proof"),
+            // Ensure the inclusion proof is valid.
+            false => Inclusion::verify_fee(fee)?,
+        }
         lap!(timer, "Verify the inclusion proof");
 
         // Compute the x- and y-coordinate of `tpk`.
@@ -157,20 +187,57 @@ impl<N: Network> Process<N> {
             bail!("The number of function calls in '{}/{}' should be 1", stack.program_id(), function.name())
         }
 
+        // Ensure the fee contains finalize inputs, if the function has a finalize scope (i.e. for a public fee).
+        if let Some((command, logic)) = function.finalize() {
+            // Ensure the fee contains finalize inputs.
+            match fee.finalize() {
+                Some(finalize) => {
+                    // Ensure the number of inputs for finalize matches in the finalize command.
+                    ensure!(
+                        finalize.len() == command.operands().len(),
+                        "The number of inputs for finalize is incorrect"
+                    );
+                    // Ensure the number of inputs for finalize matches in the finalize logic.
+                    ensure!(finalize.len() == logic.inputs().len(), "The number of inputs for finalize is incorrect");
+
+                    // Convert the finalize inputs into concatenated bits.
+                    let finalize_bits = finalize.iter().flat_map(ToBits::to_bits_le).collect::<Vec<_>>();
+                    // Compute the checksum of the finalize inputs.
+                    let checksum = N::hash_bhp1024(&finalize_bits)?;
+
+                    // Extend the inputs with the checksum of the inputs for finalize.
+                    inputs.push(*checksum);
+                }
+                None => bail!("The fee is missing inputs for 'finalize'"),
+            }
+        }
+        lap!(timer, "Construct the finalize inputs");
+
         #[cfg(debug_assertions)]
         println!("Fee public inputs ({} elements): {:#?}", inputs.len(), inputs);
 
-        // Ensure the fee contains input records.
-        ensure!(
-            fee.inputs().iter().any(|input| matches!(input, Input::Record(..))),
-            "The fee proof is the wrong type (found *no* input records)"
-        );
+        // Ensure the fee contains input records, unless it is paid from a public balance.
+        match fee.is_public() {
+            true => ensure!(
+                !fee.inputs().iter().any(|input| matches!(input, Input::Record(..))),
+                "The public fee proof is the wrong type (found input records)"
+            ),
+            false => ensure!(
+                fee.inputs().iter().any(|input| matches!(input, Input::Record(..))),
+                "The fee proof is the wrong type (found *no* input records)"
+            ),
+        }
 
+        // Retrieve the transition proof.
+        let proof = match fee.proof() {
+            Some(proof) => proof,
+            None => bail!("Fee is missing its transition proof"),
+        };
         // Retrieve the verifying key.
         let verifying_key = self.get_verifying_key(stack.program_id(), function.name())?;
         // Ensure the transition proof is valid.
         ensure!(
-            verifying_key.verify(function.name(), &inputs, fee.proof()),
+            verifying_key.verify(function.name(), &inputs, proof),
             "Fee is invalid - failed to verify transition proof"
         );
         lap!(timer, "Verify the transition proof");
@@ -47,6 +47,11 @@ use std::collections::HashMap;
 #[cfg(feature = "aleo-cli")]
 use colored::Colorize;
 
+/// The names of the 'credits.aleo' functions without published circuit keys.
+/// The circuit keys of these functions are synthesized deterministically on first use instead.
+pub(crate) const CREDITS_FUNCTIONS_WITHOUT_KEYS: [&str; 4] =
+    ["transfer_public", "transfer_private_to_public", "transfer_public_to_private", "fee_public"];
+
 #[derive(Clone)]
 pub struct Process<N: Network> {
     /// The universal SRS.
@@ -99,6 +104,34 @@ impl<N: Network> Process<N> {
         // Return success.
         Ok(())
     }
+
+    /// Adds the given program stack to the process.
+    /// This method assumes the stack was computed by a process with the same programs.
+    #[inline]
+    pub(crate) fn add_stack(&mut self, stack: Stack<N>) -> Result<()> {
+        // Ensure the program does not already exist.
+        ensure!(!self.contains_program(stack.program_id()), "Program '{}' already exists", stack.program_id());
+        // Add the stack to the process.
+        self.stacks.insert(*stack.program_id(), stack);
+        // Return success.
+        Ok(())
+    }
+
+    /// Removes the program stack for the given program ID from the process.
+    #[inline]
+    pub(crate) fn remove_stack(&mut self, program_id: &ProgramID<N>) -> Result<()> {
+        // Ensure no remaining program imports the program.
+        for stack in self.stacks.values() {
+            if stack.program().imports().contains_key(program_id) {
+                bail!("Program '{}' imports program '{program_id}', which cannot be removed", stack.program_id())
+            }
+        }
+        // Remove the stack from the process.
+        match self.stacks.shift_remove(program_id) {
+            Some(_) => Ok(()),
+            None => bail!("Program '{program_id}' does not exist"),
+        }
+    }
 }
 
 impl<N: Network> Process<N> {
@@ -119,8 +152,15 @@ impl<N: Network> Process<N> {
         let stack = Stack::new(&process, &program)?;
         lap!(timer, "Initialize stack");
 
-        // Synthesize the 'credits.aleo' circuit keys.
+        // Load the 'credits.aleo' circuit keys.
+        // Note: The circuit keys of functions without published parameters are not loaded,
+        // and are synthesized when they are first retrieved from the stack.
         for function_name in program.functions().keys() {
+            // Skip the functions without published parameters.
+            if CREDITS_FUNCTIONS_WITHOUT_KEYS.contains(&function_name.to_string().as_str()) {
+                continue;
+            }
+
             // Load the proving key.
             let proving_key = N::get_credits_proving_key(function_name.to_string())?;
             stack.insert_proving_key(function_name, ProvingKey::new(proving_key.clone()))?;
@@ -149,6 +189,26 @@ impl<N: Network> Process<N> {
         Ok(process)
     }
 
+    /// Initializes a new process, without loading the circuit keys.
+    /// This version is suitable for the web, where the process is used to authorize requests,
+    /// and the circuit keys are fetched separately if the function is executed.
+    #[cfg(feature = "wasm")]
+    #[inline]
+    pub fn load_web() -> Result<Self> {
+        // Initialize the process.
+        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+
+        // Initialize the 'credits.aleo' program.
+        let program = Program::credits()?;
+        // Compute the 'credits.aleo' program stack.
+        let stack = Stack::new(&process, &program)?;
+
+        // Add the stack to the process.
+        process.stacks.insert(*program.id(), stack);
+        // Return the process.
+        Ok(process)
+    }
+
     /// Initializes a new process with a cache of previously used keys. This version is suitable for tests
     /// (which often use nested loops that keep reusing those), as their deserialization is slow.
     #[cfg(test)]
@@ -162,8 +222,12 @@ impl<N: Network> Process<N> {
         // Compute the 'credits.aleo' program stack.
         let stack = Stack::new(&process, &program)?;
 
-        // Synthesize the 'credits.aleo' circuit keys.
+        // Load the 'credits.aleo' circuit keys.
         for function_name in program.functions().keys() {
+            // Skip the functions without published parameters.
+            if CREDITS_FUNCTIONS_WITHOUT_KEYS.contains(&function_name.to_string().as_str()) {
+                continue;
+            }
             // Cache the proving and verifying key.
             let (proving_key, verifying_key) = cache.entry(function_name.to_string()).or_insert_with(|| {
                 // Load the proving key.
@@ -331,6 +395,48 @@ function compute:
             .clone()
     }
 
+    pub(crate) fn sample_authorization() -> Authorization<CurrentNetwork> {
+        static INSTANCE: OnceCell<Authorization<CurrentNetwork>> = OnceCell::new();
+        INSTANCE
+            .get_or_init(|| {
+                // Initialize a new program.
+                let (string, program) = Program::<CurrentNetwork>::parse(
+                    r"
+program testing.aleo;
+
+function compute:
+    input r0 as u32.private;
+    input r1 as u32.public;
+    add r0 r1 into r2;
+    output r2 as u32.public;",
+                )
+                .unwrap();
+                assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+                // Initialize the RNG.
+                let rng = &mut TestRng::default();
+                // Initialize a new caller account.
+                let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+                // Construct the process.
+                let process = sample_process(&program);
+                // Authorize the function call.
+                let authorization = process
+                    .authorize::<CurrentAleo, _>(
+                        &caller_private_key,
+                        program.id(),
+                        "compute",
+                        ["5u32", "10u32"].into_iter(),
+                        rng,
+                    )
+                    .unwrap();
+                assert_eq!(authorization.len(), 1);
+                // Return the authorization.
+                authorization
+            })
+            .clone()
+    }
+
     pub(crate) fn sample_execution() -> Execution<CurrentNetwork> {
         static INSTANCE: OnceCell<Execution<CurrentNetwork>> = OnceCell::new();
         INSTANCE
@@ -1239,7 +1345,12 @@ finalize compute:
         process.verify_execution::<true>(&execution).unwrap();
 
         // Now, finalize the execution.
-        process.finalize_execution(&store, &execution).unwrap();
+        let metrics = process.finalize_execution(&store, &execution).unwrap();
+
+        // Ensure the metrics include the entries written by both 'get.or_init' and 'set'.
+        let num_bytes = Plaintext::from(Literal::Address(caller)).to_bytes_le().unwrap().len()
+            + Plaintext::<CurrentNetwork>::from_str("8u64").unwrap().to_bytes_le().unwrap().len();
+        assert_eq!(metrics, FinalizeMetrics { num_commands: 3, num_bytes: 2 * num_bytes as u64 });
 
         // Check that the account balance is now 8.
         let candidate =
@@ -1247,6 +1358,203 @@ finalize compute:
         assert_eq!(candidate, Value::from_str("8u64").unwrap());
     }
 
+    #[test]
+    fn test_process_execute_and_finalize_branch() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    input r2 as u64.public;
+    add r1 r2 into r3;
+    finalize r0 r3;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    contains account[r0] into r2;
+    branch.eq r2 true to increment;
+    set r1 into account[r0];
+    branch.eq true true to end;
+    position increment;
+    get account[r0] into r3;
+    add r3 r1 into r4;
+    set r4 into account[r0];
+    position end;
+",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the program ID.
+        let program_id = program.id();
+        // Declare the mapping.
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+
+        // Initialize the RNG.
+        let rng = &mut TestRng::default();
+
+        // Construct the process.
+        let mut process = Process::load().unwrap();
+
+        // Initialize a new program store.
+        let store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+
+        // Add the program to the process.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        // Check that the deployment verifies.
+        process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        // Finalize the deployment.
+        process.finalize_deployment(&store, &deployment).unwrap();
+
+        // Initialize a new caller account.
+        let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let caller = Address::try_from(&caller_private_key).unwrap();
+
+        // Declare the input values.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap(),
+            Value::<CurrentNetwork>::from_str("3u64").unwrap(),
+            Value::<CurrentNetwork>::from_str("5u64").unwrap(),
+        ];
+
+        // Compute the number of bytes of an account entry.
+        let num_bytes = Plaintext::from(Literal::Address(caller)).to_bytes_le().unwrap().len()
+            + Plaintext::<CurrentNetwork>::from_str("8u64").unwrap().to_bytes_le().unwrap().len();
+
+        // The first execution initializes the account, and the second execution increments it.
+        // Note: The commands skipped by a branch are not evaluated, and are excluded from the metrics.
+        for (expected, num_commands) in [("8u64", 4), ("16u64", 5)] {
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program_id, function_name, inputs.iter(), rng)
+                .unwrap();
+
+            // Execute the request.
+            let (_response, execution, _inclusion, _metrics) =
+                process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+
+            // Verify the execution.
+            process.verify_execution::<true>(&execution).unwrap();
+
+            // Now, finalize the execution.
+            let metrics = process.finalize_execution(&store, &execution).unwrap();
+            assert_eq!(metrics, FinalizeMetrics { num_commands, num_bytes: num_bytes as u64 });
+
+            // Check the account balance.
+            let candidate = store
+                .get_value(program_id, &mapping_name, &Plaintext::from(Literal::Address(caller)))
+                .unwrap()
+                .unwrap();
+            assert_eq!(candidate, Value::from_str(expected).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_process_finalize_branch_is_forward_only() {
+        // Initialize a program, with the given finalize commands.
+        let sample_program = |commands: &str| {
+            Program::<CurrentNetwork>::from_str(&format!(
+                r"
+program testing.aleo;
+
+function compute:
+    input r0 as u64.public;
+    finalize r0;
+
+finalize compute:
+    input r0 as u64.public;
+{commands}"
+            ))
+            .unwrap()
+        };
+
+        // Ensure a forward branch is allowed.
+        let program = sample_program("    branch.neq r0 0u64 to end;\n    add r0 1u64 into r1;\n    position end;");
+        Process::load().unwrap().add_program(&program).unwrap();
+
+        // Ensure a backward branch is rejected.
+        let program = sample_program("    position start;\n    add r0 1u64 into r1;\n    branch.neq r0 0u64 to start;");
+        assert!(Process::load().unwrap().add_program(&program).is_err());
+
+        // Ensure a branch to an undefined position is rejected.
+        let program = sample_program("    branch.neq r0 0u64 to end;\n    add r0 1u64 into r1;");
+        assert!(Process::load().unwrap().add_program(&program).is_err());
+
+        // Ensure a branch with operands of different types is rejected.
+        let program = sample_program("    branch.neq r0 0u32 to end;\n    add r0 1u64 into r1;\n    position end;");
+        assert!(Process::load().unwrap().add_program(&program).is_err());
+
+        // Ensure a register that is skipped by a branch cannot be read after its position.
+        let program = sample_program(
+            "    branch.neq r0 0u64 to end;\n    add r0 1u64 into r1;\n    position end;\n    add r1 1u64 into r2;",
+        );
+        assert!(Process::load().unwrap().add_program(&program).is_err());
+
+        // Ensure a register that is assigned before a branch can be read after its position.
+        let program = sample_program(
+            "    add r0 1u64 into r1;\n    branch.neq r0 0u64 to end;\n    add r1 1u64 into r2;\n    position end;\n    add r1 1u64 into r3;",
+        );
+        Process::load().unwrap().add_program(&program).unwrap();
+    }
+
+    #[test]
+    fn test_process_finalize_contains_and_remove_are_type_checked() {
+        // Initialize a program, with the given finalize commands.
+        let sample_program = |commands: &str| {
+            Program::<CurrentNetwork>::from_str(&format!(
+                r"
+program testing.aleo;
+
+mapping account:
+    key owner as address.public;
+    value amount as u64.public;
+
+function compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u64.public;
+{commands}"
+            ))
+            .unwrap()
+        };
+
+        // Ensure well-formed `contains` and `remove` commands are allowed.
+        let program = sample_program("    contains account[r0] into r2;\n    remove account[r0];");
+        Process::load().unwrap().add_program(&program).unwrap();
+
+        // Ensure an unknown mapping is rejected.
+        let program = sample_program("    contains balances[r0] into r2;");
+        assert!(Process::load().unwrap().add_program(&program).is_err());
+        let program = sample_program("    remove balances[r0];");
+        assert!(Process::load().unwrap().add_program(&program).is_err());
+
+        // Ensure a key of the wrong type is rejected.
+        let program = sample_program("    contains account[r1] into r2;");
+        assert!(Process::load().unwrap().add_program(&program).is_err());
+        let program = sample_program("    remove account[r1];");
+        assert!(Process::load().unwrap().add_program(&program).is_err());
+
+        // Ensure the destination of `contains` is a boolean.
+        let program = sample_program("    contains account[r0] into r2;\n    and r2 true into r3;");
+        Process::load().unwrap().add_program(&program).unwrap();
+        let program = sample_program("    contains account[r0] into r2;\n    add r2 r1 into r3;");
+        assert!(Process::load().unwrap().add_program(&program).is_err());
+    }
+
     #[test]
     fn test_process_execute_and_finalize_increment_decrement_via_get_set() {
         // Initialize a new program.
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> FromBytes for Authorization<N> {
+    /// Reads the authorization from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 0 {
+            return Err(error("Invalid authorization version"));
+        }
+        // Read the number of requests.
+        let num_requests = u8::read_le(&mut reader)?;
+        // Read the requests.
+        let requests = (0..num_requests).map(|_| Request::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Return the new `Authorization` instance.
+        Ok(Self::new(&requests))
+    }
+}
+
+impl<N: Network> ToBytes for Authorization<N> {
+    /// Writes the authorization to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Retrieve the requests.
+        let requests = self.requests.read();
+        // Write the version.
+        0u8.write_le(&mut writer)?;
+        // Write the number of requests.
+        (u8::try_from(requests.len()).map_err(|e| error(e.to_string()))?).write_le(&mut writer)?;
+        // Write the requests.
+        for request in requests.iter() {
+            request.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() -> Result<()> {
+        // Construct a new authorization.
+        let expected = crate::process::test_helpers::sample_authorization();
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le()?;
+        assert_eq!(expected, Authorization::read_le(&expected_bytes[..])?);
+        assert!(Authorization::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        Ok(())
+    }
+}
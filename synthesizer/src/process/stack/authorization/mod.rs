@@ -14,6 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod bytes;
+mod serialize;
+mod string;
+
 use console::{network::prelude::*, program::Request};
 
 use parking_lot::RwLock;
@@ -71,3 +75,12 @@ impl<N: Network> Authorization<N> {
         self.requests.read().clone()
     }
 }
+
+impl<N: Network> PartialEq for Authorization<N> {
+    /// Returns `true` if the authorizations contain the same requests, in the same order.
+    fn eq(&self, other: &Self) -> bool {
+        *self.requests.read() == *other.requests.read()
+    }
+}
+
+impl<N: Network> Eq for Authorization<N> {}
@@ -348,6 +348,13 @@ impl<N: Network> Stack<N> {
                                 function.name()
                             );
                         }
+                        circuit::Value::Plaintext(circuit::Plaintext::Array(..)) => {
+                            bail!(
+                                "'{}/{}' attempts to pass an 'array' into 'finalize'",
+                                self.program_id(),
+                                function.name()
+                            );
+                        }
                         circuit::Value::Record(..) => {
                             bail!(
                                 "'{}/{}' attempts to pass a 'record' into 'finalize'",
@@ -435,10 +442,15 @@ impl<N: Network> Stack<N> {
 
             // Retrieve the proving key.
             let proving_key = self.get_proving_key(function.name())?;
-            // Execute the circuit.
-            let proof = match proving_key.prove(function.name(), &assignment, rng) {
-                Ok(proof) => proof,
-                Err(error) => bail!("Execution proof failed - {error}"),
+            // Determine if the transition proof is deferred to the batch proof of the execution.
+            let is_batch = inclusion.read().is_batch();
+            // Execute the circuit, if the transition proof is not deferred.
+            let proof = match is_batch {
+                true => None,
+                false => match proving_key.prove(function.name(), &assignment, rng) {
+                    Ok(proof) => Some(proof),
+                    Err(error) => bail!("Execution proof failed - {error}"),
+                },
             };
             lap!(timer, "Execute the circuit");
 
@@ -448,6 +460,10 @@ impl<N: Network> Stack<N> {
 
             // Add the transition commitments.
             inclusion.write().insert_transition(console_request.input_ids(), &transition)?;
+            // Add the transition assignment, if the transition proof is deferred.
+            if is_batch {
+                inclusion.write().insert_transition_assignment(&transition, proving_key, assignment)?;
+            }
             // Add the transition to the execution.
             execution.write().push(transition);
 
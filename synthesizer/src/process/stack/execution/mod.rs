@@ -29,7 +29,7 @@ pub struct Execution<N: Network> {
     transitions: IndexMap<N::TransitionID, Transition<N>>,
     /// The global state root.
     global_state_root: N::StateRoot,
-    /// The inclusion proof.
+    /// The inclusion proof, or the batch proof of the transitions and inclusion, if the transition proofs are deferred.
     inclusion_proof: Option<Proof<N>>,
 }
 
@@ -59,10 +59,15 @@ impl<N: Network> Execution<N> {
         self.global_state_root
     }
 
-    /// Returns the inclusion proof.
+    /// Returns the inclusion proof, or the batch proof of the transitions and inclusion, if the transition proofs are deferred.
     pub const fn inclusion_proof(&self) -> Option<&Proof<N>> {
         self.inclusion_proof.as_ref()
     }
+
+    /// Returns `true` if the transition proofs are deferred to the batch proof of the execution.
+    pub fn is_batch(&self) -> bool {
+        !self.transitions.is_empty() && self.transitions.values().all(|transition| transition.proof().is_none())
+    }
 }
 
 impl<N: Network> Execution<N> {
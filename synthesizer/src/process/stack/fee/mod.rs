@@ -21,7 +21,7 @@ mod string;
 use crate::{snark::Proof, Input, Transition};
 use console::{
     network::prelude::*,
-    program::{Literal, Plaintext},
+    program::{Identifier, Literal, Plaintext},
     types::U64,
 };
 
@@ -67,10 +67,24 @@ impl<N: Network> Fee<N> {
         self.inclusion_proof.as_ref()
     }
 
+    /// Returns `true` if the fee is paid from a public balance, instead of a `credits` record.
+    pub fn is_public(&self) -> bool {
+        match Identifier::from_str("fee_public") {
+            Ok(fee_public) => *self.transition.function_name() == fee_public,
+            Err(_) => false,
+        }
+    }
+
     /// Returns the amount (in microcredits).
     pub fn amount(&self) -> Result<U64<N>> {
+        // Retrieve the index of the amount (in microcredits), based on the function of the fee.
+        let index = match self.transition.function_name() {
+            function_name if *function_name == Identifier::from_str("fee")? => 1,
+            function_name if *function_name == Identifier::from_str("fee_public")? => 0,
+            function_name => bail!("Failed to retrieve the fee (in microcredits) from '{function_name}'"),
+        };
         // Retrieve the amount (in microcredits) as a plaintext value.
-        match self.transition.inputs().get(1) {
+        match self.transition.inputs().get(index) {
             Some(Input::Public(_, Some(Plaintext::Literal(Literal::U64(microcredits), _)))) => Ok(*microcredits),
             _ => bail!("Failed to retrieve the fee (in microcredits) from the fee transition"),
         }
@@ -20,7 +20,7 @@ impl<N: Network> Store<N> for FinalizeRegisters<N> {
     /// Assigns the given value to the given register, assuming the register is not already assigned.
     ///
     /// # Errors
-    /// This method will halt if the given register is a register member.
+    /// This method will halt if the given register is a register access.
     /// This method will halt if the given register is an input register.
     /// This method will halt if the register is already used.
     #[inline]
@@ -56,7 +56,7 @@ impl<N: Network> Store<N> for FinalizeRegisters<N> {
                 }
             }
             // Ensure the register is not a register member.
-            Register::Member(..) => bail!("Cannot store to a register member: '{register}'"),
+            Register::Access(..) => bail!("Cannot store to a register access: '{register}'"),
         }
     }
 }
@@ -16,7 +16,9 @@
 
 use super::*;
 
-use crate::finalize::{Get, GetOrInit, Set};
+use crate::finalize::{Branch, Contains, Get, GetOrInit, Remove, Set};
+
+use indexmap::IndexSet;
 
 impl<N: Network> FinalizeTypes<N> {
     /// Initializes a new instance of `FinalizeTypes` for the given finalize.
@@ -38,8 +40,92 @@ impl<N: Network> FinalizeTypes<N> {
             finalize_types.check_command(stack, finalize.name(), command)?;
         }
 
+        // Step 3. Check the branches are well-formed.
+        for (index, command) in finalize.commands().iter().enumerate() {
+            let position = match command {
+                Command::BranchEq(branch_eq) => branch_eq.position(),
+                Command::BranchNeq(branch_neq) => branch_neq.position(),
+                _ => continue,
+            };
+            // Ensure the position is defined, and is after the branch.
+            // Note: Forward-only branches guarantee that finalize terminates.
+            match finalize.positions().get(position) {
+                Some(position_index) => ensure!(
+                    *position_index > index,
+                    "Branch to position '{position}' in '{}/{}' must jump forward.",
+                    stack.program_id(),
+                    finalize.name()
+                ),
+                None => bail!("Position '{position}' in '{}/{}' is not defined.", stack.program_id(), finalize.name()),
+            }
+        }
+
+        // Step 4. Check the registers are assigned on every path to the commands that read them.
+        Self::check_register_assignments(stack, finalize)?;
+
         Ok(finalize_types)
     }
+
+    /// Ensures each register that is read by a command is assigned on every path to the command.
+    /// Note: As branches only jump forward, the registers assigned on every path are found in a single pass.
+    fn check_register_assignments(stack: &Stack<N>, finalize: &Finalize<N>) -> Result<()> {
+        // Initialize the registers assigned on every path to the current command, starting with the inputs.
+        let mut assigned = (0..finalize.inputs().len() as u64).collect::<IndexSet<_>>();
+        // Initialize the registers assigned on every branch to a position, by the index of the position.
+        let mut branches = IndexMap::<usize, IndexSet<u64>>::new();
+
+        for (index, command) in finalize.commands().iter().enumerate() {
+            // Retain the registers that are also assigned on every branch to this command.
+            if let Some(branched) = branches.remove(&index) {
+                assigned.retain(|locator| branched.contains(locator));
+            }
+
+            // Retrieve the operands and destinations of the command.
+            let (operands, destinations) = match command {
+                Command::Instruction(instruction) => (instruction.operands().to_vec(), instruction.destinations()),
+                Command::Contains(contains) => (contains.operands(), vec![contains.destination().clone()]),
+                Command::Get(get) => (get.operands(), vec![get.destination().clone()]),
+                Command::GetOrInit(get_or_init) => (get_or_init.operands(), vec![get_or_init.destination().clone()]),
+                Command::Set(set) => (set.operands(), vec![]),
+                Command::Remove(remove) => (remove.operands(), vec![]),
+                Command::BranchEq(branch_eq) => (branch_eq.operands().to_vec(), vec![]),
+                Command::BranchNeq(branch_neq) => (branch_neq.operands().to_vec(), vec![]),
+                Command::Position(_) => (vec![], vec![]),
+            };
+
+            // Ensure each register that is read by the command is assigned.
+            for operand in &operands {
+                if let Operand::Register(register) = operand {
+                    if !assigned.contains(&register.locator()) {
+                        bail!(
+                            "Register '{register}' in '{}/{}' is not assigned on every path to '{command}'.",
+                            stack.program_id(),
+                            finalize.name()
+                        )
+                    }
+                }
+            }
+
+            // Record the registers assigned on the branch to its position.
+            let position = match command {
+                Command::BranchEq(branch_eq) => Some(branch_eq.position()),
+                Command::BranchNeq(branch_neq) => Some(branch_neq.position()),
+                _ => None,
+            };
+            if let Some(position) = position.and_then(|position| finalize.positions().get(position)) {
+                match branches.get_mut(position) {
+                    Some(branched) => branched.retain(|locator| assigned.contains(locator)),
+                    None => {
+                        branches.insert(*position, assigned.clone());
+                    }
+                }
+            }
+
+            // Add the destinations of the command to the assigned registers.
+            assigned.extend(destinations.iter().map(|destination| destination.locator()));
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network> FinalizeTypes<N> {
@@ -63,8 +149,8 @@ impl<N: Network> FinalizeTypes<N> {
                     None => Ok(()),
                 }
             }
-            // Ensure the register is a locator, and not a member.
-            Register::Member(..) => bail!("Register '{register}' must be a locator."),
+            // Ensure the register is a locator, and not an access.
+            Register::Access(..) => bail!("Register '{register}' must be a locator."),
         }
     }
 
@@ -86,8 +172,8 @@ impl<N: Network> FinalizeTypes<N> {
                     None => Ok(()),
                 }
             }
-            // Ensure the register is a locator, and not a member.
-            Register::Member(..) => bail!("Register '{register}' must be a locator."),
+            // Ensure the register is a locator, and not an access.
+            Register::Access(..) => bail!("Register '{register}' must be a locator."),
         }
     }
 }
@@ -110,10 +196,18 @@ impl<N: Network> FinalizeTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            PlaintextType::Array(array_type) => {
+                // Ensure the base element type is defined in the program.
+                if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
+                    if !stack.program().contains_struct(struct_name) {
+                        bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+                    }
+                }
+            }
         };
 
         // Insert the input register.
-        self.add_input(register.clone(), *plaintext_type)?;
+        self.add_input(register.clone(), plaintext_type.clone())?;
 
         // Ensure the register type and the input type match.
         if *plaintext_type != self.get_type(stack, register)? {
@@ -130,6 +224,12 @@ impl<N: Network> FinalizeTypes<N> {
             Command::Get(get) => self.check_get(stack, finalize_name, get)?,
             Command::GetOrInit(get_or_init) => self.check_get_or_init(stack, finalize_name, get_or_init)?,
             Command::Set(set) => self.check_set(stack, finalize_name, set)?,
+            Command::Contains(contains) => self.check_contains(stack, finalize_name, contains)?,
+            Command::Remove(remove) => self.check_remove(stack, finalize_name, remove)?,
+            Command::BranchEq(branch_eq) => self.check_branch(stack, branch_eq)?,
+            Command::BranchNeq(branch_neq) => self.check_branch(stack, branch_neq)?,
+            // A position is checked when the branches are checked.
+            Command::Position(_) => (),
         }
         Ok(())
     }
@@ -159,7 +259,7 @@ impl<N: Network> FinalizeTypes<N> {
         // Ensure the destination register is a locator (and does not reference a member).
         ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
         // Insert the destination register.
-        self.add_destination(destination, *mapping_value_type)?;
+        self.add_destination(destination, mapping_value_type.clone())?;
         Ok(())
     }
 
@@ -207,7 +307,7 @@ impl<N: Network> FinalizeTypes<N> {
         // Ensure the destination register is a locator (and does not reference a member).
         ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
         // Insert the destination register.
-        self.add_destination(destination, *mapping_value_type)?;
+        self.add_destination(destination, mapping_value_type.clone())?;
         Ok(())
     }
 
@@ -242,6 +342,78 @@ impl<N: Network> FinalizeTypes<N> {
         Ok(())
     }
 
+    /// Ensures the given `contains` command is well-formed.
+    #[inline]
+    fn check_contains(
+        &mut self,
+        stack: &Stack<N>,
+        finalize_name: &Identifier<N>,
+        contains: &Contains<N>,
+    ) -> Result<()> {
+        // Ensure the declared mapping in `contains` is defined in the program.
+        if !stack.program().contains_mapping(contains.mapping_name()) {
+            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", contains.mapping_name(), stack.program_id())
+        }
+        // Retrieve the mapping from the program.
+        // Note that the unwrap is safe, as we have already checked the mapping exists.
+        let mapping = stack.program().get_mapping(contains.mapping_name()).unwrap();
+        // Get the mapping key type.
+        let mapping_key_type = mapping.key().plaintext_type();
+        // Retrieve the register type of the key.
+        let key_type = self.get_type_from_operand(stack, contains.key())?;
+        // Check that the key type in the mapping matches the key type in the instruction.
+        if *mapping_key_type != key_type {
+            bail!(
+                "Key type in `contains` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'."
+            )
+        }
+        // Get the destination register.
+        let destination = contains.destination().clone();
+        // Ensure the destination register is a locator (and does not reference a member).
+        ensure!(matches!(destination, Register::Locator(..)), "Destination '{destination}' must be a locator.");
+        // Insert the destination register.
+        self.add_destination(destination, PlaintextType::Literal(LiteralType::Boolean))?;
+        Ok(())
+    }
+
+    /// Ensures the given `remove` command is well-formed.
+    #[inline]
+    fn check_remove(&self, stack: &Stack<N>, finalize_name: &Identifier<N>, remove: &Remove<N>) -> Result<()> {
+        // Ensure the declared mapping in `remove` is defined in the program.
+        if !stack.program().contains_mapping(remove.mapping_name()) {
+            bail!("Mapping '{}' in '{}/{finalize_name}' is not defined.", remove.mapping_name(), stack.program_id())
+        }
+        // Retrieve the mapping from the program.
+        // Note that the unwrap is safe, as we have already checked the mapping exists.
+        let mapping = stack.program().get_mapping(remove.mapping_name()).unwrap();
+        // Get the mapping key type.
+        let mapping_key_type = mapping.key().plaintext_type();
+        // Retrieve the register type of the key.
+        let key_type = self.get_type_from_operand(stack, remove.key())?;
+        // Check that the key type in the mapping matches the key type.
+        if *mapping_key_type != key_type {
+            bail!("Key type in `remove` '{key_type}' does not match the key type in the mapping '{mapping_key_type}'.")
+        }
+        Ok(())
+    }
+
+    /// Ensures the given `branch` command is well-formed.
+    #[inline]
+    fn check_branch<const VARIANT: u8>(&self, stack: &Stack<N>, branch: &Branch<N, VARIANT>) -> Result<()> {
+        // Retrieve the type of the first operand.
+        let first_type = self.get_type_from_operand(stack, branch.first())?;
+        // Retrieve the type of the second operand.
+        let second_type = self.get_type_from_operand(stack, branch.second())?;
+        // Check that the operands are of the same type.
+        if first_type != second_type {
+            bail!(
+                "Command '{}' expects operands of the same type. Found operands of type '{first_type}' and '{second_type}'.",
+                Branch::<N, VARIANT>::opcode()
+            )
+        }
+        Ok(())
+    }
+
     /// Ensures the given instruction is well-formed.
     #[inline]
     fn check_instruction(
@@ -351,6 +523,16 @@ impl<N: Network> FinalizeTypes<N> {
                         // Ensure the operand types match the struct.
                         self.matches_struct(stack, instruction.operands(), &struct_)?;
                     }
+                    RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                        // Ensure the base element type is defined in the program.
+                        if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
+                            if !stack.program().contains_struct(struct_name) {
+                                bail!("Struct '{struct_name}' is not defined.")
+                            }
+                        }
+                        // Ensure the operand types match the array type.
+                        self.matches_array(stack, instruction.operands(), array_type)?;
+                    }
                     RegisterType::Record(..) => {
                         bail!("Illegal operation: Cannot cast to a record.")
                     }
@@ -484,6 +666,20 @@ impl<N: Network> FinalizeTypes<N> {
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
+            Opcode::Sign(opcode) => {
+                // Ensure the instruction belongs to the defined set.
+                if !["sign.verify"].contains(&opcode) {
+                    bail!("Instruction '{instruction}' is not for opcode '{opcode}'.");
+                }
+                // Ensure the instruction is the correct one.
+                match opcode {
+                    "sign.verify" => ensure!(
+                        matches!(instruction, Instruction::SignVerify(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+                }
+            }
         }
         Ok(())
     }
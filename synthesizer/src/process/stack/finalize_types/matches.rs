@@ -66,7 +66,7 @@ impl<N: Network> FinalizeTypes<N> {
                     let program_ref_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
                     // Ensure the program ID type matches the member type.
                     ensure!(
-                        program_ref_type == RegisterType::Plaintext(*member_type),
+                        program_ref_type == RegisterType::Plaintext(member_type.clone()),
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{program_ref_type}' in the operand '{operand}'.",
                     )
                 }
@@ -76,7 +76,7 @@ impl<N: Network> FinalizeTypes<N> {
                     let caller_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
                     // Ensure the caller type matches the member type.
                     ensure!(
-                        caller_type == RegisterType::Plaintext(*member_type),
+                        caller_type == RegisterType::Plaintext(member_type.clone()),
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{caller_type}' in the operand '{operand}'.",
                     )
                 }
@@ -84,4 +84,66 @@ impl<N: Network> FinalizeTypes<N> {
         }
         Ok(())
     }
+
+    /// Checks that the given operands matches the layout of the array. The ordering of the operands matters.
+    pub fn matches_array(&self, stack: &Stack<N>, operands: &[Operand<N>], array_type: &ArrayType<N>) -> Result<()> {
+        // Ensure the number of array elements does not exceed the maximum.
+        if operands.len() > N::MAX_DATA_ENTRIES {
+            bail!("'{array_type}' cannot exceed {} elements", N::MAX_DATA_ENTRIES)
+        }
+
+        // Ensure the number of array elements match.
+        let num_elements = operands.len();
+        let expected_num_elements = **array_type.length() as usize;
+        if expected_num_elements != num_elements {
+            bail!("'{array_type}' expected {expected_num_elements} elements, found {num_elements} elements")
+        }
+
+        // Retrieve the element type.
+        let element_type = array_type.element_type();
+
+        // Ensure the operand types match the element type.
+        for (index, operand) in operands.iter().enumerate() {
+            match operand {
+                // Ensure the literal type matches the element type.
+                Operand::Literal(literal) => {
+                    ensure!(
+                        PlaintextType::Literal(literal.to_type()) == *element_type,
+                        "Array element '{index}' expects a {element_type}, but found '{operand}' in the operand.",
+                    )
+                }
+                // Ensure the type of the register matches the element type.
+                Operand::Register(register) => {
+                    // Retrieve the type.
+                    let plaintext_type = self.get_type(stack, register)?;
+                    // Ensure the register type matches the element type.
+                    ensure!(
+                        plaintext_type == *element_type,
+                        "Array element '{index}' expects {element_type}, but found '{plaintext_type}' in the operand '{operand}'.",
+                    )
+                }
+                // Ensure the program ID type (address) matches the element type.
+                Operand::ProgramID(..) => {
+                    // Retrieve the program ID type.
+                    let program_ref_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
+                    // Ensure the program ID type matches the element type.
+                    ensure!(
+                        program_ref_type == RegisterType::Plaintext(element_type.clone()),
+                        "Array element '{index}' expects {element_type}, but found '{program_ref_type}' in the operand '{operand}'.",
+                    )
+                }
+                // Ensure the caller type (address) matches the element type.
+                Operand::Caller => {
+                    // Retrieve the caller type.
+                    let caller_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
+                    // Ensure the caller type matches the element type.
+                    ensure!(
+                        caller_type == RegisterType::Plaintext(element_type.clone()),
+                        "Array element '{index}' expects {element_type}, but found '{caller_type}' in the operand '{operand}'.",
+                    )
+                }
+            }
+        }
+        Ok(())
+    }
 }
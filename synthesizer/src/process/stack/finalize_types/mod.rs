@@ -27,7 +27,7 @@ use crate::{
 };
 use console::{
     network::prelude::*,
-    program::{Identifier, LiteralType, PlaintextType, Register, RegisterType, Struct},
+    program::{Access, ArrayType, Identifier, LiteralType, PlaintextType, Register, RegisterType, Struct},
 };
 
 use indexmap::IndexMap;
@@ -79,43 +79,58 @@ impl<N: Network> FinalizeTypes<N> {
         // Initialize a tracker for the type of the register.
         let mut plaintext_type = if self.is_input(register) {
             // Retrieve the input value type as a register type.
-            *self.inputs.get(&register.locator()).ok_or_else(|| anyhow!("Register '{register}' does not exist"))?
+            self.inputs.get(&register.locator()).ok_or_else(|| anyhow!("Register '{register}' does not exist"))?.clone()
         } else {
             // Retrieve the destination register type.
-            *self
-                .destinations
+            self.destinations
                 .get(&register.locator())
                 .ok_or_else(|| anyhow!("Register '{register}' does not exist"))?
+                .clone()
         };
 
-        // Retrieve the member path if the register is a member. Otherwise, return the type.
+        // Retrieve the access path if the register is an access. Otherwise, return the type.
         let path = match &register {
             // If the register is a locator, then output the register type.
             Register::Locator(..) => return Ok(plaintext_type),
-            // If the register is a member, then traverse the member path to output the register type.
-            Register::Member(_, path) => {
-                // Ensure the member path is valid.
-                ensure!(!path.is_empty(), "Register '{register}' references no members.");
-                // Output the member path.
+            // If the register is an access, then traverse the access path to output the register type.
+            Register::Access(_, path) => {
+                // Ensure the access path is valid.
+                ensure!(!path.is_empty(), "Register '{register}' references no accesses.");
+                // Output the access path.
                 path
             }
         };
 
-        // Traverse the member path to find the register type.
-        for path_name in path.iter() {
+        // Traverse the access path to find the register type.
+        for access in path.iter() {
             // Update the register type at each step.
-            plaintext_type = match &plaintext_type {
-                // Ensure the plaintext type is not a literal, as the register references a member.
-                PlaintextType::Literal(..) => bail!("'{register}' references a literal."),
+            plaintext_type = match (&plaintext_type, access) {
+                // Ensure the plaintext type is not a literal, as the register references a member or an element.
+                (PlaintextType::Literal(..), _) => bail!("'{register}' references a literal."),
                 // Traverse the member path to output the register type.
-                PlaintextType::Struct(struct_name) => {
+                (PlaintextType::Struct(struct_name), Access::Member(path_name)) => {
                     // Retrieve the member type from the struct.
                     match stack.program().get_struct(struct_name)?.members().get(path_name) {
                         // Update the member type.
-                        Some(plaintext_type) => *plaintext_type,
+                        Some(plaintext_type) => plaintext_type.clone(),
                         None => bail!("'{path_name}' does not exist in struct '{struct_name}'"),
                     }
                 }
+                // Ensure the struct is not indexed.
+                (PlaintextType::Struct(struct_name), Access::Index(index)) => {
+                    bail!("'{register}' references index '{index}' of struct '{struct_name}'")
+                }
+                // Traverse the array to output the register type.
+                (PlaintextType::Array(array_type), Access::Index(index)) => {
+                    // Ensure the index is within the bounds of the array.
+                    ensure!(**index < **array_type.length(), "Index '{index}' is out of bounds for '{array_type}'");
+                    // Update the element type.
+                    array_type.element_type().clone()
+                }
+                // Ensure the array is not accessed by member.
+                (PlaintextType::Array(array_type), Access::Member(path_name)) => {
+                    bail!("'{register}' references member '{path_name}' of array '{array_type}'")
+                }
             }
         }
         // Output the member type.
@@ -15,8 +15,48 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
+use crate::process::CREDITS_FUNCTIONS_WITHOUT_KEYS;
+
+use rand::SeedableRng;
 
 impl<N: Network> Stack<N> {
+    /// Returns `true` if the given function is a 'credits.aleo' function without published circuit keys.
+    #[inline]
+    pub(crate) fn is_credits_function_without_keys(&self, function_name: &Identifier<N>) -> Result<bool> {
+        Ok(*self.program_id() == ProgramID::from_str("credits.aleo")?
+            && CREDITS_FUNCTIONS_WITHOUT_KEYS.contains(&function_name.to_string().as_str()))
+    }
+
+    /// Synthesizes the proving key and verifying key for the given 'credits.aleo' function without published keys.
+    ///
+    /// Note: The RNG only samples the burner inputs of the synthesized circuit. It is seeded
+    /// with a fixed value, to ensure every process synthesizes the same circuit keys.
+    #[inline]
+    pub(crate) fn synthesize_credits_key(&self, function_name: &Identifier<N>) -> Result<()> {
+        // Ensure the function is a 'credits.aleo' function without published keys.
+        ensure!(
+            self.is_credits_function_without_keys(function_name)?,
+            "Function '{}/{function_name}' has published circuit keys",
+            self.program_id()
+        );
+        // Initialize the RNG.
+        let rng = &mut rand_chacha::ChaChaRng::seed_from_u64(0);
+        // Synthesize the circuit keys, with the circuit environment of the network.
+        match N::ID {
+            console::network::Testnet3::ID => {
+                // Cast the stack and the function name.
+                let stack = (self as &dyn std::any::Any)
+                    .downcast_ref::<Stack<console::network::Testnet3>>()
+                    .ok_or_else(|| anyhow!("Failed to downcast the stack of '{}'", self.program_id()))?;
+                let function_name = (function_name as &dyn std::any::Any)
+                    .downcast_ref::<Identifier<console::network::Testnet3>>()
+                    .ok_or_else(|| anyhow!("Failed to downcast the function name '{function_name}'"))?;
+                stack.synthesize_key::<circuit::AleoV0, _>(function_name, rng)
+            }
+            _ => bail!("Unsupported network for synthesizing the circuit keys: {}", N::ID),
+        }
+    }
+
     /// Synthesizes the proving key and verifying key for the given function name.
     #[inline]
     pub fn synthesize_key<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
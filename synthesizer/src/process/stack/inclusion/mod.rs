@@ -36,6 +36,7 @@ use console::{
     types::{Field, Group},
 };
 
+use indexmap::IndexMap;
 use std::collections::HashMap;
 
 #[derive(Clone)]
@@ -125,7 +126,11 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
     #[cfg(not(feature = "wasm"))]
     fn get_request(url: &str) -> Result<ureq::Response> {
         let response = ureq::get(url).call()?;
-        if response.status() == 200 { Ok(response) } else { bail!("Failed to fetch from {}", url) }
+        if response.status() == 200 {
+            Ok(response)
+        } else {
+            bail!("Failed to fetch from {}", url)
+        }
     }
 }
 
@@ -143,18 +148,65 @@ struct InputTask<N: Network> {
     is_local: bool,
 }
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Default)]
 pub struct Inclusion<N: Network> {
     /// A map of transition IDs to a list of input tasks.
     input_tasks: HashMap<N::TransitionID, Vec<InputTask<N>>>,
     /// A map of commitments to (transition ID, output index) pairs.
     output_commitments: HashMap<Field<N>, (N::TransitionID, u8)>,
+    /// A map of transition IDs to (proving key, assignment) pairs, if the transition proofs are deferred.
+    transition_assignments: Option<HashMap<N::TransitionID, (ProvingKey<N>, circuit::Assignment<N::Field>)>>,
+}
+
+impl<N: Network> Debug for Inclusion<N> {
+    /// Prints the inclusion, omitting the proving keys and assignments of the deferred transitions.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Inclusion")
+            .field("input_tasks", &self.input_tasks)
+            .field("output_commitments", &self.output_commitments)
+            .field("transition_assignments", &self.transition_assignments.as_ref().map(|assignments| assignments.len()))
+            .finish()
+    }
 }
 
 impl<N: Network> Inclusion<N> {
     /// Initializes a new `Inclusion` instance.
     pub fn new() -> Self {
-        Self { input_tasks: HashMap::new(), output_commitments: HashMap::new() }
+        Self { input_tasks: HashMap::new(), output_commitments: HashMap::new(), transition_assignments: None }
+    }
+
+    /// Initializes a new `Inclusion` instance, which defers the transition proofs to the batch proof of the execution.
+    pub fn new_batch() -> Self {
+        Self {
+            input_tasks: HashMap::new(),
+            output_commitments: HashMap::new(),
+            transition_assignments: Some(HashMap::new()),
+        }
+    }
+
+    /// Returns `true` if the transition proofs are deferred to the batch proof of the execution.
+    pub const fn is_batch(&self) -> bool {
+        self.transition_assignments.is_some()
+    }
+
+    /// Inserts the proving key and assignment of the transition, to be proven in the batch proof of the execution.
+    pub fn insert_transition_assignment(
+        &mut self,
+        transition: &Transition<N>,
+        proving_key: ProvingKey<N>,
+        assignment: circuit::Assignment<N::Field>,
+    ) -> Result<()> {
+        // Ensure the transition proof is deferred.
+        if transition.proof().is_some() {
+            bail!("Inclusion expected the transition proof to be deferred")
+        }
+        match &mut self.transition_assignments {
+            Some(transition_assignments) => {
+                transition_assignments.insert(*transition.id(), (proving_key, assignment));
+                Ok(())
+            }
+            None => bail!("Inclusion does not defer the transition proofs"),
+        }
     }
 
     /// Inserts the transition to build state for the inclusion proof.
@@ -287,6 +339,7 @@ impl<N: Network> Inclusion<N> {
     }
 
     /// Returns a new execution with an inclusion proof, for the given execution.
+    /// If the transition proofs are deferred, the execution contains a batch proof of the transitions and inclusion.
     pub fn prove_execution<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
         execution: Execution<N>,
@@ -294,6 +347,17 @@ impl<N: Network> Inclusion<N> {
         global_state_root: N::StateRoot,
         rng: &mut R,
     ) -> Result<Execution<N>> {
+        // If the transition proofs are deferred, compute the batch proof of the execution.
+        if let Some(transition_assignments) = &self.transition_assignments {
+            return Self::prove_execution_batch::<A, R>(
+                execution,
+                transition_assignments,
+                assignments,
+                global_state_root,
+                rng,
+            );
+        }
+
         match assignments.is_empty() {
             true => {
                 // Ensure the global state root is not zero.
@@ -320,6 +384,67 @@ impl<N: Network> Inclusion<N> {
         }
     }
 
+    /// Returns a new execution with a batch proof of the transitions and inclusion, for the given execution.
+    fn prove_execution_batch<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        execution: Execution<N>,
+        transition_assignments: &HashMap<N::TransitionID, (ProvingKey<N>, circuit::Assignment<N::Field>)>,
+        assignments: &[InclusionAssignment<N>],
+        global_state_root: N::StateRoot,
+        rng: &mut R,
+    ) -> Result<Execution<N>> {
+        // Ensure the inclusion proof in the execution is 'None'.
+        if execution.inclusion_proof().is_some() {
+            bail!("Inclusion expected the inclusion proof in the execution to be 'None'")
+        }
+
+        // Group the transition assignments by function, in the order of the transitions.
+        let mut batches = IndexMap::<_, (&ProvingKey<N>, Vec<circuit::Assignment<N::Field>>)>::new();
+        for transition in execution.transitions() {
+            // Ensure the transition proof is deferred.
+            if transition.proof().is_some() {
+                bail!("Inclusion expected the transition proofs in the execution to be deferred")
+            }
+            // Retrieve the proving key and assignment.
+            let (proving_key, assignment) = match transition_assignments.get(transition.id()) {
+                Some(transition_assignment) => transition_assignment,
+                None => bail!("Missing the assignment for transition {} in inclusion", transition.id()),
+            };
+            // Add the assignment to the batch of its function.
+            batches
+                .entry((*transition.program_id(), *transition.function_name()))
+                .or_insert_with(|| (proving_key, vec![]))
+                .1
+                .push(assignment.clone());
+        }
+
+        // Fetch the inclusion proving key.
+        let inclusion_proving_key = ProvingKey::<N>::new(N::inclusion_proving_key().clone());
+        // Construct the inclusion batch assignments.
+        let (global_state_root, inclusion_assignments) = match assignments.is_empty() {
+            true => (global_state_root, vec![]),
+            false => Self::to_batch_assignments::<A>(assignments)?,
+        };
+        // Ensure the global state root is not zero.
+        if *global_state_root == Field::zero() {
+            bail!("Inclusion expected the global state root in the execution to *not* be zero")
+        }
+
+        // Construct the batches for each circuit.
+        let mut keys_to_assignments =
+            batches.values().map(|(proving_key, assignments)| (*proving_key, &assignments[..])).collect::<Vec<_>>();
+        if !inclusion_assignments.is_empty() {
+            keys_to_assignments.push((&inclusion_proving_key, &inclusion_assignments[..]));
+        }
+
+        // Retrieve the main transition.
+        let transition = execution.peek()?;
+        // Compute the batch proof.
+        let locator = format!("{}/{}", transition.program_id(), transition.function_name());
+        let batch_proof = ProvingKey::prove_multi_batch(&locator, &keys_to_assignments, rng)?;
+        // Return the execution.
+        Execution::from(execution.into_transitions(), global_state_root, Some(batch_proof))
+    }
+
     /// Returns the inclusion assignments for the given fee transition.
     pub fn prepare_fee<B: BlockStorage<N>, Q: Into<Query<N, B>>>(
         &self,
@@ -421,12 +546,107 @@ impl<N: Network> Inclusion<N> {
     /// Checks the inclusion proof for the execution.
     /// Note: This does *not* check that the global state root exists in the ledger.
     pub fn verify_execution(execution: &Execution<N>) -> Result<()> {
+        // Ensure the transition proofs are not deferred.
+        if execution.is_batch() {
+            bail!("Inclusion expected the execution to contain the transition proofs")
+        }
+
         // Retrieve the global state root.
         let global_state_root = execution.global_state_root();
 
         // Retrieve the inclusion proof.
         let inclusion_proof = execution.inclusion_proof();
 
+        // Construct the batch verifier inputs.
+        let batch_verifier_inputs = Self::execution_verifier_inputs(execution)?;
+
+        // If there are no batch verifier inputs, then ensure the inclusion proof is 'None'.
+        if batch_verifier_inputs.is_empty() && inclusion_proof.is_some() {
+            bail!("No input records in the execution. Expected the inclusion proof to be 'None'")
+        }
+        // If there are batch verifier inputs, then ensure the inclusion proof is 'Some'.
+        if !batch_verifier_inputs.is_empty() && inclusion_proof.is_none() {
+            bail!("Missing inclusion proof for the execution")
+        }
+
+        // Verify the inclusion proof.
+        if let Some(inclusion_proof) = inclusion_proof {
+            // Ensure the global state root is not zero.
+            if *global_state_root == Field::zero() {
+                bail!("Inclusion expected the global state root in the execution to *not* be zero")
+            }
+
+            // Fetch the inclusion verifying key.
+            let verifying_key = VerifyingKey::<N>::new(N::inclusion_verifying_key().clone());
+            // Verify the inclusion proof.
+            ensure!(
+                verifying_key.verify_batch(N::INCLUSION_FUNCTION_NAME, &batch_verifier_inputs, inclusion_proof),
+                "Inclusion proof is invalid"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Checks the batch proof of the transitions and inclusion for the execution,
+    /// given the verifying key and verifier inputs of each function in the execution.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    pub fn verify_execution_batch(
+        execution: &Execution<N>,
+        transition_batches: &[(VerifyingKey<N>, Vec<Vec<N::Field>>)],
+    ) -> Result<()> {
+        // Ensure the transition proofs are deferred.
+        if !execution.is_batch() {
+            bail!("Inclusion expected the transition proofs in the execution to be deferred")
+        }
+        // Ensure there are transition batches.
+        if transition_batches.is_empty() {
+            bail!("Inclusion expected the execution to contain transition batches")
+        }
+
+        // Retrieve the global state root.
+        let global_state_root = execution.global_state_root();
+        // Ensure the global state root is not zero.
+        if *global_state_root == Field::zero() {
+            bail!("Inclusion expected the global state root in the execution to *not* be zero")
+        }
+
+        // Retrieve the batch proof.
+        let batch_proof = match execution.inclusion_proof() {
+            Some(batch_proof) => batch_proof,
+            None => bail!("Missing batch proof for the execution"),
+        };
+
+        // Construct the inclusion batch verifier inputs.
+        let batch_verifier_inputs = Self::execution_verifier_inputs(execution)?;
+
+        // Fetch the inclusion verifying key.
+        let inclusion_verifying_key = VerifyingKey::<N>::new(N::inclusion_verifying_key().clone());
+
+        // Construct the verifier inputs for each circuit.
+        let mut keys_to_inputs =
+            transition_batches.iter().map(|(verifying_key, inputs)| (verifying_key, &inputs[..])).collect::<Vec<_>>();
+        if !batch_verifier_inputs.is_empty() {
+            keys_to_inputs.push((&inclusion_verifying_key, &batch_verifier_inputs[..]));
+        }
+
+        // Retrieve the main transition.
+        let transition = execution.peek()?;
+        // Verify the batch proof.
+        let locator = format!("{}/{}", transition.program_id(), transition.function_name());
+        ensure!(
+            VerifyingKey::verify_multi_batch(&locator, &keys_to_inputs, batch_proof),
+            "Execution is invalid - failed to verify batch proof"
+        );
+
+        Ok(())
+    }
+
+    /// Returns the inclusion batch verifier inputs for the given execution.
+    fn execution_verifier_inputs(execution: &Execution<N>) -> Result<Vec<Vec<N::Field>>> {
+        // Retrieve the global state root.
+        let global_state_root = execution.global_state_root();
+
         // Initialize an empty transaction tree.
         let mut transaction_tree = N::merkle_tree_bhp::<TRANSACTION_DEPTH>(&[])?;
         // Initialize a vector for the batch verifier inputs.
@@ -457,32 +677,7 @@ impl<N: Network> Inclusion<N> {
             transaction_tree.append(&[transaction_leaf.to_bits_le()])?;
         }
 
-        // If there are no batch verifier inputs, then ensure the inclusion proof is 'None'.
-        if batch_verifier_inputs.is_empty() && inclusion_proof.is_some() {
-            bail!("No input records in the execution. Expected the inclusion proof to be 'None'")
-        }
-        // If there are batch verifier inputs, then ensure the inclusion proof is 'Some'.
-        if !batch_verifier_inputs.is_empty() && inclusion_proof.is_none() {
-            bail!("Missing inclusion proof for the execution")
-        }
-
-        // Verify the inclusion proof.
-        if let Some(inclusion_proof) = inclusion_proof {
-            // Ensure the global state root is not zero.
-            if *global_state_root == Field::zero() {
-                bail!("Inclusion expected the global state root in the execution to *not* be zero")
-            }
-
-            // Fetch the inclusion verifying key.
-            let verifying_key = VerifyingKey::<N>::new(N::inclusion_verifying_key().clone());
-            // Verify the inclusion proof.
-            ensure!(
-                verifying_key.verify_batch(N::INCLUSION_FUNCTION_NAME, &batch_verifier_inputs, inclusion_proof),
-                "Inclusion proof is invalid"
-            );
-        }
-
-        Ok(())
+        Ok(batch_verifier_inputs)
     }
 
     /// Checks the inclusion proof for the fee.
@@ -545,6 +740,19 @@ impl<N: Network> Inclusion<N> {
         assignments: &[InclusionAssignment<N>],
         rng: &mut R,
     ) -> Result<(N::StateRoot, Proof<N>)> {
+        // Construct the batch assignments.
+        let (global_state_root, batch_assignments) = Self::to_batch_assignments::<A>(assignments)?;
+
+        // Generate the inclusion batch proof.
+        let inclusion_proof = proving_key.prove_batch(N::INCLUSION_FUNCTION_NAME, &batch_assignments, rng)?;
+        // Return the global state root and inclusion proof.
+        Ok((global_state_root, inclusion_proof))
+    }
+
+    /// Returns the global state root and circuit assignments for the given assignments.
+    fn to_batch_assignments<A: circuit::Aleo<Network = N>>(
+        assignments: &[InclusionAssignment<N>],
+    ) -> Result<(N::StateRoot, Vec<circuit::Assignment<N::Field>>)> {
         // Initialize the global state root.
         let mut global_state_root = N::StateRoot::default();
         // Initialize a vector for the batch assignments.
@@ -567,10 +775,8 @@ impl<N: Network> Inclusion<N> {
             bail!("Inclusion expected the global state root in the execution to *not* be zero")
         }
 
-        // Generate the inclusion batch proof.
-        let inclusion_proof = proving_key.prove_batch(N::INCLUSION_FUNCTION_NAME, &batch_assignments, rng)?;
-        // Return the global state root and inclusion proof.
-        Ok((global_state_root, inclusion_proof))
+        // Return the global state root and batch assignments.
+        Ok((global_state_root, batch_assignments))
     }
 }
 
@@ -662,7 +868,12 @@ mod tests {
 
         match execution_transaction {
             Transaction::Execute(_, execution, _) => {
-                assert!(Inclusion::verify_execution(&execution).is_ok());
+                // Ensure the transition proofs are deferred to the batch proof.
+                assert!(execution.is_batch());
+                // Ensure the batch proof is not accepted as an inclusion proof.
+                assert!(Inclusion::verify_execution(&execution).is_err());
+                // Ensure the batch proof is not accepted without the transitions.
+                assert!(Inclusion::verify_execution_batch(&execution, &[]).is_err());
             }
             _ => panic!("Expected an execution transaction"),
         }
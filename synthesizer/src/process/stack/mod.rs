@@ -105,6 +105,15 @@ pub struct CallMetrics<N: Network> {
     pub num_response_constraints: u64,
 }
 
+/// The metrics of finalizing an execution, which determine the cost of its finalize scopes.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FinalizeMetrics {
+    /// The number of commands that were evaluated.
+    pub num_commands: u64,
+    /// The number of bytes of the mapping entries that were written, by `set` and `get.or_init`.
+    pub num_bytes: u64,
+}
+
 #[derive(Clone)]
 pub enum CallStack<N: Network> {
     Authorize(Vec<Request<N>>, PrivateKey<N>, Authorization<N>),
@@ -349,8 +358,13 @@ impl<N: Network> Stack<N> {
     }
 
     /// Returns the proving key for the given function name.
+    /// If the function is a 'credits.aleo' function without published keys, its keys are synthesized on first use.
     #[inline]
     pub fn get_proving_key(&self, function_name: &Identifier<N>) -> Result<ProvingKey<N>> {
+        // Synthesize the circuit keys, if this is a 'credits.aleo' function without published keys.
+        if !self.contains_proving_key(function_name) && self.is_credits_function_without_keys(function_name)? {
+            self.synthesize_credits_key(function_name)?;
+        }
         // Return the proving key, if it exists.
         match self.proving_keys.read().get(function_name) {
             Some(proving_key) => Ok(proving_key.clone()),
@@ -359,8 +373,13 @@ impl<N: Network> Stack<N> {
     }
 
     /// Returns the verifying key for the given function name.
+    /// If the function is a 'credits.aleo' function without published keys, its keys are synthesized on first use.
     #[inline]
     pub fn get_verifying_key(&self, function_name: &Identifier<N>) -> Result<VerifyingKey<N>> {
+        // Synthesize the circuit keys, if this is a 'credits.aleo' function without published keys.
+        if !self.contains_verifying_key(function_name) && self.is_credits_function_without_keys(function_name)? {
+            self.synthesize_credits_key(function_name)?;
+        }
         // Return the verifying key, if it exists.
         match self.verifying_keys.read().get(function_name) {
             Some(verifying_key) => Ok(verifying_key.clone()),
@@ -68,7 +68,7 @@ impl<N: Network> RegisterTypes<N> {
             ensure!(!matches!(input.value_type(), ValueType::Constant(..)), "Constant inputs are not supported (yet)");
 
             // Check the input register type.
-            register_types.check_input(stack, input.register(), &RegisterType::from(*input.value_type()))?;
+            register_types.check_input(stack, input.register(), &RegisterType::from(input.value_type().clone()))?;
         }
 
         // Step 2. Check the instructions are well-formed.
@@ -80,7 +80,7 @@ impl<N: Network> RegisterTypes<N> {
         // Step 3. Check the outputs are well-formed.
         for output in function.outputs() {
             // Check the output operand type.
-            register_types.check_output(stack, output.operand(), &RegisterType::from(*output.value_type()))?;
+            register_types.check_output(stack, output.operand(), &RegisterType::from(output.value_type().clone()))?;
         }
 
         // Step 4. If the function has a finalize command, check that its operands are all defined.
@@ -108,6 +108,13 @@ impl<N: Network> RegisterTypes<N> {
                             function.name()
                         );
                     }
+                    RegisterType::Plaintext(PlaintextType::Array(..)) => {
+                        bail!(
+                            "'{}/{}' attempts to pass an 'array' into 'finalize'",
+                            stack.program_id(),
+                            function.name()
+                        );
+                    }
                     RegisterType::Record(..) => {
                         bail!(
                             "'{}/{}' attempts to pass a 'record' into 'finalize'",
@@ -151,8 +158,8 @@ impl<N: Network> RegisterTypes<N> {
                     None => Ok(()),
                 }
             }
-            // Ensure the register is a locator, and not a member.
-            Register::Member(..) => bail!("Register '{register}' must be a locator."),
+            // Ensure the register is a locator, and not an access.
+            Register::Access(..) => bail!("Register '{register}' must be a locator."),
         }
     }
 
@@ -174,8 +181,8 @@ impl<N: Network> RegisterTypes<N> {
                     None => Ok(()),
                 }
             }
-            // Ensure the register is a locator, and not a member.
-            Register::Member(..) => bail!("Register '{register}' must be a locator."),
+            // Ensure the register is a locator, and not an access.
+            Register::Access(..) => bail!("Register '{register}' must be a locator."),
         }
     }
 }
@@ -193,6 +200,14 @@ impl<N: Network> RegisterTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the base element type is defined in the program.
+                if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
+                    if !stack.program().contains_struct(struct_name) {
+                        bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+                    }
+                }
+            }
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -208,7 +223,7 @@ impl<N: Network> RegisterTypes<N> {
         };
 
         // Insert the input register.
-        self.add_input(register.clone(), *register_type)?;
+        self.add_input(register.clone(), register_type.clone())?;
 
         // Ensure the register type and the input type match.
         if *register_type != self.get_type(stack, register)? {
@@ -242,6 +257,14 @@ impl<N: Network> RegisterTypes<N> {
                     bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure the base element type is defined in the program.
+                if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
+                    if !stack.program().contains_struct(struct_name) {
+                        bail!("Struct '{struct_name}' in '{}' is not defined.", stack.program_id())
+                    }
+                }
+            }
             RegisterType::Record(identifier) => {
                 // Ensure the record type is defined in the program.
                 if !stack.program().contains_record(identifier) {
@@ -421,6 +444,16 @@ impl<N: Network> RegisterTypes<N> {
                         // Ensure the operand types match the struct.
                         self.matches_struct(stack, instruction.operands(), &struct_)?;
                     }
+                    RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                        // Ensure the base element type is defined in the program.
+                        if let PlaintextType::Struct(struct_name) = array_type.base_element_type() {
+                            if !stack.program().contains_struct(struct_name) {
+                                bail!("Struct '{struct_name}' is not defined.")
+                            }
+                        }
+                        // Ensure the operand types match the array type.
+                        self.matches_array(stack, instruction.operands(), array_type)?;
+                    }
                     RegisterType::Record(record_name) => {
                         // Ensure the record type is defined in the program.
                         if !stack.program().contains_record(record_name) {
@@ -565,6 +598,20 @@ impl<N: Network> RegisterTypes<N> {
                     _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
                 }
             }
+            Opcode::Sign(opcode) => {
+                // Ensure the instruction belongs to the defined set.
+                if !["sign.verify"].contains(&opcode) {
+                    bail!("Instruction '{instruction}' is not for opcode '{opcode}'.");
+                }
+                // Ensure the instruction is the correct one.
+                match opcode {
+                    "sign.verify" => ensure!(
+                        matches!(instruction, Instruction::SignVerify(..)),
+                        "Instruction '{instruction}' is not for opcode '{opcode}'."
+                    ),
+                    _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+                }
+            }
         }
         Ok(())
     }
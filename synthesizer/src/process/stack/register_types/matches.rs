@@ -61,7 +61,7 @@ impl<N: Network> RegisterTypes<N> {
                     );
                     // Ensure the register type matches the member type.
                     ensure!(
-                        register_type == RegisterType::Plaintext(*member_type),
+                        register_type == RegisterType::Plaintext(member_type.clone()),
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{register_type}' in the operand '{operand}'.",
                     )
                 }
@@ -71,7 +71,7 @@ impl<N: Network> RegisterTypes<N> {
                     let program_ref_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
                     // Ensure the program ID type matches the member type.
                     ensure!(
-                        program_ref_type == RegisterType::Plaintext(*member_type),
+                        program_ref_type == RegisterType::Plaintext(member_type.clone()),
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{program_ref_type}' in the operand '{operand}'.",
                     )
                 }
@@ -81,7 +81,7 @@ impl<N: Network> RegisterTypes<N> {
                     let caller_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
                     // Ensure the caller type matches the member type.
                     ensure!(
-                        caller_type == RegisterType::Plaintext(*member_type),
+                        caller_type == RegisterType::Plaintext(member_type.clone()),
                         "Struct member '{struct_name}.{member_name}' expects {member_type}, but found '{caller_type}' in the operand '{operand}'.",
                     )
                 }
@@ -90,6 +90,73 @@ impl<N: Network> RegisterTypes<N> {
         Ok(())
     }
 
+    /// Checks that the given operands matches the layout of the array. The ordering of the operands matters.
+    pub fn matches_array(&self, stack: &Stack<N>, operands: &[Operand<N>], array_type: &ArrayType<N>) -> Result<()> {
+        // Ensure the number of array elements does not exceed the maximum.
+        if operands.len() > N::MAX_DATA_ENTRIES {
+            bail!("'{array_type}' cannot exceed {} elements", N::MAX_DATA_ENTRIES)
+        }
+
+        // Ensure the number of array elements match.
+        let num_elements = operands.len();
+        let expected_num_elements = **array_type.length() as usize;
+        if expected_num_elements != num_elements {
+            bail!("'{array_type}' expected {expected_num_elements} elements, found {num_elements} elements")
+        }
+
+        // Retrieve the element type.
+        let element_type = array_type.element_type();
+
+        // Ensure the operand types match the element type.
+        for (index, operand) in operands.iter().enumerate() {
+            match operand {
+                // Ensure the literal type matches the element type.
+                Operand::Literal(literal) => {
+                    ensure!(
+                        PlaintextType::Literal(literal.to_type()) == *element_type,
+                        "Array element '{index}' expects a {element_type}, but found '{operand}' in the operand.",
+                    )
+                }
+                // Ensure the register type matches the element type.
+                Operand::Register(register) => {
+                    // Retrieve the register type.
+                    let register_type = self.get_type(stack, register)?;
+                    // Ensure the register type is not a record.
+                    ensure!(
+                        !matches!(register_type, RegisterType::Record(..)),
+                        "Casting a record into an array is illegal"
+                    );
+                    // Ensure the register type matches the element type.
+                    ensure!(
+                        register_type == RegisterType::Plaintext(element_type.clone()),
+                        "Array element '{index}' expects {element_type}, but found '{register_type}' in the operand '{operand}'.",
+                    )
+                }
+                // Ensure the program ID type (address) matches the element type.
+                Operand::ProgramID(..) => {
+                    // Retrieve the program ID type.
+                    let program_ref_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
+                    // Ensure the program ID type matches the element type.
+                    ensure!(
+                        program_ref_type == RegisterType::Plaintext(element_type.clone()),
+                        "Array element '{index}' expects {element_type}, but found '{program_ref_type}' in the operand '{operand}'.",
+                    )
+                }
+                // Ensure the caller type (address) matches the element type.
+                Operand::Caller => {
+                    // Retrieve the caller type.
+                    let caller_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
+                    // Ensure the caller type matches the element type.
+                    ensure!(
+                        caller_type == RegisterType::Plaintext(element_type.clone()),
+                        "Array element '{index}' expects {element_type}, but found '{caller_type}' in the operand '{operand}'.",
+                    )
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Checks that the given record matches the layout of the record type.
     /// Note: Ordering for `owner` **does** matter, however ordering
     /// for record data does **not** matter, as long as all defined members are present.
@@ -168,7 +235,7 @@ impl<N: Network> RegisterTypes<N> {
                             );
                             // Ensure the register type matches the entry type.
                             ensure!(
-                                register_type == RegisterType::Plaintext(*plaintext_type),
+                                register_type == RegisterType::Plaintext(plaintext_type.clone()),
                                 "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found '{register_type}' in the operand '{operand}'.",
                             )
                         }
@@ -179,7 +246,7 @@ impl<N: Network> RegisterTypes<N> {
                                 RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
                             // Ensure the program ID type matches the member type.
                             ensure!(
-                                program_ref_type == RegisterType::Plaintext(*plaintext_type),
+                                program_ref_type == RegisterType::Plaintext(plaintext_type.clone()),
                                 "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found '{program_ref_type}' in the operand '{operand}'.",
                             )
                         }
@@ -189,7 +256,7 @@ impl<N: Network> RegisterTypes<N> {
                             let caller_type = RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address));
                             // Ensure the caller type matches the member type.
                             ensure!(
-                                caller_type == RegisterType::Plaintext(*plaintext_type),
+                                caller_type == RegisterType::Plaintext(plaintext_type.clone()),
                                 "Record entry '{record_name}.{entry_name}' expects a '{plaintext_type}', but found '{caller_type}' in the operand '{operand}'.",
                             )
                         }
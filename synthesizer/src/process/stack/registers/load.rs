@@ -21,7 +21,7 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Load<N> for Registers<N, A> {
     ///
     /// # Errors
     /// This method will halt if the register locator is not found.
-    /// In the case of register members, this method will halt if the member is not found.
+    /// In the case of register accesses, this method will halt if the access is not found.
     #[inline]
     fn load(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Value<N>> {
         // Retrieve the register.
@@ -42,14 +42,14 @@ impl<N: Network, A: circuit::Aleo<Network = N>> Load<N> for Registers<N, A> {
         let stack_value =
             self.console_registers.get(&register.locator()).ok_or_else(|| anyhow!("'{register}' does not exist"))?;
 
-        // Return the value for the given register or register member.
+        // Return the value for the given register or register access.
         let stack_value = match register {
             // If the register is a locator, then return the stack value.
             Register::Locator(..) => stack_value.clone(),
-            // If the register is a register member, then load the specific stack value.
-            Register::Member(_, ref path) => {
+            // If the register is a register access, then load the specific stack value.
+            Register::Access(_, ref path) => {
                 match stack_value {
-                    // Retrieve the plaintext member from the path.
+                    // Retrieve the plaintext value from the path.
                     Value::Plaintext(plaintext) => Value::Plaintext(plaintext.find(path)?),
                     // Retrieve the record entry from the path.
                     Value::Record(record) => match record.find(path)? {
@@ -78,7 +78,7 @@ impl<N: Network, A: circuit::Aleo<Network = N>> LoadCircuit<N, A> for Registers<
     ///
     /// # Errors
     /// This method will halt if the register locator is not found.
-    /// In the case of register members, this method will halt if the member is not found.
+    /// In the case of register accesses, this method will halt if the access is not found.
     #[inline]
     fn load_circuit(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<circuit::Value<A>> {
         use circuit::Inject;
@@ -111,17 +111,17 @@ impl<N: Network, A: circuit::Aleo<Network = N>> LoadCircuit<N, A> for Registers<
         let circuit_value =
             self.circuit_registers.get(&register.locator()).ok_or_else(|| anyhow!("'{register}' does not exist"))?;
 
-        // Return the value for the given register or register member.
+        // Return the value for the given register or register access.
         let circuit_value = match register {
             // If the register is a locator, then return the stack value.
             Register::Locator(..) => circuit_value.clone(),
-            // If the register is a register member, then load the specific stack value.
-            Register::Member(_, ref path) => {
+            // If the register is a register access, then load the specific stack value.
+            Register::Access(_, ref path) => {
                 // Inject the path.
-                let path = path.iter().map(|member| circuit::Identifier::constant(*member)).collect::<Vec<_>>();
+                let path = path.iter().map(|access| circuit::Access::constant(*access)).collect::<Vec<_>>();
 
                 match circuit_value {
-                    // Retrieve the plaintext member from the path.
+                    // Retrieve the plaintext value from the path.
                     circuit::Value::Plaintext(plaintext) => circuit::Value::Plaintext(plaintext.find(&path)?),
                     // Retrieve the record entry from the path.
                     circuit::Value::Record(record) => match record.find(&path)? {
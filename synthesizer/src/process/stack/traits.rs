@@ -22,7 +22,7 @@ pub trait Load<N: Network> {
     ///
     /// # Errors
     /// This method should halt if the register locator is not found.
-    /// In the case of register members, this method should halt if the member is not found.
+    /// In the case of register accesses, this method should halt if the access is not found.
     fn load(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Value<N>>;
 
     /// Loads the literal of a given operand.
@@ -30,12 +30,13 @@ pub trait Load<N: Network> {
     /// # Errors
     /// This method should halt if the given operand is not a literal.
     /// This method should halt if the register locator is not found.
-    /// In the case of register members, this method should halt if the member is not found.
+    /// In the case of register accesses, this method should halt if the access is not found.
     #[inline]
     fn load_literal(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Literal<N>> {
         match self.load(stack, operand)? {
             Value::Plaintext(Plaintext::Literal(literal, ..)) => Ok(literal),
             Value::Plaintext(Plaintext::Struct(..)) => bail!("Operand must be a literal"),
+            Value::Plaintext(Plaintext::Array(..)) => bail!("Operand must be a literal"),
             Value::Record(..) => bail!("Operand must be a literal"),
         }
     }
@@ -45,7 +46,7 @@ pub trait Load<N: Network> {
     /// # Errors
     /// This method should halt if the given operand is not a plaintext.
     /// This method should halt if the register locator is not found.
-    /// In the case of register members, this method should halt if the member is not found.
+    /// In the case of register accesses, this method should halt if the access is not found.
     #[inline]
     fn load_plaintext(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<Plaintext<N>> {
         match self.load(stack, operand)? {
@@ -60,7 +61,7 @@ pub trait LoadCircuit<N: Network, A: circuit::Aleo<Network = N>> {
     ///
     /// # Errors
     /// This method should halt if the register locator is not found.
-    /// In the case of register members, this method should halt if the member is not found.
+    /// In the case of register accesses, this method should halt if the access is not found.
     fn load_circuit(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<circuit::Value<A>>;
 
     /// Loads the literal of a given operand.
@@ -68,12 +69,13 @@ pub trait LoadCircuit<N: Network, A: circuit::Aleo<Network = N>> {
     /// # Errors
     /// This method should halt if the given operand is not a literal.
     /// This method should halt if the register locator is not found.
-    /// In the case of register members, this method should halt if the member is not found.
+    /// In the case of register accesses, this method should halt if the access is not found.
     #[inline]
     fn load_literal_circuit(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<circuit::Literal<A>> {
         match self.load_circuit(stack, operand)? {
             circuit::Value::Plaintext(circuit::Plaintext::Literal(literal, ..)) => Ok(literal),
             circuit::Value::Plaintext(circuit::Plaintext::Struct(..)) => bail!("Operand must be a literal"),
+            circuit::Value::Plaintext(circuit::Plaintext::Array(..)) => bail!("Operand must be a literal"),
             circuit::Value::Record(..) => bail!("Operand must be a literal"),
         }
     }
@@ -83,7 +85,7 @@ pub trait LoadCircuit<N: Network, A: circuit::Aleo<Network = N>> {
     /// # Errors
     /// This method should halt if the given operand is not a plaintext.
     /// This method should halt if the register locator is not found.
-    /// In the case of register members, this method should halt if the member is not found.
+    /// In the case of register accesses, this method should halt if the access is not found.
     #[inline]
     fn load_plaintext_circuit(&self, stack: &Stack<N>, operand: &Operand<N>) -> Result<circuit::Plaintext<A>> {
         match self.load_circuit(stack, operand)? {
@@ -97,7 +99,7 @@ pub trait Store<N: Network> {
     /// Assigns the given value to the given register, assuming the register is not already assigned.
     ///
     /// # Errors
-    /// This method should halt if the given register is a register member.
+    /// This method should halt if the given register is a register access.
     /// This method should halt if the given register is an input register.
     /// This method should halt if the register is already used.
     fn store(&mut self, stack: &Stack<N>, register: &Register<N>, stack_value: Value<N>) -> Result<()>;
@@ -105,7 +107,7 @@ pub trait Store<N: Network> {
     /// Assigns the given literal to the given register, assuming the register is not already assigned.
     ///
     /// # Errors
-    /// This method should halt if the given register is a register member.
+    /// This method should halt if the given register is a register access.
     /// This method should halt if the given register is an input register.
     /// This method should halt if the register is already used.
     #[inline]
@@ -118,16 +120,16 @@ pub trait StoreCircuit<N: Network, A: circuit::Aleo<Network = N>> {
     /// Assigns the given value to the given register, assuming the register is not already assigned.
     ///
     /// # Errors
-    /// This method should halt if the given register is a register member.
+    /// This method should halt if the given register is a register access.
     /// This method should halt if the given register is an input register.
     /// This method should halt if the register is already used.
     fn store_circuit(&mut self, stack: &Stack<N>, register: &Register<N>, stack_value: circuit::Value<A>)
-    -> Result<()>;
+        -> Result<()>;
 
     /// Assigns the given literal to the given register, assuming the register is not already assigned.
     ///
     /// # Errors
-    /// This method should halt if the given register is a register member.
+    /// This method should halt if the given register is a register access.
     /// This method should halt if the given register is an input register.
     /// This method should halt if the register is already used.
     #[inline]
@@ -0,0 +1,217 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Load as LoadTrait, Opcode, Operand, ProgramStorage, ProgramStore, Stack, Store};
+use console::{
+    network::prelude::*,
+    program::{Identifier, Literal, Plaintext, Register, Value},
+    types::Boolean,
+};
+
+/// A contains command, e.g. `contains accounts[r0] into r1;`.
+/// Checks if `mapping` contains the key stored at `operand`, and stores the result in `destination`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Contains<N: Network> {
+    /// The mapping name.
+    mapping: Identifier<N>,
+    /// The key to access the mapping.
+    key: Operand<N>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> Contains<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("contains")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![self.key.clone()]
+    }
+
+    /// Returns the mapping name.
+    #[inline]
+    pub const fn mapping_name(&self) -> &Identifier<N> {
+        &self.mapping
+    }
+
+    /// Returns the operand containing the key.
+    #[inline]
+    pub const fn key(&self) -> &Operand<N> {
+        &self.key
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub const fn destination(&self) -> &Register<N> {
+        &self.destination
+    }
+}
+
+impl<N: Network> Contains<N> {
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize<P: ProgramStorage<N>>(
+        &self,
+        stack: &Stack<N>,
+        store: &ProgramStore<N, P>,
+        registers: &mut (impl LoadTrait<N> + Store<N>),
+    ) -> Result<()> {
+        // Ensure the mapping exists in storage.
+        if !store.contains_mapping(stack.program_id(), &self.mapping)? {
+            bail!("Mapping '{}/{}' does not exist in storage", stack.program_id(), self.mapping);
+        }
+
+        // Load the operand as a plaintext.
+        let key = registers.load_plaintext(stack, &self.key)?;
+
+        // Determine if the key exists in the mapping.
+        let contains_key = store.contains_key(stack.program_id(), &self.mapping, &key)?;
+
+        // Assign the result to the destination register.
+        let output = Value::Plaintext(Plaintext::from(Literal::Boolean(Boolean::new(contains_key))));
+        registers.store(stack, &self.destination, output)?;
+
+        Ok(())
+    }
+}
+
+impl<N: Network> Parser for Contains<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the mapping name from the string.
+        let (string, mapping) = Identifier::parse(string)?;
+        // Parse the "[" from the string.
+        let (string, _) = tag("[")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the key operand from the string.
+        let (string, key) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "]" from the string.
+        let (string, _) = tag("]")(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" keyword from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { mapping, key, destination }))
+    }
+}
+
+impl<N: Network> FromStr for Contains<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Contains<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Contains<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command.
+        write!(f, "{} ", Self::opcode())?;
+        // Print the mapping and key operand.
+        write!(f, "{}[{}] into ", self.mapping, self.key)?;
+        // Print the destination register.
+        write!(f, "{};", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for Contains<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the mapping name.
+        let mapping = Identifier::read_le(&mut reader)?;
+        // Read the key operand.
+        let key = Operand::read_le(&mut reader)?;
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { mapping, key, destination })
+    }
+}
+
+impl<N: Network> ToBytes for Contains<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the mapping name.
+        self.mapping.write_le(&mut writer)?;
+        // Write the key operand.
+        self.key.write_le(&mut writer)?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::Register};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, contains) = Contains::<CurrentNetwork>::parse("contains account[r0] into r1;").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(contains.mapping, Identifier::from_str("account").unwrap());
+        assert_eq!(contains.operands().len(), 1, "The number of operands is incorrect");
+        assert_eq!(contains.key, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(contains.destination, Register::Locator(1), "The second operand is incorrect");
+    }
+}
@@ -14,6 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod branch;
+pub use branch::*;
+
+mod contains;
+pub use contains::*;
+
 mod finalize;
 pub use finalize::*;
 
@@ -23,6 +29,12 @@ pub use get::*;
 mod get_or_init;
 pub use get_or_init::*;
 
+mod remove;
+pub use remove::*;
+
+mod position;
+pub use position::*;
+
 mod set;
 pub use set::*;
 
@@ -33,6 +45,8 @@ use console::network::prelude::*;
 pub enum Command<N: Network> {
     /// Evaluates the instruction.
     Instruction(Instruction<N>),
+    /// Checks if the `key` operand is present in `mapping`, and stores the result into `destination`.
+    Contains(Contains<N>),
     /// Gets the value stored at the `key` operand in `mapping` and stores the result into `destination`.
     Get(Get<N>),
     /// Gets the value stored at the `key` operand in `mapping` and stores the result into `destination`.
@@ -40,10 +54,19 @@ pub enum Command<N: Network> {
     GetOrInit(GetOrInit<N>),
     /// Sets the value stored at the `key` operand in the `mapping` to `value`.
     Set(Set<N>),
+    /// Removes the `key` operand and its value from the `mapping`, if it is present.
+    Remove(Remove<N>),
+    /// Jumps to `position`, if `first` equals `second`.
+    BranchEq(BranchEq<N>),
+    /// Jumps to `position`, if `first` does **not** equal `second`.
+    BranchNeq(BranchNeq<N>),
+    /// Marks a position in the finalize scope, which a branch may jump to.
+    Position(Position<N>),
 }
 
 impl<N: Network> Command<N> {
     /// Finalizes the command.
+    /// Note: A branch command must be evaluated by the caller, as it determines the next command to finalize.
     #[inline]
     pub fn finalize<P: ProgramStorage<N>>(
         &self,
@@ -56,6 +79,13 @@ impl<N: Network> Command<N> {
             Command::Get(get) => get.finalize(stack, store, registers),
             Command::GetOrInit(get_or_init) => get_or_init.finalize(stack, store, registers),
             Command::Set(set) => set.finalize(stack, store, registers),
+            Command::Contains(contains) => contains.finalize(stack, store, registers),
+            Command::Remove(remove) => remove.finalize(stack, store, registers),
+            Command::BranchEq(_) | Command::BranchNeq(_) => {
+                bail!("Cannot finalize '{self}' outside of its finalize scope")
+            }
+            // A position is a marker, and is not evaluated.
+            Command::Position(_) => Ok(()),
         }
     }
 }
@@ -74,8 +104,18 @@ impl<N: Network> FromBytes for Command<N> {
             2 => Ok(Self::GetOrInit(GetOrInit::read_le(&mut reader)?)),
             // Read the `set` operation.
             3 => Ok(Self::Set(Set::read_le(&mut reader)?)),
+            // Read the `contains` operation.
+            4 => Ok(Self::Contains(Contains::read_le(&mut reader)?)),
+            // Read the `remove` operation.
+            5 => Ok(Self::Remove(Remove::read_le(&mut reader)?)),
+            // Read the `branch.eq` command.
+            6 => Ok(Self::BranchEq(BranchEq::read_le(&mut reader)?)),
+            // Read the `branch.neq` command.
+            7 => Ok(Self::BranchNeq(BranchNeq::read_le(&mut reader)?)),
+            // Read the `position` command.
+            8 => Ok(Self::Position(Position::read_le(&mut reader)?)),
             // Invalid variant.
-            4.. => Err(error(format!("Invalid command variant: {variant}"))),
+            9.. => Err(error(format!("Invalid command variant: {variant}"))),
         }
     }
 }
@@ -108,6 +148,36 @@ impl<N: Network> ToBytes for Command<N> {
                 // Write the set.
                 set.write_le(&mut writer)
             }
+            Self::Contains(contains) => {
+                // Write the variant.
+                4u8.write_le(&mut writer)?;
+                // Write the `contains` operation.
+                contains.write_le(&mut writer)
+            }
+            Self::Remove(remove) => {
+                // Write the variant.
+                5u8.write_le(&mut writer)?;
+                // Write the `remove` operation.
+                remove.write_le(&mut writer)
+            }
+            Self::BranchEq(branch_eq) => {
+                // Write the variant.
+                6u8.write_le(&mut writer)?;
+                // Write the `branch.eq` command.
+                branch_eq.write_le(&mut writer)
+            }
+            Self::BranchNeq(branch_neq) => {
+                // Write the variant.
+                7u8.write_le(&mut writer)?;
+                // Write the `branch.neq` command.
+                branch_neq.write_le(&mut writer)
+            }
+            Self::Position(position) => {
+                // Write the variant.
+                8u8.write_le(&mut writer)?;
+                // Write the `position` command.
+                position.write_le(&mut writer)
+            }
         }
     }
 }
@@ -122,6 +192,11 @@ impl<N: Network> Parser for Command<N> {
             map(GetOrInit::parse, |get_or_init| Self::GetOrInit(get_or_init)),
             map(Get::parse, |get| Self::Get(get)),
             map(Set::parse, |set| Self::Set(set)),
+            map(Contains::parse, |contains| Self::Contains(contains)),
+            map(Remove::parse, |remove| Self::Remove(remove)),
+            map(BranchEq::parse, |branch_eq| Self::BranchEq(branch_eq)),
+            map(BranchNeq::parse, |branch_neq| Self::BranchNeq(branch_neq)),
+            map(Position::parse, |position| Self::Position(position)),
             map(Instruction::parse, |instruction| Self::Instruction(instruction)),
         ))(string)
     }
@@ -160,6 +235,11 @@ impl<N: Network> Display for Command<N> {
             Self::Get(get) => Display::fmt(get, f),
             Self::GetOrInit(get_or_init) => Display::fmt(get_or_init, f),
             Self::Set(set) => Display::fmt(set, f),
+            Self::Contains(contains) => Display::fmt(contains, f),
+            Self::Remove(remove) => Display::fmt(remove, f),
+            Self::BranchEq(branch_eq) => Display::fmt(branch_eq, f),
+            Self::BranchNeq(branch_neq) => Display::fmt(branch_neq, f),
+            Self::Position(position) => Display::fmt(position, f),
         }
     }
 }
@@ -204,6 +284,36 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         let bytes = command.to_bytes_le().unwrap();
         assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Contains
+        let expected = "contains object[r0] into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Remove
+        let expected = "remove object[r0];";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // BranchEq
+        let expected = "branch.eq r0 r1 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // BranchNeq
+        let expected = "branch.neq r0 r1 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
+
+        // Position
+        let expected = "position exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        let bytes = command.to_bytes_le().unwrap();
+        assert_eq!(command, Command::from_bytes_le(&bytes).unwrap());
     }
 
     #[test]
@@ -239,5 +349,35 @@ mod tests {
         let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
         assert_eq!(Command::Set(Set::from_str(expected).unwrap()), command);
         assert_eq!(expected, command.to_string());
+
+        // Contains
+        let expected = "contains object[r0] into r1;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Contains(Contains::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Remove
+        let expected = "remove object[r0];";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Remove(Remove::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // BranchEq
+        let expected = "branch.eq r0 r1 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::BranchEq(BranchEq::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // BranchNeq
+        let expected = "branch.neq r0 r1 to exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::BranchNeq(BranchNeq::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
+
+        // Position
+        let expected = "position exit;";
+        let command = Command::<CurrentNetwork>::parse(expected).unwrap().1;
+        assert_eq!(Command::Position(Position::from_str(expected).unwrap()), command);
+        assert_eq!(expected, command.to_string());
     }
 }
@@ -0,0 +1,187 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Load, Opcode, Operand, ProgramStorage, ProgramStore, Stack};
+use console::{network::prelude::*, program::Identifier};
+
+/// A remove command, e.g. `remove mapping[r0];`
+/// Removes the `key` entry from `mapping`, if it exists.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Remove<N: Network> {
+    /// The mapping name.
+    mapping: Identifier<N>,
+    /// The key to access the mapping.
+    key: Operand<N>,
+}
+
+impl<N: Network> Remove<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Command("remove")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> Vec<Operand<N>> {
+        vec![self.key.clone()]
+    }
+
+    /// Returns the mapping name.
+    #[inline]
+    pub const fn mapping_name(&self) -> &Identifier<N> {
+        &self.mapping
+    }
+
+    /// Returns the operand containing the key.
+    #[inline]
+    pub const fn key(&self) -> &Operand<N> {
+        &self.key
+    }
+}
+
+impl<N: Network> Remove<N> {
+    /// Finalizes the command.
+    #[inline]
+    pub fn finalize<P: ProgramStorage<N>>(
+        &self,
+        stack: &Stack<N>,
+        store: &ProgramStore<N, P>,
+        registers: &mut impl Load<N>,
+    ) -> Result<()> {
+        // Ensure the mapping exists in storage.
+        if !store.contains_mapping(stack.program_id(), &self.mapping)? {
+            bail!("Mapping '{}/{}' does not exist in storage", stack.program_id(), self.mapping);
+        }
+
+        // Load the key operand as a plaintext.
+        let key = registers.load_plaintext(stack, &self.key)?;
+
+        // Remove the key-value pair from storage, if it exists.
+        // Note: The existence check is speculative, so it accounts for the writes of earlier commands.
+        if store.contains_key(stack.program_id(), &self.mapping, &key)? {
+            store.remove_key_value(stack.program_id(), &self.mapping, &key)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<N: Network> Parser for Remove<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+
+        // Parse the mapping name from the string.
+        let (string, mapping) = Identifier::parse(string)?;
+        // Parse the "[" from the string.
+        let (string, _) = tag("[")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the key operand from the string.
+        let (string, key) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "]" from the string.
+        let (string, _) = tag("]")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the ";" from the string.
+        let (string, _) = tag(";")(string)?;
+
+        Ok((string, Self { mapping, key }))
+    }
+}
+
+impl<N: Network> FromStr for Remove<N> {
+    type Err = Error;
+
+    /// Parses a string into the command.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for Remove<N> {
+    /// Prints the command as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for Remove<N> {
+    /// Prints the command to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Print the command.
+        write!(f, "{} ", Self::opcode())?;
+        // Print the mapping and key operand.
+        write!(f, "{}[{}];", self.mapping, self.key)
+    }
+}
+
+impl<N: Network> FromBytes for Remove<N> {
+    /// Reads the command from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the mapping name.
+        let mapping = Identifier::read_le(&mut reader)?;
+        // Read the key operand.
+        let key = Operand::read_le(&mut reader)?;
+        // Return the command.
+        Ok(Self { mapping, key })
+    }
+}
+
+impl<N: Network> ToBytes for Remove<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the mapping name.
+        self.mapping.write_le(&mut writer)?;
+        // Write the key operand.
+        self.key.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::Register};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, remove) = Remove::<CurrentNetwork>::parse("remove account[r0];").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(remove.mapping, Identifier::from_str("account").unwrap());
+        assert_eq!(remove.operands().len(), 1, "The number of operands is incorrect");
+        assert_eq!(remove.key, Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+    }
+}
@@ -30,7 +30,7 @@ use console::{
 };
 
 use console::program::RegisterType;
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct Finalize<N: Network> {
@@ -41,12 +41,14 @@ pub struct Finalize<N: Network> {
     inputs: IndexSet<Input<N>>,
     /// The commands, in order of execution.
     commands: Vec<Command<N>>,
+    /// The positions, mapped to the index of their `position` command.
+    positions: IndexMap<Identifier<N>, usize>,
 }
 
 impl<N: Network> Finalize<N> {
     /// Initializes a new finalize with the given name.
     pub fn new(name: Identifier<N>) -> Self {
-        Self { name, inputs: IndexSet::new(), commands: Vec::new() }
+        Self { name, inputs: IndexSet::new(), commands: Vec::new(), positions: IndexMap::new() }
     }
 
     /// Returns the name of the associated function.
@@ -61,13 +63,18 @@ impl<N: Network> Finalize<N> {
 
     /// Returns the finalize input types.
     pub fn input_types(&self) -> Vec<PlaintextType<N>> {
-        self.inputs.iter().map(|input| *input.plaintext_type()).collect()
+        self.inputs.iter().map(|input| input.plaintext_type().clone()).collect()
     }
 
     /// Returns the finalize commands.
     pub fn commands(&self) -> &[Command<N>] {
         &self.commands
     }
+
+    /// Returns the finalize positions, mapped to the index of their `position` command.
+    pub const fn positions(&self) -> &IndexMap<Identifier<N>, usize> {
+        &self.positions
+    }
 }
 
 impl<N: Network> Finalize<N> {
@@ -99,12 +106,13 @@ impl<N: Network> Finalize<N> {
     ///
     /// # Errors
     /// This method will halt if the maximum number of commands has been reached.
+    /// This method will halt if the command is a `position` that was previously added.
     #[inline]
     pub fn add_command(&mut self, command: Command<N>) -> Result<()> {
         // Ensure the maximum number of commands has not been exceeded.
         ensure!(self.commands.len() <= N::MAX_COMMANDS, "Cannot add more than {} commands", N::MAX_COMMANDS);
 
-        // If the command is an instruction, `get` command, or `get.or_init` command, perform additional checks.
+        // If the command is an instruction, `get` command, `get.or_init` command, `contains` command, or `position` command, perform additional checks.
         match &command {
             Command::Instruction(instruction) => {
                 match instruction {
@@ -132,7 +140,24 @@ impl<N: Network> Finalize<N> {
                     "Destination register must be a locator"
                 );
             }
-            Command::Set(_) => {}
+            Command::Contains(contains) => {
+                // Ensure the destination register is a locator.
+                ensure!(
+                    matches!(contains.destination(), Register::Locator(..)),
+                    "Destination register must be a locator"
+                );
+            }
+            Command::Position(position) => {
+                // Ensure the position was not previously added.
+                ensure!(
+                    !self.positions.contains_key(position.name()),
+                    "Cannot redefine position '{}'",
+                    position.name()
+                );
+                // Record the index of the position.
+                self.positions.insert(*position.name(), self.commands.len());
+            }
+            Command::Set(_) | Command::Remove(_) | Command::BranchEq(_) | Command::BranchNeq(_) => {}
         }
 
         // Insert the command.
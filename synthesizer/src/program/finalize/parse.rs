@@ -138,6 +138,34 @@ finalize foo:
         assert_eq!(1, finalize.commands.len());
     }
 
+    #[test]
+    fn test_finalize_parse_branch() {
+        let finalize = Finalize::<CurrentNetwork>::parse(
+            r"
+finalize foo:
+    input r0 as u64.public;
+    branch.eq r0 0u64 to zero;
+    add r0 1u64 into r1;
+    position zero;",
+        )
+        .unwrap()
+        .1;
+        assert_eq!("foo", finalize.name().to_string());
+        assert_eq!(3, finalize.commands.len());
+        assert_eq!(Some(&2), finalize.positions().get(&Identifier::from_str("zero").unwrap()));
+
+        // Ensure a position cannot be redefined.
+        let result = Finalize::<CurrentNetwork>::parse(
+            r"
+finalize foo:
+    input r0 as u64.public;
+    position zero;
+    branch.eq r0 0u64 to zero;
+    position zero;",
+        );
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_finalize_display() {
         let expected = r"finalize foo:
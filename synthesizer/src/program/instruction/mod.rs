@@ -171,6 +171,8 @@ pub enum Instruction<N: Network> {
     Ternary(Ternary<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
+    /// Verifies the signature `first` against the address `second` and the message `third`, storing the outcome in `destination`.
+    SignVerify(SignVerify<N>),
 }
 
 /// Creates a match statement that applies the given operation for each instruction.
@@ -258,6 +260,7 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            SignVerify,
         }}
     };
     // A variant **without** curly braces:
@@ -410,7 +413,7 @@ mod tests {
     fn test_opcodes() {
         // Sanity check the number of instructions is unchanged.
         assert_eq!(
-            56,
+            57,
             Instruction::<CurrentNetwork>::OPCODES.len(),
             "Update me if the number of instructions changes."
         );
@@ -37,6 +37,8 @@ pub enum Opcode {
     Is(&'static str),
     /// The opcode is for a literal operation (i.e. `add`).
     Literal(&'static str),
+    /// The opcode is for a signature operation (i.e. `sign.verify`).
+    Sign(&'static str),
 }
 
 impl Deref for Opcode {
@@ -54,6 +56,7 @@ impl Deref for Opcode {
             Opcode::Hash(opcode) => opcode,
             Opcode::Is(opcode) => opcode,
             Opcode::Literal(opcode) => opcode,
+            Opcode::Sign(opcode) => opcode,
         }
     }
 }
@@ -79,6 +82,7 @@ impl Display for Opcode {
             Self::Hash(opcode) => write!(f, "{opcode}"),
             Self::Is(opcode) => write!(f, "{opcode}"),
             Self::Literal(opcode) => write!(f, "{opcode}"),
+            Self::Sign(opcode) => write!(f, "{opcode}"),
         }
     }
 }
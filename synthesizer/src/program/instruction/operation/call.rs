@@ -484,7 +484,7 @@ impl<N: Network> Call<N> {
                 bail!("Expected {} outputs, found {}", closure.outputs().len(), self.destinations.len())
             }
             // Return the output register types.
-            Ok(closure.outputs().iter().map(|output| *output.register_type()).collect())
+            Ok(closure.outputs().iter().map(|output| output.register_type().clone()).collect())
         }
         // If the operator is a function, retrieve the function and compute the output types.
         else if let Ok(function) = program.get_function(resource) {
@@ -504,7 +504,7 @@ impl<N: Network> Call<N> {
             function
                 .output_types()
                 .into_iter()
-                .map(|output_type| match (is_external, output_type) {
+                .map(|output_type| match (is_external, &output_type) {
                     // If the output is a record and the function is external, return the external record type.
                     (true, ValueType::Record(record_name)) => Ok(RegisterType::ExternalRecord(Locator::from_str(
                         &format!("{}/{}", program.id(), record_name),
@@ -704,7 +704,7 @@ mod tests {
     use super::*;
     use console::{
         network::Testnet3,
-        program::{Address, Identifier, Literal, U64},
+        program::{Access, Address, Identifier, Literal, U64},
     };
 
     type CurrentNetwork = Testnet3;
@@ -758,8 +758,10 @@ mod tests {
             "call transfer r0.owner r0.token_amount into r1 r2 r3",
             CallOperator::from_str("transfer").unwrap(),
             vec![
-                Operand::Register(Register::Member(0, vec![Identifier::from_str("owner").unwrap()])),
-                Operand::Register(Register::Member(0, vec![Identifier::from_str("token_amount").unwrap()])),
+                Operand::Register(Register::Access(0, vec![Access::Member(Identifier::from_str("owner").unwrap())])),
+                Operand::Register(Register::Access(0, vec![Access::Member(
+                    Identifier::from_str("token_amount").unwrap(),
+                )])),
             ],
             vec![Register::Locator(1), Register::Locator(2), Register::Locator(3)],
         );
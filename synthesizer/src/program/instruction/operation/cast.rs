@@ -84,7 +84,7 @@ impl<N: Network> Cast<N> {
         // Load the operands values.
         let inputs: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
 
-        match self.register_type {
+        match &self.register_type {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Ensure the operands length is at least the minimum.
@@ -109,7 +109,7 @@ impl<N: Network> Cast<N> {
                 let mut members = IndexMap::new();
                 for (member, (member_name, member_type)) in inputs.iter().zip_eq(struct_.members()) {
                     // Compute the register type.
-                    let register_type = RegisterType::Plaintext(*member_type);
+                    let register_type = RegisterType::Plaintext(member_type.clone());
                     // Retrieve the plaintext value from the entry.
                     let plaintext = match member {
                         Value::Plaintext(plaintext) => {
@@ -130,6 +130,42 @@ impl<N: Network> Cast<N> {
                 // Store the struct.
                 registers.store(stack, &self.destination, Value::Plaintext(struct_))
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure that the number of operands is equal to the length of the array.
+                if inputs.len() != **array_type.length() as usize {
+                    bail!(
+                        "Casting to the array {array_type} requires {} operands, but {} were provided",
+                        **array_type.length(),
+                        inputs.len()
+                    )
+                }
+
+                // Compute the register type of the elements.
+                let register_type = RegisterType::Plaintext(array_type.element_type().clone());
+
+                // Initialize the array elements.
+                let mut elements = Vec::with_capacity(inputs.len());
+                for element in inputs.iter() {
+                    // Retrieve the plaintext value from the entry.
+                    let plaintext = match element {
+                        Value::Plaintext(plaintext) => {
+                            // Ensure the element matches the register type.
+                            stack.matches_register_type(&Value::Plaintext(plaintext.clone()), &register_type)?;
+                            // Output the plaintext.
+                            plaintext.clone()
+                        }
+                        // Ensure the array element is not a record.
+                        Value::Record(..) => bail!("Casting a record into an array element is illegal"),
+                    };
+                    // Append the element to the array elements.
+                    elements.push(plaintext);
+                }
+
+                // Construct the array.
+                let array = Plaintext::Array(elements, Default::default());
+                // Store the array.
+                registers.store(stack, &self.destination, Value::Plaintext(array))
+            }
             RegisterType::Record(record_name) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_RECORD_ENTRIES {
@@ -167,7 +203,7 @@ impl<N: Network> Cast<N> {
                     inputs.iter().skip(N::MIN_RECORD_ENTRIES).zip_eq(record_type.entries())
                 {
                     // Compute the register type.
-                    let register_type = RegisterType::from(ValueType::from(*entry_type));
+                    let register_type = RegisterType::from(ValueType::from(entry_type.clone()));
                     // Retrieve the plaintext value from the entry.
                     let plaintext = match entry {
                         Value::Plaintext(plaintext) => {
@@ -218,7 +254,7 @@ impl<N: Network> Cast<N> {
         let inputs: Vec<_> =
             self.operands.iter().map(|operand| registers.load_circuit(stack, operand)).try_collect()?;
 
-        match self.register_type {
+        match &self.register_type {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
             RegisterType::Plaintext(PlaintextType::Struct(struct_)) => {
                 // Ensure the operands length is at least the minimum.
@@ -243,7 +279,7 @@ impl<N: Network> Cast<N> {
                 let mut members = IndexMap::new();
                 for (member, (member_name, member_type)) in inputs.iter().zip_eq(struct_.members()) {
                     // Compute the register type.
-                    let register_type = RegisterType::Plaintext(*member_type);
+                    let register_type = RegisterType::Plaintext(member_type.clone());
                     // Retrieve the plaintext value from the entry.
                     let plaintext = match member {
                         circuit::Value::Plaintext(plaintext) => {
@@ -269,6 +305,45 @@ impl<N: Network> Cast<N> {
                 // Store the struct.
                 registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(struct_))
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure that the number of operands is equal to the length of the array.
+                if inputs.len() != **array_type.length() as usize {
+                    bail!(
+                        "Casting to the array {array_type} requires {} operands, but {} were provided",
+                        **array_type.length(),
+                        inputs.len()
+                    )
+                }
+
+                // Compute the register type of the elements.
+                let register_type = RegisterType::Plaintext(array_type.element_type().clone());
+
+                // Initialize the array elements.
+                let mut elements = Vec::with_capacity(inputs.len());
+                for element in inputs.iter() {
+                    // Retrieve the plaintext value from the entry.
+                    let plaintext = match element {
+                        circuit::Value::Plaintext(plaintext) => {
+                            // Ensure the element matches the register type.
+                            stack.matches_register_type(
+                                &circuit::Value::Plaintext(plaintext.clone()).eject_value(),
+                                &register_type,
+                            )?;
+                            // Output the plaintext.
+                            plaintext.clone()
+                        }
+                        // Ensure the array element is not a record.
+                        circuit::Value::Record(..) => bail!("Casting a record into an array element is illegal"),
+                    };
+                    // Append the element to the array elements.
+                    elements.push(plaintext);
+                }
+
+                // Construct the array.
+                let array = circuit::Plaintext::Array(elements, Default::default());
+                // Store the array.
+                registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(array))
+            }
             RegisterType::Record(record_name) => {
                 // Ensure the operands length is at least the minimum.
                 if inputs.len() < N::MIN_RECORD_ENTRIES {
@@ -309,7 +384,7 @@ impl<N: Network> Cast<N> {
                     inputs.iter().skip(N::MIN_RECORD_ENTRIES).zip_eq(record_type.entries())
                 {
                     // Compute the register type.
-                    let register_type = RegisterType::from(ValueType::from(*entry_type));
+                    let register_type = RegisterType::from(ValueType::from(entry_type.clone()));
                     // Retrieve the plaintext value from the entry.
                     let plaintext = match entry {
                         circuit::Value::Plaintext(plaintext) => {
@@ -358,7 +433,7 @@ impl<N: Network> Cast<N> {
         // Load the operands values.
         let inputs: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
 
-        match self.register_type {
+        match &self.register_type {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Ensure the operands length is at least the minimum.
@@ -383,7 +458,7 @@ impl<N: Network> Cast<N> {
                 let mut members = IndexMap::new();
                 for (member, (member_name, member_type)) in inputs.iter().zip_eq(struct_.members()) {
                     // Compute the register type.
-                    let register_type = RegisterType::Plaintext(*member_type);
+                    let register_type = RegisterType::Plaintext(member_type.clone());
                     // Retrieve the plaintext value from the entry.
                     let plaintext = match member {
                         Value::Plaintext(plaintext) => {
@@ -404,6 +479,42 @@ impl<N: Network> Cast<N> {
                 // Store the struct.
                 registers.store(stack, &self.destination, Value::Plaintext(struct_))
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure that the number of operands is equal to the length of the array.
+                if inputs.len() != **array_type.length() as usize {
+                    bail!(
+                        "Casting to the array {array_type} requires {} operands, but {} were provided",
+                        **array_type.length(),
+                        inputs.len()
+                    )
+                }
+
+                // Compute the register type of the elements.
+                let register_type = RegisterType::Plaintext(array_type.element_type().clone());
+
+                // Initialize the array elements.
+                let mut elements = Vec::with_capacity(inputs.len());
+                for element in inputs.iter() {
+                    // Retrieve the plaintext value from the entry.
+                    let plaintext = match element {
+                        Value::Plaintext(plaintext) => {
+                            // Ensure the element matches the register type.
+                            stack.matches_register_type(&Value::Plaintext(plaintext.clone()), &register_type)?;
+                            // Output the plaintext.
+                            plaintext.clone()
+                        }
+                        // Ensure the array element is not a record.
+                        Value::Record(..) => bail!("Casting a record into an array element is illegal"),
+                    };
+                    // Append the element to the array elements.
+                    elements.push(plaintext);
+                }
+
+                // Construct the array.
+                let array = Plaintext::Array(elements, Default::default());
+                // Store the array.
+                registers.store(stack, &self.destination, Value::Plaintext(array))
+            }
             RegisterType::Record(_record_name) => {
                 bail!("Illegal operation: Cannot cast to a record in a finalize block.")
             }
@@ -426,7 +537,7 @@ impl<N: Network> Cast<N> {
         );
 
         // Ensure the output type is defined in the program.
-        match self.register_type {
+        match &self.register_type {
             RegisterType::Plaintext(PlaintextType::Literal(..)) => bail!("Casting to literal is currently unsupported"),
             RegisterType::Plaintext(PlaintextType::Struct(struct_name)) => {
                 // Retrieve the struct and ensure it is defined in the program.
@@ -466,6 +577,38 @@ impl<N: Network> Cast<N> {
                     }
                 }
             }
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => {
+                // Ensure that the number of input types is equal to the length of the array.
+                ensure!(
+                    input_types.len() == **array_type.length() as usize,
+                    "Casting to the array {array_type} requires {} operands, but {} were provided",
+                    **array_type.length(),
+                    input_types.len()
+                );
+                // Ensure the input types match the element type.
+                for input_type in input_types {
+                    match input_type {
+                        // Ensure the plaintext type matches the element type.
+                        RegisterType::Plaintext(plaintext_type) => {
+                            ensure!(
+                                array_type.element_type() == plaintext_type,
+                                "Array '{array_type}' element type mismatch: expected '{}', found '{plaintext_type}'",
+                                array_type.element_type()
+                            )
+                        }
+                        // Ensure the input type cannot be a record (this is unsupported behavior).
+                        RegisterType::Record(record_name) => bail!(
+                            "Array '{array_type}' element type mismatch: expected '{}', found record '{record_name}'",
+                            array_type.element_type()
+                        ),
+                        // Ensure the input type cannot be an external record (this is unsupported behavior).
+                        RegisterType::ExternalRecord(locator) => bail!(
+                            "Array '{array_type}' element type mismatch: expected '{}', found external record '{locator}'",
+                            array_type.element_type()
+                        ),
+                    }
+                }
+            }
             RegisterType::Record(record_name) => {
                 // Retrieve the record type and ensure is defined in the program.
                 let record = stack.program().get_record(&record_name)?;
@@ -522,7 +665,7 @@ impl<N: Network> Cast<N> {
             }
         }
 
-        Ok(vec![self.register_type])
+        Ok(vec![self.register_type.clone()])
     }
 }
 
@@ -684,7 +827,11 @@ impl<N: Network> ToBytes for Cast<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use console::{network::Testnet3, program::Identifier};
+    use console::{
+        network::Testnet3,
+        program::{Access, Identifier},
+        types::U32,
+    };
 
     type CurrentNetwork = Testnet3;
 
@@ -696,12 +843,12 @@ mod tests {
         assert_eq!(cast.operands.len(), 2, "The number of operands is incorrect");
         assert_eq!(
             cast.operands[0],
-            Operand::Register(Register::Member(0, vec![Identifier::from_str("owner").unwrap()])),
+            Operand::Register(Register::Access(0, vec![Access::Member(Identifier::from_str("owner").unwrap())])),
             "The first operand is incorrect"
         );
         assert_eq!(
             cast.operands[1],
-            Operand::Register(Register::Member(0, vec![Identifier::from_str("token_amount").unwrap()])),
+            Operand::Register(Register::Access(0, vec![Access::Member(Identifier::from_str("token_amount").unwrap())])),
             "The second operand is incorrect"
         );
         assert_eq!(cast.destination, Register::Locator(1), "The destination register is incorrect");
@@ -712,6 +859,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_cast_into_array() {
+        let (string, cast) = Cast::<CurrentNetwork>::parse("cast r0 r1[0u32] into r2 as [u8; 2u32]").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(cast.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(cast.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(
+            cast.operands[1],
+            Operand::Register(Register::Access(1, vec![Access::Index(U32::new(0))])),
+            "The second operand is incorrect"
+        );
+        assert_eq!(cast.destination, Register::Locator(2), "The destination register is incorrect");
+        assert_eq!(
+            cast.register_type,
+            RegisterType::Plaintext(PlaintextType::from_str("[u8; 2u32]").unwrap()),
+            "The value type is incorrect"
+        );
+        assert_eq!(cast.to_string(), "cast r0 r1[0u32] into r2 as [u8; 2u32]");
+    }
+
     #[test]
     fn test_parse_cast_into_plaintext_max_operands() {
         let mut string = "cast ".to_string();
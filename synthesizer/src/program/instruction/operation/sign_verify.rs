@@ -0,0 +1,356 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Load, LoadCircuit, Opcode, Operand, Stack, Store, StoreCircuit};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, PlaintextType, Register, RegisterType},
+    types::Boolean,
+};
+
+/// Verifies the signature `first` against the address `second` and the message `third`,
+/// storing the outcome in `destination`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SignVerify<N: Network> {
+    /// The operands as `(signature, address, message)`.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> SignVerify<N> {
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Sign("sign.verify")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly three inputs.
+        debug_assert!(self.operands.len() == 3, "Instruction '{}' must have three operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> SignVerify<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(&self, stack: &Stack<N>, registers: &mut (impl Load<N> + Store<N>)) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the signature.
+        let signature = match registers.load_literal(stack, &self.operands[0])? {
+            Literal::Signature(signature) => signature,
+            _ => bail!("Instruction '{}' expects the first operand to be a signature", Self::opcode()),
+        };
+        // Retrieve the address.
+        let address = match registers.load_literal(stack, &self.operands[1])? {
+            Literal::Address(address) => address,
+            _ => bail!("Instruction '{}' expects the second operand to be an address", Self::opcode()),
+        };
+        // Retrieve the message.
+        let message = registers.load(stack, &self.operands[2])?;
+
+        // Verify the signature.
+        let output = signature.verify(&address, &message.to_fields()?);
+        // Store the output.
+        registers.store_literal(stack, &self.destination, Literal::Boolean(Boolean::new(output)))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &Stack<N>,
+        registers: &mut (impl LoadCircuit<N, A> + StoreCircuit<N, A>),
+    ) -> Result<()> {
+        use circuit::ToFields;
+
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the signature.
+        let signature = match registers.load_literal_circuit(stack, &self.operands[0])? {
+            circuit::Literal::Signature(signature) => signature,
+            _ => bail!("Instruction '{}' expects the first operand to be a signature", Self::opcode()),
+        };
+        // Retrieve the address.
+        let address = match registers.load_literal_circuit(stack, &self.operands[1])? {
+            circuit::Literal::Address(address) => address,
+            _ => bail!("Instruction '{}' expects the second operand to be an address", Self::opcode()),
+        };
+        // Retrieve the message.
+        let message = registers.load_circuit(stack, &self.operands[2])?;
+
+        // Verify the signature.
+        let output = signature.verify(&address, &message.to_fields());
+        // Store the output.
+        registers.store_literal_circuit(stack, &self.destination, circuit::Literal::Boolean(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(&self, stack: &Stack<N>, registers: &mut (impl Load<N> + Store<N>)) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(&self, _stack: &Stack<N>, input_types: &[RegisterType<N>]) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 3 {
+            bail!("Instruction '{}' expects 3 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 3 {
+            bail!("Instruction '{}' expects 3 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Ensure the first input type is a signature.
+        if input_types[0] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Signature)) {
+            bail!(
+                "Instruction '{}' expects the first input to be a 'signature'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[0]
+            )
+        }
+        // Ensure the second input type is an address.
+        if input_types[1] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Address)) {
+            bail!(
+                "Instruction '{}' expects the second input to be an 'address'. Found input of type '{}'",
+                Self::opcode(),
+                input_types[1]
+            )
+        }
+        // Ensure the third input type is a plaintext.
+        if !matches!(input_types[2], RegisterType::Plaintext(..)) {
+            bail!(
+                "Instruction '{}' expects the third input to be a plaintext. Found input of type '{}'",
+                Self::opcode(),
+                input_types[2]
+            )
+        }
+
+        Ok(vec![RegisterType::Plaintext(PlaintextType::Literal(LiteralType::Boolean))])
+    }
+}
+
+impl<N: Network> Parser for SignVerify<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the third operand from the string.
+        let (string, third) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second, third], destination }))
+    }
+}
+
+impl<N: Network> FromStr for SignVerify<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for SignVerify<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for SignVerify<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            eprintln!("The number of operands must be 3, found {}", self.operands.len());
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for SignVerify<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(3);
+        // Read the operands.
+        for _ in 0..3 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for SignVerify<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 3.
+        if self.operands.len() != 3 {
+            return Err(error(format!("The number of operands must be 3, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Authorization, CallStack, Process, Program, Registers};
+    use circuit::{AleoV0, Eject};
+    use console::{
+        account::{Address, PrivateKey, Signature},
+        network::Testnet3,
+        program::{Identifier, Plaintext, Value},
+        types::Field,
+    };
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = AleoV0;
+
+    #[test]
+    fn test_parse() {
+        let (string, sign) = SignVerify::<CurrentNetwork>::parse("sign.verify r0 r1 r2 into r3").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(sign.operands.len(), 3, "The number of operands is incorrect");
+        assert_eq!(sign.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(sign.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(sign.operands[2], Operand::Register(Register::Locator(2)), "The third operand is incorrect");
+        assert_eq!(sign.destination, Register::Locator(3), "The destination register is incorrect");
+    }
+
+    #[test]
+    fn test_sign_verify() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            "program testing.aleo;
+            function run:
+                input r0 as signature.private;
+                input r1 as address.private;
+                input r2 as field.private;
+                sign.verify r0 r1 r2 into r3;",
+        )?;
+        // Initialize the stack.
+        let stack = crate::Stack::new(&Process::load()?, &program)?;
+        // Initialize the operation.
+        let operation = SignVerify::<CurrentNetwork>::from_str("sign.verify r0 r1 r2 into r3")?;
+
+        // Sample a signer, and sign a message.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let address = Address::try_from(&private_key)?;
+        let message = Field::<CurrentNetwork>::rand(rng);
+        let plaintext = Plaintext::from(Literal::Field(message));
+        let signature = Signature::sign(&private_key, &plaintext.to_fields()?, rng)?;
+
+        // Ensure the signature verifies for the signed message, and fails for another message.
+        for (message, expected) in [(message, true), (Field::rand(rng), false)] {
+            let inputs = [Literal::Signature(Box::new(signature)), Literal::Address(address), Literal::Field(message)]
+                .map(|literal| Value::Plaintext(Plaintext::from(literal)));
+
+            // Initialize the registers.
+            let mut registers = Registers::<CurrentNetwork, CurrentAleo>::new(
+                CallStack::evaluate(Authorization::new(&[]))?,
+                stack.get_register_types(&Identifier::from_str("run")?)?.clone(),
+            );
+            for (index, input) in inputs.iter().enumerate() {
+                use circuit::Inject;
+
+                let register = Register::Locator(index as u64);
+                registers.store(&stack, &register, input.clone())?;
+                registers.store_circuit(
+                    &stack,
+                    &register,
+                    circuit::Value::new(circuit::Mode::Private, input.clone()),
+                )?;
+            }
+
+            // Evaluate the instruction.
+            operation.evaluate(&stack, &mut registers)?;
+            let output = registers.load_literal(&stack, &Operand::Register(Register::Locator(3)))?;
+            assert_eq!(output, Literal::Boolean(Boolean::new(expected)));
+
+            // Execute the instruction.
+            operation.execute::<CurrentAleo>(&stack, &mut registers)?;
+            let output = registers.load_literal_circuit(&stack, &Operand::Register(Register::Locator(3)))?;
+            assert_eq!(output.eject_value(), Literal::Boolean(Boolean::new(expected)));
+            assert!(<CurrentAleo as circuit::Environment>::is_satisfied());
+
+            // Reset the circuit.
+            <CurrentAleo as circuit::Environment>::reset();
+        }
+        Ok(())
+    }
+}
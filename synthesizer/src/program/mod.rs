@@ -104,6 +104,10 @@ impl<N: Network> Program<N> {
             r"
 program credits.aleo;
 
+mapping account:
+    key owner as address.public;
+    value microcredits as u64.public;
+
 record credits:
     owner as address.private;
     microcredits as u64.private;
@@ -147,6 +151,70 @@ function fee:
     sub r0.microcredits r1 into r2;
     cast r0.owner r2 into r3 as credits.record;
     output r3 as credits.record;
+
+function transfer_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize self.caller r0 r1;
+
+finalize transfer_public:
+    input r0 as address.public;
+    input r1 as address.public;
+    input r2 as u64.public;
+    get.or_init account[r0] 0u64 into r3;
+    gte r3 r2 into r4;
+    assert.eq r4 true;
+    sub r3 r2 into r5;
+    set r5 into account[r0];
+    get.or_init account[r1] 0u64 into r6;
+    add r6 r2 into r7;
+    set r7 into account[r1];
+
+function transfer_private_to_public:
+    input r0 as credits.record;
+    input r1 as address.public;
+    input r2 as u64.public;
+    sub r0.microcredits r2 into r3;
+    cast r0.owner r3 into r4 as credits.record;
+    output r4 as credits.record;
+    finalize r1 r2;
+
+finalize transfer_private_to_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_init account[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into account[r0];
+
+function transfer_public_to_private:
+    input r0 as address.private;
+    input r1 as u64.public;
+    cast r0 r1 into r2 as credits.record;
+    output r2 as credits.record;
+    finalize self.caller r1;
+
+finalize transfer_public_to_private:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_init account[r0] 0u64 into r2;
+    gte r2 r1 into r3;
+    assert.eq r3 true;
+    sub r2 r1 into r4;
+    set r4 into account[r0];
+
+function fee_public:
+    input r0 as u64.public;
+    assert.neq r0 0u64;
+    finalize self.caller r0;
+
+finalize fee_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_init account[r0] 0u64 into r2;
+    gte r2 r1 into r3;
+    assert.eq r3 true;
+    sub r2 r1 into r4;
+    set r4 into account[r0];
 ",
         )
     }
@@ -368,6 +436,14 @@ impl<N: Network> Program<N> {
                         bail!("'{member_identifier}' in struct '{}' is not defined.", struct_name)
                     }
                 }
+                PlaintextType::Array(array_type) => {
+                    // Ensure the base element struct name exists in the program.
+                    if let PlaintextType::Struct(member_identifier) = array_type.base_element_type() {
+                        if !self.structs.contains_key(member_identifier) {
+                            bail!("'{member_identifier}' in struct '{}' is not defined.", struct_name)
+                        }
+                    }
+                }
             }
         }
 
@@ -418,6 +494,13 @@ impl<N: Network> Program<N> {
                             bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
                         }
                     }
+                    PlaintextType::Array(array_type) => {
+                        if let PlaintextType::Struct(identifier) = array_type.base_element_type() {
+                            if !self.structs.contains_key(identifier) {
+                                bail!("Struct '{identifier}' in record '{record_name}' is not defined.")
+                            }
+                        }
+                    }
                 },
             }
         }
@@ -550,6 +633,7 @@ impl<N: Network> Program<N> {
         "u128",
         "scalar",
         "string",
+        "signature",
         // Boolean
         "true",
         "false",
@@ -801,6 +885,29 @@ function swap:
         Ok(())
     }
 
+    #[test]
+    fn test_program_credits() -> Result<()> {
+        // Load the credits program.
+        let program = Program::<CurrentNetwork>::credits()?;
+
+        // Ensure the public balances mapping exists.
+        assert!(program.contains_mapping(&Identifier::from_str("account")?));
+
+        // Ensure the public functions exist and finalize.
+        for name in ["transfer_public", "transfer_private_to_public", "transfer_public_to_private", "fee_public"] {
+            let function = program.get_function(&Identifier::from_str(name)?)?;
+            assert!(function.finalize().is_some(), "'{name}' should have a finalize scope");
+        }
+
+        // Ensure the private functions do not finalize.
+        for name in ["mint", "transfer", "join", "split", "fee"] {
+            let function = program.get_function(&Identifier::from_str(name)?)?;
+            assert!(function.finalize().is_none(), "'{name}' should not have a finalize scope");
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_evaluate_function() {
         let program = Program::<CurrentNetwork>::from_str(
@@ -926,6 +1033,63 @@ function compute:
         assert_eq!(expected, candidate[0]);
     }
 
+    #[test]
+    fn test_program_evaluate_array() {
+        // Initialize a new program.
+        let (string, program) = Program::<CurrentNetwork>::parse(
+            r"
+program example.aleo;
+
+struct message:
+    values as [field; 2u32];
+
+function compute:
+    input r0 as [field; 2u32].private;
+    input r1 as message.private;
+    add r0[0u32] r1.values[1u32] into r2;
+    cast r2 r0[1u32] into r3 as [field; 2u32];
+    output r3 as [field; 2u32].private;",
+        )
+        .unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+
+        // Declare the function name.
+        let function_name = Identifier::from_str("compute").unwrap();
+        // Declare the input values.
+        let r0 = Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("[1field, 2field]").unwrap());
+        let r1 = Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("{ values: [3field, 4field] }").unwrap());
+        // Declare the expected output value.
+        let expected = Value::Plaintext(Plaintext::from_str("[5field, 2field]").unwrap());
+
+        // Construct the process.
+        let process = crate::process::test_helpers::sample_process(&program);
+
+        // Compute the authorization.
+        let authorization = {
+            // Initialize an RNG.
+            let rng = &mut TestRng::default();
+
+            // Initialize caller private key.
+            let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+            // Authorize the function call.
+            let authorization = process
+                .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, [r0, r1].iter(), rng)
+                .unwrap();
+            assert_eq!(authorization.len(), 1);
+            authorization
+        };
+
+        // Retrieve the stack.
+        let stack = process.get_stack(program.id()).unwrap();
+
+        // Compute the output value.
+        let response = stack.evaluate_function::<CurrentAleo>(CallStack::evaluate(authorization).unwrap()).unwrap();
+        let candidate = response.outputs();
+        assert_eq!(1, candidate.len());
+        assert_eq!(expected, candidate[0]);
+    }
+
     #[test]
     fn test_program_evaluate_record_and_function() {
         // Initialize a new program.
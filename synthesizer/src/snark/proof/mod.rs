@@ -57,7 +57,7 @@ mod tests {
                 // Sample a transition.
                 let transition = crate::process::test_helpers::sample_transition();
                 // Return the proof.
-                transition.proof().clone()
+                transition.proof().unwrap().clone()
             })
             .clone()
     }
@@ -74,6 +74,29 @@ impl<N: Network> ProvingKey<N> {
         println!("{}", format!(" • Executed '{function_name}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
         Ok(batch_proof)
     }
+
+    /// Returns a proof for the given batches of assignments on one or more circuits.
+    pub fn prove_multi_batch<R: Rng + CryptoRng>(
+        locator: &str,
+        keys_to_assignments: &[(&ProvingKey<N>, &[circuit::Assignment<N::Field>])],
+        rng: &mut R,
+    ) -> Result<Proof<N>> {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Prepare the circuit proving keys.
+        let keys_to_assignments = keys_to_assignments
+            .iter()
+            .map(|(proving_key, assignments)| (proving_key.proving_key.as_ref(), *assignments))
+            .collect::<Vec<_>>();
+        // Compute the batch proof.
+        let batch_proof =
+            Proof::new(Marlin::<N>::prove_multi_batch(N::marlin_fs_parameters(), &keys_to_assignments, rng)?);
+
+        #[cfg(feature = "aleo-cli")]
+        println!("{}", format!(" • Executed '{locator}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
+        Ok(batch_proof)
+    }
 }
 
 impl<N: Network> Deref for ProvingKey<N> {
@@ -81,6 +81,39 @@ impl<N: Network> VerifyingKey<N> {
             }
         }
     }
+
+    /// Returns `true` if the batch proof is valid for the given public inputs on one or more circuits.
+    pub fn verify_multi_batch(
+        locator: &str,
+        keys_to_inputs: &[(&VerifyingKey<N>, &[Vec<N::Field>])],
+        proof: &Proof<N>,
+    ) -> bool {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Prepare the circuit verifying keys.
+        let keys_to_inputs = keys_to_inputs
+            .iter()
+            .map(|(verifying_key, inputs)| (verifying_key.verifying_key.as_ref(), *inputs))
+            .collect::<Vec<_>>();
+        // Verify the batch proof.
+        match Marlin::<N>::verify_multi_batch(N::marlin_fs_parameters(), &keys_to_inputs, proof) {
+            Ok(is_valid) => {
+                #[cfg(feature = "aleo-cli")]
+                {
+                    let elapsed = timer.elapsed().as_millis();
+                    println!("{}", format!(" • Verified '{locator}' (in {elapsed} ms)").dimmed());
+                }
+
+                is_valid
+            }
+            Err(error) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verifier failed: {error}").dimmed());
+                false
+            }
+        }
+    }
 }
 
 impl<N: Network> Deref for VerifyingKey<N> {
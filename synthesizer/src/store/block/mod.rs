@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod scan;
+pub use scan::*;
+
 use crate::{
     atomic_write_batch,
     block::{Block, Header, Transactions},
@@ -288,6 +291,76 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         Ok(())
     }
 
+    /// Removes the entries of the block at the given `block height` that are present in storage.
+    /// Unlike `remove`, this method tolerates missing entries, and is used to roll back a partially written block.
+    fn remove_incomplete(&self, block_height: u32) -> Result<()> {
+        // Retrieve the block hash, if it exists.
+        let block_hash = self.get_block_hash(block_height)?;
+        // Retrieve the state root, if it exists.
+        let state_root = self.state_root_map().get(&block_height)?.map(|state_root| cow_to_copied!(state_root));
+        // Retrieve the transaction IDs, falling back to the reverse transaction IDs if they are missing.
+        let transaction_ids = match &block_hash {
+            Some(block_hash) => match self.transactions_map().get(block_hash)? {
+                Some(transaction_ids) => cow_to_cloned!(transaction_ids),
+                None => self
+                    .reverse_transactions_map()
+                    .iter()
+                    .filter(|(_, hash)| **hash == *block_hash)
+                    .map(|(transaction_id, _)| cow_to_copied!(transaction_id))
+                    .collect(),
+            },
+            None => vec![],
+        };
+        // Retrieve the coinbase solution, if it exists.
+        let coinbase = match &block_hash {
+            Some(block_hash) => self.coinbase_solution_map().get(block_hash)?.and_then(|c| cow_to_cloned!(c)),
+            None => None,
+        };
+
+        atomic_write_batch!(self, {
+            // Remove the (block height, state root) pair.
+            self.state_root_map().remove(&block_height)?;
+            // Remove the (state root, block height) pair.
+            if let Some(state_root) = &state_root {
+                self.reverse_state_root_map().remove(state_root)?;
+            }
+            // Remove the block hash.
+            self.id_map().remove(&block_height)?;
+
+            if let Some(block_hash) = &block_hash {
+                // Remove the block height.
+                self.reverse_id_map().remove(block_hash)?;
+                // Remove the block header.
+                self.header_map().remove(block_hash)?;
+                // Remove the transaction IDs.
+                self.transactions_map().remove(block_hash)?;
+                // Remove the block coinbase solution.
+                self.coinbase_solution_map().remove(block_hash)?;
+                // Remove the block signature.
+                self.signature_map().remove(block_hash)?;
+            }
+
+            // Remove the block transactions.
+            for transaction_id in &transaction_ids {
+                // Remove the reverse transaction ID.
+                self.reverse_transactions_map().remove(transaction_id)?;
+                // Remove the entries of the transaction that are present in storage.
+                self.transaction_store().remove_incomplete(transaction_id)?;
+            }
+
+            // Remove the block coinbase puzzle commitment.
+            if let Some(coinbase) = &coinbase {
+                for puzzle_commitment in coinbase.partial_solutions().iter().map(|s| s.commitment()) {
+                    self.coinbase_puzzle_commitment_map().remove(&puzzle_commitment)?;
+                }
+            }
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
     /// Returns the block height that contains the given `state root`.
     fn find_block_height_from_state_root(&self, state_root: N::StateRoot) -> Result<Option<u32>> {
         match self.reverse_state_root_map().get(&state_root)? {
@@ -518,6 +591,64 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         // Return the block.
         Ok(Some(Block::from(previous_hash, header, transactions, coinbase, signature)?))
     }
+
+    /// Checks that the block at the given `block height` is complete, and that its reverse indexes are consistent.
+    fn check_block(&self, block_height: u32) -> Result<()> {
+        // Retrieve the block hash.
+        let block_hash = match self.get_block_hash(block_height)? {
+            Some(block_hash) => block_hash,
+            None => bail!("Missing block hash for block {block_height}"),
+        };
+        // Ensure the reverse block hash entry points to the block height.
+        if self.get_block_height(&block_hash)? != Some(block_height) {
+            bail!("Invalid reverse entry for block {block_height} ('{block_hash}')")
+        }
+
+        // Retrieve the state root.
+        let state_root = match self.get_state_root(block_height)? {
+            Some(state_root) => state_root,
+            None => bail!("Missing state root for block {block_height} ('{block_hash}')"),
+        };
+        // Ensure the reverse state root entry points to the block height.
+        if self.find_block_height_from_state_root(state_root)? != Some(block_height) {
+            bail!("Invalid reverse state root entry for block {block_height} ('{block_hash}')")
+        }
+
+        // Retrieve the block.
+        let block = match self.get_block(&block_hash)? {
+            Some(block) => block,
+            None => bail!("Missing block {block_height} ('{block_hash}')"),
+        };
+        // Ensure the block hash matches.
+        if block.hash() != block_hash {
+            bail!("Mismatching block hash for block {block_height} ('{block_hash}')")
+        }
+        // Ensure the transactions match the block header.
+        if block.transactions().to_root()? != block.transactions_root() {
+            bail!("Mismatching transactions root for block {block_height} ('{block_hash}')")
+        }
+
+        // Check the block transactions.
+        for transaction in block.transactions().values() {
+            // Ensure the reverse transaction ID entry points to the block.
+            if self.find_block_hash(&transaction.id())? != Some(block_hash) {
+                bail!("Invalid reverse entry for transaction '{}' in block {block_height}", transaction.id())
+            }
+            // Check the transaction.
+            self.transaction_store().check_integrity(transaction)?;
+        }
+
+        // Ensure the reverse puzzle commitment entries point to the block.
+        if let Some(coinbase) = block.coinbase() {
+            for puzzle_commitment in coinbase.partial_solutions().iter().map(|s| s.commitment()) {
+                if self.find_block_hash_from_puzzle_commitment(&puzzle_commitment)? != Some(block_hash) {
+                    bail!("Invalid reverse entry for puzzle commitment '{puzzle_commitment}' in block {block_height}")
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// An in-memory block storage.
@@ -745,6 +876,127 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         Ok(())
     }
 
+    /// Removes the latest block from storage, tolerating any of its entries being missing.
+    /// This method is used to roll back a block that was only partially written.
+    pub fn remove_last_incomplete(&self) -> Result<()> {
+        // Acquire the write lock on the block tree.
+        let mut tree = self.tree.write();
+
+        // Determine the block height to remove.
+        let height = match self.storage.id_map().keys().max() {
+            Some(height) => cow_to_copied!(height),
+            None => bail!("Failed to remove the latest block: no blocks in storage"),
+        };
+        // Ensure the block height matches the number of leaves in the Merkle tree.
+        ensure!(height == u32::try_from(tree.number_of_leaves())? - 1, "Block height mismatch");
+
+        // Prepare an updated Merkle tree removing the latest block hash.
+        let updated_tree = tree.prepare_remove_last_n(1)?;
+
+        // Remove the block.
+        self.storage.remove_incomplete(height)?;
+
+        // Update the block tree.
+        *tree = updated_tree;
+        // Return success.
+        Ok(())
+    }
+
+    /// Checks that the block at the given `block height` is complete, and that its reverse indexes are consistent.
+    /// If the block is the latest block, this method also ensures its state root matches the block tree.
+    pub fn check_block(&self, block_height: u32) -> Result<()> {
+        // Check the block.
+        self.storage.check_block(block_height)?;
+
+        // Acquire the read lock on the block tree.
+        let tree = self.tree.read();
+        // If this is the latest block, ensure the state root matches the block tree.
+        if u32::try_from(tree.number_of_leaves())? == block_height.saturating_add(1) {
+            let state_root = self.storage.get_state_root(block_height)?;
+            ensure!(state_root == Some((*tree.root()).into()), "Mismatching state root for block {block_height}");
+        }
+        Ok(())
+    }
+
+    /// Checks the integrity of the block store. This method ensures every block is complete,
+    /// every state root matches the block tree, and every reverse index points to a stored block.
+    ///
+    /// Note: This method walks the entire ledger, and is intended for offline diagnostics.
+    pub fn check_integrity(&self) -> Result<()> {
+        // Acquire the read lock on the block tree.
+        let tree = self.tree.read();
+        // Retrieve the number of blocks.
+        let num_blocks = u32::try_from(tree.number_of_leaves())?;
+
+        // Ensure there are no blocks beyond the block tree.
+        ensure!(self.storage.id_map().keys().count() == tree.number_of_leaves(), "Found a gap in the block hashes");
+        ensure!(
+            self.storage.state_root_map().keys().count() == tree.number_of_leaves(),
+            "Found a gap in the state roots"
+        );
+
+        // Recompute the block tree, ensuring each state root matches the block tree at its height.
+        let mut block_tree: BlockTree<N> = N::merkle_tree_bhp(&[])?;
+        for block_height in 0..num_blocks {
+            // Check the block.
+            self.storage.check_block(block_height)?;
+            // Retrieve the block hash.
+            let block_hash = match self.storage.get_block_hash(block_height)? {
+                Some(block_hash) => block_hash,
+                None => bail!("Missing block hash for block {block_height}"),
+            };
+            // Append the block hash to the block tree.
+            block_tree = block_tree.prepare_append(&[block_hash.to_bits_le()])?;
+            // Ensure the state root matches the block tree.
+            if self.storage.get_state_root(block_height)? != Some((*block_tree.root()).into()) {
+                bail!("Mismatching state root for block {block_height} ('{block_hash}')")
+            }
+        }
+        // Ensure the recomputed block tree matches the block tree.
+        ensure!(block_tree.root() == tree.root(), "Mismatching block tree root");
+
+        // Ensure every reverse block hash entry points to a stored block.
+        for (block_hash, block_height) in self.storage.reverse_id_map().iter() {
+            if self.storage.get_block_hash(*block_height)? != Some(*block_hash) {
+                bail!("Found a dangling reverse entry for block {} ('{}')", *block_height, *block_hash)
+            }
+        }
+        // Ensure every reverse state root entry points to a stored block.
+        for (state_root, block_height) in self.storage.reverse_state_root_map().iter() {
+            if self.storage.get_state_root(*block_height)? != Some(*state_root) {
+                bail!("Found a dangling reverse state root entry for block {}", *block_height)
+            }
+        }
+        // Ensure every reverse transaction ID entry points to a stored block.
+        for (transaction_id, block_hash) in self.storage.reverse_transactions_map().iter() {
+            match self.storage.transactions_map().get(&block_hash)? {
+                Some(transaction_ids) if transaction_ids.contains(&transaction_id) => (),
+                _ => bail!("Found a dangling reverse entry for transaction '{}'", *transaction_id),
+            }
+        }
+        // Ensure every reverse puzzle commitment entry points to a stored block.
+        for (puzzle_commitment, block_hash) in self.storage.coinbase_puzzle_commitment_map().iter() {
+            if !self.storage.reverse_id_map().contains_key(&block_hash)? {
+                bail!("Found a dangling reverse entry for puzzle commitment '{}'", *puzzle_commitment)
+            }
+        }
+
+        // Ensure every stored transaction belongs to a block.
+        for transaction_id in self.transaction_store().transaction_ids() {
+            if self.storage.find_block_hash(&transaction_id)?.is_none() {
+                bail!("Found a transaction '{}' that does not belong to a block", *transaction_id)
+            }
+        }
+        // Ensure every stored transition belongs to a transaction.
+        for transition_id in self.transition_store().transition_ids() {
+            if self.transaction_store().find_transaction_id_from_transition_id(&transition_id)?.is_none() {
+                bail!("Found a transition '{}' that does not belong to a transaction", *transition_id)
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns the transaction store.
     pub fn transaction_store(&self) -> &TransactionStore<N, B::TransactionStorage> {
         self.storage.transaction_store()
@@ -869,6 +1121,12 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.reverse_state_root_map().contains_key(state_root)
     }
 
+    /// Returns the height of the latest block, or `None` if there are no blocks in storage.
+    /// Note: The height is derived from the block tree, so this method does not iterate over storage.
+    pub fn max_height(&self) -> Option<u32> {
+        u32::try_from(self.tree.read().number_of_leaves()).ok()?.checked_sub(1)
+    }
+
     /// Returns `true` if the given block height exists.
     pub fn contains_block_height(&self, height: u32) -> Result<bool> {
         self.storage.id_map().contains_key(&height)
@@ -925,6 +1183,7 @@ mod tests {
         // Ensure the block does not exist.
         let candidate = block_store.get_block(&block_hash).unwrap();
         assert_eq!(None, candidate);
+        assert_eq!(None, block_store.max_height());
 
         // Insert the block.
         block_store.insert(&block).unwrap();
@@ -932,6 +1191,7 @@ mod tests {
         // Retrieve the block.
         let candidate = block_store.get_block(&block_hash).unwrap();
         assert_eq!(Some(block), candidate);
+        assert_eq!(Some(0), block_store.max_height());
 
         // Remove the block.
         block_store.remove_last_n(1).unwrap();
@@ -939,6 +1199,7 @@ mod tests {
         // Ensure the block does not exist.
         let candidate = block_store.get_block(&block_hash).unwrap();
         assert_eq!(None, candidate);
+        assert_eq!(None, block_store.max_height());
     }
 
     #[test]
@@ -981,4 +1242,111 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[test]
+    fn test_remove_last_incomplete() {
+        let mut rng = TestRng::default();
+
+        // Sample the block.
+        let block = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+        let block_hash = block.hash();
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Emulate a partial write, by removing the state root and the transaction IDs of the block.
+        block_store.storage.state_root_map().remove(&0).unwrap();
+        block_store.storage.transactions_map().remove(&block_hash).unwrap();
+        assert!(block_store.check_block(0).is_err());
+        assert!(block_store.remove_last_n(1).is_err());
+
+        // Remove the incomplete block.
+        block_store.remove_last_incomplete().unwrap();
+
+        // Ensure the block does not exist.
+        assert_eq!(None, block_store.get_block(&block_hash).unwrap());
+        assert_eq!(None, block_store.get_block_hash(0).unwrap());
+        assert_eq!(None, block_store.get_block_height(&block_hash).unwrap());
+
+        // Ensure a block can be inserted again.
+        block_store.insert(&block).unwrap();
+        block_store.check_block(0).unwrap();
+    }
+
+    #[test]
+    fn test_remove_last_incomplete_with_incomplete_transaction() {
+        let mut rng = TestRng::default();
+
+        // Sample the block.
+        let block = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+        let block_hash = block.hash();
+        let transaction = block.transactions().iter().next().unwrap();
+        let transition_id = *transaction.transition_ids().next().unwrap();
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Emulate a partial write, by removing the state root of the block and a transition of the transaction.
+        block_store.storage.state_root_map().remove(&0).unwrap();
+        block_store.transition_store().remove(&transition_id).unwrap();
+        assert!(block_store.transaction_store().get_transaction(&transaction.id()).is_err());
+
+        // Remove the incomplete block.
+        block_store.remove_last_incomplete().unwrap();
+
+        // Ensure the block and its transactions do not exist.
+        assert_eq!(None, block_store.get_block_hash(0).unwrap());
+        assert_eq!(0, block_store.transaction_store().transaction_ids().count());
+        assert_eq!(0, block_store.transition_store().transition_ids().count());
+        // Ensure the block store is consistent.
+        block_store.check_integrity().unwrap();
+
+        // Ensure a block can be inserted again.
+        block_store.insert(&block).unwrap();
+        assert_eq!(Some(block_hash), block_store.get_block_hash(0).unwrap());
+        block_store.check_integrity().unwrap();
+    }
+
+    #[test]
+    fn test_check_integrity() {
+        let mut rng = TestRng::default();
+
+        // Sample the block.
+        let block = crate::vm::test_helpers::sample_genesis_block(&mut rng);
+        let transaction_id = *block.transaction_ids().next().unwrap();
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<_, BlockMemory<_>>::open(None).unwrap();
+
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Ensure the block store is consistent.
+        block_store.check_block(0).unwrap();
+        block_store.check_integrity().unwrap();
+
+        // Remove the reverse transaction ID entry.
+        block_store.storage.reverse_transactions_map().remove(&transaction_id).unwrap();
+
+        // Ensure the block store is inconsistent.
+        assert!(block_store.check_block(0).is_err());
+        assert!(block_store.check_integrity().is_err());
+
+        // Restore the reverse transaction ID entry.
+        block_store.storage.reverse_transactions_map().insert(transaction_id, block.hash()).unwrap();
+        block_store.check_integrity().unwrap();
+
+        // Insert a dangling reverse block hash entry.
+        block_store.storage.reverse_id_map().insert(Default::default(), 1).unwrap();
+
+        // Ensure the block is consistent, but the block store is not.
+        block_store.check_block(0).unwrap();
+        assert!(block_store.check_integrity().is_err());
+    }
 }
@@ -0,0 +1,212 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use console::{
+    account::{PrivateKey, ViewKey},
+    program::{Plaintext, Record},
+};
+
+use core::ops::Range;
+
+/// A record owned by a view key, as found by `BlockStore::scan_records`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScannedRecord<N: Network> {
+    /// The block height of the record.
+    height: u32,
+    /// The commitment of the record.
+    commitment: Field<N>,
+    /// The decrypted record.
+    record: Record<N, Plaintext<N>>,
+    /// Whether the record is spent, if a private key was provided.
+    is_spent: Option<bool>,
+}
+
+impl<N: Network> ScannedRecord<N> {
+    /// Returns the block height of the record.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the commitment of the record.
+    pub const fn commitment(&self) -> &Field<N> {
+        &self.commitment
+    }
+
+    /// Returns the decrypted record.
+    pub const fn record(&self) -> &Record<N, Plaintext<N>> {
+        &self.record
+    }
+
+    /// Returns `Some(true)` if the record is spent, `Some(false)` if it is unspent,
+    /// and `None` if the spent status is unknown (i.e. no private key was provided).
+    pub const fn is_spent(&self) -> Option<bool> {
+        self.is_spent
+    }
+
+    /// Returns the decrypted record, consuming `self`.
+    pub fn into_record(self) -> Record<N, Plaintext<N>> {
+        self.record
+    }
+}
+
+impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
+    /// Returns the records owned by the given view key, for the blocks in the given range of heights.
+    ///
+    /// If a private key is provided, the serial number of each record is computed,
+    /// and the record is marked as spent if its serial number exists in the ledger.
+    pub fn scan_records(
+        &self,
+        view_key: &ViewKey<N>,
+        private_key: Option<&PrivateKey<N>>,
+        heights: Range<u32>,
+    ) -> Result<Vec<ScannedRecord<N>>> {
+        // Ensure the private key corresponds to the view key.
+        if let Some(private_key) = private_key {
+            ensure!(ViewKey::try_from(private_key)? == *view_key, "The private key does not match the view key");
+        }
+        // Compute the x-coordinate of the address, once for all records.
+        let address_x_coordinate = view_key.to_address().to_x_coordinate();
+
+        // Scan each block in the range.
+        let records = cfg_into_iter!(heights)
+            .map(|height| {
+                // Retrieve the block hash.
+                let block_hash = match self.get_block_hash(height)? {
+                    Some(block_hash) => block_hash,
+                    None => bail!("Missing block hash for block {height}"),
+                };
+                // Retrieve the block transactions.
+                let transactions = match self.get_block_transactions(&block_hash)? {
+                    Some(transactions) => transactions,
+                    None => bail!("Missing transactions for block {height}"),
+                };
+
+                // Decrypt the records that belong to the view key.
+                transactions
+                    .records()
+                    .filter(|(_, record)| record.is_owner_with_address_x_coordinate(view_key, &address_x_coordinate))
+                    .map(|(commitment, record)| {
+                        // Decrypt the record.
+                        let record = record.decrypt(view_key)?;
+                        // Determine whether the record is spent, if a private key was provided.
+                        let is_spent = match private_key {
+                            Some(private_key) => {
+                                let serial_number =
+                                    Record::<N, Plaintext<N>>::serial_number(*private_key, *commitment)?;
+                                Some(self.transition_store().contains_serial_number(&serial_number)?)
+                            }
+                            None => None,
+                        };
+                        Ok(ScannedRecord { height, commitment: *commitment, record, is_spent })
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Return the records, in order of block height.
+        Ok(records.into_iter().flatten().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::{Metadata, Transaction};
+    use console::program::Value;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_scan_records() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis block, and its owner.
+        let block = crate::vm::test_helpers::sample_genesis_block(rng);
+        let private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        block_store.insert(&block).unwrap();
+
+        // Scan the records, without a private key.
+        let records = block_store.scan_records(&view_key, None, 0..1).unwrap();
+        assert_eq!(records.len(), block.records().count());
+        for (scanned, (commitment, record)) in records.iter().zip(block.records()) {
+            assert_eq!(scanned.height(), 0);
+            assert_eq!(scanned.commitment(), commitment);
+            assert_eq!(scanned.record(), &record.decrypt(&view_key).unwrap());
+            assert_eq!(scanned.is_spent(), None);
+        }
+
+        // Scan the records, with a private key.
+        let records = block_store.scan_records(&view_key, Some(&private_key), 0..1).unwrap();
+        assert_eq!(records.len(), block.records().count());
+        assert!(records.iter().all(|record| record.is_spent() == Some(false)));
+
+        // Ensure a different view key does not find any records.
+        let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let other_view_key = ViewKey::try_from(&other_private_key).unwrap();
+        assert!(block_store.scan_records(&other_view_key, None, 0..1).unwrap().is_empty());
+
+        // Ensure a mismatched private key is rejected.
+        assert!(block_store.scan_records(&view_key, Some(&other_private_key), 0..1).is_err());
+        // Ensure a missing block is rejected.
+        assert!(block_store.scan_records(&view_key, None, 0..2).is_err());
+
+        // Spend the first record, by splitting it in a new block.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let inputs = [Value::Record(records[0].record().clone()), Value::from_str("1u64").unwrap()];
+        let transaction =
+            Transaction::execute(&vm, &private_key, ("credits.aleo", "split"), inputs.into_iter(), None, None, rng)
+                .unwrap();
+        let transactions = Transactions::from(&[transaction]);
+        let header = block.header();
+        let metadata = Metadata::new(
+            CurrentNetwork::ID,
+            1,
+            1,
+            header.total_supply_in_microcredits(),
+            header.cumulative_proof_target(),
+            header.coinbase_target(),
+            header.proof_target(),
+            header.last_coinbase_target(),
+            header.last_coinbase_timestamp(),
+            header.timestamp() + 1,
+        )
+        .unwrap();
+        let header = Header::from(
+            *block_store.current_state_root(),
+            transactions.to_root().unwrap(),
+            Field::zero(),
+            Field::zero(),
+            metadata,
+        )
+        .unwrap();
+        let next_block = Block::new(&private_key, block.hash(), header, transactions, None, rng).unwrap();
+        block_store.insert(&next_block).unwrap();
+
+        // Ensure only the first record is spent.
+        let records = block_store.scan_records(&view_key, Some(&private_key), 0..1).unwrap();
+        assert_eq!(records[0].is_spent(), Some(true));
+        assert!(records[1..].iter().all(|record| record.is_spent() == Some(false)));
+        // Ensure the outputs of the split are found, and are unspent.
+        let records = block_store.scan_records(&view_key, Some(&private_key), 1..2).unwrap();
+        assert_eq!(records.len(), 2);
+        assert!(records.iter().all(|record| record.height() == 1 && record.is_spent() == Some(false)));
+    }
+}
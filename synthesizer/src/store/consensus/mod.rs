@@ -14,31 +14,39 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::store::{
-    BlockMemory,
-    BlockStorage,
-    BlockStore,
-    ProgramMemory,
-    ProgramStorage,
-    ProgramStore,
-    TransactionMemory,
-    TransactionStorage,
-    TransactionStore,
-    TransitionMemory,
-    TransitionStorage,
-    TransitionStore,
+use crate::{
+    atomic_write_batch,
+    process::Deployment,
+    store::{
+        BlockMemory,
+        BlockStorage,
+        BlockStore,
+        ProgramMemory,
+        ProgramStorage,
+        ProgramStore,
+        TransactionMemory,
+        TransactionStorage,
+        TransactionStore,
+        TransitionMemory,
+        TransitionStorage,
+        TransitionStore,
+    },
 };
-use console::network::prelude::*;
+use console::{network::prelude::*, program::ProgramID};
 
 use anyhow::Result;
-use core::marker::PhantomData;
+use core::{marker::PhantomData, ops::RangeInclusive};
 
 /// A trait for consensus storage.
 pub trait ConsensusStorage<N: Network>: 'static + Clone + Send + Sync {
     /// The program storage.
     type ProgramStorage: ProgramStorage<N>;
     /// The block storage.
-    type BlockStorage: BlockStorage<N, TransactionStorage = Self::TransactionStorage, TransitionStorage = Self::TransitionStorage>;
+    type BlockStorage: BlockStorage<
+        N,
+        TransactionStorage = Self::TransactionStorage,
+        TransitionStorage = Self::TransitionStorage,
+    >;
     /// The transaction storage.
     type TransactionStorage: TransactionStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The transition storage.
@@ -141,11 +149,16 @@ pub struct ConsensusStore<N: Network, C: ConsensusStorage<N>> {
 
 impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
     /// Initializes the consensus store.
+    /// If the latest blocks in storage are incomplete, they are rolled back.
     pub fn open(dev: Option<u16>) -> Result<Self> {
         // Initialize the consensus storage.
         let storage = C::open(dev)?;
+        // Initialize the consensus store.
+        let store = Self { storage, _phantom: PhantomData };
+        // Roll back any incomplete blocks.
+        store.rollback_incomplete_blocks()?;
         // Return the consensus store.
-        Ok(Self { storage, _phantom: PhantomData })
+        Ok(store)
     }
 
     /// Initializes a consensus store from storage.
@@ -198,3 +211,133 @@ impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
         self.storage.dev()
     }
 }
+
+impl<N: Network, C: ConsensusStorage<N>> ConsensusStore<N, C> {
+    /// Checks that the block at the given `block height` is complete, and that its reverse indexes are consistent.
+    /// This method also ensures the mappings of every program deployed in the block are initialized.
+    pub fn check_block(&self, block_height: u32) -> Result<()> {
+        // Check the block.
+        self.block_store().check_block(block_height)?;
+
+        // Retrieve the block hash.
+        let block_hash = match self.block_store().get_block_hash(block_height)? {
+            Some(block_hash) => block_hash,
+            None => bail!("Missing block hash for block {block_height}"),
+        };
+        // Retrieve the block transactions.
+        let transactions = match self.block_store().get_block_transactions(&block_hash)? {
+            Some(transactions) => transactions,
+            None => bail!("Missing transactions for block {block_height} ('{block_hash}')"),
+        };
+        // Ensure the mappings of each deployed program are initialized.
+        for deployment in transactions.deployments() {
+            self.check_deployment_is_finalized(deployment)?;
+        }
+        Ok(())
+    }
+
+    /// Checks the integrity of the consensus store. This method ensures every block is complete,
+    /// every state root matches the block tree, every reverse index points to a stored entry,
+    /// and the program store is consistent with the deployments in the block store.
+    ///
+    /// Note: This method walks the entire ledger, and is intended for offline diagnostics.
+    pub fn check_integrity(&self) -> Result<()> {
+        // Check the block store.
+        self.block_store().check_integrity()?;
+        // Check the program store.
+        self.program_store().check_integrity()?;
+
+        // Ensure the mappings of each deployed program are initialized.
+        for transaction_id in self.transaction_store().deployment_transaction_ids() {
+            match self.transaction_store().get_deployment(&transaction_id)? {
+                Some(deployment) => self.check_deployment_is_finalized(&deployment)?,
+                None => bail!("Missing deployment for transaction '{}'", *transaction_id),
+            }
+        }
+        // Ensure each program in the program store was deployed.
+        // Note: `credits.aleo` is exempt, as its mappings are initialized by the VM instead of a deployment.
+        let credits_program_id = ProgramID::from_str("credits.aleo")?;
+        for program_id in self.program_store().program_ids() {
+            if *program_id != credits_program_id && !self.transaction_store().contains_program_id(&program_id)? {
+                bail!("Found program '{}' in the program store without a deployment", *program_id)
+            }
+        }
+        Ok(())
+    }
+
+    /// Ensures the mappings of the given deployment are initialized in the program store.
+    fn check_deployment_is_finalized(&self, deployment: &Deployment<N>) -> Result<()> {
+        // Retrieve the program ID.
+        let program_id = deployment.program_id();
+        // Ensure each mapping is initialized.
+        for mapping_name in deployment.program().mappings().keys() {
+            if !self.program_store().contains_mapping(program_id, mapping_name)? {
+                bail!("Mapping '{program_id}/{mapping_name}' was not finalized")
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the last 'n' blocks from storage, and reverts their changes to the program state.
+    pub fn remove_last_n(&self, n: u32) -> Result<()> {
+        // Determine the block heights to remove.
+        let heights = self.last_n_heights(n)?;
+
+        atomic_write_batch!(self, {
+            // Revert the changes to the program state, in descending order.
+            for block_height in heights.rev() {
+                self.program_store().revert_finalize_diff(block_height)?;
+            }
+            // Remove the blocks.
+            self.block_store().remove_last_n(n)
+        });
+
+        Ok(())
+    }
+
+    /// Returns the heights of the last 'n' blocks in storage.
+    pub(crate) fn last_n_heights(&self, n: u32) -> Result<RangeInclusive<u32>> {
+        // Ensure 'n' is non-zero.
+        ensure!(n > 0, "Cannot remove zero blocks");
+        // Determine the block heights.
+        match self.block_store().max_height() {
+            Some(end_height) => {
+                let start_height = end_height
+                    .checked_sub(n - 1)
+                    .ok_or_else(|| anyhow!("Failed to remove last '{n}' blocks: block height underflow"))?;
+                Ok(start_height..=end_height)
+            }
+            None => bail!("Failed to remove last '{n}' blocks: no blocks in storage"),
+        }
+    }
+
+    /// Rolls back the latest blocks, for as long as they are incomplete. An incomplete block is left behind
+    /// if the process is interrupted while a block is being written to a persistent storage backend.
+    ///
+    /// The finalized state of each rolled back block is reverted by replaying its finalize diff. The finalize diff
+    /// is written in the same atomic batch as the finalized state, so if it is missing, no state was finalized.
+    /// The entries of the block itself are removed even if some of them are missing from storage.
+    fn rollback_incomplete_blocks(&self) -> Result<()> {
+        while let Some(block_height) = self.block_store().max_height() {
+            // Check the latest block, stopping once it is complete.
+            let error = match self.check_block(block_height) {
+                Ok(()) => break,
+                Err(error) => error,
+            };
+            warn!("Rolling back incomplete block {block_height} - {error}");
+
+            // Determine whether the finalize diff of the block is available.
+            let has_finalize_diff = self.program_store().get_finalize_diff(block_height)?.is_some();
+
+            atomic_write_batch!(self, {
+                // Revert the changes to the program state.
+                if has_finalize_diff {
+                    self.program_store().revert_finalize_diff(block_height)?;
+                }
+                // Remove the block, tolerating any of its missing entries.
+                self.block_store().remove_last_incomplete()
+            });
+        }
+        Ok(())
+    }
+}
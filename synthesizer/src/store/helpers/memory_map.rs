@@ -192,6 +192,7 @@ impl<
 {
     type Iterator = core::iter::Map<btree_map::IntoIter<Vec<u8>, V>, fn((Vec<u8>, V)) -> (Cow<'a, K>, Cow<'a, V>)>;
     type Keys = core::iter::Map<btree_map::IntoKeys<Vec<u8>, V>, fn(Vec<u8>) -> Cow<'a, K>>;
+    type PendingIterator = std::vec::IntoIter<(Cow<'a, K>, Option<Cow<'a, V>>)>;
     type Values = core::iter::Map<btree_map::IntoValues<Vec<u8>, V>, fn(V) -> Cow<'a, V>>;
 
     ///
@@ -233,6 +234,25 @@ impl<
         if self.batch_in_progress.load(Ordering::SeqCst) { self.atomic_batch.lock().get(key).cloned() } else { None }
     }
 
+    ///
+    /// Returns an iterator visiting each key-value pair scheduled to be written as part of an atomic batch.
+    /// A key that is removed in the batch is paired with `None`.
+    ///
+    fn iter_pending(&'a self) -> Self::PendingIterator {
+        // Return early if there is no atomic batch in progress.
+        match self.batch_in_progress.load(Ordering::SeqCst) {
+            true => self
+                .atomic_batch
+                .lock()
+                .clone()
+                .into_iter()
+                .map(|(key, value)| (Cow::Owned(key), value.map(Cow::Owned)))
+                .collect::<Vec<_>>()
+                .into_iter(),
+            false => Vec::new().into_iter(),
+        }
+    }
+
     ///
     /// Returns an iterator visiting each key-value pair in the map.
     ///
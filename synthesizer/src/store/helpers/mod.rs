@@ -15,6 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 pub mod memory_map;
+pub mod overlay_map;
+
+#[cfg(feature = "rocks")]
+pub mod rocksdb;
 
 use console::network::prelude::*;
 
@@ -71,6 +75,7 @@ pub trait MapRead<
 {
     type Iterator: Iterator<Item = (Cow<'a, K>, Cow<'a, V>)>;
     type Keys: Iterator<Item = Cow<'a, K>>;
+    type PendingIterator: Iterator<Item = (Cow<'a, K>, Option<Cow<'a, V>>)>;
     type Values: Iterator<Item = Cow<'a, V>>;
 
     ///
@@ -102,6 +107,25 @@ pub trait MapRead<
         K: Borrow<Q>,
         Q: PartialEq + Eq + Hash + Serialize + ?Sized;
 
+    ///
+    /// Returns `true` if the given key exists in the atomic batch first, if it is scheduled
+    /// to be inserted or removed as part of it, or in the map, otherwise.
+    ///
+    fn contains_key_speculative<Q>(&self, key: &Q) -> Result<bool>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        // Return early in case of errors in order to not conceal them.
+        let map_value = self.contains_key(key)?;
+
+        // Return whether the key is inserted in the atomic batch, if it exists, or the map value, otherwise.
+        match self.get_batched(key) {
+            Some(value) => Ok(value.is_some()),
+            None => Ok(map_value),
+        }
+    }
+
     ///
     /// Returns the value for the given key from the atomic batch first, if it exists,
     /// or return from the map, otherwise.
@@ -125,6 +149,12 @@ pub trait MapRead<
         }
     }
 
+    ///
+    /// Returns an iterator visiting each key-value pair scheduled to be written as part of an atomic batch.
+    /// A key that is removed in the batch is paired with `None`.
+    ///
+    fn iter_pending(&'a self) -> Self::PendingIterator;
+
     ///
     /// Returns an iterator visiting each key-value pair in the map.
     ///
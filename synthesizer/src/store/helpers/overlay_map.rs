@@ -0,0 +1,433 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::store::helpers::{Map, MapRead};
+use console::network::prelude::*;
+use indexmap::IndexMap;
+
+use core::{borrow::Borrow, hash::Hash, marker::PhantomData};
+use parking_lot::{Mutex, RwLock};
+use std::{
+    borrow::Cow,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// A copy-on-write overlay of a map. Reads fall through to the underlying map,
+/// while writes are kept in the overlay, until they are committed to the underlying map.
+///
+/// Note: The changes are not rebased onto the underlying map, which must therefore not be
+/// modified between the creation of the overlay and its commit.
+#[derive(Clone)]
+pub struct OverlayMap<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    M: for<'a> Map<'a, K, V>,
+> {
+    /// The underlying map.
+    map: M,
+    /// The changes to the underlying map, where `None` denotes a removed key.
+    changes: Arc<RwLock<IndexMap<K, Option<V>>>>,
+    /// A flag indicating whether an atomic batch is in progress on the overlay.
+    batch_in_progress: Arc<AtomicBool>,
+    /// The writes queued in the atomic batch of the overlay, where `None` denotes a removed key.
+    atomic_batch: Arc<Mutex<IndexMap<K, Option<V>>>>,
+    /// PhantomData.
+    _phantom: PhantomData<V>,
+}
+
+impl<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    M: for<'a> Map<'a, K, V>,
+> OverlayMap<K, V, M>
+{
+    /// Initializes a new overlay of the given map.
+    pub fn new(map: M) -> Self {
+        Self {
+            map,
+            changes: Default::default(),
+            batch_in_progress: Default::default(),
+            atomic_batch: Default::default(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the changes to the underlying map, where `None` denotes a removed key.
+    pub fn changes(&self) -> IndexMap<K, Option<V>> {
+        self.changes.read().clone()
+    }
+
+    /// Writes the changes to the underlying map, and clears them from the overlay.
+    /// If an atomic batch is in progress on the underlying map, the changes become part of it.
+    pub fn commit(&self) -> Result<()> {
+        // Ensure there is no atomic batch in progress on the overlay.
+        ensure!(!self.is_atomic_in_progress(), "Cannot commit an overlay with an atomic batch in progress");
+        // Retrieve the changes.
+        let changes = core::mem::take(&mut *self.changes.write());
+        // Apply each change to the underlying map.
+        for (key, value) in changes {
+            match value {
+                Some(value) => self.map.insert(key, value)?,
+                None => self.map.remove(&key)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    M: for<'b> Map<'b, K, V>,
+> Map<'a, K, V> for OverlayMap<K, V, M>
+{
+    ///
+    /// Inserts the given key-value pair into the overlay.
+    ///
+    fn insert(&self, key: K, value: V) -> Result<()> {
+        match self.batch_in_progress.load(Ordering::SeqCst) {
+            // If a batch is in progress, add the key-value pair to the batch.
+            true => self.atomic_batch.lock().insert(key, Some(value)),
+            // Otherwise, add the key-value pair to the changes.
+            false => self.changes.write().insert(key, Some(value)),
+        };
+        Ok(())
+    }
+
+    ///
+    /// Removes the key-value pair for the given key from the overlay.
+    ///
+    fn remove(&self, key: &K) -> Result<()> {
+        match self.batch_in_progress.load(Ordering::SeqCst) {
+            // If a batch is in progress, add the key-None pair to the batch.
+            true => self.atomic_batch.lock().insert(*key, None),
+            // Otherwise, add the key-None pair to the changes.
+            false => self.changes.write().insert(*key, None),
+        };
+        Ok(())
+    }
+
+    ///
+    /// Begins an atomic operation. Any further calls to `insert` and `remove` will be queued
+    /// without being added to the changes until `finish_atomic` is called.
+    ///
+    fn start_atomic(&self) {
+        // Set the atomic batch flag to `true`.
+        self.batch_in_progress.store(true, Ordering::SeqCst);
+        // Ensure that the atomic batch is empty.
+        assert!(self.atomic_batch.lock().is_empty());
+    }
+
+    ///
+    /// Checks whether an atomic operation is currently in progress.
+    ///
+    fn is_atomic_in_progress(&self) -> bool {
+        self.batch_in_progress.load(Ordering::SeqCst)
+    }
+
+    ///
+    /// Aborts the current atomic operation.
+    ///
+    fn abort_atomic(&self) {
+        // Clear the atomic batch.
+        *self.atomic_batch.lock() = Default::default();
+        // Set the atomic batch flag to `false`.
+        self.batch_in_progress.store(false, Ordering::SeqCst);
+    }
+
+    ///
+    /// Finishes an atomic operation, adding all the queued writes to the changes.
+    ///
+    fn finish_atomic(&self) -> Result<()> {
+        // Retrieve the atomic batch.
+        let operations = core::mem::take(&mut *self.atomic_batch.lock());
+        // Add the queued operations to the changes.
+        self.changes.write().extend(operations);
+        // Set the atomic batch flag to `false`.
+        self.batch_in_progress.store(false, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+impl<
+    'a,
+    K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    M: for<'b> Map<'b, K, V>,
+> MapRead<'a, K, V> for OverlayMap<K, V, M>
+{
+    type Iterator = std::vec::IntoIter<(Cow<'a, K>, Cow<'a, V>)>;
+    type Keys = std::vec::IntoIter<Cow<'a, K>>;
+    type PendingIterator = std::vec::IntoIter<(Cow<'a, K>, Option<Cow<'a, V>>)>;
+    type Values = std::vec::IntoIter<Cow<'a, V>>;
+
+    ///
+    /// Returns `true` if the given key exists in the overlay.
+    ///
+    fn contains_key<Q>(&self, key: &Q) -> Result<bool>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        // Check the atomic batch of the overlay first, if one is in progress.
+        if let Some(value) = self.get_batched(key) {
+            return Ok(value.is_some());
+        }
+        // Note: The underlying map is read speculatively, in case the overlay is used during an atomic batch.
+        match self.changes.read().get(key) {
+            Some(value) => Ok(value.is_some()),
+            None => self.map.contains_key_speculative(key),
+        }
+    }
+
+    ///
+    /// Returns the value for the given key from the overlay, if it exists.
+    ///
+    fn get<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        match self.changes.read().get(key) {
+            Some(value) => Ok(value.clone().map(Cow::Owned)),
+            None => self.map.get_speculative(key),
+        }
+    }
+
+    ///
+    /// Returns the current value for the given key if it is scheduled
+    /// to be inserted as part of an atomic batch.
+    ///
+    /// If the key does not exist, returns `None`.
+    /// If the key is removed in the batch, returns `Some(None)`.
+    /// If the key is inserted in the batch, returns `Some(Some(value))`.
+    ///
+    fn get_batched<Q>(&self, key: &Q) -> Option<Option<V>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        // Return early if there is no atomic batch in progress.
+        if self.batch_in_progress.load(Ordering::SeqCst) { self.atomic_batch.lock().get(key).cloned() } else { None }
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair scheduled to be written as part of an atomic batch.
+    /// A key that is removed in the batch is paired with `None`.
+    ///
+    fn iter_pending(&'a self) -> Self::PendingIterator {
+        // Return early if there is no atomic batch in progress.
+        match self.batch_in_progress.load(Ordering::SeqCst) {
+            true => self
+                .atomic_batch
+                .lock()
+                .clone()
+                .into_iter()
+                .map(|(key, value)| (Cow::Owned(key), value.map(Cow::Owned)))
+                .collect::<Vec<_>>()
+                .into_iter(),
+            false => Vec::new().into_iter(),
+        }
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the overlay.
+    ///
+    fn iter(&'a self) -> Self::Iterator {
+        // Acquire the read lock on the changes.
+        let changes = self.changes.read();
+        // Retrieve the writes in the atomic batch of the underlying map, in case the overlay is used during the batch.
+        let pending = self
+            .map
+            .iter_pending()
+            .map(|(key, value)| (key.into_owned(), value.map(Cow::into_owned)))
+            .collect::<IndexMap<_, _>>();
+        // Retrieve the entries of the underlying map that have not been changed.
+        let entries = self.map.iter().filter(|(key, _)| !changes.contains_key(&**key) && !pending.contains_key(&**key));
+        // Append the entries that have been inserted into the atomic batch of the underlying map.
+        let batched = pending
+            .iter()
+            .filter(|(key, _)| !changes.contains_key(*key))
+            .filter_map(|(key, value)| Some((Cow::Owned(*key), Cow::Owned(value.clone()?))));
+        // Append the entries that have been inserted into the overlay.
+        let inserted = changes.iter().filter_map(|(key, value)| Some((Cow::Owned(*key), Cow::Owned(value.clone()?))));
+        entries.chain(batched).chain(inserted).collect::<Vec<_>>().into_iter()
+    }
+
+    ///
+    /// Returns an iterator over each key in the overlay.
+    ///
+    fn keys(&'a self) -> Self::Keys {
+        self.iter().map(|(key, _)| key).collect::<Vec<_>>().into_iter()
+    }
+
+    ///
+    /// Returns an iterator over each value in the overlay.
+    ///
+    fn values(&'a self) -> Self::Values {
+        self.iter().map(|(_, value)| value).collect::<Vec<_>>().into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::helpers::memory_map::MemoryMap;
+
+    #[test]
+    fn test_overlay_reads_and_writes() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = [(0, "0".to_string()), (1, "1".to_string())].into_iter().collect();
+        // Initialize an overlay of the map.
+        let overlay = OverlayMap::new(map.clone());
+
+        // Ensure the overlay reads from the map.
+        assert_eq!(overlay.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+        assert!(overlay.contains_key(&1).unwrap());
+        assert_eq!(overlay.keys().count(), 2);
+
+        // Update, remove, and insert an item in the overlay.
+        overlay.insert(0, "zero".to_string()).unwrap();
+        overlay.remove(&1).unwrap();
+        overlay.insert(2, "2".to_string()).unwrap();
+
+        // Ensure the overlay reflects the changes.
+        assert_eq!(overlay.get(&0).unwrap(), Some(Cow::Owned("zero".to_string())));
+        assert!(!overlay.contains_key(&1).unwrap());
+        assert_eq!(overlay.get(&2).unwrap(), Some(Cow::Owned("2".to_string())));
+        assert_eq!(overlay.keys().map(|key| *key).collect::<Vec<_>>(), vec![0, 2]);
+
+        // Ensure the map is unchanged.
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+        assert!(map.contains_key(&1).unwrap());
+        assert!(!map.contains_key(&2).unwrap());
+
+        // Commit the overlay.
+        overlay.commit().unwrap();
+        assert!(overlay.changes().is_empty());
+
+        // Ensure the map reflects the changes.
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Owned("zero".to_string())));
+        assert!(!map.contains_key(&1).unwrap());
+        assert_eq!(map.get(&2).unwrap(), Some(Cow::Owned("2".to_string())));
+    }
+
+    #[test]
+    fn test_overlay_atomic_writes_can_be_aborted() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = [(0, "0".to_string())].into_iter().collect();
+        // Initialize an overlay of the map.
+        let overlay = OverlayMap::new(map.clone());
+
+        // Start an atomic write batch.
+        overlay.start_atomic();
+        overlay.insert(0, "zero".to_string()).unwrap();
+        overlay.insert(1, "1".to_string()).unwrap();
+
+        // Ensure the items are only in the batch.
+        assert_eq!(overlay.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+        assert_eq!(overlay.get_speculative(&0).unwrap(), Some(Cow::Owned("zero".to_string())));
+        assert!(overlay.commit().is_err());
+
+        // Abort the atomic write batch.
+        overlay.abort_atomic();
+        assert_eq!(overlay.get_speculative(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+        assert!(overlay.changes().is_empty());
+
+        // Start and finish an atomic write batch.
+        overlay.start_atomic();
+        overlay.insert(1, "1".to_string()).unwrap();
+        overlay.finish_atomic().unwrap();
+
+        // Ensure the item is in the overlay, but not in the map.
+        assert_eq!(overlay.get(&1).unwrap(), Some(Cow::Owned("1".to_string())));
+        assert!(!map.contains_key(&1).unwrap());
+    }
+
+    #[test]
+    fn test_overlay_contains_key_reads_the_atomic_batch() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = [(0, "0".to_string())].into_iter().collect();
+        // Initialize an overlay of the map.
+        let overlay = OverlayMap::new(map.clone());
+
+        // Start an atomic write batch.
+        overlay.start_atomic();
+
+        // Ensure a key inserted in the batch is contained.
+        assert!(!overlay.contains_key(&1).unwrap());
+        overlay.insert(1, "1".to_string()).unwrap();
+        assert!(overlay.contains_key(&1).unwrap());
+        assert!(overlay.contains_key_speculative(&1).unwrap());
+
+        // Ensure a key removed in the batch is not contained.
+        assert!(overlay.contains_key(&0).unwrap());
+        overlay.remove(&0).unwrap();
+        assert!(!overlay.contains_key(&0).unwrap());
+        assert!(!overlay.contains_key_speculative(&0).unwrap());
+
+        // Ensure a key inserted and then removed in the batch is not contained.
+        overlay.remove(&1).unwrap();
+        assert!(!overlay.contains_key(&1).unwrap());
+
+        // Abort the atomic write batch.
+        overlay.abort_atomic();
+        assert!(overlay.contains_key(&0).unwrap());
+        assert!(!overlay.contains_key(&1).unwrap());
+
+        // Ensure the writes of a finished atomic write batch are contained.
+        overlay.start_atomic();
+        overlay.insert(2, "2".to_string()).unwrap();
+        overlay.remove(&0).unwrap();
+        overlay.finish_atomic().unwrap();
+        assert!(overlay.contains_key(&2).unwrap());
+        assert!(!overlay.contains_key(&0).unwrap());
+        assert!(map.contains_key(&0).unwrap());
+    }
+
+    #[test]
+    fn test_overlay_iter_reads_the_atomic_batch_of_the_map() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> =
+            [(0, "0".to_string()), (1, "1".to_string()), (2, "2".to_string())].into_iter().collect();
+        // Initialize an overlay of the map.
+        let overlay = OverlayMap::new(map.clone());
+        overlay.insert(2, "two".to_string()).unwrap();
+
+        // Start an atomic write batch on the map.
+        map.start_atomic();
+        map.insert(0, "zero".to_string()).unwrap();
+        map.remove(&1).unwrap();
+        map.insert(2, "2".to_string()).unwrap();
+        map.insert(3, "3".to_string()).unwrap();
+
+        // Ensure the overlay iterates over the batched writes of the map, and keeps its own changes on top.
+        let entries = overlay.iter().map(|(key, value)| (*key, value.into_owned())).collect::<IndexMap<_, _>>();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[&0], "zero");
+        assert_eq!(entries[&2], "two");
+        assert_eq!(entries[&3], "3");
+        assert_eq!(overlay.keys().count(), overlay.values().count());
+
+        // Abort the atomic write batch on the map.
+        map.abort_atomic();
+        assert_eq!(overlay.keys().map(|key| *key).collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+}
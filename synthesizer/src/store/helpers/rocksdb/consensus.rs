@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::store::{BlockDB, BlockStore, ConsensusStorage, ProgramDB, ProgramStore, TransactionDB, TransitionDB};
+use console::network::prelude::*;
+
+/// A RocksDB consensus storage.
+#[derive(Clone)]
+pub struct ConsensusDB<N: Network> {
+    /// The program store.
+    program_store: ProgramStore<N, ProgramDB<N>>,
+    /// The block store.
+    block_store: BlockStore<N, BlockDB<N>>,
+}
+
+#[rustfmt::skip]
+impl<N: Network> ConsensusStorage<N> for ConsensusDB<N> {
+    type ProgramStorage = ProgramDB<N>;
+    type BlockStorage = BlockDB<N>;
+    type TransactionStorage = TransactionDB<N>;
+    type TransitionStorage = TransitionDB<N>;
+
+    /// Initializes the consensus storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        // Initialize the program store.
+        let program_store = ProgramStore::<N, ProgramDB<N>>::open(dev)?;
+        // Initialize the block store.
+        let block_store = BlockStore::<N, BlockDB<N>>::open(dev)?;
+        // Return the consensus storage.
+        Ok(Self {
+            program_store,
+            block_store,
+        })
+    }
+
+    /// Returns the program store.
+    fn program_store(&self) -> &ProgramStore<N, Self::ProgramStorage> {
+        &self.program_store
+    }
+
+    /// Returns the block store.
+    fn block_store(&self) -> &BlockStore<N, Self::BlockStorage> {
+        &self.block_store
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        store::{
+            helpers::{
+                rocksdb::{MapID, RocksDB, TempLedger},
+                Map,
+            },
+            ConsensusStore,
+        },
+        vm::{test_helpers::CurrentNetwork, VM},
+    };
+
+    #[test]
+    fn test_reopen() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        let ledger = TempLedger::new();
+        {
+            // Initialize a new VM.
+            let store = ConsensusStore::<CurrentNetwork, ConsensusDB<_>>::open(Some(ledger.dev)).unwrap();
+            let vm = VM::from(store).unwrap();
+            // Add the genesis block.
+            vm.add_next_block(&genesis).unwrap();
+        }
+
+        // Reopen the VM, after the database has been closed.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusDB<_>>::open(Some(ledger.dev)).unwrap();
+        let vm = VM::from(store).unwrap();
+
+        // Ensure the ledger is intact.
+        assert_eq!(vm.block_store().heights().max().map(|height| *height), Some(0));
+        assert_eq!(vm.block_store().get_block(&genesis.hash()).unwrap(), Some(genesis.clone()));
+        assert_eq!(vm.block_store().current_state_root(), vm.block_store().get_state_root(0).unwrap().unwrap());
+        for transaction_id in genesis.transaction_ids() {
+            assert!(vm.transaction_store().contains_transaction_id(transaction_id).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_rollback_incomplete_block() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        let ledger = TempLedger::new();
+        {
+            // Initialize a new VM.
+            let store = ConsensusStore::<CurrentNetwork, ConsensusDB<_>>::open(Some(ledger.dev)).unwrap();
+            let vm = VM::from(store.clone()).unwrap();
+            // Add the genesis block.
+            vm.add_next_block(&genesis).unwrap();
+            // Ensure the ledger is consistent.
+            store.check_integrity().unwrap();
+
+            // Emulate a partial write, by removing the reverse state root entry of the genesis block.
+            let state_root = store.block_store().get_state_root(0).unwrap().unwrap();
+            let reverse_state_root_map = RocksDB::open_map::<<CurrentNetwork as Network>::StateRoot, u32>(
+                CurrentNetwork::ID,
+                Some(ledger.dev),
+                MapID::BlockReverseStateRoot,
+            )
+            .unwrap();
+            reverse_state_root_map.remove(&state_root).unwrap();
+            assert!(store.check_block(0).is_err());
+            assert!(store.check_integrity().is_err());
+        }
+
+        // Reopen the ledger, after the database has been closed.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusDB<_>>::open(Some(ledger.dev)).unwrap();
+
+        // Ensure the incomplete block was rolled back.
+        assert_eq!(store.block_store().heights().max().map(|height| *height), None);
+        for transaction_id in genesis.transaction_ids() {
+            assert!(!store.transaction_store().contains_transaction_id(transaction_id).unwrap());
+        }
+        store.check_integrity().unwrap();
+    }
+
+    #[test]
+    fn test_rollback_block_with_missing_entries() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        let ledger = TempLedger::new();
+        {
+            // Initialize a new VM.
+            let store = ConsensusStore::<CurrentNetwork, ConsensusDB<_>>::open(Some(ledger.dev)).unwrap();
+            let vm = VM::from(store.clone()).unwrap();
+            // Add the genesis block.
+            vm.add_next_block(&genesis).unwrap();
+            assert!(store.program_store().get_finalize_diff(0).unwrap().is_some());
+
+            // Emulate a partial write, by removing the transaction IDs of the genesis block.
+            let transactions_map = RocksDB::open_map::<
+                <CurrentNetwork as Network>::BlockHash,
+                Vec<<CurrentNetwork as Network>::TransactionID>,
+            >(CurrentNetwork::ID, Some(ledger.dev), MapID::BlockTransactions)
+            .unwrap();
+            transactions_map.remove(&genesis.hash()).unwrap();
+            assert!(store.check_block(0).is_err());
+            // Ensure the block can no longer be removed with the strict removal.
+            assert!(store.block_store().remove_last_n(1).is_err());
+        }
+
+        // Reopen the ledger, after the database has been closed.
+        let store = ConsensusStore::<CurrentNetwork, ConsensusDB<_>>::open(Some(ledger.dev)).unwrap();
+
+        // Ensure the incomplete block was rolled back, along with its finalized state.
+        assert_eq!(store.block_store().heights().max().map(|height| *height), None);
+        assert!(store.block_store().get_block_height(&genesis.hash()).unwrap().is_none());
+        assert!(store.program_store().get_finalize_diff(0).unwrap().is_none());
+        for transaction_id in genesis.transaction_ids() {
+            assert!(!store.transaction_store().contains_transaction_id(transaction_id).unwrap());
+        }
+        store.check_integrity().unwrap();
+    }
+}
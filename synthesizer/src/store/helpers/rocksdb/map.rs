@@ -0,0 +1,742 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{RocksDB, PREFIX_LEN};
+use crate::store::helpers::{Map, MapRead};
+use console::network::prelude::*;
+use indexmap::IndexMap;
+
+use core::{borrow::Borrow, hash::Hash, marker::PhantomData};
+use parking_lot::Mutex;
+use std::{
+    borrow::Cow,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// A map backed by a RocksDB database. The keys of each map are prefixed with its context,
+/// which allows all of the maps of a ledger to live in a single database.
+#[derive(Clone)]
+pub struct DataMap<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+> {
+    /// The database.
+    pub(super) database: Arc<RocksDB>,
+    /// The prefix of every key in the map.
+    pub(super) context: Vec<u8>,
+    /// The flag indicating whether an atomic batch is in progress for this map.
+    pub(super) batch_in_progress: Arc<AtomicBool>,
+    /// The operations queued for this map in the current atomic batch.
+    pub(super) atomic_batch: Arc<Mutex<IndexMap<K, Option<V>>>>,
+}
+
+impl<
+        K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    > DataMap<K, V>
+{
+    /// Returns the given key, serialized and prefixed with the context of the map.
+    fn create_prefixed_key<Q>(&self, key: &Q) -> Result<Vec<u8>>
+    where
+        K: Borrow<Q>,
+        Q: Serialize + ?Sized,
+    {
+        let mut raw_key = self.context.clone();
+        bincode::serialize_into(&mut raw_key, key)?;
+        Ok(raw_key)
+    }
+}
+
+impl<
+        'a,
+        K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    > Map<'a, K, V> for DataMap<K, V>
+{
+    ///
+    /// Inserts the given key-value pair into the map.
+    ///
+    fn insert(&self, key: K, value: V) -> Result<()> {
+        // Determine if an atomic batch is in progress.
+        let is_batch = self.is_atomic_in_progress();
+
+        match is_batch {
+            // If a batch is in progress, add the key-value pair to the batch.
+            true => {
+                self.atomic_batch.lock().insert(key, Some(value));
+            }
+            // Otherwise, insert the key-value pair directly into the map.
+            false => {
+                self.database.put(self.create_prefixed_key(&key)?, bincode::serialize(&value)?)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Removes the key-value pair for the given key from the map.
+    ///
+    fn remove(&self, key: &K) -> Result<()> {
+        // Determine if an atomic batch is in progress.
+        let is_batch = self.is_atomic_in_progress();
+
+        match is_batch {
+            // If a batch is in progress, add the key-None pair to the batch.
+            true => {
+                self.atomic_batch.lock().insert(*key, None);
+            }
+            // Otherwise, remove the key-value pair directly from the map.
+            false => {
+                self.database.delete(self.create_prefixed_key(key)?)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Begins an atomic operation. Any further calls to `insert` and `remove` will be queued
+    /// without an actual write taking place until `finish_atomic` is called.
+    ///
+    fn start_atomic(&self) {
+        // Acquire the lock on the owner of the atomic batch of the database.
+        let mut atomic_owner = self.database.atomic_owner.lock();
+        // Wait until the atomic batch of the database is not in progress on another thread.
+        // Note: This serializes the atomic batches of concurrent writers, as the batch is shared by all of the maps.
+        let thread = std::thread::current().id();
+        while atomic_owner.map_or(false, |owner| owner != thread) {
+            self.database.atomic_released.wait(&mut atomic_owner);
+        }
+        *atomic_owner = Some(thread);
+
+        // Set the atomic batch flag to `true`.
+        self.batch_in_progress.store(true, Ordering::SeqCst);
+        // Increment the atomic depth of the database.
+        self.database.atomic_depth.fetch_add(1, Ordering::SeqCst);
+        // Ensure that the atomic batch is empty.
+        assert!(self.atomic_batch.lock().is_empty());
+    }
+
+    ///
+    /// Checks whether an atomic operation is currently in progress. This can be done to ensure
+    /// that lower-level operations don't start and finish their individual atomic write batch
+    /// if they are already part of a larger one.
+    ///
+    fn is_atomic_in_progress(&self) -> bool {
+        // Note: A batch that is in progress on another thread is not part of this one, so it is not counted.
+        self.batch_in_progress.load(Ordering::SeqCst) && self.database.is_atomic_owner()
+    }
+
+    ///
+    /// Aborts the current atomic operation.
+    ///
+    fn abort_atomic(&self) {
+        // Acquire the lock on the owner of the atomic batch of the database.
+        let mut atomic_owner = self.database.atomic_owner.lock();
+
+        // Clear the atomic batch of the map.
+        *self.atomic_batch.lock() = Default::default();
+
+        // Set the atomic batch flag to `false`, and leave the atomic batch of the database if the map was part of it.
+        // Note: The abort cascades through the stores, so a map may be aborted more than once.
+        if self.batch_in_progress.swap(false, Ordering::SeqCst) {
+            // Clear the atomic batch of the database, which may already contain the operations of other maps.
+            *self.database.atomic_batch.lock() = Default::default();
+            // Decrement the atomic depth of the database.
+            let previous_atomic_depth = self
+                .database
+                .atomic_depth
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |depth| Some(depth.saturating_sub(1)))
+                .unwrap_or_default();
+            // If this is the last map to abort, release the atomic batch of the database.
+            if previous_atomic_depth <= 1 {
+                *atomic_owner = None;
+                self.database.atomic_released.notify_all();
+            }
+        }
+    }
+
+    ///
+    /// Finishes an atomic operation, performing all the queued writes.
+    ///
+    fn finish_atomic(&self) -> Result<()> {
+        // Retrieve the atomic batch of the map.
+        let operations = core::mem::take(&mut *self.atomic_batch.lock());
+
+        if !operations.is_empty() {
+            // Prepare the key and value for each queued operation.
+            // Note: This step is taken to ensure (with 100% certainty) that there will be
+            // no chance to fail partway through adding the queued operations to the batch.
+            let prepared_operations = operations
+                .into_iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        Some(value) => Some(bincode::serialize(&value)?),
+                        None => None,
+                    };
+                    Ok((self.create_prefixed_key(&key)?, value))
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            // Add the queued operations to the atomic batch of the database.
+            let mut atomic_batch = self.database.atomic_batch.lock();
+            for (key, value) in prepared_operations {
+                match value {
+                    Some(value) => atomic_batch.put(key, value),
+                    None => atomic_batch.delete(key),
+                }
+            }
+        }
+
+        // Acquire the lock on the owner of the atomic batch of the database.
+        let mut atomic_owner = self.database.atomic_owner.lock();
+
+        // Set the atomic batch flag to `false`.
+        self.batch_in_progress.store(false, Ordering::SeqCst);
+
+        // Decrement the atomic depth of the database.
+        // Note: The depth saturates at zero, in case the batch was aborted by another map in the meantime.
+        let previous_atomic_depth = self
+            .database
+            .atomic_depth
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |depth| Some(depth.saturating_sub(1)))
+            .unwrap_or_default();
+
+        // If this is the last map to finish, write the atomic batch of the database.
+        // Note: This is what makes the writes across all of the maps (and stores) atomic.
+        if previous_atomic_depth <= 1 {
+            let batch = core::mem::take(&mut *self.database.atomic_batch.lock());
+            // Ensure no map iteration failed, as the batch may be based on an incomplete scan.
+            let result = match self.database.iterator_error.lock().take() {
+                Some(error) => {
+                    Err(anyhow!("Failed to write the atomic batch, as an iteration over the ledger failed: {error}"))
+                }
+                None => self.database.write(batch).map_err(Into::into),
+            };
+            // Release the atomic batch of the database, once it is written.
+            *atomic_owner = None;
+            self.database.atomic_released.notify_all();
+            return result;
+        }
+
+        Ok(())
+    }
+}
+
+impl<
+        'a,
+        K: 'a + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        V: 'a + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    > MapRead<'a, K, V> for DataMap<K, V>
+{
+    type Iterator = Iter<'a, K, V>;
+    type Keys = Keys<'a, K>;
+    type PendingIterator = std::vec::IntoIter<(Cow<'a, K>, Option<Cow<'a, V>>)>;
+    type Values = Values<'a, V>;
+
+    ///
+    /// Returns `true` if the given key exists in the map.
+    ///
+    fn contains_key<Q>(&self, key: &Q) -> Result<bool>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        Ok(self.database.get_pinned(self.create_prefixed_key(key)?)?.is_some())
+    }
+
+    ///
+    /// Returns the value for the given key from the map, if it exists.
+    ///
+    fn get<Q>(&'a self, key: &Q) -> Result<Option<Cow<'a, V>>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        match self.database.get_pinned(self.create_prefixed_key(key)?)? {
+            Some(bytes) => Ok(Some(Cow::Owned(bincode::deserialize(&bytes)?))),
+            None => Ok(None),
+        }
+    }
+
+    ///
+    /// Returns the current value for the given key if it is scheduled
+    /// to be inserted as part of an atomic batch.
+    ///
+    /// If the key does not exist, returns `None`.
+    /// If the key is removed in the batch, returns `Some(None)`.
+    /// If the key is inserted in the batch, returns `Some(Some(value))`.
+    ///
+    fn get_batched<Q>(&self, key: &Q) -> Option<Option<V>>
+    where
+        K: Borrow<Q>,
+        Q: PartialEq + Eq + Hash + Serialize + ?Sized,
+    {
+        // Return early if there is no atomic batch in progress.
+        if self.is_atomic_in_progress() { self.atomic_batch.lock().get(key).cloned() } else { None }
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair scheduled to be written as part of an atomic batch.
+    /// A key that is removed in the batch is paired with `None`.
+    ///
+    fn iter_pending(&'a self) -> Self::PendingIterator {
+        // Return early if there is no atomic batch in progress.
+        match self.is_atomic_in_progress() {
+            true => self
+                .atomic_batch
+                .lock()
+                .clone()
+                .into_iter()
+                .map(|(key, value)| (Cow::Owned(key), value.map(Cow::Owned)))
+                .collect::<Vec<_>>()
+                .into_iter(),
+            false => Vec::new().into_iter(),
+        }
+    }
+
+    ///
+    /// Returns an iterator visiting each key-value pair in the map.
+    ///
+    fn iter(&'a self) -> Self::Iterator {
+        Iter::new(self.database.prefix_iterator(&self.context), &self.database, &self.context)
+    }
+
+    ///
+    /// Returns an iterator over each key in the map.
+    ///
+    fn keys(&'a self) -> Self::Keys {
+        Keys::new(self.database.prefix_iterator(&self.context), &self.database, &self.context)
+    }
+
+    ///
+    /// Returns an iterator over each value in the map.
+    ///
+    fn values(&'a self) -> Self::Values {
+        Values::new(self.database.prefix_iterator(&self.context), &self.database, &self.context)
+    }
+}
+
+/// Returns the next raw key-value pair from the given iterator, if it belongs to the map with the given context.
+///
+/// If the underlying read fails, the iteration ends, and the error is recorded in the database.
+/// As the map iterators cannot return an error, the recorded error fails the next atomic write batch,
+/// so that an incomplete scan is never silently written back to the ledger.
+fn next_entry(
+    db_iter: &mut rocksdb::DBIterator<'_>,
+    database: &RocksDB,
+    context: &[u8],
+) -> Option<(Box<[u8]>, Box<[u8]>)> {
+    match db_iter.next()? {
+        // Ensure the key belongs to the map; the prefix extractor ensures this already,
+        // but the check is cheap and guards against reading the entries of a different map.
+        Ok((key, value)) => match key.starts_with(context) {
+            true => Some((key, value)),
+            false => None,
+        },
+        Err(error) => {
+            tracing::error!("RocksDB iterator error: {error}");
+            *database.iterator_error.lock() = Some(error.to_string());
+            None
+        }
+    }
+}
+
+/// An iterator over all key-value pairs in a data map.
+pub struct Iter<'a, K, V> {
+    db_iter: rocksdb::DBIterator<'a>,
+    database: &'a RocksDB,
+    context: &'a [u8],
+    _phantom: PhantomData<(K, V)>,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    fn new(db_iter: rocksdb::DBIterator<'a>, database: &'a RocksDB, context: &'a [u8]) -> Self {
+        Self { db_iter, database, context, _phantom: PhantomData }
+    }
+}
+
+impl<'a, K: 'a + Clone + for<'de> Deserialize<'de>, V: 'a + Clone + for<'de> Deserialize<'de>> Iterator
+    for Iter<'a, K, V>
+{
+    type Item = (Cow<'a, K>, Cow<'a, V>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = next_entry(&mut self.db_iter, self.database, self.context)?;
+        // Note: The 'unwrap' is safe here, because the keys and values are defined by us.
+        let key = bincode::deserialize(&key[PREFIX_LEN..]).unwrap();
+        let value = bincode::deserialize(&value).unwrap();
+        Some((Cow::Owned(key), Cow::Owned(value)))
+    }
+}
+
+/// An iterator over the keys of a data map.
+pub struct Keys<'a, K> {
+    db_iter: rocksdb::DBIterator<'a>,
+    database: &'a RocksDB,
+    context: &'a [u8],
+    _phantom: PhantomData<K>,
+}
+
+impl<'a, K> Keys<'a, K> {
+    fn new(db_iter: rocksdb::DBIterator<'a>, database: &'a RocksDB, context: &'a [u8]) -> Self {
+        Self { db_iter, database, context, _phantom: PhantomData }
+    }
+}
+
+impl<'a, K: 'a + Clone + for<'de> Deserialize<'de>> Iterator for Keys<'a, K> {
+    type Item = Cow<'a, K>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, _) = next_entry(&mut self.db_iter, self.database, self.context)?;
+        // Note: The 'unwrap' is safe here, because the keys are defined by us.
+        Some(Cow::Owned(bincode::deserialize(&key[PREFIX_LEN..]).unwrap()))
+    }
+}
+
+/// An iterator over the values of a data map.
+pub struct Values<'a, V> {
+    db_iter: rocksdb::DBIterator<'a>,
+    database: &'a RocksDB,
+    context: &'a [u8],
+    _phantom: PhantomData<V>,
+}
+
+impl<'a, V> Values<'a, V> {
+    fn new(db_iter: rocksdb::DBIterator<'a>, database: &'a RocksDB, context: &'a [u8]) -> Self {
+        Self { db_iter, database, context, _phantom: PhantomData }
+    }
+}
+
+impl<'a, V: 'a + Clone + for<'de> Deserialize<'de>> Iterator for Values<'a, V> {
+    type Item = Cow<'a, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, value) = next_entry(&mut self.db_iter, self.database, self.context)?;
+        // Note: The 'unwrap' is safe here, because the values are defined by us.
+        Some(Cow::Owned(bincode::deserialize(&value).unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::store::helpers::rocksdb::{MapID, TempLedger};
+    use console::{account::Address, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    /// Opens a map with the given ID in a fresh database, which is destroyed once the returned guard is dropped.
+    fn open_map<K, V>(map_id: MapID) -> (DataMap<K, V>, TempLedger)
+    where
+        K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    {
+        let ledger = TempLedger::new();
+        (RocksDB::open_map(CurrentNetwork::ID, Some(ledger.dev), map_id).unwrap(), ledger)
+    }
+
+    #[test]
+    fn test_contains_key() {
+        // Initialize an address.
+        let address =
+            Address::<CurrentNetwork>::from_str("aleo1q6qstg8q8shwqf5m6q5fcenuwsdqsvp4hhsgfnx5chzjm3secyzqt9mxm8")
+                .unwrap();
+
+        // Initialize a map.
+        let (map, _ledger) = open_map::<Address<CurrentNetwork>, ()>(MapID::Test0);
+        map.insert(address, ()).unwrap();
+        assert!(map.contains_key(&address).unwrap());
+    }
+
+    #[test]
+    fn test_insert_and_get_speculative() {
+        // Initialize a map.
+        let (map, _ledger) = open_map::<usize, String>(MapID::Test0);
+
+        // Sanity check.
+        assert!(map.iter().next().is_none());
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Insert an item into the map.
+        map.insert(0, "0".to_string()).unwrap();
+
+        // Check that the item is not yet in the map.
+        assert!(map.get(&0).unwrap().is_none());
+        // Check that the item is in the batch.
+        assert_eq!(map.get_batched(&0), Some(Some("0".to_string())));
+        // Check that the item can be speculatively retrieved.
+        assert_eq!(map.get_speculative(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        // Check that the item is present in the map now.
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+        // Check that the item is not in the batch.
+        assert_eq!(map.get_batched(&0), None);
+    }
+
+    #[test]
+    fn test_atomic_writes_are_batched() {
+        // The number of items that will be inserted into the map.
+        const NUM_ITEMS: usize = 10;
+
+        // Initialize a map.
+        let (map, _ledger) = open_map::<usize, String>(MapID::Test0);
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Queue (since a batch is in progress) NUM_ITEMS insertions.
+        for i in 0..NUM_ITEMS {
+            map.insert(i, i.to_string()).unwrap();
+        }
+
+        // The map should still contain no items.
+        assert!(map.iter().next().is_none());
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        // Check that the items are present in the map now.
+        for i in 0..NUM_ITEMS {
+            assert_eq!(map.get(&i).unwrap(), Some(Cow::Borrowed(&i.to_string())));
+        }
+        assert_eq!(map.iter().count(), NUM_ITEMS);
+        assert_eq!(map.keys().count(), NUM_ITEMS);
+        assert_eq!(map.values().count(), NUM_ITEMS);
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Queue (since a batch is in progress) NUM_ITEMS removals.
+        for i in 0..NUM_ITEMS {
+            map.remove(&i).unwrap();
+        }
+
+        // The map should still contain all the items.
+        assert_eq!(map.iter().count(), NUM_ITEMS);
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        // Check that the map is empty now.
+        assert!(map.iter().next().is_none());
+    }
+
+    #[test]
+    fn test_atomic_writes_can_be_aborted() {
+        // The number of items that will be queued to be inserted into the map.
+        const NUM_ITEMS: usize = 10;
+
+        // Initialize a map.
+        let (map, _ledger) = open_map::<usize, String>(MapID::Test0);
+
+        // Start an atomic write batch.
+        map.start_atomic();
+
+        // Queue (since a batch is in progress) NUM_ITEMS insertions.
+        for i in 0..NUM_ITEMS {
+            map.insert(i, i.to_string()).unwrap();
+        }
+
+        // Abort the current atomic write batch.
+        map.abort_atomic();
+
+        // The map should still contain no items.
+        assert!(map.iter().next().is_none());
+
+        // Start another atomic write batch.
+        map.start_atomic();
+
+        // Queue (since a batch is in progress) NUM_ITEMS insertions.
+        for i in 0..NUM_ITEMS {
+            map.insert(i, i.to_string()).unwrap();
+        }
+
+        // Finish the current atomic write batch.
+        map.finish_atomic().unwrap();
+
+        // The map should contain NUM_ITEMS items now.
+        assert_eq!(map.iter().count(), NUM_ITEMS);
+    }
+
+    #[test]
+    fn test_atomic_writes_span_maps() {
+        let ledger = TempLedger::new();
+
+        // Initialize two maps in the same database.
+        let map_0: DataMap<usize, String> =
+            RocksDB::open_map(CurrentNetwork::ID, Some(ledger.dev), MapID::Test0).unwrap();
+        let map_1: DataMap<usize, String> =
+            RocksDB::open_map(CurrentNetwork::ID, Some(ledger.dev), MapID::Test1).unwrap();
+
+        // Start an atomic write batch on both maps.
+        map_0.start_atomic();
+        map_1.start_atomic();
+
+        map_0.insert(0, "0".to_string()).unwrap();
+        map_1.insert(1, "1".to_string()).unwrap();
+
+        // Finish the first map; its writes must be deferred until the second map is finished.
+        map_0.finish_atomic().unwrap();
+        assert!(map_0.get(&0).unwrap().is_none());
+
+        // Finish the second map, which writes the batch of both maps.
+        map_1.finish_atomic().unwrap();
+        assert_eq!(map_0.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+        assert_eq!(map_1.get(&1).unwrap(), Some(Cow::Owned("1".to_string())));
+
+        // Ensure the maps do not see each other's entries.
+        assert!(map_0.get(&1).unwrap().is_none());
+        assert!(map_1.get(&0).unwrap().is_none());
+        assert_eq!(map_0.iter().count(), 1);
+        assert_eq!(map_1.keys().collect::<Vec<_>>(), vec![Cow::<usize>::Owned(1)]);
+    }
+
+    #[test]
+    fn test_nested_atomic_writes_can_be_aborted() {
+        let ledger = TempLedger::new();
+
+        // Initialize two maps in the same database.
+        let map_0: DataMap<usize, String> =
+            RocksDB::open_map(CurrentNetwork::ID, Some(ledger.dev), MapID::Test0).unwrap();
+        let map_1: DataMap<usize, String> =
+            RocksDB::open_map(CurrentNetwork::ID, Some(ledger.dev), MapID::Test1).unwrap();
+
+        // Start an atomic write batch on the first map, and a nested one on the second map.
+        map_0.start_atomic();
+        map_0.insert(0, "0".to_string()).unwrap();
+        map_1.start_atomic();
+        map_1.insert(1, "1".to_string()).unwrap();
+
+        // Finish the nested batch, and then abort the outer batch.
+        map_1.finish_atomic().unwrap();
+        map_0.abort_atomic();
+
+        // Ensure neither map contains the queued items.
+        assert!(map_0.iter().next().is_none());
+        assert!(map_1.iter().next().is_none());
+        assert!(!map_0.is_atomic_in_progress());
+        assert!(!map_1.is_atomic_in_progress());
+
+        // Start another atomic write batch on both maps, and abort the nested batch.
+        map_0.start_atomic();
+        map_0.insert(0, "0".to_string()).unwrap();
+        map_1.start_atomic();
+        map_1.insert(1, "1".to_string()).unwrap();
+        map_1.abort_atomic();
+        map_0.abort_atomic();
+
+        // Ensure neither map contains the queued items.
+        assert!(map_0.iter().next().is_none());
+        assert!(map_1.iter().next().is_none());
+
+        // Ensure a subsequent atomic write batch only writes its own items.
+        map_1.start_atomic();
+        map_1.insert(2, "2".to_string()).unwrap();
+        map_1.finish_atomic().unwrap();
+        assert!(map_0.iter().next().is_none());
+        assert_eq!(map_1.keys().collect::<Vec<_>>(), vec![Cow::<usize>::Owned(2)]);
+    }
+
+    #[test]
+    fn test_atomic_writes_are_serialized_across_threads() {
+        let ledger = TempLedger::new();
+
+        // Initialize two maps in the same database.
+        let map_0: DataMap<usize, String> =
+            RocksDB::open_map(CurrentNetwork::ID, Some(ledger.dev), MapID::Test0).unwrap();
+        let map_1: DataMap<usize, String> =
+            RocksDB::open_map(CurrentNetwork::ID, Some(ledger.dev), MapID::Test1).unwrap();
+
+        // Start an atomic write batch on the first map.
+        map_0.start_atomic();
+        map_0.insert(0, "0".to_string()).unwrap();
+
+        // Start an atomic write batch on another thread, which must wait for the first batch to finish.
+        let started = Arc::new(AtomicBool::new(false));
+        let handle = {
+            let (map, started) = (map_1.clone(), started.clone());
+            std::thread::spawn(move || {
+                map.start_atomic();
+                started.store(true, Ordering::SeqCst);
+                map.insert(1, "1".to_string()).unwrap();
+                map.finish_atomic().unwrap();
+            })
+        };
+
+        // Ensure the other thread is waiting, and does not queue its items into the first batch.
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        assert!(!started.load(Ordering::SeqCst));
+        assert!(map_0.is_atomic_in_progress());
+        assert!(!map_1.is_atomic_in_progress());
+
+        // Finish the first atomic write batch, and ensure only the items of the first map are written.
+        map_0.finish_atomic().unwrap();
+        assert_eq!(map_0.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+
+        // Ensure the other thread writes its items once the first batch is released.
+        handle.join().unwrap();
+        assert!(started.load(Ordering::SeqCst));
+        assert_eq!(map_1.get(&1).unwrap(), Some(Cow::Owned("1".to_string())));
+        assert_eq!(map_0.iter().count(), 1);
+    }
+
+    #[test]
+    fn test_iterator_error_fails_the_atomic_batch() {
+        let (map, _ledger) = open_map::<usize, String>(MapID::Test0);
+
+        // Record an iterator error, as if an iteration over the map failed.
+        *map.database.iterator_error.lock() = Some("Corruption".to_string());
+
+        // Ensure the atomic write batch fails, and does not write its items.
+        map.start_atomic();
+        map.insert(0, "0".to_string()).unwrap();
+        assert!(map.finish_atomic().is_err());
+        assert!(map.get(&0).unwrap().is_none());
+        assert!(!map.is_atomic_in_progress());
+
+        // Ensure a subsequent atomic write batch succeeds.
+        map.start_atomic();
+        map.insert(0, "0".to_string()).unwrap();
+        map.finish_atomic().unwrap();
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+    }
+
+    #[test]
+    fn test_reopen() {
+        let ledger = TempLedger::new();
+
+        {
+            let map: DataMap<usize, String> =
+                RocksDB::open_map(CurrentNetwork::ID, Some(ledger.dev), MapID::Test0).unwrap();
+            map.insert(0, "0".to_string()).unwrap();
+        }
+
+        // Reopen the map, after the database has been closed.
+        let map: DataMap<usize, String> =
+            RocksDB::open_map(CurrentNetwork::ID, Some(ledger.dev), MapID::Test0).unwrap();
+        assert_eq!(map.get(&0).unwrap(), Some(Cow::Owned("0".to_string())));
+    }
+}
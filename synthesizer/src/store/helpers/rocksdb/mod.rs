@@ -0,0 +1,302 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod map;
+pub use map::*;
+
+mod block;
+pub use block::*;
+
+mod consensus;
+pub use consensus::*;
+
+mod program;
+pub use program::*;
+
+mod transaction;
+pub use transaction::*;
+
+mod transition;
+pub use transition::*;
+
+use console::network::prelude::*;
+
+use core::hash::Hash;
+use indexmap::IndexMap;
+use once_cell::sync::Lazy;
+use parking_lot::{Condvar, Mutex};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize},
+        Arc,
+        Weak,
+    },
+    thread::ThreadId,
+};
+
+/// The length of the prefix of every key in the database, which consists of
+/// the network ID (2 bytes) followed by the map ID (2 bytes).
+pub const PREFIX_LEN: usize = 4;
+
+/// The registry of the databases that are currently open, keyed by their storage directory.
+///
+/// RocksDB does not allow the same directory to be opened more than once per process, so every
+/// map (and every store) opened for the same network and development ID must share one instance.
+/// The registry holds weak references, so that the database is closed once its last map is dropped.
+static DATABASES: Lazy<Mutex<HashMap<PathBuf, Weak<RocksDB>>>> = Lazy::new(Default::default);
+
+/// An instance of a RocksDB database, shared by all of the maps of a ledger.
+pub struct RocksDB {
+    /// The RocksDB instance.
+    rocksdb: rocksdb::DB,
+    /// The network ID.
+    network_id: u16,
+    /// The optional development ID.
+    dev: Option<u16>,
+    /// The atomic write batch, shared by all of the maps in the database.
+    atomic_batch: Mutex<rocksdb::WriteBatch>,
+    /// The number of maps that are currently part of the atomic write batch. It is incremented by
+    /// every call to `start_atomic` and decremented by every call to `finish_atomic`; the batch is
+    /// only physically written once the depth returns to zero.
+    atomic_depth: AtomicUsize,
+    /// The thread that owns the atomic write batch in progress, if any. As the atomic write batch is
+    /// shared by all of the maps, a thread that starts a batch waits until the batch of another thread is done.
+    atomic_owner: Mutex<Option<ThreadId>>,
+    /// The condition variable that is notified once the atomic write batch in progress is released.
+    atomic_released: Condvar,
+    /// The error of a failed iteration over a map, if any. As the map iterators cannot return an error, the
+    /// error fails the next atomic write batch, so that it is not written based on an incomplete scan.
+    iterator_error: Mutex<Option<String>>,
+}
+
+impl Deref for RocksDB {
+    type Target = rocksdb::DB;
+
+    fn deref(&self) -> &Self::Target {
+        &self.rocksdb
+    }
+}
+
+impl RocksDB {
+    /// Opens the database for the given network ID and optional development ID.
+    /// If the database is already open in this process, the existing instance is returned.
+    pub fn open(network_id: u16, dev: Option<u16>) -> Result<Arc<Self>> {
+        // Determine the storage directory.
+        let path = Self::storage_dir(network_id, dev);
+
+        // Acquire the lock on the registry of open databases.
+        let mut databases = DATABASES.lock();
+
+        // Return the existing instance, if the database is already open.
+        if let Some(database) = databases.get(&path).and_then(Weak::upgrade) {
+            return Ok(database);
+        }
+
+        // Prepare the database options.
+        let mut options = rocksdb::Options::default();
+        options.create_if_missing(true);
+        // Use all of the available threads for background compactions and flushes.
+        options.increase_parallelism(num_cpus());
+        // Bound every iterator to the prefix of the map it was created for.
+        options.set_prefix_extractor(rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN));
+
+        // Open the database.
+        let rocksdb = rocksdb::DB::open(&options, &path)
+            .map_err(|error| anyhow!("Failed to open the ledger at '{}': {error}", path.display()))?;
+
+        let database = Arc::new(Self {
+            rocksdb,
+            network_id,
+            dev,
+            atomic_batch: Default::default(),
+            atomic_depth: Default::default(),
+            atomic_owner: Default::default(),
+            atomic_released: Default::default(),
+            iterator_error: Default::default(),
+        });
+
+        // Register the database.
+        databases.insert(path, Arc::downgrade(&database));
+
+        Ok(database)
+    }
+
+    /// Opens the map with the given ID, for the given network ID and optional development ID.
+    pub fn open_map<
+        K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    >(
+        network_id: u16,
+        dev: Option<u16>,
+        map_id: MapID,
+    ) -> Result<DataMap<K, V>> {
+        // Open the database.
+        let database = Self::open(network_id, dev)?;
+
+        // Construct the key prefix of the map.
+        let mut context = network_id.to_le_bytes().to_vec();
+        context.extend_from_slice(&(map_id as u16).to_le_bytes());
+
+        Ok(DataMap {
+            database,
+            context,
+            batch_in_progress: Arc::new(AtomicBool::new(false)),
+            atomic_batch: Arc::new(Mutex::new(IndexMap::new())),
+        })
+    }
+
+    /// Returns `true` if the atomic write batch in progress is owned by the current thread.
+    fn is_atomic_owner(&self) -> bool {
+        *self.atomic_owner.lock() == Some(std::thread::current().id())
+    }
+
+    /// Returns the network ID.
+    pub const fn network_id(&self) -> u16 {
+        self.network_id
+    }
+
+    /// Returns the optional development ID.
+    pub const fn dev(&self) -> Option<u16> {
+        self.dev
+    }
+
+    /// Returns the storage directory for the given network ID and optional development ID.
+    fn storage_dir(network_id: u16, dev: Option<u16>) -> PathBuf {
+        // In tests, the ledger is stored in the temporary directory, in order to keep the repository clean.
+        #[cfg(test)]
+        {
+            let mut path = std::env::temp_dir();
+            path.push(format!(".ledger-{network_id}-{}", dev.unwrap_or_default()));
+            path
+        }
+        #[cfg(not(test))]
+        {
+            aleo_std::aleo_ledger_dir(network_id, dev)
+        }
+    }
+}
+
+/// A development ID for a test ledger, whose files are removed once it is dropped.
+#[cfg(test)]
+pub(crate) struct TempLedger {
+    /// The development ID.
+    pub(crate) dev: u16,
+}
+
+#[cfg(test)]
+impl TempLedger {
+    /// Samples a new development ID.
+    pub(crate) fn new() -> Self {
+        Self { dev: rand::random() }
+    }
+}
+
+#[cfg(test)]
+impl Drop for TempLedger {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(RocksDB::storage_dir(console::network::Testnet3::ID, Some(self.dev)));
+    }
+}
+
+/// Returns the number of threads available to RocksDB.
+fn num_cpus() -> i32 {
+    std::thread::available_parallelism().map_or(1, |threads| i32::try_from(threads.get()).unwrap_or(i32::MAX))
+}
+
+/// The ID of every map in the database, which is used as part of the prefix of each of its keys.
+///
+/// Note: The IDs are persisted in storage, so existing variants must never be reordered or renumbered.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum MapID {
+    /* Block */
+    BlockStateRoot = 0,
+    BlockReverseStateRoot = 1,
+    BlockID = 2,
+    BlockReverseID = 3,
+    BlockHeader = 4,
+    BlockTransactions = 5,
+    BlockReverseTransactions = 6,
+    BlockCoinbaseSolution = 7,
+    BlockCoinbasePuzzleCommitment = 8,
+    BlockSignature = 9,
+
+    /* Transaction */
+    TransactionID = 100,
+
+    /* Deployment */
+    DeploymentID = 200,
+    DeploymentEdition = 201,
+    DeploymentReverseID = 202,
+    DeploymentOwner = 203,
+    DeploymentProgram = 204,
+    DeploymentVerifyingKey = 205,
+    DeploymentCertificate = 206,
+    DeploymentFee = 207,
+    DeploymentReverseFee = 208,
+
+    /* Execution */
+    ExecutionID = 300,
+    ExecutionReverseID = 301,
+    ExecutionInclusion = 302,
+    ExecutionFee = 303,
+
+    /* Transition */
+    TransitionLocator = 400,
+    TransitionFinalize = 401,
+    TransitionProof = 402,
+    TransitionTPK = 403,
+    TransitionReverseTPK = 404,
+    TransitionTCM = 405,
+    TransitionReverseTCM = 406,
+
+    /* Transition Input */
+    InputID = 500,
+    InputReverseID = 501,
+    InputConstant = 502,
+    InputPublic = 503,
+    InputPrivate = 504,
+    InputRecord = 505,
+    InputRecordTag = 506,
+    InputExternalRecord = 507,
+
+    /* Transition Output */
+    OutputID = 600,
+    OutputReverseID = 601,
+    OutputConstant = 602,
+    OutputPublic = 603,
+    OutputPrivate = 604,
+    OutputRecord = 605,
+    OutputRecordNonce = 606,
+    OutputExternalRecord = 607,
+
+    /* Program */
+    ProgramID = 700,
+    ProgramIndex = 701,
+    ProgramMappingID = 702,
+    ProgramKeyValueID = 703,
+    ProgramKey = 704,
+    ProgramValue = 705,
+    ProgramFinalizeDiff = 706,
+
+    /* Test */
+    #[cfg(test)]
+    Test0 = 60000,
+    #[cfg(test)]
+    Test1 = 60001,
+}
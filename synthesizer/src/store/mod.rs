@@ -15,6 +15,18 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 pub mod helpers;
+#[cfg(feature = "rocks")]
+pub use helpers::rocksdb::{
+    BlockDB,
+    ConsensusDB,
+    DeploymentDB,
+    ExecutionDB,
+    InputDB,
+    OutputDB,
+    ProgramDB,
+    TransactionDB,
+    TransitionDB,
+};
 
 mod block;
 pub use block::*;
@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// A mapping entry that was written, as `(program ID, mapping name, key, old entry, new value)`,
+/// where the old entry is the `(index, value)` of the key in the mapping, and `None` denotes a missing entry.
+pub type FinalizeEntry<N> = (ProgramID<N>, Identifier<N>, Plaintext<N>, Option<(u32, Value<N>)>, Option<Value<N>>);
+
+/// The changes made to the program state when finalizing the transactions of a block.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct FinalizeDiff<N: Network> {
+    /// The programs that were added, in the order they were added.
+    programs: Vec<ProgramID<N>>,
+    /// The mappings that were initialized, in the order they were initialized.
+    mappings: Vec<(ProgramID<N>, Identifier<N>)>,
+    /// The mapping entries that were written.
+    entries: Vec<FinalizeEntry<N>>,
+}
+
+impl<N: Network> FinalizeDiff<N> {
+    /// Initializes a new finalize diff.
+    pub const fn new(
+        programs: Vec<ProgramID<N>>,
+        mappings: Vec<(ProgramID<N>, Identifier<N>)>,
+        entries: Vec<FinalizeEntry<N>>,
+    ) -> Self {
+        Self { programs, mappings, entries }
+    }
+
+    /// Returns the programs that were added.
+    pub fn programs(&self) -> &[ProgramID<N>] {
+        &self.programs
+    }
+
+    /// Returns the mappings that were initialized.
+    pub fn mappings(&self) -> &[(ProgramID<N>, Identifier<N>)] {
+        &self.mappings
+    }
+
+    /// Returns the mapping entries that were written.
+    pub fn entries(&self) -> &[FinalizeEntry<N>] {
+        &self.entries
+    }
+
+    /// Returns `true` if the program state was not changed.
+    pub fn is_empty(&self) -> bool {
+        self.programs.is_empty() && self.mappings.is_empty() && self.entries.is_empty()
+    }
+}
@@ -14,6 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod diff;
+pub use diff::*;
+
+mod overlay;
+pub use overlay::*;
+
 use crate::{
     atomic_write_batch,
     cow_to_cloned,
@@ -29,7 +35,7 @@ use console::{
 use anyhow::Result;
 use core::marker::PhantomData;
 use indexmap::{IndexMap, IndexSet};
-use std::collections::BTreeMap;
+use std::{borrow::Cow, collections::BTreeMap};
 
 /// A trait for program state storage. Note: For the program logic, see `DeploymentStorage`.
 ///
@@ -55,6 +61,8 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
     type KeyMap: for<'a> Map<'a, Field<N>, Plaintext<N>>;
     /// The mapping of `key ID` to `value`.
     type ValueMap: for<'a> Map<'a, Field<N>, Value<N>>;
+    /// The mapping of `block height` to `finalize diff`.
+    type FinalizeDiffMap: for<'a> Map<'a, u32, FinalizeDiff<N>>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -71,6 +79,8 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
     fn key_map(&self) -> &Self::KeyMap;
     /// Returns the value map.
     fn value_map(&self) -> &Self::ValueMap;
+    /// Returns the finalize diff map.
+    fn finalize_diff_map(&self) -> &Self::FinalizeDiffMap;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16>;
@@ -83,6 +93,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().start_atomic();
         self.key_map().start_atomic();
         self.value_map().start_atomic();
+        self.finalize_diff_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -93,6 +104,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.key_value_id_map().is_atomic_in_progress()
             || self.key_map().is_atomic_in_progress()
             || self.value_map().is_atomic_in_progress()
+            || self.finalize_diff_map().is_atomic_in_progress()
     }
 
     /// Aborts an atomic batch write operation.
@@ -103,6 +115,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().abort_atomic();
         self.key_map().abort_atomic();
         self.value_map().abort_atomic();
+        self.finalize_diff_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -112,7 +125,8 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         self.mapping_id_map().finish_atomic()?;
         self.key_value_id_map().finish_atomic()?;
         self.key_map().finish_atomic()?;
-        self.value_map().finish_atomic()
+        self.value_map().finish_atomic()?;
+        self.finalize_diff_map().finish_atomic()
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
@@ -184,7 +198,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())?;
 
         // Ensure the key ID does not already exist.
-        if self.key_map().contains_key(&key_id)? {
+        if self.key_map().contains_key_speculative(&key_id)? {
             bail!("Illegal operation: key ID '{key_id}' already exists in storage - cannot insert again.")
         }
         // Retrieve the key-value IDs for the mapping ID.
@@ -241,7 +255,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
             }
         };
         // If the key ID does not exist, insert it in the key-value ID map.
-        if !self.key_map().contains_key(&key_id)? {
+        if !self.key_map().contains_key_speculative(&key_id)? {
             // Ensure the key ID does not already exist.
             // If this fails, then there is inconsistent state, and likely data corruption.
             if key_value_ids.contains_key(&key_id) {
@@ -289,7 +303,8 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
             bail!("Illegal operation: key ID '{key_id}' does not exist in storage - cannot remove key-value.");
         }
         // Remove the key ID.
-        key_value_ids.remove(&key_id);
+        // Note: The order of the remaining key-value IDs is preserved, so that a removal can be reverted exactly.
+        key_value_ids.shift_remove(&key_id);
 
         atomic_write_batch!(self, {
             // Update the key-value ID map with the new key ID.
@@ -373,9 +388,12 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
             self.program_index_map().remove(program_id)?;
 
             // Update each subsequent deployment index.
-            for (program_id, index) in self.program_index_map().iter() {
-                if *index > *deployment_index {
-                    self.program_index_map().insert(*program_id, index.saturating_sub(1))?;
+            // Note: The indexes are read speculatively, as other programs may have been removed in the same batch.
+            for program_id in self.program_index_map().keys() {
+                if let Some(index) = self.program_index_map().get_speculative(&program_id)? {
+                    if *index > *deployment_index {
+                        self.program_index_map().insert(*program_id, index.saturating_sub(1))?;
+                    }
                 }
             }
 
@@ -415,6 +433,100 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         Ok(())
     }
 
+    /// Stores the given finalize diff for the given `block height` in storage.
+    fn insert_finalize_diff(&self, block_height: u32, finalize_diff: FinalizeDiff<N>) -> Result<()> {
+        // Ensure the finalize diff does not already exist.
+        if self.finalize_diff_map().contains_key(&block_height)? {
+            bail!("Illegal operation: finalize diff for block {block_height} already exists in storage.")
+        }
+        self.finalize_diff_map().insert(block_height, finalize_diff)
+    }
+
+    /// Reverts the finalize diff for the given `block height`, restoring the program state from before the block,
+    /// and removes the finalize diff from storage.
+    fn revert_finalize_diff(&self, block_height: u32) -> Result<()> {
+        // Retrieve the finalize diff.
+        let finalize_diff = match self.finalize_diff_map().get_speculative(&block_height)? {
+            Some(finalize_diff) => cow_to_cloned!(finalize_diff),
+            None => bail!("Illegal operation: finalize diff for block {block_height} does not exist in storage."),
+        };
+
+        // Group the mapping entries by mapping.
+        let mut entries_by_mapping = IndexMap::<_, Vec<_>>::new();
+        for (program_id, mapping_name, key, old_entry, new_value) in finalize_diff.entries() {
+            entries_by_mapping.entry((program_id, mapping_name)).or_default().push((key, old_entry, new_value));
+        }
+
+        atomic_write_batch!(self, {
+            // Restore the old entries of each mapping.
+            for ((program_id, mapping_name), entries) in entries_by_mapping {
+                // Retrieve the mapping ID.
+                let mapping_id = match self.get_mapping_id(program_id, mapping_name)? {
+                    Some(mapping_id) => mapping_id,
+                    None => bail!("Illegal operation: mapping '{mapping_name}' is not initialized - cannot revert."),
+                };
+                // Retrieve the key-value IDs for the mapping ID.
+                let mut key_value_ids = match self.key_value_id_map().get_speculative(&mapping_id)? {
+                    Some(key_value_ids) => cow_to_cloned!(key_value_ids),
+                    None => bail!("Illegal operation: mapping ID '{mapping_id}' is not initialized - cannot revert."),
+                };
+
+                // Remove the new entries, preserving the order of the remaining entries.
+                let mut old_entries = Vec::new();
+                for (key, old_entry, new_value) in entries {
+                    // Compute the key ID.
+                    let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
+                    if new_value.is_some() {
+                        if key_value_ids.shift_remove(&key_id).is_none() {
+                            bail!("Illegal operation: key ID '{key_id}' does not exist in storage - cannot revert.");
+                        }
+                        self.key_map().remove(&key_id)?;
+                        self.value_map().remove(&key_id)?;
+                    }
+                    if let Some((index, old_value)) = old_entry {
+                        old_entries.push((*index, key_id, key, old_value));
+                    }
+                }
+
+                // Restore the old entries at their old index, in ascending order of index.
+                old_entries.sort_unstable_by_key(|(index, ..)| *index);
+                for (index, key_id, key, old_value) in old_entries {
+                    // Compute the value ID.
+                    let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&old_value.to_bits_le())?).to_bits_le())?;
+                    // Ensure the index is within bounds.
+                    let index = usize::try_from(index)?;
+                    if index > key_value_ids.len() || key_value_ids.contains_key(&key_id) {
+                        bail!("Illegal operation: key ID '{key_id}' cannot be restored at index {index}.");
+                    }
+                    // Insert the key-value ID at the old index.
+                    key_value_ids.insert(key_id, value_id);
+                    key_value_ids.move_index(key_value_ids.len() - 1, index);
+                    // Insert the key.
+                    self.key_map().insert(key_id, key.clone())?;
+                    // Insert the value.
+                    self.value_map().insert(key_id, old_value.clone())?;
+                }
+
+                // Update the key-value ID map with the old key-value IDs.
+                self.key_value_id_map().insert(mapping_id, key_value_ids)?;
+            }
+            // Remove each mapping that was initialized in an existing program, in reverse order.
+            for (program_id, mapping_name) in finalize_diff.mappings().iter().rev() {
+                if !finalize_diff.programs().contains(program_id) {
+                    self.remove_mapping(program_id, mapping_name)?;
+                }
+            }
+            // Remove each program that was added, in reverse order.
+            for program_id in finalize_diff.programs().iter().rev() {
+                self.remove_program(program_id)?;
+            }
+            // Remove the finalize diff.
+            self.finalize_diff_map().remove(&block_height)
+        });
+
+        Ok(())
+    }
+
     /// Returns `true` if the given `program ID` exist.
     fn contains_program(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.program_id_map().contains_key(program_id).and(self.program_index_map().contains_key(program_id))
@@ -422,7 +534,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
 
     /// Returns `true` if the given `program ID` and `mapping name` exist.
     fn contains_mapping(&self, program_id: &ProgramID<N>, mapping_name: &Identifier<N>) -> Result<bool> {
-        self.mapping_id_map().contains_key(&(*program_id, *mapping_name))
+        self.mapping_id_map().contains_key_speculative(&(*program_id, *mapping_name))
     }
 
     /// Returns `true` if the given `program ID`, `mapping name`, and `key` exist.
@@ -440,7 +552,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         // Compute the key ID.
         let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
         // Return whether the key ID exists.
-        self.key_map().contains_key(&key_id)
+        self.key_map().contains_key_speculative(&key_id)
     }
 
     /// Returns the mapping names for the given `program ID`.
@@ -475,7 +587,7 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         // Compute the key ID.
         let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
         // Ensure the key ID exists.
-        match self.key_map().contains_key(&key_id)? {
+        match self.key_map().contains_key_speculative(&key_id)? {
             true => Ok(Some(key_id)),
             false => Ok(None),
         }
@@ -512,6 +624,41 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
+    /// Returns the key-value pairs for the given `program ID` and `mapping name`.
+    fn get_mapping(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<Option<Vec<(Plaintext<N>, Value<N>)>>> {
+        // Retrieve the mapping ID.
+        let mapping_id = match self.get_mapping_id(program_id, mapping_name)? {
+            Some(mapping_id) => mapping_id,
+            None => return Ok(None),
+        };
+        // Retrieve the key-value IDs for the mapping.
+        let key_value_ids = match self.key_value_id_map().get_speculative(&mapping_id)? {
+            Some(key_value_ids) => cow_to_cloned!(key_value_ids),
+            None => bail!("Missing key-value IDs for mapping '{mapping_name}' in program '{program_id}'"),
+        };
+        // Retrieve the key-value pairs, in the order they were inserted.
+        key_value_ids
+            .keys()
+            .map(|key_id| match (self.get_key(key_id)?, self.get_value_from_key_id(key_id)?) {
+                (Some(key), Some(value)) => Ok((key, value)),
+                _ => bail!("Missing key-value pair for mapping '{mapping_name}' in program '{program_id}'"),
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(Some)
+    }
+
+    /// Returns the finalize diff for the given `block height`.
+    fn get_finalize_diff(&self, block_height: u32) -> Result<Option<FinalizeDiff<N>>> {
+        match self.finalize_diff_map().get_speculative(&block_height)? {
+            Some(finalize_diff) => Ok(Some(cow_to_cloned!(finalize_diff))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the checksum.
     fn get_checksum(&self) -> Result<Field<N>> {
         // Compute all mapping checksums.
@@ -533,6 +680,70 @@ pub trait ProgramStorage<N: Network>: 'static + Clone + Send + Sync {
         // Compute the checksum as `Hash( all mapping checksums )`.
         N::hash_bhp1024(&preimage.into_values().flatten().collect::<Vec<_>>())
     }
+
+    /// Checks that every program, mapping, and key-value pair in storage is consistent.
+    fn check_integrity(&self) -> Result<()> {
+        // Check each program.
+        for (program_id, mapping_names) in self.program_id_map().iter() {
+            // Ensure the program has a deployment index.
+            if !self.program_index_map().contains_key(&program_id)? {
+                bail!("Missing deployment index for program '{}'", *program_id)
+            }
+            // Check each mapping.
+            for mapping_name in mapping_names.iter() {
+                // Retrieve the mapping ID.
+                let mapping_id = match self.get_mapping_id(&program_id, mapping_name)? {
+                    Some(mapping_id) => mapping_id,
+                    None => bail!("Missing mapping ID for mapping '{}/{mapping_name}'", *program_id),
+                };
+                // Ensure the mapping ID matches.
+                if mapping_id != N::hash_bhp1024(&(&*program_id, mapping_name).to_bits_le())? {
+                    bail!("Mismatching mapping ID for mapping '{}/{mapping_name}'", *program_id)
+                }
+                // Retrieve the key-value IDs for the mapping ID.
+                let key_value_ids = match self.key_value_id_map().get(&mapping_id)? {
+                    Some(key_value_ids) => key_value_ids,
+                    None => bail!("Missing key-value IDs for mapping '{}/{mapping_name}'", *program_id),
+                };
+                // Ensure each key-value pair exists.
+                for (key_id, value_id) in key_value_ids.iter() {
+                    // Retrieve the key.
+                    let key = match self.get_key(key_id)? {
+                        Some(key) => key,
+                        None => bail!("Missing key '{key_id}' in mapping '{}/{mapping_name}'", *program_id),
+                    };
+                    // Retrieve the value.
+                    let value = match self.get_value_from_key_id(key_id)? {
+                        Some(value) => value,
+                        None => bail!("Missing value for key '{key}' in mapping '{}/{mapping_name}'", *program_id),
+                    };
+                    // Ensure the key ID matches.
+                    if *key_id != N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())? {
+                        bail!("Mismatching key ID for key '{key}' in mapping '{}/{mapping_name}'", *program_id)
+                    }
+                    // Ensure the value ID matches.
+                    if *value_id != N::hash_bhp1024(&(*key_id, N::hash_bhp1024(&value.to_bits_le())?).to_bits_le())? {
+                        bail!("Mismatching value ID for key '{key}' in mapping '{}/{mapping_name}'", *program_id)
+                    }
+                }
+            }
+        }
+
+        // Ensure every deployment index belongs to a program.
+        for program_id in self.program_index_map().keys() {
+            if !self.program_id_map().contains_key(&program_id)? {
+                bail!("Found a dangling deployment index for program '{}'", *program_id)
+            }
+        }
+        // Ensure every mapping ID belongs to a program.
+        for (program_id, mapping_name) in self.mapping_id_map().keys().map(|locator| cow_to_copied!(locator)) {
+            match self.program_id_map().get(&program_id)? {
+                Some(mapping_names) if mapping_names.contains(&mapping_name) => (),
+                _ => bail!("Found a dangling mapping ID for mapping '{program_id}/{mapping_name}'"),
+            }
+        }
+        Ok(())
+    }
 }
 
 /// An in-memory program state storage.
@@ -550,6 +761,8 @@ pub struct ProgramMemory<N: Network> {
     key_map: MemoryMap<Field<N>, Plaintext<N>>,
     /// The value map.
     value_map: MemoryMap<Field<N>, Value<N>>,
+    /// The finalize diff map.
+    finalize_diff_map: MemoryMap<u32, FinalizeDiff<N>>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -562,6 +775,7 @@ impl<N: Network> ProgramStorage<N> for ProgramMemory<N> {
     type KeyValueIDMap = MemoryMap<Field<N>, IndexMap<Field<N>, Field<N>>>;
     type KeyMap = MemoryMap<Field<N>, Plaintext<N>>;
     type ValueMap = MemoryMap<Field<N>, Value<N>>;
+    type FinalizeDiffMap = MemoryMap<u32, FinalizeDiff<N>>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -572,6 +786,7 @@ impl<N: Network> ProgramStorage<N> for ProgramMemory<N> {
             key_value_id_map: MemoryMap::default(),
             key_map: MemoryMap::default(),
             value_map: MemoryMap::default(),
+            finalize_diff_map: MemoryMap::default(),
             dev,
         })
     }
@@ -606,6 +821,11 @@ impl<N: Network> ProgramStorage<N> for ProgramMemory<N> {
         &self.value_map
     }
 
+    /// Returns the finalize diff map.
+    fn finalize_diff_map(&self) -> &Self::FinalizeDiffMap {
+        &self.finalize_diff_map
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
@@ -684,6 +904,22 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
         self.storage.remove_program(program_id)
     }
 
+    /// Stores the given finalize diff for the given `block height` in storage.
+    pub fn insert_finalize_diff(&self, block_height: u32, finalize_diff: FinalizeDiff<N>) -> Result<()> {
+        self.storage.insert_finalize_diff(block_height, finalize_diff)
+    }
+
+    /// Reverts the finalize diff for the given `block height`, restoring the program state from before the block,
+    /// and removes the finalize diff from storage.
+    pub fn revert_finalize_diff(&self, block_height: u32) -> Result<()> {
+        self.storage.revert_finalize_diff(block_height)
+    }
+
+    /// Checks that every program, mapping, and key-value pair in storage is consistent.
+    pub fn check_integrity(&self) -> Result<()> {
+        self.storage.check_integrity()
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -710,6 +946,20 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
     }
 }
 
+impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
+    /// Returns a copy-on-write overlay of the program store.
+    /// Writes to the overlay are not applied to this program store until `commit_overlay` is called.
+    pub fn overlay(&self) -> ProgramStore<N, ProgramOverlay<N, P>> {
+        ProgramStore::from(ProgramOverlay::new(&self.storage))
+    }
+
+    /// Writes the changes in the given overlay of this program store, in a single atomic batch.
+    pub fn commit_overlay(&self, overlay: &ProgramStore<N, ProgramOverlay<N, P>>) -> Result<()> {
+        atomic_write_batch!(self, { overlay.storage.commit() });
+        Ok(())
+    }
+}
+
 impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
     /// Returns `true` if the given `program ID` exist.
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> Result<bool> {
@@ -747,6 +997,27 @@ impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
     ) -> Result<Option<Value<N>>> {
         self.storage.get_value(program_id, mapping_name, key)
     }
+
+    /// Returns the key-value pairs for the given `program ID` and `mapping name`.
+    pub fn get_mapping(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<Option<Vec<(Plaintext<N>, Value<N>)>>> {
+        self.storage.get_mapping(program_id, mapping_name)
+    }
+
+    /// Returns the finalize diff for the given `block height`.
+    pub fn get_finalize_diff(&self, block_height: u32) -> Result<Option<FinalizeDiff<N>>> {
+        self.storage.get_finalize_diff(block_height)
+    }
+}
+
+impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, P> {
+    /// Returns an iterator over the program IDs, for all programs in storage.
+    pub fn program_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
+        self.storage.program_id_map().keys()
+    }
 }
 
 #[cfg(test)]
@@ -783,6 +1054,8 @@ mod tests {
         assert!(!program_store.contains_key(&program_id, &mapping_name, &key).unwrap());
         // Ensure the value returns None.
         assert!(program_store.get_value(&program_id, &mapping_name, &key).unwrap().is_none());
+        // Ensure the mapping is empty.
+        assert!(program_store.get_mapping(&program_id, &mapping_name).unwrap().unwrap().is_empty());
 
         // Insert a (key, value) pair.
         program_store.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
@@ -794,6 +1067,11 @@ mod tests {
         assert!(program_store.contains_key(&program_id, &mapping_name, &key).unwrap());
         // Ensure the value returns Some(value).
         assert_eq!(value, program_store.get_value(&program_id, &mapping_name, &key).unwrap().unwrap());
+        // Ensure the mapping contains the (key, value) pair.
+        assert_eq!(
+            vec![(key.clone(), value.clone())],
+            program_store.get_mapping(&program_id, &mapping_name).unwrap().unwrap()
+        );
 
         // Ensure removing the key succeeds.
         program_store.remove_key_value(&program_id, &mapping_name, &key).unwrap();
@@ -812,6 +1090,8 @@ mod tests {
         assert!(program_store.contains_program(&program_id).unwrap());
         // Ensure the mapping name is no longer initialized.
         assert!(!program_store.contains_mapping(&program_id, &mapping_name).unwrap());
+        // Ensure the mapping returns None.
+        assert!(program_store.get_mapping(&program_id, &mapping_name).unwrap().is_none());
         // Ensure the key is still removed.
         assert!(!program_store.contains_key(&program_id, &mapping_name, &key).unwrap());
         // Ensure the value still returns None.
@@ -1032,6 +1312,9 @@ mod tests {
             assert!(!program_store.contains_key(&program_id, &mapping_name, &key).unwrap());
             // Ensure the value returns None.
             assert!(program_store.get_value(&program_id, &mapping_name, &key).unwrap().is_none());
+
+            // Ensure removing the key again fails.
+            assert!(program_store.remove_key_value(&program_id, &mapping_name, &key).is_err());
         }
     }
 
@@ -0,0 +1,380 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::store::helpers::overlay_map::OverlayMap;
+
+/// A copy-on-write overlay of a program state storage.
+/// Writes to the overlay are not applied to the underlying storage until they are committed.
+#[derive(Clone)]
+pub struct ProgramOverlay<N: Network, P: ProgramStorage<N>> {
+    /// The underlying program state storage.
+    base: P,
+    /// The program ID map.
+    program_id_map: OverlayMap<ProgramID<N>, IndexSet<Identifier<N>>, P::ProgramIDMap>,
+    /// The program index map.
+    program_index_map: OverlayMap<ProgramID<N>, u32, P::ProgramIndexMap>,
+    /// The mapping ID map.
+    mapping_id_map: OverlayMap<(ProgramID<N>, Identifier<N>), Field<N>, P::MappingIDMap>,
+    /// The key-value ID map.
+    key_value_id_map: OverlayMap<Field<N>, IndexMap<Field<N>, Field<N>>, P::KeyValueIDMap>,
+    /// The key map.
+    key_map: OverlayMap<Field<N>, Plaintext<N>, P::KeyMap>,
+    /// The value map.
+    value_map: OverlayMap<Field<N>, Value<N>, P::ValueMap>,
+    /// The finalize diff map.
+    finalize_diff_map: OverlayMap<u32, FinalizeDiff<N>, P::FinalizeDiffMap>,
+    /// The optional development ID.
+    dev: Option<u16>,
+}
+
+impl<N: Network, P: ProgramStorage<N>> ProgramOverlay<N, P> {
+    /// Initializes a new overlay of the given program state storage.
+    pub fn new(storage: &P) -> Self {
+        Self {
+            base: storage.clone(),
+            program_id_map: OverlayMap::new(storage.program_id_map().clone()),
+            program_index_map: OverlayMap::new(storage.program_index_map().clone()),
+            mapping_id_map: OverlayMap::new(storage.mapping_id_map().clone()),
+            key_value_id_map: OverlayMap::new(storage.key_value_id_map().clone()),
+            key_map: OverlayMap::new(storage.key_map().clone()),
+            value_map: OverlayMap::new(storage.value_map().clone()),
+            finalize_diff_map: OverlayMap::new(storage.finalize_diff_map().clone()),
+            dev: storage.dev(),
+        }
+    }
+
+    /// Writes the changes in the overlay to the underlying storage, and clears them from the overlay.
+    /// If an atomic batch is in progress on the underlying storage, the changes become part of it.
+    pub fn commit(&self) -> Result<()> {
+        self.program_id_map.commit()?;
+        self.program_index_map.commit()?;
+        self.mapping_id_map.commit()?;
+        self.key_value_id_map.commit()?;
+        self.key_map.commit()?;
+        self.value_map.commit()?;
+        self.finalize_diff_map.commit()
+    }
+
+    /// Returns the changes in the overlay to the programs, mappings, and mapping entries of the underlying storage.
+    pub fn finalize_diff(&self) -> Result<FinalizeDiff<N>> {
+        // Retrieve the programs that were added.
+        let mut programs = Vec::new();
+        for (program_id, mapping_names) in self.program_id_map.changes() {
+            if mapping_names.is_some() && !self.base.contains_program(&program_id)? {
+                programs.push(program_id);
+            }
+        }
+
+        // Retrieve the mappings that were initialized.
+        let mut mappings = Vec::new();
+        for ((program_id, mapping_name), mapping_id) in self.mapping_id_map.changes() {
+            ensure!(mapping_id.is_some(), "Mapping '{program_id}/{mapping_name}' was removed, which cannot be diffed");
+            if !self.base.contains_mapping(&program_id, &mapping_name)? {
+                mappings.push((program_id, mapping_name));
+            }
+        }
+
+        // Retrieve the mapping entries that were written.
+        let mut entries = Vec::new();
+        let key_value_id_changes = self.key_value_id_map.changes();
+        if !key_value_id_changes.is_empty() {
+            // Retrieve the program ID and mapping name of each changed mapping ID.
+            let locators: IndexMap<_, _> = self
+                .mapping_id_map
+                .iter()
+                .filter(|(_, mapping_id)| key_value_id_changes.contains_key(&**mapping_id))
+                .map(|(locator, mapping_id)| (cow_to_copied!(mapping_id), cow_to_copied!(locator)))
+                .collect();
+
+            for (mapping_id, new_key_value_ids) in key_value_id_changes {
+                // Retrieve the program ID and mapping name.
+                let (program_id, mapping_name) = match (locators.get(&mapping_id), new_key_value_ids.is_some()) {
+                    (Some(locator), true) => *locator,
+                    _ => bail!("Mapping ID '{mapping_id}' was removed, which cannot be diffed"),
+                };
+                let new_key_value_ids = new_key_value_ids.unwrap_or_default();
+                // Retrieve the old key-value IDs.
+                let old_key_value_ids = match self.base.key_value_id_map().get_speculative(&mapping_id)? {
+                    Some(key_value_ids) => cow_to_cloned!(key_value_ids),
+                    None => IndexMap::new(),
+                };
+
+                // Retrieve the number of leading entries that are still in their old order.
+                // Note: The entries after these were appended, so their old index must be recorded to revert them.
+                let mut num_in_place = 0;
+                let mut last_index = None;
+                for key_id in new_key_value_ids.keys() {
+                    match old_key_value_ids.get_index_of(key_id) {
+                        Some(index) if last_index.map_or(true, |last_index| index > last_index) => {
+                            num_in_place += 1;
+                            last_index = Some(index);
+                        }
+                        _ => break,
+                    }
+                }
+
+                // Add the entries that were inserted, updated, or moved, in the order of the mapping.
+                for (position, (key_id, value_id)) in new_key_value_ids.iter().enumerate() {
+                    if position >= num_in_place || old_key_value_ids.get(key_id) != Some(value_id) {
+                        let key = match self.get_key(key_id)? {
+                            Some(key) => key,
+                            None => bail!("Missing key '{key_id}' in mapping '{program_id}/{mapping_name}'"),
+                        };
+                        let old_entry = match old_key_value_ids.get_index_of(key_id) {
+                            Some(index) => Some(self.old_entry(key_id, index)?),
+                            None => None,
+                        };
+                        let new_value = self.get_value_from_key_id(key_id)?;
+                        entries.push((program_id, mapping_name, key, old_entry, new_value));
+                    }
+                }
+                // Add the entries that were removed.
+                for (index, key_id) in old_key_value_ids.keys().enumerate() {
+                    if !new_key_value_ids.contains_key(key_id) {
+                        let key = match self.base.get_key(key_id)? {
+                            Some(key) => key,
+                            None => bail!("Missing key '{key_id}' in mapping '{program_id}/{mapping_name}'"),
+                        };
+                        let old_entry = self.old_entry(key_id, index)?;
+                        entries.push((program_id, mapping_name, key, Some(old_entry), None));
+                    }
+                }
+            }
+        }
+
+        Ok(FinalizeDiff::new(programs, mappings, entries))
+    }
+
+    /// Returns the old index and old value of the given key ID in the underlying storage.
+    fn old_entry(&self, key_id: &Field<N>, index: usize) -> Result<(u32, Value<N>)> {
+        match self.base.get_value_from_key_id(key_id)? {
+            Some(value) => Ok((u32::try_from(index)?, value)),
+            None => bail!("Missing value for key '{key_id}' in the underlying storage"),
+        }
+    }
+}
+
+impl<N: Network, P: ProgramStorage<N>> ProgramStore<N, ProgramOverlay<N, P>> {
+    /// Returns the changes in the overlay to the programs, mappings, and mapping entries of the underlying store.
+    pub fn finalize_diff(&self) -> Result<FinalizeDiff<N>> {
+        self.storage.finalize_diff()
+    }
+}
+
+#[rustfmt::skip]
+impl<N: Network, P: ProgramStorage<N>> ProgramStorage<N> for ProgramOverlay<N, P> {
+    type ProgramIDMap = OverlayMap<ProgramID<N>, IndexSet<Identifier<N>>, P::ProgramIDMap>;
+    type ProgramIndexMap = OverlayMap<ProgramID<N>, u32, P::ProgramIndexMap>;
+    type MappingIDMap = OverlayMap<(ProgramID<N>, Identifier<N>), Field<N>, P::MappingIDMap>;
+    type KeyValueIDMap = OverlayMap<Field<N>, IndexMap<Field<N>, Field<N>>, P::KeyValueIDMap>;
+    type KeyMap = OverlayMap<Field<N>, Plaintext<N>, P::KeyMap>;
+    type ValueMap = OverlayMap<Field<N>, Value<N>, P::ValueMap>;
+    type FinalizeDiffMap = OverlayMap<u32, FinalizeDiff<N>, P::FinalizeDiffMap>;
+
+    /// Initializes an overlay of a newly-opened program state storage.
+    fn open(dev: Option<u16>) -> Result<Self> {
+        Ok(Self::new(&P::open(dev)?))
+    }
+
+    /// Returns the program ID map.
+    fn program_id_map(&self) -> &Self::ProgramIDMap {
+        &self.program_id_map
+    }
+
+    /// Returns the program index map.
+    fn program_index_map(&self) -> &Self::ProgramIndexMap {
+        &self.program_index_map
+    }
+
+    /// Returns the mapping ID map.
+    fn mapping_id_map(&self) -> &Self::MappingIDMap {
+        &self.mapping_id_map
+    }
+
+    /// Returns the key-value ID map.
+    fn key_value_id_map(&self) -> &Self::KeyValueIDMap {
+        &self.key_value_id_map
+    }
+
+    /// Returns the key map.
+    fn key_map(&self) -> &Self::KeyMap {
+        &self.key_map
+    }
+
+    /// Returns the value map.
+    fn value_map(&self) -> &Self::ValueMap {
+        &self.value_map
+    }
+
+    /// Returns the finalize diff map.
+    fn finalize_diff_map(&self) -> &Self::FinalizeDiffMap {
+        &self.finalize_diff_map
+    }
+
+    /// Returns the optional development ID.
+    fn dev(&self) -> Option<u16> {
+        self.dev
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_overlay_commit_and_discard() {
+        // Initialize the program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Initialize the key and value.
+        let key = Plaintext::from_str("123456789field").unwrap();
+        let value = Value::from_str("987654321u128").unwrap();
+
+        // Initialize a new program store.
+        let program_store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        let checksum = program_store.storage.get_checksum().unwrap();
+
+        // Write a key-value pair to an overlay, and discard it.
+        {
+            let overlay = program_store.overlay();
+            overlay.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+            assert_eq!(overlay.get_value(&program_id, &mapping_name, &key).unwrap(), Some(value.clone()));
+            assert_ne!(overlay.storage.get_checksum().unwrap(), checksum);
+        }
+        // Ensure the program store is unchanged.
+        assert!(!program_store.contains_key(&program_id, &mapping_name, &key).unwrap());
+        assert_eq!(program_store.storage.get_checksum().unwrap(), checksum);
+
+        // Write a key-value pair to an overlay, and commit it.
+        let overlay = program_store.overlay();
+        overlay.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+        let expected_checksum = overlay.storage.get_checksum().unwrap();
+        program_store.commit_overlay(&overlay).unwrap();
+
+        // Ensure the program store contains the key-value pair.
+        assert_eq!(program_store.get_value(&program_id, &mapping_name, &key).unwrap(), Some(value));
+        assert_eq!(program_store.storage.get_checksum().unwrap(), expected_checksum);
+        program_store.check_integrity().unwrap();
+    }
+
+    #[test]
+    fn test_finalize_diff_revert() {
+        // Initialize the program IDs and mapping names.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let new_program_id = ProgramID::<CurrentNetwork>::from_str("world.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Initialize the keys and values.
+        let key = Plaintext::from_str("123456789field").unwrap();
+        let new_key = Plaintext::from_str("987654321field").unwrap();
+        let value = Value::from_str("1u128").unwrap();
+        let new_value = Value::from_str("2u128").unwrap();
+
+        // Initialize a new program store, with a key-value pair.
+        let program_store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        program_store.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+        let checksum = program_store.storage.get_checksum().unwrap();
+
+        // Update the key, insert a new key, and add a new program in an overlay.
+        let overlay = program_store.overlay();
+        overlay.update_key_value(&program_id, &mapping_name, key.clone(), new_value.clone()).unwrap();
+        overlay.update_key_value(&program_id, &mapping_name, new_key.clone(), value.clone()).unwrap();
+        overlay.initialize_mapping(&new_program_id, &mapping_name).unwrap();
+        overlay.update_key_value(&new_program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+
+        // Ensure the finalize diff contains the changes.
+        let finalize_diff = overlay.finalize_diff().unwrap();
+        assert_eq!(finalize_diff.programs(), [new_program_id]);
+        assert_eq!(finalize_diff.mappings(), [(new_program_id, mapping_name)]);
+        assert_eq!(finalize_diff.entries(), [
+            (program_id, mapping_name, key.clone(), Some((0, value.clone())), Some(new_value.clone())),
+            (program_id, mapping_name, new_key.clone(), None, Some(value.clone())),
+            (new_program_id, mapping_name, key.clone(), None, Some(value.clone())),
+        ]);
+
+        // Commit the overlay, along with the finalize diff.
+        program_store.commit_overlay(&overlay).unwrap();
+        program_store.insert_finalize_diff(1, finalize_diff.clone()).unwrap();
+        assert_eq!(program_store.get_finalize_diff(1).unwrap(), Some(finalize_diff));
+        assert_eq!(program_store.get_value(&program_id, &mapping_name, &key).unwrap(), Some(new_value));
+        assert!(program_store.contains_program(&new_program_id).unwrap());
+
+        // Revert the finalize diff.
+        program_store.revert_finalize_diff(1).unwrap();
+        assert!(program_store.get_finalize_diff(1).unwrap().is_none());
+        assert!(program_store.revert_finalize_diff(1).is_err());
+
+        // Ensure the program store is restored.
+        assert_eq!(program_store.get_value(&program_id, &mapping_name, &key).unwrap(), Some(value));
+        assert!(!program_store.contains_key(&program_id, &mapping_name, &new_key).unwrap());
+        assert!(!program_store.contains_program(&new_program_id).unwrap());
+        assert_eq!(program_store.storage.get_checksum().unwrap(), checksum);
+        program_store.check_integrity().unwrap();
+    }
+
+    #[test]
+    fn test_finalize_diff_revert_removals() {
+        // Initialize the program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        // Initialize the keys and values.
+        let keys: Vec<Plaintext<CurrentNetwork>> =
+            (0..4).map(|i| Plaintext::from_str(&format!("{i}field")).unwrap()).collect();
+        let value = Value::from_str("1u128").unwrap();
+        let new_value = Value::from_str("2u128").unwrap();
+
+        // Initialize a new program store, with four key-value pairs.
+        let program_store = ProgramStore::<_, ProgramMemory<_>>::open(None).unwrap();
+        program_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        for key in &keys {
+            program_store.insert_key_value(&program_id, &mapping_name, key.clone(), value.clone()).unwrap();
+        }
+        let checksum = program_store.storage.get_checksum().unwrap();
+
+        // Remove the first and third keys, and reinsert the first key with its old value.
+        let overlay = program_store.overlay();
+        overlay.remove_key_value(&program_id, &mapping_name, &keys[0]).unwrap();
+        overlay.remove_key_value(&program_id, &mapping_name, &keys[2]).unwrap();
+        overlay.update_key_value(&program_id, &mapping_name, keys[0].clone(), value.clone()).unwrap();
+        // Update the last key.
+        overlay.update_key_value(&program_id, &mapping_name, keys[3].clone(), new_value.clone()).unwrap();
+
+        // Ensure the finalize diff contains the changes.
+        let finalize_diff = overlay.finalize_diff().unwrap();
+        assert!(finalize_diff.programs().is_empty());
+        assert!(finalize_diff.mappings().is_empty());
+        assert_eq!(finalize_diff.entries(), [
+            (program_id, mapping_name, keys[3].clone(), Some((3, value.clone())), Some(new_value)),
+            (program_id, mapping_name, keys[0].clone(), Some((0, value.clone())), Some(value.clone())),
+            (program_id, mapping_name, keys[2].clone(), Some((2, value)), None),
+        ]);
+
+        // Commit the overlay, along with the finalize diff, and revert it.
+        program_store.commit_overlay(&overlay).unwrap();
+        program_store.insert_finalize_diff(1, finalize_diff).unwrap();
+        assert_ne!(program_store.storage.get_checksum().unwrap(), checksum);
+        program_store.revert_finalize_diff(1).unwrap();
+
+        // Ensure the program store is restored, including the order of the mapping.
+        assert_eq!(program_store.storage.get_checksum().unwrap(), checksum);
+        program_store.check_integrity().unwrap();
+    }
+}
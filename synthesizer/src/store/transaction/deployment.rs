@@ -263,6 +263,87 @@ pub trait DeploymentStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Removes the entries of the deployment for the given `transaction ID` that are present in storage.
+    /// Unlike `remove`, this method tolerates missing entries, and is used to roll back a partial write.
+    fn remove_incomplete(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the program ID and edition, falling back to the program ID and its edition if they are missing.
+        let program_id_and_edition = match self
+            .reverse_id_map()
+            .iter()
+            .find(|(_, id)| **id == *transaction_id)
+            .map(|(program_id_and_edition, _)| cow_to_copied!(program_id_and_edition))
+        {
+            Some(program_id_and_edition) => Some(program_id_and_edition),
+            None => match self.get_program_id(transaction_id)? {
+                Some(program_id) => self.get_edition(&program_id)?.map(|edition| (program_id, edition)),
+                None => None,
+            },
+        };
+        // Retrieve the program ID.
+        let program_id = match program_id_and_edition {
+            Some((program_id, _)) => Some(program_id),
+            None => self.get_program_id(transaction_id)?,
+        };
+        // Retrieve the keys of the verifying keys and certificates of the program.
+        let function_keys = match program_id_and_edition {
+            Some((program_id, edition)) => self
+                .verifying_key_map()
+                .keys()
+                .chain(self.certificate_map().keys())
+                .map(|key| cow_to_copied!(key))
+                .filter(|(id, _, e)| *id == program_id && *e == edition)
+                .collect::<Vec<_>>(),
+            None => vec![],
+        };
+        // Retrieve the fee transition ID, falling back to the reverse fee entry if it is missing.
+        let fee_transition_id = match self.fee_map().get(transaction_id)? {
+            Some(fee) => Some(cow_to_cloned!(fee).0),
+            None => self
+                .reverse_fee_map()
+                .iter()
+                .find(|(_, id)| **id == *transaction_id)
+                .map(|(transition_id, _)| cow_to_copied!(transition_id)),
+        };
+
+        atomic_write_batch!(self, {
+            // Remove the program ID.
+            self.id_map().remove(transaction_id)?;
+            // Remove the edition.
+            if let Some(program_id) = &program_id {
+                self.edition_map().remove(program_id)?;
+            }
+
+            if let Some(program_id_and_edition) = &program_id_and_edition {
+                // Remove the reverse program ID.
+                self.reverse_id_map().remove(program_id_and_edition)?;
+                // Remove the owner.
+                self.owner_map().remove(program_id_and_edition)?;
+                // Remove the program.
+                self.program_map().remove(program_id_and_edition)?;
+            }
+
+            // Remove the verifying keys and certificates.
+            for function_key in &function_keys {
+                // Remove the verifying key.
+                self.verifying_key_map().remove(function_key)?;
+                // Remove the certificate.
+                self.certificate_map().remove(function_key)?;
+            }
+
+            // Remove the fee.
+            self.fee_map().remove(transaction_id)?;
+            if let Some(fee_transition_id) = &fee_transition_id {
+                self.reverse_fee_map().remove(fee_transition_id)?;
+                // Remove the fee transition.
+                self.transition_store().remove(fee_transition_id)?;
+            }
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
     /// Returns the transaction ID that contains the given `program ID`.
     fn find_transaction_id_from_program_id(&self, program_id: &ProgramID<N>) -> Result<Option<N::TransactionID>> {
         // Retrieve the edition.
@@ -594,6 +675,11 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.remove(transaction_id)
     }
 
+    /// Removes the entries of the deployment for the given `transaction ID` that are present in storage.
+    pub fn remove_incomplete(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.remove_incomplete(transaction_id)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
@@ -194,6 +194,46 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Removes the entries of the execution for the given `transaction ID` that are present in storage.
+    /// Unlike `remove`, this method tolerates missing entries, and is used to roll back a partial write.
+    fn remove_incomplete(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the transition IDs, including the fee transition ID, from the reverse transition IDs.
+        let transition_ids = self
+            .reverse_id_map()
+            .iter()
+            .filter(|(_, id)| **id == *transaction_id)
+            .map(|(transition_id, _)| cow_to_copied!(transition_id))
+            .collect::<Vec<_>>();
+        // Retrieve the transition IDs and fee transition ID, if they exist.
+        let (mut transition_ids, fee_transition_id) = match self.id_map().get(transaction_id)? {
+            Some(ids) => cow_to_cloned!(ids),
+            None => (transition_ids, None),
+        };
+        transition_ids.extend(fee_transition_id);
+
+        atomic_write_batch!(self, {
+            // Remove the transition IDs.
+            self.id_map().remove(transaction_id)?;
+
+            // Remove the transitions, including the fee transition.
+            for transition_id in &transition_ids {
+                // Remove the transition ID.
+                self.reverse_id_map().remove(transition_id)?;
+                // Remove the transition.
+                self.transition_store().remove(transition_id)?;
+            }
+
+            // Remove the global state root and inclusion proof.
+            self.inclusion_map().remove(transaction_id)?;
+            // Remove the fee.
+            self.fee_map().remove(transaction_id)?;
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -386,6 +426,11 @@ impl<N: Network, E: ExecutionStorage<N>> ExecutionStore<N, E> {
         self.storage.remove(transaction_id)
     }
 
+    /// Removes the entries of the execution for the given `transaction ID` that are present in storage.
+    pub fn remove_incomplete(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.remove_incomplete(transaction_id)
+    }
+
     /// Returns the transition store.
     pub fn transition_store(&self) -> &TransitionStore<N, E::TransitionStorage> {
         self.storage.transition_store()
@@ -159,6 +159,35 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
         Ok(())
     }
 
+    /// Removes the entries of the transaction for the given `transaction ID` that are present in storage.
+    /// Unlike `remove`, this method tolerates missing entries, and is used to roll back a partial write.
+    fn remove_incomplete(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        // Retrieve the transaction type, if it exists.
+        let transaction_type =
+            self.id_map().get(transaction_id)?.map(|transaction_type| cow_to_copied!(transaction_type));
+
+        atomic_write_batch!(self, {
+            // Remove the transaction type.
+            self.id_map().remove(transaction_id)?;
+            // Remove the transaction.
+            match transaction_type {
+                // Remove the deployment transaction.
+                Some(TransactionType::Deploy) => self.deployment_store().remove_incomplete(transaction_id)?,
+                // Remove the execution transaction.
+                Some(TransactionType::Execute) => self.execution_store().remove_incomplete(transaction_id)?,
+                // If the transaction type is missing, remove the entries of either type of transaction.
+                None => {
+                    self.deployment_store().remove_incomplete(transaction_id)?;
+                    self.execution_store().remove_incomplete(transaction_id)?;
+                }
+            };
+
+            Ok(())
+        });
+
+        Ok(())
+    }
+
     /// Returns the transaction ID that contains the given `transition ID`.
     fn find_transaction_id_from_transition_id(
         &self,
@@ -187,6 +216,52 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
             TransactionType::Execute => self.execution_store().get_transaction(transaction_id),
         }
     }
+
+    /// Checks that the reverse indexes of the given `transaction` are consistent with storage.
+    fn check_integrity(&self, transaction: &Transaction<N>) -> Result<()> {
+        // Retrieve the transaction ID.
+        let transaction_id = transaction.id();
+        // Retrieve the transaction type.
+        let transaction_type = match self.id_map().get(&transaction_id)? {
+            Some(transaction_type) => cow_to_copied!(transaction_type),
+            None => bail!("Missing type for transaction '{transaction_id}'"),
+        };
+
+        match (transaction_type, transaction) {
+            (TransactionType::Deploy, Transaction::Deploy(_, _, deployment, _)) => {
+                // Ensure the reverse program ID entry points to the transaction.
+                let program_id = deployment.program_id();
+                if self.find_transaction_id_from_program_id(program_id)? != Some(transaction_id) {
+                    bail!("Invalid reverse entry for program '{program_id}' in transaction '{transaction_id}'")
+                }
+                // Ensure the reverse fee entry points to the transaction.
+                for transition_id in transaction.transition_ids() {
+                    if self.deployment_store().find_transaction_id_from_transition_id(transition_id)?
+                        != Some(transaction_id)
+                    {
+                        bail!("Invalid reverse entry for transition '{transition_id}' ('{transaction_id}')")
+                    }
+                }
+            }
+            (TransactionType::Execute, Transaction::Execute(..)) => {
+                // Ensure the reverse entry of each transition points to the transaction.
+                for transition_id in transaction.transition_ids() {
+                    if self.execution_store().find_transaction_id_from_transition_id(transition_id)?
+                        != Some(transaction_id)
+                    {
+                        bail!("Invalid reverse entry for transition '{transition_id}' ('{transaction_id}')")
+                    }
+                }
+            }
+            _ => bail!("Mismatching type for transaction '{transaction_id}'"),
+        }
+
+        // Check the transitions.
+        for transition in transaction.transitions() {
+            self.transition_store().check_integrity(transition)?;
+        }
+        Ok(())
+    }
 }
 
 /// An in-memory transaction storage.
@@ -266,6 +341,16 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.remove(transaction_id)
     }
 
+    /// Removes the entries of the transaction for the given `transaction ID` that are present in storage.
+    pub fn remove_incomplete(&self, transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.remove_incomplete(transaction_id)
+    }
+
+    /// Checks that the reverse indexes of the given `transaction` are consistent with storage.
+    pub fn check_integrity(&self, transaction: &Transaction<N>) -> Result<()> {
+        self.storage.check_integrity(transaction)
+    }
+
     /// Returns the transition store.
     pub fn transition_store(&self) -> &TransitionStore<N, T::TransitionStorage> {
         self.storage.transition_store()
@@ -17,6 +17,7 @@
 use crate::{
     atomic_write_batch,
     block::Input,
+    cow_to_copied,
     store::helpers::{memory_map::MemoryMap, Map, MapRead},
 };
 use console::{
@@ -151,11 +152,16 @@ pub trait InputStorage<N: Network>: Clone + Send + Sync {
 
     /// Removes the input for the given `transition ID`.
     fn remove(&self, transition_id: &N::TransitionID) -> Result<()> {
-        // Retrieve the input IDs.
+        // Retrieve the input IDs, falling back to the reverse input IDs if they are missing.
         let input_ids: Vec<_> = match self.id_map().get(transition_id)? {
             Some(Cow::Borrowed(ids)) => ids.to_vec(),
             Some(Cow::Owned(ids)) => ids.into_iter().collect(),
-            None => return Ok(()),
+            None => self
+                .reverse_id_map()
+                .iter()
+                .filter(|(_, id)| **id == *transition_id)
+                .map(|(input_id, _)| cow_to_copied!(input_id))
+                .collect(),
         };
 
         atomic_write_batch!(self, {
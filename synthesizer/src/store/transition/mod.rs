@@ -151,8 +151,10 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             self.output_store().insert(transition_id, transition.outputs())?;
             // Store the finalize inputs.
             self.finalize_map().insert(transition_id, transition.finalize().cloned())?;
-            // Store the proof.
-            self.proof_map().insert(transition_id, transition.proof().clone())?;
+            // Store the proof, if it is not deferred to the execution proof.
+            if let Some(proof) = transition.proof() {
+                self.proof_map().insert(transition_id, proof.clone())?;
+            }
             // Store `tpk`.
             self.tpk_map().insert(transition_id, *transition.tpk())?;
             // Store the reverse `tpk` entry.
@@ -169,16 +171,21 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
     }
 
     /// Removes the input for the given `transition ID`.
+    /// The entries of the transition that are present in storage are removed, even if the transition is incomplete.
     fn remove(&self, transition_id: &N::TransitionID) -> Result<()> {
-        // Retrieve the `tpk`.
+        // Retrieve the `tpk`, falling back to the reverse `tpk` entry if it is missing.
         let tpk = match self.tpk_map().get(transition_id)? {
-            Some(tpk) => cow_to_copied!(tpk),
-            None => return Ok(()),
+            Some(tpk) => Some(cow_to_copied!(tpk)),
+            None => {
+                self.reverse_tpk_map().iter().find(|(_, id)| **id == *transition_id).map(|(tpk, _)| cow_to_copied!(tpk))
+            }
         };
-        // Retrieve the `tcm`.
+        // Retrieve the `tcm`, falling back to the reverse `tcm` entry if it is missing.
         let tcm = match self.tcm_map().get(transition_id)? {
-            Some(tcm) => cow_to_copied!(tcm),
-            None => return Ok(()),
+            Some(tcm) => Some(cow_to_copied!(tcm)),
+            None => {
+                self.reverse_tcm_map().iter().find(|(_, id)| **id == *transition_id).map(|(tcm, _)| cow_to_copied!(tcm))
+            }
         };
 
         atomic_write_batch!(self, {
@@ -195,11 +202,15 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             // Remove `tpk`.
             self.tpk_map().remove(transition_id)?;
             // Remove the reverse `tpk` entry.
-            self.reverse_tpk_map().remove(&tpk)?;
+            if let Some(tpk) = &tpk {
+                self.reverse_tpk_map().remove(tpk)?;
+            }
             // Remove `tcm`.
             self.tcm_map().remove(transition_id)?;
             // Remove the reverse `tcm` entry.
-            self.reverse_tcm_map().remove(&tcm)?;
+            if let Some(tcm) = &tcm {
+                self.reverse_tcm_map().remove(tcm)?;
+            }
 
             Ok(())
         });
@@ -220,15 +231,15 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
         let outputs = self.output_store().get_outputs(transition_id)?;
         // Retrieve the finalize inputs.
         let finalize = self.finalize_map().get(transition_id)?;
-        // Retrieve the proof.
-        let proof = self.proof_map().get(transition_id)?;
+        // Retrieve the proof, if it exists.
+        let proof = self.proof_map().get(transition_id)?.map(|proof| cow_to_cloned!(proof));
         // Retrieve `tpk`.
         let tpk = self.tpk_map().get(transition_id)?;
         // Retrieve `tcm`.
         let tcm = self.tcm_map().get(transition_id)?;
 
-        match (finalize, proof, tpk, tcm) {
-            (Some(finalize), Some(proof), Some(tpk), Some(tcm)) => {
+        match (finalize, tpk, tcm) {
+            (Some(finalize), Some(tpk), Some(tcm)) => {
                 // Construct the transition.
                 let transition = Transition::new(
                     program_id,
@@ -236,7 +247,7 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
                     inputs,
                     outputs,
                     cow_to_cloned!(finalize),
-                    cow_to_cloned!(proof),
+                    proof,
                     cow_to_cloned!(tpk),
                     cow_to_cloned!(tcm),
                 )?;
@@ -249,6 +260,35 @@ pub trait TransitionStorage<N: Network>: Clone + Send + Sync {
             _ => bail!("Transition '{transition_id}' is missing some data (possible corruption)"),
         }
     }
+
+    /// Checks that the reverse indexes of the given `transition` are consistent with storage.
+    fn check_integrity(&self, transition: &Transition<N>) -> Result<()> {
+        // Retrieve the transition ID.
+        let transition_id = transition.id();
+        // Ensure the reverse `tpk` entry points to the transition.
+        match self.reverse_tpk_map().get(transition.tpk())? {
+            Some(id) if cow_to_copied!(id) == *transition_id => (),
+            _ => bail!("Invalid reverse 'tpk' entry for transition '{transition_id}'"),
+        }
+        // Ensure the reverse `tcm` entry points to the transition.
+        match self.reverse_tcm_map().get(transition.tcm())? {
+            Some(id) if cow_to_copied!(id) == *transition_id => (),
+            _ => bail!("Invalid reverse 'tcm' entry for transition '{transition_id}'"),
+        }
+        // Ensure the reverse entry of each input ID points to the transition.
+        for input_id in transition.input_ids() {
+            if self.input_store().find_transition_id(input_id)? != Some(*transition_id) {
+                bail!("Invalid reverse entry for input '{input_id}' in transition '{transition_id}'")
+            }
+        }
+        // Ensure the reverse entry of each output ID points to the transition.
+        for output_id in transition.output_ids() {
+            if self.output_store().find_transition_id(output_id)? != Some(*transition_id) {
+                bail!("Invalid reverse entry for output '{output_id}' in transition '{transition_id}'")
+            }
+        }
+        Ok(())
+    }
 }
 
 /// An in-memory transition storage.
@@ -418,6 +458,11 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.storage.remove(transition_id)
     }
 
+    /// Checks that the reverse indexes of the given `transition` are consistent with storage.
+    pub fn check_integrity(&self, transition: &Transition<N>) -> Result<()> {
+        self.storage.check_integrity(transition)
+    }
+
     /// Starts an atomic batch write operation.
     pub fn start_atomic(&self) {
         self.storage.start_atomic();
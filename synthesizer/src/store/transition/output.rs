@@ -17,6 +17,7 @@
 use crate::{
     atomic_write_batch,
     block::Output,
+    cow_to_copied,
     store::helpers::{memory_map::MemoryMap, Map, MapRead},
 };
 use console::{
@@ -153,11 +154,16 @@ pub trait OutputStorage<N: Network>: Clone + Send + Sync {
 
     /// Removes the output for the given `transition ID`.
     fn remove(&self, transition_id: &N::TransitionID) -> Result<()> {
-        // Retrieve the output IDs.
+        // Retrieve the output IDs, falling back to the reverse output IDs if they are missing.
         let output_ids: Vec<_> = match self.id_map().get(transition_id)? {
             Some(Cow::Borrowed(ids)) => ids.to_vec(),
             Some(Cow::Owned(ids)) => ids.into_iter().collect(),
-            None => return Ok(()),
+            None => self
+                .reverse_id_map()
+                .iter()
+                .filter(|(_, id)| **id == *transition_id)
+                .map(|(output_id, _)| cow_to_copied!(output_id))
+                .collect(),
         };
 
         atomic_write_batch!(self, {
@@ -41,9 +41,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 let authorization = cast_ref!(authorization as Authorization<$network>);
                 lap!(timer, "Prepare the authorization");
 
-                // Execute the call.
+                // Execute the call, deferring the transition proofs to the batch proof of the execution.
                 let (response, execution, inclusion, metrics) =
-                    $process.execute::<$aleo, _>(authorization.clone(), rng)?;
+                    $process.execute_batch::<$aleo, _>(authorization.clone(), rng)?;
                 lap!(timer, "Execute the call");
 
                 // Prepare the assignments.
@@ -57,10 +57,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
                 lap!(timer, "Prepare the assignments");
 
-                // Compute the inclusion proof and update the execution.
+                // Compute the batch proof of the transitions and inclusion, and update the execution.
                 let execution =
                     inclusion.prove_execution::<$aleo, _>(execution, assignments, global_state_root.into(), rng)?;
-                lap!(timer, "Compute the inclusion proof");
+                lap!(timer, "Compute the batch proof");
 
                 // Prepare the return.
                 let response = cast_ref!(response as Response<N>).clone();
@@ -140,6 +140,56 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Process the logic.
         process!(self, logic)
     }
+
+    /// Executes a fee for the given private key and fee amount (in microcredits), paid from the public balance.
+    #[inline]
+    pub fn execute_fee_public<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<(Response<N>, Fee<N>, Vec<CallMetrics<N>>)> {
+        let timer = timer!("VM::execute_fee_public");
+
+        // Prepare the query.
+        let query = match query {
+            Some(query) => query,
+            None => Query::VM(self.block_store().clone()),
+        };
+        lap!(timer, "Prepare the query");
+
+        // Compute the core logic.
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the private key.
+                let private_key = cast_ref!(&private_key as PrivateKey<$network>);
+                lap!(timer, "Prepare the private key");
+
+                // Execute the call to fee.
+                let (response, fee_transition, _inclusion, metrics) =
+                    $process.execute_fee_public::<$aleo, _>(private_key, fee_in_microcredits, rng)?;
+                lap!(timer, "Execute the call to fee");
+
+                // Construct the fee, without an inclusion proof, as the public fee does not consume any records.
+                let fee_transition = cast_ref!(fee_transition as Transition<N>).clone();
+                let fee = Fee::from(fee_transition, query.current_state_root()?, None);
+                lap!(timer, "Construct the fee");
+
+                // Prepare the return.
+                let response = cast_ref!(response as Response<N>).clone();
+                let metrics = cast_ref!(metrics as Vec<CallMetrics<N>>).clone();
+                lap!(timer, "Prepare the response and metrics");
+
+                finish!(timer);
+
+                // Return the response, fee, metrics.
+                Ok((response, fee, metrics))
+            }};
+        }
+        // Process the logic.
+        process!(self, logic)
+    }
 }
 
 #[cfg(test)]
@@ -243,13 +293,13 @@ mod tests {
 
         // Assert the size of the transaction.
         let transaction_size_in_bytes = transaction.to_bytes_le().unwrap().len();
-        assert_eq!(2566, transaction_size_in_bytes, "Update me if serialization has changed");
+        assert_eq!(2443, transaction_size_in_bytes, "Update me if serialization has changed");
 
         // Assert the size of the execution.
         assert!(matches!(transaction, Transaction::Execute(_, _, _)));
         if let Transaction::Execute(_, execution, _) = &transaction {
             let execution_size_in_bytes = execution.to_bytes_le().unwrap().len();
-            assert_eq!(2531, execution_size_in_bytes, "Update me if serialization has changed");
+            assert_eq!(2408, execution_size_in_bytes, "Update me if serialization has changed");
         }
     }
 
@@ -278,13 +328,13 @@ mod tests {
 
         // Assert the size of the transaction.
         let transaction_size_in_bytes = transaction.to_bytes_le().unwrap().len();
-        assert_eq!(2451, transaction_size_in_bytes, "Update me if serialization has changed");
+        assert_eq!(2328, transaction_size_in_bytes, "Update me if serialization has changed");
 
         // Assert the size of the execution.
         assert!(matches!(transaction, Transaction::Execute(_, _, _)));
         if let Transaction::Execute(_, execution, _) = &transaction {
             let execution_size_in_bytes = execution.to_bytes_le().unwrap().len();
-            assert_eq!(2416, execution_size_in_bytes, "Update me if serialization has changed");
+            assert_eq!(2293, execution_size_in_bytes, "Update me if serialization has changed");
         }
     }
 
@@ -312,13 +362,13 @@ mod tests {
 
         // Assert the size of the transaction.
         let transaction_size_in_bytes = transaction.to_bytes_le().unwrap().len();
-        assert_eq!(2463, transaction_size_in_bytes, "Update me if serialization has changed");
+        assert_eq!(2340, transaction_size_in_bytes, "Update me if serialization has changed");
 
         // Assert the size of the execution.
         assert!(matches!(transaction, Transaction::Execute(_, _, _)));
         if let Transaction::Execute(_, execution, _) = &transaction {
             let execution_size_in_bytes = execution.to_bytes_le().unwrap().len();
-            assert_eq!(2428, execution_size_in_bytes, "Update me if serialization has changed");
+            assert_eq!(2305, execution_size_in_bytes, "Update me if serialization has changed");
         }
     }
 
@@ -17,34 +17,38 @@
 use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
-    /// Finalizes the given transactions into the VM.
+    /// Finalizes the given transactions into the VM, issues the given rewards (in microcredits),
+    /// and returns the changes to the program state.
     /// This method assumes the given transactions **are valid**.
     #[inline]
-    pub fn finalize(&self, transactions: &Transactions<N>) -> Result<()> {
+    pub fn finalize(
+        &self,
+        transactions: &Transactions<N>,
+        rewards: &IndexMap<Address<N>, u64>,
+    ) -> Result<FinalizeDiff<N>> {
         let timer = timer!("VM::finalize");
-        atomic_write_batch!(self, {
-            // Acquire the write lock on the process.
-            let mut process = self.process.write();
-
-            for transaction in transactions.values() {
-                // Finalize the transaction.
-                match transaction {
-                    Transaction::Deploy(_, _, deployment, _) => {
-                        process.finalize_deployment(self.program_store(), deployment)?;
-                        lap!(timer, "Finalize deployment");
-                    }
-                    Transaction::Execute(_, execution, _) => {
-                        process.finalize_execution(self.program_store(), execution)?;
-                        lap!(timer, "Finalize execution");
-                    }
-                }
-            }
-            Ok(())
-        });
+
+        // Speculatively finalize the transactions.
+        let speculate = self.speculate(transactions.values());
+        lap!(timer, "Speculate the transactions");
+
+        // Ensure all of the transactions were accepted.
+        if let Some((transaction, error)) = speculate.rejected().first() {
+            bail!("Transaction '{}' failed to finalize - {error}", transaction.id());
+        }
+
+        // Issue the rewards.
+        speculate.issue_rewards(rewards)?;
+        lap!(timer, "Issue the rewards");
+
+        // Retrieve the changes to the program state.
+        let finalize_diff = speculate.finalize_diff()?;
+        // Commit the speculation.
+        self.commit_speculate(speculate)?;
 
         finish!(timer);
 
-        Ok(())
+        Ok(finalize_diff)
     }
 }
 
@@ -63,9 +67,18 @@ mod tests {
         let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
 
         // Finalize the transaction.
-        vm.finalize(&Transactions::from(&[deployment_transaction.clone()])).unwrap();
+        let finalize_diff =
+            vm.finalize(&Transactions::from(&[deployment_transaction.clone()]), &IndexMap::new()).unwrap();
+
+        // Ensure the finalize diff contains the deployed program.
+        match &deployment_transaction {
+            Transaction::Deploy(_, _, deployment, _) => {
+                assert_eq!(finalize_diff.programs(), [*deployment.program_id()])
+            }
+            _ => unreachable!(),
+        }
 
         // Ensure the VM can't redeploy the same transaction.
-        assert!(vm.finalize(&Transactions::from(&[deployment_transaction])).is_err());
+        assert!(vm.finalize(&Transactions::from(&[deployment_transaction]), &IndexMap::new()).is_err());
     }
 }
@@ -0,0 +1,173 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::process::{Deployment, Execution, FinalizeMetrics};
+use console::network::prelude::*;
+
+/// The fee (in microcredits) per byte of a deployment.
+pub const DEPLOYMENT_FEE_PER_BYTE: u64 = 1_000;
+/// The fee (in microcredits) per constraint in the circuits of a deployment.
+pub const DEPLOYMENT_FEE_PER_CONSTRAINT: u64 = 10;
+/// The fee (in microcredits) per byte of an execution.
+pub const EXECUTION_FEE_PER_BYTE: u64 = 1_000;
+/// The fee (in microcredits) per transition in an execution.
+pub const EXECUTION_FEE_PER_TRANSITION: u64 = 100_000;
+/// The fee (in microcredits) per finalize command that is evaluated in an execution.
+pub const EXECUTION_FEE_PER_FINALIZE_COMMAND: u64 = 10_000;
+/// The fee (in microcredits) per byte of the mapping entries written in the finalize scopes of an execution.
+pub const EXECUTION_FEE_PER_FINALIZE_BYTE: u64 = 1_000;
+
+/// Returns the minimum fee (in microcredits) to deploy the given deployment.
+///
+/// The cost is the storage cost of the deployment, plus the synthesis cost of its circuits.
+/// Note: The number of constraints is read from the verifying keys, which are bound to the
+/// circuits synthesized by the `Stack` through the certificates checked in `Stack::verify_deployment`.
+pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<u64> {
+    // Compute the storage cost.
+    let num_bytes = u64::try_from(deployment.to_bytes_le()?.len())?;
+    let storage_cost = num_bytes
+        .checked_mul(DEPLOYMENT_FEE_PER_BYTE)
+        .ok_or_else(|| anyhow!("The storage cost of the deployment overflowed"))?;
+
+    // Compute the synthesis cost.
+    let num_constraints = deployment
+        .verifying_keys()
+        .iter()
+        .try_fold(0u64, |sum, (_, (verifying_key, _))| {
+            sum.checked_add(u64::try_from(verifying_key.circuit_info.num_constraints).ok()?)
+        })
+        .ok_or_else(|| anyhow!("The number of constraints in the deployment overflowed"))?;
+    let synthesis_cost = num_constraints
+        .checked_mul(DEPLOYMENT_FEE_PER_CONSTRAINT)
+        .ok_or_else(|| anyhow!("The synthesis cost of the deployment overflowed"))?;
+
+    // Return the total cost.
+    storage_cost.checked_add(synthesis_cost).ok_or_else(|| anyhow!("The cost of the deployment overflowed"))
+}
+
+/// Returns the minimum fee (in microcredits) to execute the given execution, excluding its fee transition
+/// and its finalize scopes.
+///
+/// The cost is the storage cost of the execution, plus a fixed cost per transition.
+/// Note: The cost of the finalize scopes depends on the program state, and is computed with `finalize_cost`
+/// when the execution is finalized.
+pub fn execution_cost<N: Network>(execution: &Execution<N>) -> Result<u64> {
+    // Compute the storage cost.
+    let num_bytes = u64::try_from(execution.to_bytes_le()?.len())?;
+    let storage_cost = num_bytes
+        .checked_mul(EXECUTION_FEE_PER_BYTE)
+        .ok_or_else(|| anyhow!("The storage cost of the execution overflowed"))?;
+
+    // Compute the transition cost.
+    let transition_cost = u64::try_from(execution.len())?
+        .checked_mul(EXECUTION_FEE_PER_TRANSITION)
+        .ok_or_else(|| anyhow!("The transition cost of the execution overflowed"))?;
+
+    // Return the total cost.
+    storage_cost.checked_add(transition_cost).ok_or_else(|| anyhow!("The cost of the execution overflowed"))
+}
+
+/// Returns the minimum fee (in microcredits) for the finalize scopes of an execution, given their metrics.
+///
+/// The cost is a fixed cost per evaluated command, plus the storage cost of the mapping entries
+/// written by `set` and `get.or_init`. Commands skipped by a branch are not charged.
+pub fn finalize_cost(metrics: &FinalizeMetrics) -> Result<u64> {
+    // Compute the command cost.
+    let command_cost = metrics
+        .num_commands
+        .checked_mul(EXECUTION_FEE_PER_FINALIZE_COMMAND)
+        .ok_or_else(|| anyhow!("The command cost of the finalize scopes overflowed"))?;
+
+    // Compute the storage cost.
+    let storage_cost = metrics
+        .num_bytes
+        .checked_mul(EXECUTION_FEE_PER_FINALIZE_BYTE)
+        .ok_or_else(|| anyhow!("The storage cost of the finalize scopes overflowed"))?;
+
+    // Return the total cost.
+    command_cost.checked_add(storage_cost).ok_or_else(|| anyhow!("The cost of the finalize scopes overflowed"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::Transaction;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_deployment_cost() {
+        let rng = &mut TestRng::default();
+
+        // Fetch a deployment transaction.
+        let transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let deployment = match &transaction {
+            Transaction::Deploy(_, _, deployment, _) => deployment,
+            _ => panic!("Expected a deployment transaction"),
+        };
+
+        // Compute the expected cost.
+        let num_bytes = deployment.to_bytes_le().unwrap().len() as u64;
+        let num_constraints = deployment
+            .verifying_keys()
+            .iter()
+            .map(|(_, (verifying_key, _))| verifying_key.circuit_info.num_constraints as u64)
+            .sum::<u64>();
+        assert!(num_constraints > 0);
+        let expected = num_bytes * DEPLOYMENT_FEE_PER_BYTE + num_constraints * DEPLOYMENT_FEE_PER_CONSTRAINT;
+
+        // Ensure the cost matches, and is covered by the fee.
+        let cost = deployment_cost::<CurrentNetwork>(deployment).unwrap();
+        assert_eq!(cost, expected);
+        assert!(*transaction.fee().unwrap() >= cost);
+    }
+
+    #[test]
+    fn test_execution_cost() {
+        let rng = &mut TestRng::default();
+
+        // Fetch an execution transaction.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_with_fee(rng);
+        let execution = match &transaction {
+            Transaction::Execute(_, execution, _) => execution,
+            _ => panic!("Expected an execution transaction"),
+        };
+
+        // Compute the expected cost.
+        let num_bytes = execution.to_bytes_le().unwrap().len() as u64;
+        let expected = num_bytes * EXECUTION_FEE_PER_BYTE + EXECUTION_FEE_PER_TRANSITION;
+
+        // Ensure the cost matches.
+        let cost = execution_cost(execution).unwrap();
+        assert_eq!(cost, expected);
+    }
+
+    #[test]
+    fn test_finalize_cost() {
+        // Ensure an empty finalize scope is free.
+        assert_eq!(finalize_cost(&FinalizeMetrics::default()).unwrap(), 0);
+
+        // Ensure the commands and the written bytes are charged.
+        let metrics = FinalizeMetrics { num_commands: 7, num_bytes: 120 };
+        let expected = 7 * EXECUTION_FEE_PER_FINALIZE_COMMAND + 120 * EXECUTION_FEE_PER_FINALIZE_BYTE;
+        assert_eq!(finalize_cost(&metrics).unwrap(), expected);
+
+        // Ensure an overflow is an error.
+        assert!(finalize_cost(&FinalizeMetrics { num_commands: u64::MAX, num_bytes: 0 }).is_err());
+        assert!(finalize_cost(&FinalizeMetrics { num_commands: 1, num_bytes: u64::MAX }).is_err());
+    }
+}
@@ -14,4 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod cost;
+pub use cost::*;
+
 mod macros;
@@ -15,33 +15,50 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 mod helpers;
+pub use helpers::*;
 
 mod authorize;
 mod deploy;
 mod execute;
 mod finalize;
+mod speculate;
 mod verify;
 
+pub use speculate::*;
+
 use crate::{
     atomic_write_batch,
     block::{Block, Transaction, Transactions, Transition},
     cast_ref,
+    coinbase_puzzle::{CoinbasePuzzle, EpochChallenge},
     process,
     process::{Authorization, Deployment, Execution, Fee, Inclusion, InclusionAssignment, Process, Query},
     program::Program,
-    store::{BlockStore, ConsensusStorage, ConsensusStore, ProgramStore, TransactionStore, TransitionStore},
+    store::{
+        BlockStore,
+        ConsensusStorage,
+        ConsensusStore,
+        FinalizeDiff,
+        ProgramOverlay,
+        ProgramStorage,
+        ProgramStore,
+        TransactionStore,
+        TransitionStore,
+    },
     CallMetrics,
 };
 use console::{
-    account::PrivateKey,
+    account::{Address, PrivateKey},
     network::prelude::*,
-    program::{Identifier, Plaintext, ProgramID, Record, Response, Value},
-    types::Field,
+    program::{Identifier, Literal, Plaintext, ProgramID, Record, Response, Value},
+    types::{Field, U64},
 };
 
 use aleo_std::prelude::{finish, lap, timer};
+use indexmap::IndexMap;
+use once_cell::sync::OnceCell;
 use parking_lot::RwLock;
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
 #[derive(Clone)]
 pub struct VM<N: Network, C: ConsensusStorage<N>> {
@@ -49,6 +66,8 @@ pub struct VM<N: Network, C: ConsensusStorage<N>> {
     process: Arc<RwLock<Process<N>>>,
     /// The VM store.
     store: ConsensusStore<N, C>,
+    /// The coinbase puzzle, which is loaded on first use.
+    coinbase_puzzle: Arc<OnceCell<CoinbasePuzzle<N>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -58,20 +77,50 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Initialize a new process.
         let mut process = Process::load()?;
 
-        // Retrieve the transaction store.
-        let transaction_store = store.transaction_store();
+        // Retrieve the block store.
+        let block_store = store.block_store();
+        // Retrieve the blocks that contain a deployment, ordered by block height.
+        // Note: The deployments must be loaded in the order they were added to the ledger,
+        // as a program can only be loaded once all of the programs it imports are loaded.
+        let mut deployment_blocks = BTreeMap::new();
+        for transaction_id in store.transaction_store().deployment_transaction_ids() {
+            // Retrieve the block hash.
+            let block_hash = match block_store.find_block_hash(&transaction_id)? {
+                Some(block_hash) => block_hash,
+                None => bail!("Deployment transaction '{transaction_id}' is not found in any block."),
+            };
+            // Retrieve the block height.
+            match block_store.get_block_height(&block_hash)? {
+                Some(height) => deployment_blocks.insert(height, block_hash),
+                None => bail!("Block '{block_hash}' is not found in storage."),
+            };
+        }
         // Load the deployments from the store.
-        for transaction_id in transaction_store.deployment_transaction_ids() {
-            // Retrieve the deployment.
-            match transaction_store.get_deployment(&transaction_id)? {
-                // Load the deployment.
-                Some(deployment) => process.load_deployment(&deployment)?,
-                None => bail!("Deployment transaction '{transaction_id}' is not found in storage."),
+        for block_hash in deployment_blocks.values() {
+            // Retrieve the block transactions.
+            let transactions = match block_store.get_block_transactions(block_hash)? {
+                Some(transactions) => transactions,
+                None => bail!("Transactions for block '{block_hash}' are not found in storage."),
             };
+            // Load the deployments, in the order they appear in the block.
+            for deployment in transactions.deployments() {
+                process.load_deployment(deployment)?;
+            }
+        }
+
+        // Initialize the VM.
+        let vm = Self { process: Arc::new(RwLock::new(process)), store, coinbase_puzzle: Default::default() };
+
+        // Initialize the 'credits.aleo' mappings, if they do not exist.
+        let program = Program::<N>::credits()?;
+        for mapping in program.mappings().values() {
+            if !vm.program_store().contains_mapping(program.id(), mapping.name())? {
+                vm.program_store().initialize_mapping(program.id(), mapping.name())?;
+            }
         }
 
         // Return the new VM.
-        Ok(Self { process: Arc::new(RwLock::new(process)), store })
+        Ok(vm)
     }
 
     /// Returns `true` if a program with the given program ID exists.
@@ -80,21 +129,49 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         self.process.read().contains_program(program_id)
     }
 
-    /// Adds the given block into the VM.
+    /// Adds the given block into the VM, if it is a valid next block.
     #[inline]
     pub fn add_next_block(&self, block: &Block<N>) -> Result<()> {
-        // First, insert the block.
-        self.block_store().insert(block)?;
-        // Next, finalize the transactions.
-        match self.finalize(block.transactions()) {
-            Ok(_) => Ok(()),
-            Err(error) => {
-                // Rollback the block.
-                self.block_store().remove_last_n(1)?;
-                // Return the error.
-                Err(error)
+        // Ensure the block is a valid next block.
+        self.check_next_block(block)?;
+        // Compute the rewards issued in the block.
+        let rewards = self.block_rewards(block)?;
+
+        // Finalize the transactions and insert the block in a single atomic batch,
+        // so that a persistent storage never holds a block without its finalized state.
+        atomic_write_batch!(self, {
+            // First, finalize the transactions, and issue the rewards.
+            let finalize_diff = self.finalize(block.transactions(), &rewards)?;
+            // Next, store the changes to the program state, so that they can be reverted.
+            self.program_store().insert_finalize_diff(block.height(), finalize_diff)?;
+            // Lastly, insert the block.
+            self.block_store().insert(block)
+        });
+        Ok(())
+    }
+
+    /// Removes the last 'n' blocks from the VM, and reverts their changes to the program state.
+    #[inline]
+    pub fn remove_last_n(&self, n: u32) -> Result<()> {
+        // Acquire the write lock on the process.
+        let mut process = self.process.write();
+
+        // Retrieve the programs that were deployed in the blocks.
+        let mut program_ids = Vec::new();
+        for block_height in self.store.last_n_heights(n)? {
+            if let Some(finalize_diff) = self.program_store().get_finalize_diff(block_height)? {
+                program_ids.extend_from_slice(finalize_diff.programs());
             }
         }
+
+        // Remove the blocks, and revert their changes to the program state.
+        self.store.remove_last_n(n)?;
+
+        // Remove the deployed programs from the process.
+        for program_id in program_ids {
+            process.remove_stack(&program_id)?;
+        }
+        Ok(())
     }
 
     /// Returns the process.
@@ -103,6 +180,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         self.process.clone()
     }
 
+    /// Returns the coinbase puzzle, loading it on first use.
+    #[inline]
+    pub fn coinbase_puzzle(&self) -> Result<&CoinbasePuzzle<N>> {
+        self.coinbase_puzzle.get_or_try_init(CoinbasePuzzle::load)
+    }
+
     /// Returns the program store.
     #[inline]
     pub fn program_store(&self) -> &ProgramStore<N, C::ProgramStorage> {
@@ -261,7 +344,7 @@ function compute:
 
                 // Prepare the fee.
                 let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
-                let fee = (credits, 10);
+                let fee = (credits, 100_000_000);
 
                 // Initialize the VM.
                 let vm = sample_vm();
@@ -0,0 +1,373 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The result of speculatively finalizing transactions against a copy-on-write overlay of the program store.
+/// A speculation is applied with `VM::commit_speculate`, or discarded by dropping it.
+pub struct Speculate<N: Network, P: ProgramStorage<N>> {
+    /// The copy-on-write process, which is cloned from the VM on the first accepted deployment.
+    process: Option<Process<N>>,
+    /// The overlay of the program store.
+    program_store: ProgramStore<N, ProgramOverlay<N, P>>,
+    /// The accepted transactions.
+    accepted: Vec<Transaction<N>>,
+    /// The rejected transactions, along with the reason they were rejected.
+    rejected: Vec<(Transaction<N>, Error)>,
+}
+
+impl<N: Network, P: ProgramStorage<N>> Speculate<N, P> {
+    /// Initializes a new speculation over the given program store.
+    fn new(program_store: &ProgramStore<N, P>) -> Self {
+        Self { process: None, program_store: program_store.overlay(), accepted: vec![], rejected: vec![] }
+    }
+
+    /// Returns the transactions that were accepted, in the order they were speculated.
+    pub fn accepted(&self) -> &[Transaction<N>] {
+        &self.accepted
+    }
+
+    /// Returns the transactions that were rejected, along with the reason they were rejected.
+    pub fn rejected(&self) -> &[(Transaction<N>, Error)] {
+        &self.rejected
+    }
+
+    /// Returns the speculative program store.
+    pub fn program_store(&self) -> &ProgramStore<N, ProgramOverlay<N, P>> {
+        &self.program_store
+    }
+
+    /// Returns the changes to the program state, from finalizing the accepted transactions.
+    pub fn finalize_diff(&self) -> Result<FinalizeDiff<N>> {
+        self.program_store.finalize_diff()
+    }
+
+    /// Issues the given rewards (in microcredits), by adding each reward to the public balance of its recipient
+    /// in the `credits.aleo/account` mapping. The rewards are part of the changes to the program state.
+    pub fn issue_rewards(&self, rewards: &IndexMap<Address<N>, u64>) -> Result<()> {
+        // Prepare the program ID and mapping name.
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        let mapping_name = Identifier::from_str("account")?;
+
+        for (address, reward) in rewards.iter().filter(|(_, reward)| **reward > 0) {
+            // Retrieve the public balance of the recipient.
+            let key = Plaintext::from(Literal::Address(*address));
+            let balance = match self.program_store.get_value(&program_id, &mapping_name, &key)? {
+                Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) => *balance,
+                Some(value) => bail!("Invalid public balance for '{address}' - {value}"),
+                None => 0,
+            };
+            // Add the reward to the public balance.
+            let balance = match balance.checked_add(*reward) {
+                Some(balance) => balance,
+                None => bail!("The public balance of '{address}' overflowed"),
+            };
+            let value = Value::Plaintext(Plaintext::from(Literal::U64(U64::new(balance))));
+            self.program_store.update_key_value(&program_id, &mapping_name, key, value)?;
+        }
+        Ok(())
+    }
+
+    /// Speculatively finalizes the given transaction, and records whether it was accepted or rejected.
+    fn speculate(&mut self, process: &Process<N>, transaction: &Transaction<N>) {
+        // Start an atomic batch on the overlay, so that a rejected transaction leaves no trace.
+        self.program_store.start_atomic();
+
+        // The program ID of the stack that was added to the copy-on-write process, if any.
+        let mut deployed_program_id = None;
+
+        // Finalize the transaction, and then its fee.
+        let result = match transaction {
+            Transaction::Deploy(_, _, deployment, fee) => self
+                .process
+                .get_or_insert_with(|| process.clone())
+                .finalize_deployment(&self.program_store, deployment)
+                .and_then(|_| {
+                    deployed_program_id = Some(*deployment.program_id());
+                    process.finalize_fee(&self.program_store, fee)
+                }),
+            Transaction::Execute(_, execution, fee) => {
+                let process = self.process.as_ref().unwrap_or(process);
+                process.finalize_execution(&self.program_store, execution).and_then(|metrics| match fee {
+                    Some(fee) => {
+                        // Ensure the fee covers the cost of the execution, including its finalize scopes.
+                        let cost = execution_cost(execution)?
+                            .checked_add(finalize_cost(&metrics)?)
+                            .ok_or_else(|| anyhow!("The cost of the execution overflowed"))?;
+                        ensure!(*fee.amount()? >= cost, "Insufficient fee - expected at least {cost} microcredits");
+                        process.finalize_fee(&self.program_store, fee)
+                    }
+                    None => Ok(()),
+                })
+            }
+        };
+
+        // Keep the writes of an accepted transaction, and discard the writes of a rejected transaction.
+        match result.and_then(|_| self.program_store.finish_atomic()) {
+            Ok(()) => self.accepted.push(transaction.clone()),
+            Err(error) => {
+                self.program_store.abort_atomic();
+                // Remove the stack of the rejected deployment from the copy-on-write process.
+                if let (Some(program_id), Some(process)) = (deployed_program_id, &mut self.process) {
+                    if let Err(error) = process.remove_stack(&program_id) {
+                        warn!("Failed to remove the stack of the rejected program '{program_id}': {error}");
+                    }
+                }
+                self.rejected.push((transaction.clone(), error));
+            }
+        }
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Speculatively finalizes the given transactions, without modifying the VM.
+    /// A transaction that fails to finalize is rejected, and does not affect the transactions after it.
+    /// This method assumes the given transactions **are valid**.
+    pub fn speculate<'a>(
+        &self,
+        transactions: impl IntoIterator<Item = &'a Transaction<N>>,
+    ) -> Speculate<N, C::ProgramStorage> {
+        let timer = timer!("VM::speculate");
+
+        // Initialize the speculation.
+        let mut speculate = Speculate::new(self.program_store());
+        // Acquire the read lock on the process.
+        let process = self.process.read();
+
+        for transaction in transactions {
+            // Speculatively finalize the transaction.
+            speculate.speculate(&process, transaction);
+            lap!(timer, "Speculate transaction '{}'", transaction.id());
+        }
+
+        finish!(timer);
+
+        speculate
+    }
+
+    /// Commits the given speculation into the VM.
+    /// Note: The program store must not be modified between the speculation and its commit.
+    pub fn commit_speculate(&self, speculate: Speculate<N, C::ProgramStorage>) -> Result<()> {
+        let timer = timer!("VM::commit_speculate");
+
+        // Acquire the write lock on the process.
+        let mut process = self.process.write();
+
+        // Retrieve the stacks of the accepted deployments.
+        let mut stacks = Vec::new();
+        if let Some(speculative_process) = &speculate.process {
+            for transaction in &speculate.accepted {
+                if let Transaction::Deploy(_, _, deployment, _) = transaction {
+                    // Ensure the program was not added to the VM since the speculation.
+                    let program_id = deployment.program_id();
+                    ensure!(!process.contains_program(program_id), "Program '{program_id}' already exists");
+                    stacks.push(speculative_process.get_stack(program_id)?.clone());
+                }
+            }
+        }
+
+        // Write the speculative program state.
+        atomic_write_batch!(self, { self.program_store().commit_overlay(&speculate.program_store) });
+        lap!(timer, "Commit the program store");
+
+        // Add the deployed programs to the process.
+        for stack in stacks {
+            process.add_stack(stack)?;
+        }
+
+        finish!(timer);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::CurrentNetwork;
+    use console::{account::ViewKey, program::ProgramOwner};
+    use snarkvm_utilities::TestRng;
+
+    #[test]
+    fn test_speculate() {
+        let rng = &mut TestRng::default();
+
+        let vm = crate::vm::test_helpers::sample_vm();
+
+        // Fetch a deployment transaction.
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let program_id = match &deployment_transaction {
+            Transaction::Deploy(_, _, deployment, _) => *deployment.program_id(),
+            _ => unreachable!(),
+        };
+
+        // Speculate the same deployment twice.
+        let speculate = vm.speculate([&deployment_transaction, &deployment_transaction]);
+        assert_eq!(speculate.accepted().len(), 1);
+        assert_eq!(speculate.rejected().len(), 1);
+        assert!(speculate.program_store().contains_program(&program_id).unwrap());
+
+        // Ensure the VM is unchanged.
+        assert!(!vm.contains_program(&program_id));
+        assert!(!vm.program_store().contains_program(&program_id).unwrap());
+
+        // Discard the speculation, and speculate again.
+        drop(speculate);
+        let speculate = vm.speculate([&deployment_transaction]);
+        assert_eq!(speculate.accepted().len(), 1);
+        assert!(speculate.rejected().is_empty());
+
+        // Commit the speculation.
+        vm.commit_speculate(speculate).unwrap();
+        assert!(vm.contains_program(&program_id));
+        assert!(vm.program_store().contains_program(&program_id).unwrap());
+
+        // Ensure the deployment is now rejected.
+        let speculate = vm.speculate([&deployment_transaction]);
+        assert!(speculate.accepted().is_empty());
+        assert_eq!(speculate.rejected().len(), 1);
+    }
+
+    #[test]
+    fn test_speculate_rejected_deployment() {
+        let rng = &mut TestRng::default();
+
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch a deployment transaction.
+        let deployment_transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let (owner, deployment) = match &deployment_transaction {
+            Transaction::Deploy(_, owner, deployment, _) => (*owner, *deployment.clone()),
+            _ => unreachable!(),
+        };
+        let program_id = *deployment.program_id();
+
+        // Construct the same deployment, with a public fee from an account without a public balance.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let fee = Transaction::execute_fee_public(&vm, &private_key, 100_000_000, None, rng).unwrap();
+        let id = Transaction::Deploy(Default::default(), owner, Box::new(deployment.clone()), fee.clone())
+            .to_root()
+            .unwrap();
+        let owner = ProgramOwner::new(&private_key, id.into(), rng).unwrap();
+        let unpaid_transaction = Transaction::from_deployment(owner, deployment, fee).unwrap();
+
+        // Ensure the deployment with the unpaid fee is rejected, and does not affect the deployment after it.
+        let speculate = vm.speculate([&unpaid_transaction, &deployment_transaction]);
+        assert_eq!(speculate.rejected().len(), 1);
+        assert_eq!(speculate.rejected()[0].0, unpaid_transaction);
+        assert_eq!(speculate.accepted(), [deployment_transaction]);
+
+        // Ensure only the stack of the accepted deployment is committed.
+        vm.commit_speculate(speculate).unwrap();
+        assert!(vm.contains_program(&program_id));
+        assert!(vm.program_store().contains_program(&program_id).unwrap());
+    }
+
+    #[test]
+    fn test_speculate_contains_and_remove_read_earlier_commands() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM, with the genesis block.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+        let records =
+            genesis.records().map(|(_, record)| record.decrypt(&caller_view_key).unwrap()).collect::<Vec<_>>();
+
+        // Deploy a program that reads the mapping entries written by its earlier commands.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program mappings.aleo;
+
+mapping entries:
+    key left as field.public;
+    value right as field.public;
+
+function set_then_contains:
+    input r0 as field.public;
+    finalize r0;
+
+finalize set_then_contains:
+    input r0 as field.public;
+    set r0 into entries[r0];
+    contains entries[r0] into r1;
+    assert.eq r1 true;
+
+function set_then_remove:
+    input r0 as field.public;
+    finalize r0;
+
+finalize set_then_remove:
+    input r0 as field.public;
+    set r0 into entries[r0];
+    remove entries[r0];
+    contains entries[r0] into r1;
+    assert.eq r1 false;
+
+function remove_then_contains:
+    input r0 as field.public;
+    finalize r0;
+
+finalize remove_then_contains:
+    input r0 as field.public;
+    remove entries[r0];
+    contains entries[r0] into r1;
+    assert.eq r1 false;",
+        )
+        .unwrap();
+        let program_id = *program.id();
+        let mapping_name = Identifier::from_str("entries").unwrap();
+        let fee = (records[0].clone(), 100_000_000);
+        let deployment_transaction = Transaction::deploy(&vm, &caller_private_key, &program, fee, None, rng).unwrap();
+        vm.finalize(&Transactions::from(&[deployment_transaction]), &IndexMap::new()).unwrap();
+
+        // Prepare an execution of the given function, without a fee.
+        let mut execute = |function_name: &str, key: &str| {
+            let authorization =
+                vm.authorize(&caller_private_key, "mappings.aleo", function_name, [key].into_iter(), rng).unwrap();
+            Transaction::execute_authorization(&vm, authorization, None, None, rng).unwrap()
+        };
+        let transactions = [
+            execute("set_then_contains", "0field"),
+            execute("remove_then_contains", "0field"),
+            execute("set_then_remove", "1field"),
+            execute("set_then_contains", "2field"),
+        ];
+
+        // Ensure each finalize scope reads the writes of its earlier commands.
+        let speculate = vm.speculate(transactions.iter());
+        assert!(speculate.rejected().is_empty());
+        assert_eq!(speculate.accepted().len(), transactions.len());
+
+        // Ensure only the entry that was set and not removed is in the speculative program store.
+        let contains = |key: &str| {
+            let key = Plaintext::from_str(key).unwrap();
+            speculate.program_store().contains_key(&program_id, &mapping_name, &key).unwrap()
+        };
+        assert!(!contains("0field"));
+        assert!(!contains("1field"));
+        assert!(contains("2field"));
+
+        // Finalize the transactions, and ensure the VM matches the speculation.
+        drop(speculate);
+        vm.finalize(&Transactions::from(&transactions), &IndexMap::new()).unwrap();
+        let mapping = vm.program_store().get_mapping(&program_id, &mapping_name).unwrap().unwrap();
+        assert_eq!(mapping, [(Plaintext::from_str("2field").unwrap(), Value::from_str("2field").unwrap())]);
+    }
+}
@@ -127,6 +127,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 }
                 // Verify the signature corresponds to the transaction ID.
                 ensure!(owner.verify(*id), "Invalid signature for the deployment transaction '{id}'");
+                // Ensure the fee covers the cost of the deployment.
+                let cost = deployment_cost(deployment)?;
+                if *fee.amount()? < cost {
+                    bail!("Transaction '{id}' has an insufficient fee (deployment) - expected at least {cost} microcredits");
+                }
                 // Verify the fee.
                 self.check_fee(fee)?;
                 // Verify the deployment.
@@ -139,8 +144,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 }
                 // TODO (raychu86): Remove `is_split` check once batch executions are supported.
                 // Ensure the fee is present, if the transaction is not a coinbase or split.
-                if !transaction.is_coinbase() && !transaction.is_split() && fee.is_none() {
-                    bail!("Transaction is missing a fee (execution)");
+                if !transaction.is_coinbase() && !transaction.is_split() {
+                    match fee {
+                        // Ensure the fee covers the cost of the execution, excluding its finalize scopes.
+                        Some(fee) => {
+                            let cost = execution_cost(execution)?;
+                            if *fee.amount()? < cost {
+                                bail!(
+                                    "Transaction '{}' has an insufficient fee (execution) - expected at least {cost} microcredits",
+                                    transaction.id()
+                                );
+                            }
+                        }
+                        None => bail!("Transaction is missing a fee (execution)"),
+                    }
                 }
                 // Verify the fee.
                 if let Some(fee) = fee {
@@ -158,6 +175,277 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         Ok(())
     }
 
+    /// Returns the epoch challenge for the block at the given `block height`.
+    ///
+    /// The epoch challenge is derived from the epoch number, and the hash of the block right before the epoch started
+    /// (or the genesis block hash for the first epoch), so it must be available in the ledger.
+    pub fn epoch_challenge(&self, block_height: u32) -> Result<EpochChallenge<N>> {
+        // Compute the epoch number.
+        let epoch_number = block_height / N::NUM_BLOCKS_PER_EPOCH;
+        // Compute the height of the block right before the epoch started.
+        let epoch_block_height = (epoch_number * N::NUM_BLOCKS_PER_EPOCH).saturating_sub(1);
+        // Retrieve the epoch block hash.
+        let epoch_block_hash = match self.block_store().get_block_hash(epoch_block_height)? {
+            Some(epoch_block_hash) => epoch_block_hash,
+            None => bail!("Missing the epoch block hash for block {block_height} (at block {epoch_block_height})"),
+        };
+        // Construct the epoch challenge.
+        EpochChallenge::new(epoch_number, epoch_block_hash, N::COINBASE_PUZZLE_DEGREE)
+    }
+
+    /// Returns the rewards (in microcredits) issued in the given block, which must be the next block in the ledger.
+    ///
+    /// The rewards are paid out into the `credits.aleo/account` mapping when the block is added to the VM.
+    /// The genesis block issues no rewards, as its transactions mint the starting supply.
+    pub fn block_rewards(&self, block: &Block<N>) -> Result<IndexMap<Address<N>, u64>> {
+        // Retrieve the latest block height, returning no rewards for the genesis block.
+        let latest_height = match self.block_store().max_height() {
+            Some(latest_height) => latest_height,
+            None => return Ok(IndexMap::new()),
+        };
+        // Retrieve the latest block header.
+        let latest_header = match self.block_store().get_block_hash(latest_height)? {
+            Some(latest_hash) => match self.block_store().get_block_header(&latest_hash)? {
+                Some(latest_header) => latest_header,
+                None => bail!("Missing the block header for block {latest_height}"),
+            },
+            None => bail!("Missing the block hash for block {latest_height}"),
+        };
+        // Compute the rewards issued in the block.
+        crate::block::to_block_rewards(
+            block.signature().to_address(),
+            latest_header.total_supply_in_microcredits(),
+            block.height(),
+            block.coinbase_target(),
+            block.coinbase(),
+        )
+    }
+
+    /// Verifies the given block is a valid next block for the ledger. On failure, returns an error.
+    pub fn check_next_block(&self, block: &Block<N>) -> Result<()> {
+        let timer = timer!("VM::check_next_block");
+
+        // Ensure the block does not already exist in the ledger.
+        if self.block_store().contains_block_hash(&block.hash())? {
+            bail!("Block '{}' already exists in the ledger", block.hash());
+        }
+
+        // Ensure the block header is well-formed.
+        if !block.header().is_valid() {
+            bail!("Invalid block header for block '{}'", block.hash());
+        }
+
+        // Ensure the transactions root matches the transactions in the block.
+        if block.transactions_root() != block.transactions().to_root()? {
+            bail!("Invalid transactions root for block '{}'", block.hash());
+        }
+
+        // Retrieve the latest block height, if the ledger is not empty.
+        let latest_height = self.block_store().max_height();
+
+        match latest_height {
+            // Ensure the first block in the ledger is a genesis block.
+            None => {
+                if !block.is_genesis() {
+                    bail!("The first block in the ledger must be a genesis block");
+                }
+            }
+            // Ensure the block header is consistent with the latest block header.
+            Some(latest_height) => {
+                // Retrieve the latest block hash.
+                let latest_hash = match self.block_store().get_block_hash(latest_height)? {
+                    Some(latest_hash) => latest_hash,
+                    None => bail!("Missing the block hash for block {latest_height}"),
+                };
+                // Retrieve the latest block header.
+                let latest_header = match self.block_store().get_block_header(&latest_hash)? {
+                    Some(latest_header) => latest_header,
+                    None => bail!("Missing the block header for block {latest_height}"),
+                };
+
+                // Ensure the height is incremented by one.
+                if block.height() != latest_height.saturating_add(1) {
+                    bail!("Expected block height {}, found {}", latest_height.saturating_add(1), block.height());
+                }
+                // Ensure the previous block hash is the latest block hash.
+                if block.previous_hash() != latest_hash {
+                    bail!("Block {} does not build on the latest block '{latest_hash}'", block.height());
+                }
+                // Ensure the previous state root is the current state root.
+                if block.previous_state_root() != *self.block_store().current_state_root() {
+                    bail!("Block {} does not build on the current state root", block.height());
+                }
+                // Ensure the round is after the latest round.
+                if block.round() <= latest_header.round() {
+                    bail!(
+                        "Block {} has round {}, expected a round after {}",
+                        block.height(),
+                        block.round(),
+                        latest_header.round()
+                    );
+                }
+                // Ensure the timestamp is after the latest timestamp.
+                if block.timestamp() <= latest_header.timestamp() {
+                    bail!("Block {} has a timestamp that is not after the latest block", block.height());
+                }
+
+                // Ensure the coinbase target and proof target are retargeted correctly.
+                let (coinbase_target, proof_target) = latest_header.next_targets(block.timestamp())?;
+                if block.coinbase_target() != coinbase_target {
+                    bail!("Block {} has an incorrect coinbase target", block.height());
+                }
+                if block.proof_target() != proof_target {
+                    bail!("Block {} has an incorrect proof target", block.height());
+                }
+
+                // Determine the expected cumulative proof target, and last coinbase target and timestamp.
+                let (cumulative_proof_target, last_coinbase_target, last_coinbase_timestamp) = match block.coinbase() {
+                    Some(coinbase) => (
+                        latest_header.cumulative_proof_target().saturating_add(coinbase.to_cumulative_proof_target()?),
+                        block.coinbase_target(),
+                        block.timestamp(),
+                    ),
+                    None => (
+                        latest_header.cumulative_proof_target(),
+                        latest_header.last_coinbase_target(),
+                        latest_header.last_coinbase_timestamp(),
+                    ),
+                };
+                // Ensure the cumulative proof target is correct.
+                if block.cumulative_proof_target() != cumulative_proof_target {
+                    bail!("Block {} has an incorrect cumulative proof target", block.height());
+                }
+                // Ensure the last coinbase target and timestamp are correct.
+                if block.last_coinbase_target() != last_coinbase_target
+                    || block.last_coinbase_timestamp() != last_coinbase_timestamp
+                {
+                    bail!("Block {} has an incorrect last coinbase target or timestamp", block.height());
+                }
+
+                // Compute the rewards issued in the block.
+                let rewards = self.block_rewards(block)?;
+                // Compute the expected total supply, after issuing the rewards and burning the fees.
+                let total_supply = crate::block::to_next_total_supply(
+                    latest_header.total_supply_in_microcredits(),
+                    &rewards,
+                    block.transactions(),
+                )?;
+                // Ensure the total supply is correct.
+                if block.total_supply_in_microcredits() != total_supply {
+                    bail!("Block {} has an incorrect total supply", block.height());
+                }
+            }
+        }
+        lap!(timer, "Check the block header");
+
+        // Ensure there are no duplicate transaction IDs.
+        if has_duplicates(block.transaction_ids()) {
+            bail!("Found duplicate transactions in block {}", block.height());
+        }
+        // Ensure there are no duplicate transition IDs.
+        if has_duplicates(block.transition_ids()) {
+            bail!("Found duplicate transitions in block {}", block.height());
+        }
+        // Ensure there are no duplicate transition public keys.
+        if has_duplicates(block.transition_public_keys()) {
+            bail!("Found duplicate transition public keys in block {}", block.height());
+        }
+        // Ensure there are no duplicate serial numbers.
+        if has_duplicates(block.serial_numbers()) {
+            bail!("Found duplicate serial numbers in block {}", block.height());
+        }
+        // Ensure there are no duplicate commitments.
+        if has_duplicates(block.commitments()) {
+            bail!("Found duplicate commitments in block {}", block.height());
+        }
+        // Ensure there are no duplicate nonces.
+        if has_duplicates(block.nonces()) {
+            bail!("Found duplicate nonces in block {}", block.height());
+        }
+        lap!(timer, "Check for duplicate elements");
+
+        // Ensure the transactions do not already exist in the ledger.
+        for transaction_id in block.transaction_ids() {
+            if self.transaction_store().contains_transaction_id(transaction_id)? {
+                bail!("Transaction '{transaction_id}' already exists in the ledger");
+            }
+        }
+        // Ensure the transitions do not already exist in the ledger.
+        for transition_id in block.transition_ids() {
+            if self.transition_store().contains_transition_id(transition_id)? {
+                bail!("Transition '{transition_id}' already exists in the ledger");
+            }
+        }
+        // Ensure the transition public keys do not already exist in the ledger.
+        for tpk in block.transition_public_keys() {
+            if self.transition_store().contains_tpk(tpk)? {
+                bail!("Transition public key '{tpk}' already exists in the ledger");
+            }
+        }
+        // Ensure the serial numbers do not already exist in the ledger (i.e. no double spends).
+        for serial_number in block.serial_numbers() {
+            if self.transition_store().contains_serial_number(serial_number)? {
+                bail!("Serial number '{serial_number}' already exists in the ledger");
+            }
+        }
+        // Ensure the commitments do not already exist in the ledger.
+        for commitment in block.commitments() {
+            if self.transition_store().contains_commitment(commitment)? {
+                bail!("Commitment '{commitment}' already exists in the ledger");
+            }
+        }
+        // Ensure the nonces do not already exist in the ledger.
+        for nonce in block.nonces() {
+            if self.transition_store().contains_nonce(nonce)? {
+                bail!("Nonce '{nonce}' already exists in the ledger");
+            }
+        }
+        lap!(timer, "Check the transactions against the ledger");
+
+        // Ensure each transaction is valid.
+        for transaction in block.transactions().values() {
+            if let Err(error) = self.check_transaction(transaction) {
+                bail!("Invalid transaction '{}' in block {} - {error}", transaction.id(), block.height());
+            }
+        }
+        lap!(timer, "Verify the transactions");
+
+        // Ensure the puzzle commitments are unique, and do not already exist in the ledger.
+        if let Some(puzzle_commitments) = block.puzzle_commitments() {
+            let puzzle_commitments = puzzle_commitments.collect::<Vec<_>>();
+            if has_duplicates(puzzle_commitments.iter()) {
+                bail!("Found duplicate puzzle commitments in block {}", block.height());
+            }
+            for puzzle_commitment in &puzzle_commitments {
+                if self.block_store().contains_puzzle_commitment(puzzle_commitment)? {
+                    bail!("Puzzle commitment '{puzzle_commitment}' already exists in the ledger");
+                }
+            }
+        }
+
+        // Ensure the coinbase solution is valid for the epoch challenge, and meets the retargeted targets.
+        if let Some(coinbase) = block.coinbase() {
+            // Compute the epoch challenge.
+            let epoch_challenge = self.epoch_challenge(block.height())?;
+            // Verify the coinbase solution.
+            match self.coinbase_puzzle()?.verify(
+                coinbase,
+                &epoch_challenge,
+                block.coinbase_target(),
+                block.proof_target(),
+            ) {
+                Ok(true) => (),
+                Ok(false) => bail!("Invalid coinbase solution in block {}", block.height()),
+                Err(error) => bail!("Invalid coinbase solution in block {} - {error}", block.height()),
+            }
+        }
+        lap!(timer, "Verify the coinbase solution");
+
+        finish!(timer);
+
+        Ok(())
+    }
+
     /// Verifies the given deployment. On failure, returns an error.
     #[inline]
     fn check_deployment(&self, deployment: &Deployment<N>) -> Result<()> {
@@ -236,7 +524,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 mod tests {
     use super::*;
 
-    use crate::{Block, Header, Inclusion, Metadata, Transaction, Transactions};
+    use crate::{Block, CoinbaseSolution, EpochChallenge, Header, Inclusion, Metadata, Transaction, Transactions};
     use console::{
         account::{Address, ViewKey},
         types::Field,
@@ -244,6 +532,78 @@ mod tests {
 
     type CurrentNetwork = test_helpers::CurrentNetwork;
 
+    /// Samples a block on top of the latest block, with the given height, round, total supply, and timestamp.
+    fn sample_next_block<R: Rng + CryptoRng>(
+        vm: &VM<CurrentNetwork, crate::store::ConsensusMemory<CurrentNetwork>>,
+        private_key: &PrivateKey<CurrentNetwork>,
+        transactions: &[Transaction<CurrentNetwork>],
+        metadata: (u32, u64, u64, i64),
+        rng: &mut R,
+    ) -> Result<Block<CurrentNetwork>> {
+        sample_next_block_with_coinbase(vm, private_key, transactions, None, metadata, rng)
+    }
+
+    /// Samples a block on top of the latest block, with the given coinbase solution, height, round, total supply, and timestamp.
+    fn sample_next_block_with_coinbase<R: Rng + CryptoRng>(
+        vm: &VM<CurrentNetwork, crate::store::ConsensusMemory<CurrentNetwork>>,
+        private_key: &PrivateKey<CurrentNetwork>,
+        transactions: &[Transaction<CurrentNetwork>],
+        coinbase: Option<CoinbaseSolution<CurrentNetwork>>,
+        (height, round, total_supply_in_microcredits, timestamp): (u32, u64, u64, i64),
+        rng: &mut R,
+    ) -> Result<Block<CurrentNetwork>> {
+        // Retrieve the latest block.
+        let latest_height = vm.block_store().max_height().unwrap();
+        let latest_hash = vm.block_store().get_block_hash(latest_height)?.unwrap();
+        let latest_block = vm.block_store().get_block(&latest_hash)?.unwrap();
+
+        // Compute the coinbase target and proof target.
+        let (coinbase_target, proof_target) = latest_block.header().next_targets(timestamp)?;
+
+        // Compute the cumulative proof target, the last coinbase target and timestamp, and the accumulator point.
+        let (cumulative_proof_target, last_coinbase_target, last_coinbase_timestamp, accumulator_point) =
+            match &coinbase {
+                Some(coinbase) => (
+                    latest_block.cumulative_proof_target().saturating_add(coinbase.to_cumulative_proof_target()?),
+                    coinbase_target,
+                    timestamp,
+                    coinbase.to_accumulator_point()?,
+                ),
+                None => (
+                    latest_block.cumulative_proof_target(),
+                    latest_block.last_coinbase_target(),
+                    latest_block.last_coinbase_timestamp(),
+                    Field::zero(),
+                ),
+            };
+
+        // Construct the transactions.
+        let transactions = Transactions::from(transactions);
+        // Construct the metadata associated with the block.
+        let metadata = Metadata::new(
+            CurrentNetwork::ID,
+            round,
+            height,
+            total_supply_in_microcredits,
+            cumulative_proof_target,
+            coinbase_target,
+            proof_target,
+            last_coinbase_target,
+            last_coinbase_timestamp,
+            timestamp,
+        )?;
+        // Construct the block header.
+        let header = Header::from(
+            *vm.block_store().current_state_root(),
+            transactions.to_root()?,
+            Field::zero(),
+            accumulator_point,
+            metadata,
+        )?;
+        // Construct the block.
+        Block::new(private_key, latest_hash, header, transactions, coinbase, rng)
+    }
+
     #[test]
     fn test_verify() {
         let rng = &mut TestRng::default();
@@ -294,14 +654,19 @@ mod tests {
 
         match transaction {
             Transaction::Execute(_, execution, _) => {
-                // Ensure the inclusion proof *does not* exist.
-                assert!(execution.inclusion_proof().is_none()); // This is 'None', because this execution called 'credits.aleo/mint'.
-                // Verify the inclusion.
-                assert!(Inclusion::verify_execution(&execution).is_ok());
+                // Ensure the transition proofs are deferred to the batch proof.
+                assert!(execution.is_batch());
+                // Ensure the batch proof exists.
+                assert!(execution.inclusion_proof().is_some());
                 // Verify the execution.
                 assert!(vm.check_execution(&execution).is_ok());
                 assert!(vm.verify_execution(&execution));
 
+                // Ensure the execution is invalid without the batch proof.
+                let candidate =
+                    Execution::from(execution.transitions().cloned(), execution.global_state_root(), None).unwrap();
+                assert!(!vm.verify_execution(&candidate));
+
                 // Ensure that deserialization doesn't break the transaction verification.
                 let serialized_execution = execution.to_string();
                 let recovered_execution: Execution<CurrentNetwork> =
@@ -383,7 +748,7 @@ mod tests {
 
         // Prepare the fee.
         let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
-        let fee = (credits, 10);
+        let fee = (credits, 100_000_000);
 
         // Initialize the VM.
         let vm = crate::vm::test_helpers::sample_vm();
@@ -393,18 +758,29 @@ mod tests {
         // Deploy.
         let program = crate::vm::test_helpers::sample_program();
         let deployment_transaction = Transaction::deploy(&vm, &caller_private_key, &program, fee, None, rng).unwrap();
+        assert!(vm.check_transaction(&deployment_transaction).is_ok());
 
         // Construct the new block header.
         let transactions = Transactions::from(&[deployment_transaction]);
+        // Compute the coinbase target and proof target.
+        let (coinbase_target, proof_target) =
+            genesis.header().next_targets(CurrentNetwork::GENESIS_TIMESTAMP + 1).unwrap();
+        // Compute the total supply, after issuing the block reward to the caller and burning the fee.
+        let rewards =
+            crate::block::to_block_rewards(address, genesis.total_supply_in_microcredits(), 1, coinbase_target, None)
+                .unwrap();
+        let total_supply =
+            crate::block::to_next_total_supply(genesis.total_supply_in_microcredits(), &rewards, &transactions)
+                .unwrap();
         // Construct the metadata associated with the block.
         let deployment_metadata = Metadata::new(
             CurrentNetwork::ID,
             1,
             1,
-            CurrentNetwork::STARTING_SUPPLY,
+            total_supply,
             0,
-            CurrentNetwork::GENESIS_COINBASE_TARGET,
-            CurrentNetwork::GENESIS_PROOF_TARGET,
+            coinbase_target,
+            proof_target,
             genesis.last_coinbase_target(),
             genesis.last_coinbase_timestamp(),
             CurrentNetwork::GENESIS_TIMESTAMP + 1,
@@ -432,17 +808,24 @@ mod tests {
 
         // Prepare the fee.
         let credits = records.values().next().unwrap().decrypt(&caller_view_key).unwrap();
-        let fee_in_microcredits = 10;
-
-        // Execute the fee.
-        let fee = Transaction::execute_fee(&vm, &caller_private_key, credits, fee_in_microcredits, None, rng).unwrap();
+        let fee_in_microcredits = 10_000_000;
 
         // Prepare the inputs.
         let inputs = [
             Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(),
             Value::<CurrentNetwork>::from_str("10u64").unwrap(),
-        ]
-        .into_iter();
+        ];
+
+        // Execute with an insufficient fee.
+        let fee = Transaction::execute_fee(&vm, &caller_private_key, credits.clone(), 1, None, rng).unwrap();
+        let authorization = vm.authorize(&caller_private_key, "testing.aleo", "mint", inputs.clone(), rng).unwrap();
+        let transaction = Transaction::execute_authorization(&vm, authorization, Some(fee), None, rng).unwrap();
+        // Ensure the transaction is rejected.
+        assert!(vm.check_transaction(&transaction).is_err());
+        assert!(!vm.verify_transaction(&transaction));
+
+        // Execute the fee.
+        let fee = Transaction::execute_fee(&vm, &caller_private_key, credits, fee_in_microcredits, None, rng).unwrap();
 
         // Authorize.
         let authorization = vm.authorize(&caller_private_key, "testing.aleo", "mint", inputs, rng).unwrap();
@@ -455,4 +838,317 @@ mod tests {
         assert!(vm.check_transaction(&transaction).is_ok());
         assert!(vm.verify_transaction(&transaction));
     }
+
+    #[test]
+    fn test_check_next_block() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm();
+        // Initialize the genesis block.
+        let genesis = crate::vm::test_helpers::sample_genesis_block(rng);
+
+        // Ensure the genesis block is a valid next block.
+        assert!(vm.check_next_block(&genesis).is_ok());
+        vm.add_next_block(&genesis).unwrap();
+        // Ensure the genesis block can not be added again.
+        assert!(vm.check_next_block(&genesis).is_err());
+        assert!(vm.add_next_block(&genesis).is_err());
+
+        // Prepare the expected metadata for the next block.
+        let supply = genesis.total_supply_in_microcredits();
+        let timestamp = genesis.timestamp() + 1;
+
+        // Ensure a block with transactions that already exist in the ledger is invalid.
+        let transactions = genesis.transactions().values().cloned().collect::<Vec<_>>();
+        let block = sample_next_block(&vm, &caller_private_key, &transactions, (1, 1, supply, timestamp), rng).unwrap();
+        assert!(vm.check_next_block(&block).is_err());
+
+        // Construct a new transaction.
+        let inputs = [address.to_string(), "1_u64".to_string()].into_iter();
+        let transaction =
+            Transaction::execute(&vm, &caller_private_key, ("credits.aleo", "mint"), inputs, None, None, rng).unwrap();
+        let transactions = [transaction];
+
+        // Compute the expected total supply, after issuing the block reward to the caller.
+        let rewards = crate::block::to_block_rewards(address, supply, 1, genesis.coinbase_target(), None).unwrap();
+        let supply = crate::block::to_next_total_supply(supply, &rewards, &Transactions::from(&transactions)).unwrap();
+
+        // Ensure a block with an incorrect height is invalid.
+        let block = sample_next_block(&vm, &caller_private_key, &transactions, (2, 1, supply, timestamp), rng).unwrap();
+        assert!(vm.check_next_block(&block).is_err());
+        // Ensure a block with an incorrect round is invalid.
+        let block = sample_next_block(&vm, &caller_private_key, &transactions, (1, 0, supply, timestamp), rng);
+        assert!(block.is_err() || vm.check_next_block(&block.unwrap()).is_err());
+        // Ensure a block with an incorrect total supply is invalid.
+        let block =
+            sample_next_block(&vm, &caller_private_key, &transactions, (1, 1, supply + 1, timestamp), rng).unwrap();
+        assert!(vm.check_next_block(&block).is_err());
+        // Ensure a block with an incorrect timestamp is invalid.
+        let block =
+            sample_next_block(&vm, &caller_private_key, &transactions, (1, 1, supply, genesis.timestamp()), rng);
+        assert!(block.is_err() || vm.check_next_block(&block.unwrap()).is_err());
+
+        // Ensure a block with the correct metadata is valid.
+        let block = sample_next_block(&vm, &caller_private_key, &transactions, (1, 1, supply, timestamp), rng).unwrap();
+        assert!(vm.check_next_block(&block).is_ok());
+        vm.add_next_block(&block).unwrap();
+
+        // Ensure the same transactions can not be included in another block.
+        let block =
+            sample_next_block(&vm, &caller_private_key, &transactions, (2, 2, supply, timestamp + 1), rng).unwrap();
+        assert!(vm.check_next_block(&block).is_err());
+    }
+
+    #[test]
+    fn test_add_next_block_issues_rewards() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM, with the genesis block.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Prepare a method to retrieve the public balance of the caller.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from(Literal::Address(address));
+        let balance = |vm: &VM<CurrentNetwork, crate::store::ConsensusMemory<CurrentNetwork>>| {
+            let value = vm.program_store().get_value(&program_id, &mapping_name, &key).unwrap();
+            match value {
+                Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) => *balance,
+                None => 0,
+                Some(value) => panic!("Invalid public balance - {value}"),
+            }
+        };
+        assert_eq!(balance(&vm), 0);
+
+        // Construct a new transaction.
+        let inputs = [address.to_string(), "1_u64".to_string()].into_iter();
+        let transaction =
+            Transaction::execute(&vm, &caller_private_key, ("credits.aleo", "mint"), inputs, None, None, rng).unwrap();
+        let transactions = [transaction];
+
+        // Compute the expected total supply, after issuing the block reward to the caller.
+        let supply = genesis.total_supply_in_microcredits();
+        let rewards = crate::block::to_block_rewards(address, supply, 1, genesis.coinbase_target(), None).unwrap();
+        let supply = crate::block::to_next_total_supply(supply, &rewards, &Transactions::from(&transactions)).unwrap();
+
+        // Add the next block.
+        let timestamp = genesis.timestamp() + 1;
+        let block = sample_next_block(&vm, &caller_private_key, &transactions, (1, 1, supply, timestamp), rng).unwrap();
+        assert_eq!(vm.block_rewards(&block).unwrap(), rewards);
+        vm.add_next_block(&block).unwrap();
+
+        // Ensure the minted rewards equal the increase in the total supply.
+        let minted = balance(&vm);
+        assert!(minted > 0);
+        assert_eq!(minted, block.total_supply_in_microcredits() - genesis.total_supply_in_microcredits());
+
+        // Ensure the minted rewards are reverted along with the block.
+        vm.remove_last_n(1).unwrap();
+        assert_eq!(balance(&vm), 0);
+    }
+
+    #[test]
+    fn test_public_transfers_and_public_fee() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller, and a recipient.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let caller_view_key = ViewKey::try_from(&caller_private_key).unwrap();
+        let address = Address::try_from(&caller_private_key).unwrap();
+        let recipient = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Initialize the VM, with the genesis block.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Prepare a method to retrieve the public balance of an account.
+        let program_id = ProgramID::from_str("credits.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let balance = |vm: &VM<CurrentNetwork, crate::store::ConsensusMemory<CurrentNetwork>>, address| {
+            let key = Plaintext::from(Literal::Address(address));
+            match vm.program_store().get_value(&program_id, &mapping_name, &key).unwrap() {
+                Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) => *balance,
+                None => 0,
+                Some(value) => panic!("Invalid public balance - {value}"),
+            }
+        };
+
+        // Fetch the unspent records.
+        let records =
+            genesis.records().map(|(_, record)| record.decrypt(&caller_view_key).unwrap()).collect::<Vec<_>>();
+        let fee_in_microcredits = 10_000_000;
+
+        // Fund the public balance of the caller from a record, paying a private fee.
+        let inputs = [records[0].to_string(), address.to_string(), "100_000_000_u64".to_string()].into_iter();
+        let fee = Some((records[1].clone(), fee_in_microcredits));
+        let transaction = Transaction::execute(
+            &vm,
+            &caller_private_key,
+            ("credits.aleo", "transfer_private_to_public"),
+            inputs,
+            fee,
+            None,
+            rng,
+        )
+        .unwrap();
+        assert!(vm.check_transaction(&transaction).is_ok());
+        let transactions = [transaction];
+
+        // Add the next block, which issues the block reward to the caller.
+        let supply = genesis.total_supply_in_microcredits();
+        let rewards = crate::block::to_block_rewards(address, supply, 1, genesis.coinbase_target(), None).unwrap();
+        let supply = crate::block::to_next_total_supply(supply, &rewards, &Transactions::from(&transactions)).unwrap();
+        let timestamp = genesis.timestamp() + 1;
+        let block = sample_next_block(&vm, &caller_private_key, &transactions, (1, 1, supply, timestamp), rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        let funded = balance(&vm, address);
+        assert_eq!(funded, 100_000_000 + rewards[&address]);
+
+        // Transfer publicly to the recipient, paying a public fee.
+        let inputs = [recipient.to_string(), "1_000_000_u64".to_string()].into_iter();
+        let authorization = vm.authorize(&caller_private_key, "credits.aleo", "transfer_public", inputs, rng).unwrap();
+        let fee = Transaction::execute_fee_public(&vm, &caller_private_key, fee_in_microcredits, None, rng).unwrap();
+        assert!(vm.verify_fee(&fee));
+        let transfer_public = Transaction::execute_authorization(&vm, authorization, Some(fee), None, rng).unwrap();
+        assert!(vm.check_transaction(&transfer_public).is_ok());
+        assert!(vm.verify_transaction(&transfer_public));
+
+        // Withdraw publicly into a record, paying a private fee.
+        let inputs = [address.to_string(), "2_000_000_u64".to_string()].into_iter();
+        let fee = Some((records[2].clone(), fee_in_microcredits));
+        let transfer_public_to_private = Transaction::execute(
+            &vm,
+            &caller_private_key,
+            ("credits.aleo", "transfer_public_to_private"),
+            inputs,
+            fee,
+            None,
+            rng,
+        )
+        .unwrap();
+        assert!(vm.check_transaction(&transfer_public_to_private).is_ok());
+        let transactions = [transfer_public, transfer_public_to_private];
+
+        // Add the next block, and ensure the public balances are updated.
+        let supply = block.total_supply_in_microcredits();
+        let rewards = crate::block::to_block_rewards(address, supply, 2, block.coinbase_target(), None).unwrap();
+        let supply = crate::block::to_next_total_supply(supply, &rewards, &Transactions::from(&transactions)).unwrap();
+        let block =
+            sample_next_block(&vm, &caller_private_key, &transactions, (2, 2, supply, timestamp + 1), rng).unwrap();
+        vm.add_next_block(&block).unwrap();
+        assert_eq!(balance(&vm, recipient), 1_000_000);
+        assert_eq!(balance(&vm, address), funded + rewards[&address] - 1_000_000 - 2_000_000 - fee_in_microcredits);
+    }
+
+    #[test]
+    fn test_check_next_block_rejects_invalid_transaction() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM, with the genesis block.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+
+        // Fetch an execution transaction that is missing a fee.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_without_fee(rng);
+        assert!(vm.check_transaction(&transaction).is_err());
+        let transactions = [transaction];
+
+        // Compute the expected total supply, after issuing the block reward to the caller.
+        let supply = genesis.total_supply_in_microcredits();
+        let rewards = crate::block::to_block_rewards(address, supply, 1, genesis.coinbase_target(), None).unwrap();
+        let supply = crate::block::to_next_total_supply(supply, &rewards, &Transactions::from(&transactions)).unwrap();
+
+        // Ensure the block with the invalid transaction is invalid, even though its header is correct.
+        let timestamp = genesis.timestamp() + 1;
+        let block = sample_next_block(&vm, &caller_private_key, &transactions, (1, 1, supply, timestamp), rng).unwrap();
+        let error = vm.check_next_block(&block).unwrap_err();
+        assert!(error.to_string().contains("Invalid transaction"), "{error}");
+        assert!(vm.add_next_block(&block).is_err());
+    }
+
+    #[test]
+    fn test_check_next_block_rejects_invalid_coinbase() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new caller.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Initialize the VM, with the genesis block.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis =
+            vm.block_store().get_block(&vm.block_store().get_block_hash(0).unwrap().unwrap()).unwrap().unwrap();
+        let timestamp = genesis.timestamp() + 1;
+        let (coinbase_target, proof_target) = genesis.header().next_targets(timestamp).unwrap();
+
+        // Compute a coinbase solution for an epoch challenge that does not match the ledger.
+        let puzzle = vm.coinbase_puzzle().unwrap();
+        let epoch_challenge = vm.epoch_challenge(1).unwrap();
+        let bad_epoch_challenge = EpochChallenge::new(
+            epoch_challenge.epoch_number() + 1,
+            genesis.hash(),
+            CurrentNetwork::COINBASE_PUZZLE_DEGREE,
+        )
+        .unwrap();
+        let solutions = std::sync::Mutex::new(Vec::new());
+        let terminator = std::sync::atomic::AtomicBool::new(false);
+        puzzle
+            .search(&bad_epoch_challenge, address, 0..u64::MAX, proof_target, &terminator, |solution| {
+                let mut solutions = solutions.lock().unwrap();
+                if !terminator.load(std::sync::atomic::Ordering::Relaxed) {
+                    solutions.push(solution);
+                }
+                // Stop the search once the solutions meet the coinbase target.
+                let cumulative_target = solutions.iter().map(|s| u128::from(s.to_target().unwrap())).sum::<u128>();
+                if cumulative_target >= u128::from(coinbase_target)
+                    || solutions.len() == CurrentNetwork::MAX_PROVER_SOLUTIONS
+                {
+                    terminator.store(true, std::sync::atomic::Ordering::Relaxed);
+                }
+            })
+            .unwrap();
+        let solutions = solutions.into_inner().unwrap();
+        let coinbase = puzzle.accumulate_unchecked(&bad_epoch_challenge, &solutions).unwrap();
+        assert!(
+            puzzle
+                .verify(&coinbase, &epoch_challenge, coinbase_target, proof_target)
+                .map_or(true, |is_valid| !is_valid)
+        );
+
+        // Compute the expected total supply, after issuing the block and coinbase rewards.
+        let supply = genesis.total_supply_in_microcredits();
+        let rewards = crate::block::to_block_rewards(address, supply, 1, coinbase_target, Some(&coinbase)).unwrap();
+        let supply = crate::block::to_next_total_supply(supply, &rewards, &Transactions::from(&[])).unwrap();
+
+        // Ensure the block with the invalid coinbase solution is invalid, even though its header is correct.
+        let block = sample_next_block_with_coinbase(
+            &vm,
+            &caller_private_key,
+            &[],
+            Some(coinbase),
+            (1, 1, supply, timestamp),
+            rng,
+        )
+        .unwrap();
+        let error = vm.check_next_block(&block).unwrap_err();
+        assert!(error.to_string().contains("Invalid coinbase solution"), "{error}");
+        assert!(vm.add_next_block(&block).is_err());
+    }
 }
@@ -14,7 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::cli::commands::{Build, Clean, New, Run, Update};
+#[cfg(feature = "rocks")]
+use crate::cli::commands::Devnet;
+use crate::cli::commands::{Build, Clean, Deploy, Execute, New, Run, Update};
 
 use anyhow::Result;
 use clap::Parser;
@@ -36,6 +38,13 @@ pub enum Command {
     Build(Build),
     #[clap(name = "clean")]
     Clean(Clean),
+    #[clap(name = "deploy")]
+    Deploy(Deploy),
+    #[cfg(feature = "rocks")]
+    #[clap(name = "devnet")]
+    Devnet(Devnet),
+    #[clap(name = "execute")]
+    Execute(Execute),
     #[clap(name = "new")]
     New(New),
     #[clap(name = "run")]
@@ -50,6 +59,10 @@ impl Command {
         match self {
             Self::Build(command) => command.parse(),
             Self::Clean(command) => command.parse(),
+            Self::Deploy(command) => command.parse(),
+            #[cfg(feature = "rocks")]
+            Self::Devnet(command) => command.parse(),
+            Self::Execute(command) => command.parse(),
             Self::New(command) => command.parse(),
             Self::Run(command) => command.parse(),
             Self::Update(command) => command.parse(),
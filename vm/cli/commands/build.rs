@@ -19,7 +19,8 @@ use super::*;
 /// Compiles an Aleo program.
 #[derive(Debug, Parser)]
 pub struct Build {
-    /// Uses the specified endpoint.
+    /// Fetches the proving and verifying keys of the program functions from the specified build endpoint,
+    /// instead of synthesizing them locally.
     #[clap(long)]
     endpoint: Option<String>,
     /// Toggles offline mode.
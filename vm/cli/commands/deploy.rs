@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Deploys an Aleo program, and produces a transaction
+#[derive(Debug, Parser)]
+pub struct Deploy {
+    /// The fee in microcredits.
+    #[clap(long)]
+    fee: u64,
+    /// The record to spend the fee from.
+    #[clap(long, parse(try_from_str))]
+    record: Record<CurrentNetwork, Plaintext<CurrentNetwork>>,
+    /// Uses the specified endpoint to query the global state.
+    #[clap(long, default_value = DEFAULT_QUERY_ENDPOINT)]
+    query: String,
+    /// Stores the transaction at the given path.
+    #[clap(long)]
+    store: Option<PathBuf>,
+    /// Stores the transaction as bytes, instead of JSON.
+    #[clap(long, requires = "store")]
+    bytes: bool,
+    /// Broadcasts the transaction to the specified endpoint.
+    #[clap(long)]
+    broadcast: Option<String>,
+}
+
+impl Deploy {
+    /// Deploys an Aleo program, and produces a transaction.
+    pub fn parse(self) -> Result<String> {
+        // Derive the program directory path.
+        let path = std::env::current_dir()?;
+
+        // Load the package.
+        let package = Package::open(&path)?;
+
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Compute the transaction.
+        let transaction = package.deploy_transaction::<Aleo, BlockMemory<CurrentNetwork>, _>(
+            None,
+            package.manifest_file().development_private_key(),
+            (self.record, self.fee),
+            Query::from(self.query),
+            rng,
+        )?;
+
+        // Store and broadcast the transaction.
+        handle_transaction(&transaction, self.store, self.bytes, self.broadcast)?;
+
+        // Prepare the path string.
+        let path_string = format!("(in \"{}\")", path.display());
+
+        Ok(format!(
+            "✅ Created transaction '{}' for '{}' {}",
+            transaction.id().to_string().bold(),
+            package.program_id().to_string().bold(),
+            path_string.dimmed()
+        ))
+    }
+}
@@ -0,0 +1,450 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{
+    prelude::{Address, CryptoRng, Entry, Field, Literal, Network, PrivateKey, Rng, ViewKey, Zero},
+    synthesizer::{
+        to_block_rewards,
+        to_next_total_supply,
+        Block,
+        CoinbaseSolution,
+        ConsensusDB,
+        ConsensusStore,
+        Header,
+        Metadata,
+        ProverSolution,
+        Transaction,
+        Transactions,
+        VM,
+    },
+};
+
+use anyhow::{bail, ensure};
+use std::{
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+type CurrentVM = VM<CurrentNetwork, ConsensusDB<CurrentNetwork>>;
+
+/// Runs a local development network, with a ledger stored in the Aleo ledger directory of the development ID
+#[derive(Debug, Parser)]
+pub struct Devnet {
+    /// The development ID of the local ledger.
+    #[clap(long, default_value = "0")]
+    dev: u16,
+    /// Mines blocks without solving the coinbase puzzle, so the blocks do not issue a coinbase reward.
+    #[clap(long)]
+    skip_coinbase: bool,
+    /// Specify a devnet subcommand.
+    #[clap(subcommand)]
+    command: DevnetCommand,
+}
+
+#[derive(Debug, Parser)]
+pub enum DevnetCommand {
+    /// Initializes the local ledger with a genesis block.
+    #[clap(name = "init")]
+    Init,
+    /// Deploys the program in the package, and mines a block with the deployment.
+    #[clap(name = "deploy")]
+    Deploy {
+        /// The fee in microcredits.
+        #[clap(long)]
+        fee: u64,
+    },
+    /// Executes a program function, and mines a block with the execution.
+    #[clap(name = "execute")]
+    Execute {
+        /// The function name.
+        #[clap(parse(try_from_str))]
+        function: Identifier<CurrentNetwork>,
+        /// The function inputs.
+        #[clap(parse(try_from_str))]
+        inputs: Vec<Value<CurrentNetwork>>,
+        /// The fee in microcredits.
+        #[clap(long)]
+        fee: Option<u64>,
+    },
+}
+
+impl Devnet {
+    /// Runs the devnet subcommand, and mines the resulting block into the local ledger.
+    pub fn parse(self) -> Result<String> {
+        // Derive the program directory path.
+        let path = std::env::current_dir()?;
+        // Run the devnet subcommand in the program directory.
+        self.run(&path)
+    }
+
+    /// Runs the devnet subcommand for the package in the given directory.
+    fn run(self, path: &Path) -> Result<String> {
+        // Load the package.
+        let package = Package::open(path)?;
+        // Retrieve the development private key.
+        let private_key = package.manifest_file().development_private_key();
+
+        // Initialize an RNG.
+        let rng = &mut rand::thread_rng();
+
+        // Open the local ledger.
+        let vm = CurrentVM::from(ConsensusStore::open(Some(self.dev))?)?;
+
+        // Construct the next block.
+        let block = match self.command {
+            DevnetCommand::Init => {
+                // Ensure the local ledger is empty.
+                ensure!(latest_block(&vm)?.is_none(), "The devnet ledger is already initialized");
+                // Construct the genesis block.
+                Block::genesis(&vm, private_key, rng)?
+            }
+            DevnetCommand::Deploy { fee } => {
+                // Find a record to pay the fee with.
+                let credits = find_credits(&vm, private_key, fee)?;
+                // Compute the deployment transaction.
+                let transaction = Transaction::deploy(&vm, private_key, package.program(), (credits, fee), None, rng)?;
+                // Mine the deployment transaction.
+                mine(&vm, private_key, transaction, self.skip_coinbase, rng)?
+            }
+            DevnetCommand::Execute { function, inputs, fee } => {
+                // Find a record to pay the fee with, if a fee is specified.
+                let fee = match fee {
+                    Some(fee) => Some((find_credits(&vm, private_key, fee)?, fee)),
+                    None => None,
+                };
+                // Compute the execution transaction.
+                let transaction = Transaction::execute(
+                    &vm,
+                    private_key,
+                    (*package.program_id(), function),
+                    inputs.into_iter(),
+                    fee,
+                    None,
+                    rng,
+                )?;
+                // Mine the execution transaction.
+                mine(&vm, private_key, transaction, self.skip_coinbase, rng)?
+            }
+        };
+
+        // Add the block to the local ledger.
+        vm.add_next_block(&block)?;
+
+        // Log the program mappings.
+        log_mappings(&vm)?;
+        // Log the records in the block that belong to the developer.
+        log_records(&block, private_key)?;
+
+        // Prepare the path string.
+        let path_string = format!("(in \"{}\")", path.display());
+
+        Ok(format!(
+            "✅ Mined block {} ('{}') on the devnet {}",
+            block.height().to_string().bold(),
+            block.hash().to_string().bold(),
+            path_string.dimmed()
+        ))
+    }
+}
+
+/// Returns the latest block in the local ledger, if the ledger is initialized.
+fn latest_block(vm: &CurrentVM) -> Result<Option<Block<CurrentNetwork>>> {
+    // Retrieve the latest block height.
+    let height = match vm.block_store().max_height() {
+        Some(height) => height,
+        None => return Ok(None),
+    };
+    // Retrieve the latest block hash.
+    let block_hash = match vm.block_store().get_block_hash(height)? {
+        Some(block_hash) => block_hash,
+        None => bail!("Missing the block hash for block {height} in the devnet ledger"),
+    };
+    // Retrieve the latest block.
+    vm.block_store().get_block(&block_hash)
+}
+
+/// Returns an unspent `credits.aleo` record of the developer, with at least the given amount of microcredits.
+fn find_credits(
+    vm: &CurrentVM,
+    private_key: &PrivateKey<CurrentNetwork>,
+    fee_in_microcredits: u64,
+) -> Result<Record<CurrentNetwork, Plaintext<CurrentNetwork>>> {
+    // Derive the view key.
+    let view_key = ViewKey::try_from(private_key)?;
+    // Prepare the identifier for the microcredits entry.
+    let microcredits = Identifier::from_str("microcredits")?;
+
+    for (commitment, record) in vm.transition_store().records() {
+        // Skip the records that do not belong to the developer.
+        if !record.is_owner(&view_key) {
+            continue;
+        }
+        // Skip the records that are already spent.
+        let serial_number =
+            Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::serial_number(*private_key, *commitment)?;
+        if vm.transition_store().contains_serial_number(&serial_number)? {
+            continue;
+        }
+        // Decrypt the record.
+        let record = record.decrypt(&view_key)?;
+        // Return the record, if it holds enough microcredits.
+        if let Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) = record.data().get(&microcredits) {
+            if **amount >= fee_in_microcredits {
+                return Ok(record);
+            }
+        }
+    }
+    bail!("Failed to find an unspent record with at least {fee_in_microcredits} microcredits in the devnet ledger")
+}
+
+/// Mines a new block with the given transaction, on top of the latest block in the local ledger.
+///
+/// Unless `skip_coinbase` is set, the developer solves the coinbase puzzle for the block.
+fn mine<R: Rng + CryptoRng>(
+    vm: &CurrentVM,
+    private_key: &PrivateKey<CurrentNetwork>,
+    transaction: Transaction<CurrentNetwork>,
+    skip_coinbase: bool,
+    rng: &mut R,
+) -> Result<Block<CurrentNetwork>> {
+    // Retrieve the latest block.
+    let latest_block = match latest_block(vm)? {
+        Some(block) => block,
+        None => bail!("The devnet ledger is not initialized, run 'snarkvm devnet init' first"),
+    };
+
+    // Prepare the timestamp, ensuring it is after the latest block.
+    let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?.as_secs() as i64;
+    let timestamp = now.max(latest_block.timestamp().saturating_add(1));
+    // Compute the coinbase target and proof target.
+    let (coinbase_target, proof_target) = latest_block.header().next_targets(timestamp)?;
+
+    // Solve the coinbase puzzle, unless it is skipped.
+    let coinbase = match skip_coinbase {
+        true => None,
+        false => Some(solve_coinbase(vm, private_key, latest_block.height() + 1, coinbase_target, proof_target, rng)?),
+    };
+    // Compute the cumulative proof target, the last coinbase target and timestamp, and the accumulator point.
+    let (cumulative_proof_target, last_coinbase_target, last_coinbase_timestamp, accumulator_point) = match &coinbase {
+        Some(coinbase) => (
+            latest_block.cumulative_proof_target().saturating_add(coinbase.to_cumulative_proof_target()?),
+            coinbase_target,
+            timestamp,
+            coinbase.to_accumulator_point()?,
+        ),
+        None => (
+            latest_block.cumulative_proof_target(),
+            latest_block.last_coinbase_target(),
+            latest_block.last_coinbase_timestamp(),
+            Field::zero(),
+        ),
+    };
+
+    // Construct the transactions.
+    let transactions = Transactions::from(&[transaction]);
+    // Compute the total supply, after issuing the rewards to the developer and burning the fee.
+    let previous_total_supply = latest_block.total_supply_in_microcredits();
+    let rewards = to_block_rewards(
+        Address::try_from(private_key)?,
+        previous_total_supply,
+        latest_block.height() + 1,
+        coinbase_target,
+        coinbase.as_ref(),
+    )?;
+    let total_supply = to_next_total_supply(previous_total_supply, &rewards, &transactions)?;
+    // Construct the metadata associated with the block.
+    let metadata = Metadata::new(
+        CurrentNetwork::ID,
+        latest_block.round() + 1,
+        latest_block.height() + 1,
+        total_supply,
+        cumulative_proof_target,
+        coinbase_target,
+        proof_target,
+        last_coinbase_target,
+        last_coinbase_timestamp,
+        timestamp,
+    )?;
+    // Construct the block header.
+    let header = Header::from(
+        *vm.block_store().current_state_root(),
+        transactions.to_root()?,
+        Field::zero(),
+        accumulator_point,
+        metadata,
+    )?;
+
+    // Construct the block.
+    Block::new(private_key, latest_block.hash(), header, transactions, coinbase, rng)
+}
+
+/// Solves the coinbase puzzle for the block at the given height, by searching for prover solutions of the developer
+/// until they meet the coinbase target, and returns the accumulated coinbase solution.
+fn solve_coinbase<R: Rng + CryptoRng>(
+    vm: &CurrentVM,
+    private_key: &PrivateKey<CurrentNetwork>,
+    height: u32,
+    coinbase_target: u64,
+    proof_target: u64,
+    rng: &mut R,
+) -> Result<CoinbaseSolution<CurrentNetwork>> {
+    // Retrieve the coinbase puzzle and the epoch challenge.
+    let puzzle = vm.coinbase_puzzle()?;
+    let epoch_challenge = vm.epoch_challenge(height)?;
+
+    // Start the search at a random nonce, so the puzzle commitments differ from those of earlier blocks.
+    let start = rng.gen_range(0..u64::MAX / 2);
+
+    // Search for prover solutions, until their cumulative target meets the coinbase target.
+    let solutions = Mutex::new(Vec::new());
+    let terminator = AtomicBool::new(false);
+    let on_solution = |solution: ProverSolution<CurrentNetwork>| {
+        let mut solutions = solutions.lock().unwrap_or_else(|error| error.into_inner());
+        if terminator.load(Ordering::Relaxed) {
+            return;
+        }
+        solutions.push(solution);
+        // Stop the search, once the coinbase target is met, or the maximum number of prover solutions is reached.
+        let cumulative_target =
+            solutions.iter().map(|solution| solution.to_target().map(u128::from).unwrap_or_default()).sum::<u128>();
+        if cumulative_target >= u128::from(coinbase_target) || solutions.len() == CurrentNetwork::MAX_PROVER_SOLUTIONS {
+            terminator.store(true, Ordering::Relaxed);
+        }
+    };
+    puzzle.search(
+        &epoch_challenge,
+        Address::try_from(private_key)?,
+        start..u64::MAX,
+        proof_target,
+        &terminator,
+        on_solution,
+    )?;
+    let solutions = solutions.into_inner().unwrap_or_else(|error| error.into_inner());
+
+    // Accumulate the prover solutions into the coinbase solution.
+    let coinbase = puzzle.accumulate_unchecked(&epoch_challenge, &solutions)?;
+    // Ensure the coinbase solution is valid.
+    ensure!(
+        puzzle.verify(&coinbase, &epoch_challenge, coinbase_target, proof_target)?,
+        "Failed to solve the coinbase puzzle for block {height}"
+    );
+    Ok(coinbase)
+}
+
+/// Logs the key-value pairs in every program mapping in the local ledger.
+fn log_mappings(vm: &CurrentVM) -> Result<()> {
+    println!("\n🗺  Mappings\n");
+    for program_id in vm.program_store().program_ids() {
+        for mapping_name in vm.program_store().get_mapping_names(&program_id)?.unwrap_or_default() {
+            println!(" • {}", format!("{program_id}/{mapping_name}").bold());
+            for (key, value) in vm.program_store().get_mapping(&program_id, &mapping_name)?.unwrap_or_default() {
+                println!("     {key} => {value}");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Logs the records in the given block that belong to the developer.
+fn log_records(block: &Block<CurrentNetwork>, private_key: &PrivateKey<CurrentNetwork>) -> Result<()> {
+    // Derive the view key.
+    let view_key = ViewKey::try_from(private_key)?;
+
+    println!("\n📦 Records\n");
+    for (commitment, record) in block.records() {
+        if record.is_owner(&view_key) {
+            println!(" • {}\n{}", commitment.to_string().dimmed(), record.decrypt(&view_key)?);
+        }
+    }
+    println!();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the devnet subcommand for the given development ID and arguments.
+    fn devnet(dev: u16, args: &[&str]) -> Devnet {
+        let dev = dev.to_string();
+        Devnet::try_parse_from(["snarkvm", "--dev", &dev].iter().chain(args)).unwrap()
+    }
+
+    /// The directories of a test, which are removed once it is dropped (even if the test panics).
+    struct TempDirectories(Vec<std::path::PathBuf>);
+
+    impl Drop for TempDirectories {
+        fn drop(&mut self) {
+            for directory in &self.0 {
+                let _ = std::fs::remove_dir_all(directory);
+            }
+        }
+    }
+
+    #[test]
+    fn test_init_deploy_execute() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_package();
+        let address = Address::try_from(package.manifest_file().development_private_key()).unwrap();
+
+        // Sample a development ID for the local ledger.
+        let dev = rand::random();
+        let ledger_directory = aleo_std::aleo_ledger_dir(CurrentNetwork::ID, Some(dev));
+
+        // Remove the local ledger and the temporary directory once the test is done (to conserve space).
+        let _directories = TempDirectories(vec![ledger_directory, directory.clone()]);
+
+        // Initialize the local ledger.
+        devnet(dev, &["init"]).run(&directory).unwrap();
+        // Ensure the local ledger can not be initialized twice.
+        assert!(devnet(dev, &["init"]).run(&directory).is_err());
+        // Deploy the program, without solving the coinbase puzzle.
+        devnet(dev, &["--skip-coinbase", "deploy", "--fee", "100000000"]).run(&directory).unwrap();
+        // Execute a function of the program, solving the coinbase puzzle.
+        let inputs = ["mint", &address.to_string(), "100u64", "--fee", "100000000"];
+        devnet(dev, &inputs).run(&directory).unwrap();
+
+        {
+            // Reopen the local ledger.
+            let vm = CurrentVM::from(ConsensusStore::open(Some(dev)).unwrap()).unwrap();
+            // Ensure the blocks were mined.
+            let latest_block = latest_block(&vm).unwrap().unwrap();
+            assert_eq!(latest_block.height(), 2);
+            // Ensure the program was deployed.
+            assert!(vm.contains_program(package.program_id()));
+            // Ensure only the last block contains a coinbase solution.
+            assert!(latest_block.coinbase().is_some());
+            let deployment_block = vm.block_store().get_block(&latest_block.previous_hash()).unwrap().unwrap();
+            assert!(deployment_block.coinbase().is_none());
+            // Ensure the rewards were paid out to the public balance of the developer.
+            let balance = vm
+                .program_store()
+                .get_value(
+                    &ProgramID::from_str("credits.aleo").unwrap(),
+                    &Identifier::from_str("account").unwrap(),
+                    &Plaintext::from(Literal::Address(address)),
+                )
+                .unwrap();
+            assert!(
+                matches!(balance, Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) if *balance > 0)
+            );
+        }
+    }
+}
@@ -20,6 +20,17 @@ pub use build::*;
 pub mod clean;
 pub use clean::*;
 
+pub mod deploy;
+pub use deploy::*;
+
+#[cfg(feature = "rocks")]
+pub mod devnet;
+#[cfg(feature = "rocks")]
+pub use devnet::*;
+
+pub mod execute;
+pub use execute::*;
+
 pub mod new;
 pub use new::*;
 
@@ -31,14 +42,47 @@ pub use update::*;
 
 use crate::{
     package::Package,
-    prelude::{Identifier, Locator, ProgramID, Value},
+    prelude::{Identifier, Locator, Plaintext, ProgramID, Record, ToBytes, Value},
+    synthesizer::{BlockMemory, Query, Transaction},
 };
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use clap::Parser;
 use colored::Colorize;
 use core::str::FromStr;
-use std::collections::HashMap;
+use std::{collections::HashMap, path::PathBuf};
 
 pub(crate) type CurrentNetwork = crate::prelude::Testnet3;
 pub(crate) type Aleo = crate::circuit::AleoV0;
+
+/// The default endpoint used to query the global state.
+pub(crate) const DEFAULT_QUERY_ENDPOINT: &str = "https://vm.aleo.org/api";
+
+/// Stores the transaction at the given path (as JSON, or as bytes if `bytes` is set),
+/// and broadcasts the transaction to the given endpoint, if one is specified.
+pub(crate) fn handle_transaction(
+    transaction: &Transaction<CurrentNetwork>,
+    store: Option<PathBuf>,
+    bytes: bool,
+    broadcast: Option<String>,
+) -> Result<()> {
+    // Store the transaction, if a path is specified, or print it otherwise.
+    match store {
+        Some(path) => {
+            match bytes {
+                true => std::fs::write(&path, transaction.to_bytes_le()?)?,
+                false => std::fs::write(&path, serde_json::to_string_pretty(transaction)?)?,
+            }
+            println!("💾 Stored the transaction at \"{}\"\n", path.display());
+        }
+        None => println!("{}\n", serde_json::to_string_pretty(transaction)?),
+    }
+
+    // Broadcast the transaction, if an endpoint is specified.
+    if let Some(endpoint) = broadcast {
+        ureq::post(&endpoint).send_json(transaction)?;
+        println!("📡 Broadcasted the transaction to '{endpoint}'\n");
+    }
+
+    Ok(())
+}
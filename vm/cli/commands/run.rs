@@ -27,7 +27,8 @@ pub struct Run {
     /// The function inputs.
     #[clap(parse(try_from_str))]
     inputs: Vec<Value<CurrentNetwork>>,
-    /// Uses the specified endpoint.
+    /// Fetches the proving and verifying keys of the program functions from the specified build endpoint,
+    /// instead of synthesizing them locally.
     #[clap(long)]
     endpoint: Option<String>,
     /// Toggles offline mode.
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::synthesizer::Deployment;
+use crate::{prelude::ProgramOwner, synthesizer::Deployment};
 use snarkvm_console::types::Address;
 use snarkvm_utilities::DeserializeExt;
 
@@ -178,6 +178,33 @@ impl<N: Network> Package<N> {
             None => Ok(deployment),
         }
     }
+
+    /// Deploys the program, and returns the deployment transaction.
+    /// The transaction spends the given credits record to pay the fee (in microcredits).
+    pub fn deploy_transaction<
+        A: crate::circuit::Aleo<Network = N, BaseField = N::Field>,
+        B: BlockStorage<N>,
+        R: Rng + CryptoRng,
+    >(
+        &self,
+        endpoint: Option<String>,
+        private_key: &PrivateKey<N>,
+        (credits, fee_in_microcredits): (Record<N, Plaintext<N>>, u64),
+        query: Query<N, B>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Compute the deployment.
+        let deployment = self.deploy::<A>(endpoint)?;
+        // Compute the fee.
+        let fee = self.execute_fee::<A, B, R>(private_key, credits, fee_in_microcredits, query, rng)?;
+
+        // Construct the owner.
+        let id = Transaction::deployment_id(&deployment, &fee)?;
+        let owner = ProgramOwner::new(private_key, id, rng)?;
+
+        // Construct the transaction.
+        Transaction::from_deployment(owner, deployment, fee)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<N: Network> Package<N> {
+    /// Executes a program function with the given inputs, and returns the transaction.
+    /// If a fee is given, the transaction spends the credits record to pay the fee (in microcredits).
+    #[allow(clippy::too_many_arguments)]
+    pub fn execute<
+        A: crate::circuit::Aleo<Network = N, BaseField = N::Field>,
+        B: BlockStorage<N>,
+        R: Rng + CryptoRng,
+    >(
+        &self,
+        endpoint: Option<String>,
+        private_key: &PrivateKey<N>,
+        function_name: Identifier<N>,
+        inputs: &[Value<N>],
+        fee: Option<(Record<N, Plaintext<N>>, u64)>,
+        query: Query<N, B>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Compute the execution.
+        let (_response, execution, inclusion, _metrics) =
+            self.run::<A, R>(endpoint, private_key, function_name, inputs, rng)?;
+
+        // Prepare the assignments.
+        let (assignments, global_state_root) = inclusion.prepare_execution(&execution, query.clone())?;
+        // Compute the inclusion proof and update the execution.
+        let execution = inclusion.prove_execution::<A, R>(execution, &assignments, global_state_root, rng)?;
+
+        // Compute the fee.
+        let fee = match fee {
+            None => None,
+            Some((credits, fee_in_microcredits)) => {
+                Some(self.execute_fee::<A, B, R>(private_key, credits, fee_in_microcredits, query, rng)?)
+            }
+        };
+
+        // Construct the transaction.
+        Transaction::from_execution(execution, fee)
+    }
+
+    /// Executes the fee, spending the given credits record to pay the fee (in microcredits).
+    pub fn execute_fee<
+        A: crate::circuit::Aleo<Network = N, BaseField = N::Field>,
+        B: BlockStorage<N>,
+        R: Rng + CryptoRng,
+    >(
+        &self,
+        private_key: &PrivateKey<N>,
+        credits: Record<N, Plaintext<N>>,
+        fee_in_microcredits: u64,
+        query: Query<N, B>,
+        rng: &mut R,
+    ) -> Result<Fee<N>> {
+        // Construct the process.
+        let process = self.get_process()?;
+
+        // Execute the call to fee.
+        let (_response, fee_transition, inclusion, _metrics) =
+            process.execute_fee::<A, R>(private_key, credits, fee_in_microcredits, rng)?;
+
+        // Prepare the assignments.
+        let assignments = inclusion.prepare_fee(&fee_transition, query)?;
+        // Compute the inclusion proof and construct the fee.
+        inclusion.prove_fee::<A, R>(fee_transition, &assignments, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::synthesizer::{Block, ConsensusMemory, ConsensusStore, VM};
+    use snarkvm_utilities::TestRng;
+
+    type CurrentNetwork = snarkvm_console::network::Testnet3;
+    type CurrentAleo = snarkvm_circuit::network::AleoV0;
+
+    #[test]
+    fn test_execute() {
+        // Samples a new package at a temporary directory.
+        let (directory, package) = crate::package::test_helpers::sample_package();
+
+        // Initialize an RNG.
+        let rng = &mut TestRng::default();
+
+        // Initialize a VM with the genesis block, to serve as the query.
+        let vm =
+            VM::<CurrentNetwork, ConsensusMemory<CurrentNetwork>>::from(ConsensusStore::open(None).unwrap()).unwrap();
+        let genesis = Block::genesis(&vm, &PrivateKey::new(rng).unwrap(), rng).unwrap();
+        vm.add_next_block(&genesis).unwrap();
+
+        // Sample the function inputs.
+        let (private_key, function_name, inputs) =
+            crate::package::test_helpers::sample_package_run(package.program_id());
+        // Execute the program function.
+        let transaction = package
+            .execute::<CurrentAleo, _, _>(
+                None,
+                &private_key,
+                function_name,
+                &inputs,
+                None,
+                Query::from(vm.block_store()),
+                rng,
+            )
+            .unwrap();
+
+        // Ensure the transaction is an execution without a fee.
+        match transaction {
+            Transaction::Execute(_, execution, fee) => {
+                assert_eq!(execution.len(), 1);
+                assert_eq!(execution.global_state_root(), vm.block_store().current_state_root());
+                assert!(fee.is_none());
+            }
+            _ => panic!("Expected an execution transaction"),
+        }
+
+        // Proactively remove the temporary directory (to conserve space).
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -17,6 +17,7 @@
 mod build;
 mod clean;
 mod deploy;
+mod execute;
 mod is_build_required;
 mod run;
 
@@ -31,15 +32,30 @@ use crate::{
         Identifier,
         Locator,
         Network,
+        Plaintext,
         PrivateKey,
         ProgramID,
+        Record,
         Response,
         Serialize,
         SerializeStruct,
         Serializer,
         Value,
     },
-    synthesizer::{CallOperator, Execution, Inclusion, Instruction, Process, Program, ProvingKey, VerifyingKey},
+    synthesizer::{
+        BlockStorage,
+        CallOperator,
+        Execution,
+        Fee,
+        Inclusion,
+        Instruction,
+        Process,
+        Program,
+        ProvingKey,
+        Query,
+        Transaction,
+        VerifyingKey,
+    },
 };
 
 use anyhow::{bail, ensure, Error, Result};
@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_console::account::{Address as AddressNative, PrivateKey as PrivateKeyNative, ViewKey as ViewKeyNative};
+
+use core::str::FromStr;
+use rand::rngs::OsRng;
+use wasm_bindgen::prelude::*;
+
+/// An account private key.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrivateKey(pub(crate) PrivateKeyNative<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl PrivateKey {
+    /// Samples a new private key.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Result<PrivateKey, String> {
+        Ok(Self(PrivateKeyNative::new(&mut OsRng).map_err(to_js_error)?))
+    }
+
+    /// Parses a private key from a string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(private_key: &str) -> Result<PrivateKey, String> {
+        Ok(Self(PrivateKeyNative::from_str(private_key).map_err(to_js_error)?))
+    }
+
+    /// Returns the private key as a string.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the view key of the private key.
+    #[wasm_bindgen(js_name = toViewKey)]
+    pub fn to_view_key(&self) -> Result<ViewKey, String> {
+        ViewKey::from_private_key(self)
+    }
+
+    /// Returns the address of the private key.
+    #[wasm_bindgen(js_name = toAddress)]
+    pub fn to_address(&self) -> Result<Address, String> {
+        Address::from_private_key(self)
+    }
+
+    /// Signs the given message with the private key.
+    pub fn sign(&self, message: &[u8]) -> Result<Signature, String> {
+        Signature::sign(self, message)
+    }
+}
+
+/// An account view key.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ViewKey(pub(crate) ViewKeyNative<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl ViewKey {
+    /// Derives the view key from the given private key.
+    #[wasm_bindgen(js_name = fromPrivateKey)]
+    pub fn from_private_key(private_key: &PrivateKey) -> Result<ViewKey, String> {
+        Ok(Self(ViewKeyNative::try_from(&private_key.0).map_err(to_js_error)?))
+    }
+
+    /// Parses a view key from a string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(view_key: &str) -> Result<ViewKey, String> {
+        Ok(Self(ViewKeyNative::from_str(view_key).map_err(to_js_error)?))
+    }
+
+    /// Returns the view key as a string.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the address of the view key.
+    #[wasm_bindgen(js_name = toAddress)]
+    pub fn to_address(&self) -> Address {
+        Address::from_view_key(self)
+    }
+}
+
+/// An account address.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Address(pub(crate) AddressNative<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl Address {
+    /// Derives the address from the given private key.
+    #[wasm_bindgen(js_name = fromPrivateKey)]
+    pub fn from_private_key(private_key: &PrivateKey) -> Result<Address, String> {
+        Ok(Self(AddressNative::try_from(&private_key.0).map_err(to_js_error)?))
+    }
+
+    /// Derives the address from the given view key.
+    #[wasm_bindgen(js_name = fromViewKey)]
+    pub fn from_view_key(view_key: &ViewKey) -> Address {
+        Self(view_key.0.to_address())
+    }
+
+    /// Parses an address from a string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(address: &str) -> Result<Address, String> {
+        Ok(Self(AddressNative::from_str(address).map_err(to_js_error)?))
+    }
+
+    /// Returns the address as a string.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns `true` if the given signature is valid for the given message and this address.
+    pub fn verify(&self, message: &[u8], signature: &Signature) -> bool {
+        signature.verify(self, message)
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_circuit::network::AleoV0;
+use snarkvm_synthesizer::{Authorization as AuthorizationNative, Process, Program};
+
+use core::str::FromStr;
+use rand::rngs::OsRng;
+use wasm_bindgen::prelude::*;
+
+/// The circuit environment used by the bindings.
+type CurrentAleo = AleoV0;
+
+/// An authorization of a program function call.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Authorization(pub(crate) AuthorizationNative<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl Authorization {
+    /// Authorizes a call to the given function of the given program, with the given inputs.
+    ///
+    /// The program is given as source code, and is authorized alongside `credits.aleo`.
+    /// Note: Programs that import programs other than `credits.aleo` are not supported.
+    #[allow(clippy::boxed_local)]
+    pub fn new(
+        private_key: &PrivateKey,
+        program: &str,
+        function_name: &str,
+        inputs: Box<[JsValue]>,
+    ) -> Result<Authorization, String> {
+        // Parse the program.
+        let program = Program::<CurrentNetwork>::from_str(program).map_err(to_js_error)?;
+        // Parse the inputs.
+        let inputs = inputs
+            .iter()
+            .map(|input| input.as_string().ok_or_else(|| "Inputs must be strings".to_string()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Initialize the process.
+        let mut process = Process::<CurrentNetwork>::load_web().map_err(to_js_error)?;
+        // Add the program to the process, if it is not 'credits.aleo'.
+        if !process.contains_program(program.id()) {
+            process.add_program(&program).map_err(to_js_error)?;
+        }
+
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&private_key.0, program.id(), function_name, inputs.iter(), &mut OsRng)
+            .map_err(to_js_error)?;
+        Ok(Self(authorization))
+    }
+
+    /// Parses an authorization from a JSON string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(authorization: &str) -> Result<Authorization, String> {
+        Ok(Self(AuthorizationNative::from_str(authorization).map_err(to_js_error)?))
+    }
+
+    /// Returns the authorization as a JSON string.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the number of requests in the authorization.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the authorization has no requests.
+    #[wasm_bindgen(js_name = isEmpty)]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
@@ -0,0 +1,39 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+mod account;
+pub use account::*;
+
+#[cfg(feature = "synthesizer")]
+mod authorize;
+#[cfg(feature = "synthesizer")]
+pub use authorize::*;
+
+mod record;
+pub use record::*;
+
+mod signature;
+pub use signature::*;
+
+use snarkvm_console::network::Testnet3;
+
+/// The network used by the bindings.
+type CurrentNetwork = Testnet3;
+
+/// A helper to convert an error into a string, which is thrown as an exception in JavaScript.
+fn to_js_error(error: impl core::fmt::Display) -> String {
+    error.to_string()
+}
@@ -0,0 +1,74 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_console::program::{Ciphertext, Plaintext, Record};
+
+use core::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// An encrypted record.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordCiphertext(pub(crate) Record<CurrentNetwork, Ciphertext<CurrentNetwork>>);
+
+#[wasm_bindgen]
+impl RecordCiphertext {
+    /// Parses an encrypted record from a string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(record: &str) -> Result<RecordCiphertext, String> {
+        Ok(Self(Record::from_str(record).map_err(to_js_error)?))
+    }
+
+    /// Returns the encrypted record as a string.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns `true` if the given view key is the owner of the record.
+    #[wasm_bindgen(js_name = isOwner)]
+    pub fn is_owner(&self, view_key: &ViewKey) -> bool {
+        self.0.is_owner(&view_key.0)
+    }
+
+    /// Decrypts the record with the given view key.
+    pub fn decrypt(&self, view_key: &ViewKey) -> Result<RecordPlaintext, String> {
+        Ok(RecordPlaintext(self.0.decrypt(&view_key.0).map_err(to_js_error)?))
+    }
+}
+
+/// A decrypted record.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordPlaintext(pub(crate) Record<CurrentNetwork, Plaintext<CurrentNetwork>>);
+
+#[wasm_bindgen]
+impl RecordPlaintext {
+    /// Parses a decrypted record from a string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(record: &str) -> Result<RecordPlaintext, String> {
+        Ok(Self(Record::from_str(record).map_err(to_js_error)?))
+    }
+
+    /// Returns the decrypted record as a string.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
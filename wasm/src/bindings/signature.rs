@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_console::account::Signature as SignatureNative;
+
+use core::str::FromStr;
+use rand::rngs::OsRng;
+use wasm_bindgen::prelude::*;
+
+/// An account signature.
+#[wasm_bindgen]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature(pub(crate) SignatureNative<CurrentNetwork>);
+
+#[wasm_bindgen]
+impl Signature {
+    /// Signs the given message with the given private key.
+    pub fn sign(private_key: &PrivateKey, message: &[u8]) -> Result<Signature, String> {
+        Ok(Self(private_key.0.sign_bytes(message, &mut OsRng).map_err(to_js_error)?))
+    }
+
+    /// Returns `true` if the signature is valid for the given address and message.
+    pub fn verify(&self, address: &Address, message: &[u8]) -> bool {
+        self.0.verify_bytes(&address.0, message)
+    }
+
+    /// Parses a signature from a string.
+    #[wasm_bindgen(js_name = fromString)]
+    pub fn from_string(signature: &str) -> Result<Signature, String> {
+        Ok(Self(SignatureNative::from_str(signature).map_err(to_js_error)?))
+    }
+
+    /// Returns the signature as a string.
+    #[wasm_bindgen(js_name = toString)]
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        self.0.to_string()
+    }
+}
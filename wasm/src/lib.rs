@@ -29,5 +29,8 @@ pub use snarkvm_utilities::*;
 #[cfg(feature = "synthesizer")]
 pub use snarkvm_synthesizer::*;
 
+#[cfg(feature = "console")]
+pub mod bindings;
+
 #[cfg(test)]
 mod tests;
@@ -14,13 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::bindings;
 use snarkvm_console::{
     account::{Address, PrivateKey, ViewKey},
-    network::Testnet3,
+    network::{Network, Testnet3},
+    program::{Plaintext, Record},
+    types::Scalar,
 };
-use snarkvm_utilities::TestRng;
+use snarkvm_utilities::{TestRng, Uniform};
 
 use core::str::FromStr;
+use wasm_bindgen::JsValue;
 use wasm_bindgen_test::*;
 
 const ITERATIONS: usize = 1000;
@@ -60,3 +64,104 @@ fn test_account_sign() {
         assert!(result, "Failed to execute signature verification");
     }
 }
+
+#[wasm_bindgen_test]
+fn test_bindings_account() {
+    const ALEO_PRIVATE_KEY: &str = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p";
+    const ALEO_VIEW_KEY: &str = "AViewKey1n1n3ZbnVEtXVe3La2xWkUvY3EY7XaCG6RZJJ3tbvrrrD";
+    const ALEO_ADDRESS: &str = "aleo1wvgwnqvy46qq0zemj0k6sfp3zv0mp77rw97khvwuhac05yuwscxqmfyhwf";
+
+    let private_key = bindings::PrivateKey::from_string(ALEO_PRIVATE_KEY).unwrap();
+    assert_eq!(ALEO_PRIVATE_KEY, private_key.to_string());
+
+    let view_key = private_key.to_view_key().unwrap();
+    assert_eq!(ALEO_VIEW_KEY, view_key.to_string());
+    assert_eq!(view_key, bindings::ViewKey::from_string(ALEO_VIEW_KEY).unwrap());
+
+    let address = view_key.to_address();
+    assert_eq!(ALEO_ADDRESS, address.to_string());
+    assert_eq!(address, private_key.to_address().unwrap());
+    assert_eq!(address, bindings::Address::from_string(ALEO_ADDRESS).unwrap());
+
+    // Ensure invalid strings are rejected.
+    assert!(bindings::PrivateKey::from_string(ALEO_VIEW_KEY).is_err());
+    assert!(bindings::ViewKey::from_string(ALEO_ADDRESS).is_err());
+    assert!(bindings::Address::from_string(ALEO_PRIVATE_KEY).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_bindings_sign() {
+    // Sample a new private key and address.
+    let private_key = bindings::PrivateKey::new().unwrap();
+    let address = private_key.to_address().unwrap();
+
+    // Sign a message with the account private key.
+    let signature = private_key.sign("hello world!".as_bytes()).unwrap();
+    assert!(signature.verify(&address, "hello world!".as_bytes()));
+    assert!(address.verify("hello world!".as_bytes(), &signature));
+    assert!(!signature.verify(&address, "goodbye world!".as_bytes()));
+
+    // Ensure the signature survives a string round trip.
+    let candidate = bindings::Signature::from_string(&signature.to_string()).unwrap();
+    assert_eq!(signature, candidate);
+}
+
+#[wasm_bindgen_test]
+fn test_bindings_record() {
+    let mut rng = TestRng::default();
+
+    // Sample a new account.
+    let private_key = bindings::PrivateKey::new().unwrap();
+    let view_key = private_key.to_view_key().unwrap();
+    let address = view_key.to_address();
+
+    // Construct a record owned by the account.
+    let randomizer = Scalar::<Testnet3>::rand(&mut rng);
+    let nonce = Testnet3::g_scalar_multiply(&randomizer);
+    let plaintext = Record::<Testnet3, Plaintext<Testnet3>>::from_str(&format!(
+        "{{ owner: {}.private, microcredits: 1500u64.private, _nonce: {nonce}.public }}",
+        address.to_string()
+    ))
+    .unwrap();
+    let ciphertext =
+        bindings::RecordCiphertext::from_string(&plaintext.encrypt(randomizer).unwrap().to_string()).unwrap();
+
+    // Ensure the owner can decrypt the record.
+    assert!(ciphertext.is_owner(&view_key));
+    let candidate = ciphertext.decrypt(&view_key).unwrap();
+    assert_eq!(plaintext.to_string(), candidate.to_string());
+    assert_eq!(candidate, bindings::RecordPlaintext::from_string(&plaintext.to_string()).unwrap());
+
+    // Ensure another account can not decrypt the record.
+    let other_view_key = bindings::PrivateKey::new().unwrap().to_view_key().unwrap();
+    assert!(!ciphertext.is_owner(&other_view_key));
+    assert!(ciphertext.decrypt(&other_view_key).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_bindings_authorize() {
+    const PROGRAM: &str = r"
+program hello.aleo;
+
+function hello:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;";
+
+    // Sample a new private key.
+    let private_key = bindings::PrivateKey::new().unwrap();
+
+    // Authorize the function call.
+    let inputs = vec![JsValue::from_str("5u32"), JsValue::from_str("10u32")].into_boxed_slice();
+    let authorization = bindings::Authorization::new(&private_key, PROGRAM, "hello", inputs).unwrap();
+    assert_eq!(authorization.len(), 1);
+
+    // Ensure the authorization survives a string round trip.
+    let candidate = bindings::Authorization::from_string(&authorization.to_string()).unwrap();
+    assert_eq!(authorization, candidate);
+
+    // Ensure invalid inputs are rejected.
+    let inputs = vec![JsValue::from_str("5u32")].into_boxed_slice();
+    assert!(bindings::Authorization::new(&private_key, PROGRAM, "hello", inputs).is_err());
+}